@@ -0,0 +1,36 @@
+%builtins output pedersen range_check ecdsa bitwise ec_op keccak poseidon
+
+from starkware.cairo.bootloaders.simple_bootloader.run_simple_bootloader import (
+    run_simple_bootloader,
+)
+from starkware.cairo.common.cairo_builtins import HashBuiltin
+
+// The main function of cairo-lang's simple bootloader
+func main{
+    output_ptr: felt*,
+    pedersen_ptr: HashBuiltin*,
+    range_check_ptr,
+    ecdsa_ptr,
+    bitwise_ptr,
+    ec_op_ptr,
+    keccak_ptr,
+    poseidon_ptr,
+}() {
+    %{
+        from starkware.cairo.bootloaders.simple_bootloader.objects import SimpleBootloaderInput
+        simple_bootloader_input = SimpleBootloaderInput.Schema().load(program_input)
+    %}
+
+    // Execute tasks.
+    run_simple_bootloader{
+        output_ptr=output_ptr,
+        pedersen_ptr=pedersen_ptr,
+        range_check_ptr=range_check_ptr,
+        ecdsa_ptr=ecdsa_ptr,
+        bitwise_ptr=bitwise_ptr,
+        ec_op_ptr=ec_op_ptr,
+        keccak_ptr=keccak_ptr,
+        poseidon_ptr=poseidon_ptr,
+    }();
+    return ();
+}
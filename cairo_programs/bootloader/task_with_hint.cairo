@@ -0,0 +1,12 @@
+%builtins output
+
+from starkware.cairo.common.serialize import serialize_word
+
+// A task of the simple bootloader, with a hint of its own
+func main{output_ptr: felt*}() {
+    alloc_locals;
+    local x;
+    %{ ids.x = 7 %}
+    serialize_word(x);
+    return ();
+}
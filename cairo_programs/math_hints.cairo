@@ -0,0 +1,102 @@
+%builtins range_check
+
+from starkware.cairo.common.math import assert_le_felt, assert_nn_le
+from starkware.cairo.common.uint256 import Uint256
+from starkware.starknet.common.storage import normalize_address
+
+// Returns the square root of x if it is a quadratic residue, and the one of x / 3 otherwise.
+func quad_residue_root(x: felt) -> (y: felt) {
+    alloc_locals;
+    local y: felt;
+    %{
+        from starkware.crypto.signature.signature import FIELD_PRIME
+        from starkware.python.math_utils import div_mod, is_quad_residue, sqrt
+
+        x = ids.x
+        if is_quad_residue(x, FIELD_PRIME):
+            ids.y = sqrt(x, FIELD_PRIME)
+        else:
+            ids.y = sqrt(div_mod(x, 3, FIELD_PRIME), FIELD_PRIME)
+    %}
+    return (y=y);
+}
+
+func get_felt_bitlength(x: felt) -> (bit_length: felt) {
+    alloc_locals;
+    local bit_length: felt;
+    %{
+        x = ids.x
+        ids.bit_length = x.bit_length()
+    %}
+    return (bit_length=bit_length);
+}
+
+// Returns the even square root of xx modulo 2**255 - 19.
+func split_xx(xx: Uint256) -> (x: Uint256) {
+    alloc_locals;
+    local x: Uint256;
+    %{
+        PRIME = 2**255 - 19
+        II = pow(2, (PRIME - 1) // 4, PRIME)
+
+        xx = ids.xx.low + (ids.xx.high<<128)
+        x = pow(xx, (PRIME + 3) // 8, PRIME)
+        if (x * x - xx) % PRIME != 0:
+            x = (x * II) % PRIME
+        if x % 2 != 0:
+            x = PRIME - x
+        ids.x.low = x & ((1<<128)-1)
+        ids.x.high = x >> 128
+    %}
+    return (x=x);
+}
+
+// The assert_le_felt of cairo-lang v0.8, which checks small inputs with assert_nn_le.
+func assert_le_felt_v_0_8{range_check_ptr}(a: felt, b: felt) {
+    alloc_locals;
+    local small_inputs: felt;
+    %{
+        from starkware.cairo.common.math_utils import assert_integer
+        assert_integer(ids.a)
+        assert_integer(ids.b)
+        a = ids.a % PRIME
+        b = ids.b % PRIME
+        assert a <= b, f'a = {a} is not less than or equal to b = {b}.'
+
+        ids.small_inputs = int(
+            a < range_check_builtin.bound and (b - a) < range_check_builtin.bound)
+    %}
+    if (small_inputs != 0) {
+        assert_nn_le(a, b);
+        return ();
+    }
+    assert_le_felt(a, b);
+    return ();
+}
+
+func main{range_check_ptr: felt}() {
+    let (y) = quad_residue_root(4);
+    assert y = 2;
+    let (y) = quad_residue_root(3);
+    assert y = 1;
+
+    let (bit_length) = get_felt_bitlength(2 ** 250 + 1);
+    assert bit_length = 251;
+    let (bit_length) = get_felt_bitlength(0);
+    assert bit_length = 0;
+
+    let (x) = split_xx(Uint256(96041728533064653893136611586985700281, 447909743051131415));
+    assert x = Uint256(
+        340282366908592784562140039541644754648, 170141183460469231731687303715884105727
+    );
+
+    assert_le_felt_v_0_8(1, 2);
+    assert_le_felt_v_0_8(1, 2 ** 200);
+
+    let (res) = normalize_address(1);
+    assert res = 1;
+    let (res) = normalize_address(2 ** 251);
+    assert res = 256;
+
+    return ();
+}
@@ -0,0 +1,46 @@
+%builtins poseidon
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.builtin_poseidon.poseidon import (
+    poseidon_hash,
+    poseidon_hash_single,
+    poseidon_hash_many,
+)
+from starkware.cairo.common.cairo_builtins import PoseidonBuiltin
+
+// Writes value, value + 1, ..., value + n - 1 into array
+func fill_array(array: felt*, value: felt, n: felt) {
+    if (n == 0) {
+        return ();
+    }
+    assert array[0] = value;
+    return fill_array(array + 1, value + 1, n - 1);
+}
+
+func main{poseidon_ptr: PoseidonBuiltin*}() {
+    let (hash) = poseidon_hash(
+        432657406296795801522720714884497944153978199173083335289656073816867431721,
+        156336752379587748928249886010274100796903013888606063037095162691849789242,
+    );
+    assert hash = 335170156548504528758791410370028807050072714685327456669628172427173165451;
+
+    let (hash) = poseidon_hash_single(
+        278591510285887603159181793564350634833177938525624845163565026004707200982
+    );
+    assert hash = 484354348329425969185098251694037957896755481452651490986126884370226820181;
+
+    // Odd length, absorbed by chunks of 2
+    let (elements: felt*) = alloc();
+    assert elements[0] = 275553193911298658432997273666878041363444178246812643008696871314965068871;
+    assert elements[1] = 113411257837949962731516387807982602081163934831205381748096971248452002496;
+    assert elements[2] = 123958338326956447432561268205192637142644288135402780028800054817940537450;
+    let (hash) = poseidon_hash_many(3, elements);
+    assert hash = 869680094428371581891248672938089301918937027851598225485772221279358215415;
+
+    // Even length, absorbed by chunks of 10 and then of 2
+    let (elements: felt*) = alloc();
+    fill_array(elements, 1, 12);
+    let (hash) = poseidon_hash_many(12, elements);
+    assert hash = 1051038847703699268004077270017095006114312931474714482749266235462771311430;
+
+    return ();
+}
@@ -0,0 +1,50 @@
+%builtins range_check
+
+from starkware.cairo.common.cairo_secp.bigint import BigInt3, nondet_bigint3
+from starkware.cairo.common.cairo_secp.ec import EcPoint
+
+// Computes the slope of the secp256r1 curve at the given point, guessing it in a hint.
+func compute_doubling_slope{range_check_ptr}(point: EcPoint) -> (slope: BigInt3) {
+    %{ from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_ALPHA as ALPHA %}
+    %{ from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P %}
+    %{
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        from starkware.python.math_utils import ec_double_slope
+
+        # Compute the slope.
+        x = pack(ids.point.x, PRIME)
+        y = pack(ids.point.y, PRIME)
+        value = slope = ec_double_slope(point=(x, y), alpha=ALPHA, p=SECP_P)
+    %}
+    let (slope) = nondet_bigint3();
+    return (slope=slope);
+}
+
+// Reduces x modulo the secp256r1 field prime.
+func reduce{range_check_ptr}(x: BigInt3) -> (res: BigInt3) {
+    %{ from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P %}
+    %{
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        value = pack(ids.x, PRIME) % SECP_P
+    %}
+    let (res) = nondet_bigint3();
+    return (res=res);
+}
+
+func main{range_check_ptr: felt}() {
+    // The generator of secp256r1.
+    let g = EcPoint(
+        BigInt3(52227620040540588600771222, 33347259622618539004134583, 8091721874918813684698062),
+        BigInt3(59685082318776612195095029, 54599710628478995760242092, 6036146923926000695307902),
+    );
+    let (slope) = compute_doubling_slope(g);
+    assert slope = BigInt3(
+        18381657395733761514123165, 60250936249481871670557728, 12262137276504867214243183
+    );
+
+    // 2**256 + 1
+    let (res) = reduce(BigInt3(1, 0, 19342813113834066795298816));
+    assert res = BigInt3(2, 77371252455336267181194240, 4503599626321919);
+
+    return ();
+}
@@ -0,0 +1,37 @@
+%builtins range_check
+
+from starkware.cairo.common.uint256 import split_64
+
+// Splits a felt into its 128 low and high bits. Unlike split_felt, it doesn't check that the
+// value fits in the field.
+func split_128{range_check_ptr}(a: felt) -> (low: felt, high: felt) {
+    alloc_locals;
+    local low: felt;
+    local high: felt;
+
+    %{
+        ids.low = ids.a & ((1<<128) - 1)
+        ids.high = ids.a >> 128
+    %}
+    assert [range_check_ptr] = low;
+    assert [range_check_ptr + 1] = high;
+    let range_check_ptr = range_check_ptr + 2;
+    assert a = low + high * 2 ** 128;
+    return (low, high);
+}
+
+func main{range_check_ptr: felt}() {
+    let (low, high) = split_128(4083388403051261561560495289181218537477);
+    assert low = 5;
+    assert high = 12;
+
+    let (low, high) = split_128(850981239023189021389081239089023);
+    assert low = 850981239023189021389081239089023;
+    assert high = 0;
+
+    let (low, high) = split_64(850981239023189021389081239089023);
+    assert low = 7249717543555297151;
+    assert high = 46131785404667;
+
+    return ();
+}
@@ -0,0 +1,79 @@
+%builtins range_check
+
+from starkware.cairo.common.uint256 import (
+    Uint256,
+    split_64,
+    uint256_add,
+    uint256_check,
+    uint256_lt,
+    uint256_mul,
+)
+
+const HALF_SHIFT = 2 ** 64;
+
+// A Uint256 along with some of its 64 bit limb combinations.
+struct Uint256Expand {
+    B0: felt,
+    b01: felt,
+    b12: felt,
+    b23: felt,
+    b3: felt,
+}
+
+func uint256_expand{range_check_ptr}(a: Uint256) -> (b: Uint256Expand) {
+    let (a0, a1) = split_64(a.low);
+    let (a2, a3) = split_64(a.high);
+
+    return (b=Uint256Expand(a0 * HALF_SHIFT, a.low, a1 + a2 * HALF_SHIFT, a.high, a3));
+}
+
+// Unsigned integer division between two integers, with the divisor given expanded.
+func uint256_expanded_unsigned_div_rem{range_check_ptr}(a: Uint256, div: Uint256Expand) -> (
+    quotient: Uint256, remainder: Uint256
+) {
+    alloc_locals;
+    local quotient: Uint256;
+    local remainder: Uint256;
+
+    %{
+        a = (ids.a.high << 128) + ids.a.low
+        div = (ids.div.b23 << 128) + ids.div.b01
+        quotient, remainder = divmod(a, div)
+
+        ids.quotient.low = quotient & ((1 << 128) - 1)
+        ids.quotient.high = quotient >> 128
+        ids.remainder.low = remainder & ((1 << 128) - 1)
+        ids.remainder.high = remainder >> 128
+    %}
+    uint256_check(quotient);
+    uint256_check(remainder);
+
+    let div_uint256 = Uint256(div.b01, div.b23);
+    let (res_mul, carry) = uint256_mul(quotient, div_uint256);
+    assert carry = Uint256(0, 0);
+
+    let (check_val, add_carry) = uint256_add(res_mul, remainder);
+    assert check_val = a;
+    assert add_carry = 0;
+
+    let (is_valid) = uint256_lt(remainder, div_uint256);
+    assert is_valid = 1;
+    return (quotient=quotient, remainder=remainder);
+}
+
+func main{range_check_ptr: felt}() {
+    let (div) = uint256_expand(Uint256(3, 7));
+    let (quotient, remainder) = uint256_expanded_unsigned_div_rem(Uint256(89, 72), div);
+    assert quotient = Uint256(10, 0);
+    assert remainder = Uint256(59, 2);
+
+    let (div) = uint256_expand(Uint256(5, 2));
+    let (quotient, remainder) = uint256_expanded_unsigned_div_rem(
+        Uint256(-3618502788666131213697322783095070105282824848410658236509717448704103809099, 2),
+        div,
+    );
+    assert quotient = Uint256(1, 0);
+    assert remainder = Uint256(340282366920938463463374607431768211377, 0);
+
+    return ();
+}
@@ -0,0 +1,86 @@
+%builtins range_check
+
+from starkware.cairo.common.uint256 import (
+    Uint256,
+    uint256_add,
+    uint256_check,
+    uint256_lt,
+    uint256_mul,
+)
+
+// Computes (a * b) / div, returning the 512 bits of the quotient and the remainder.
+func uint256_mul_div_mod{range_check_ptr}(a: Uint256, b: Uint256, div: Uint256) -> (
+    quotient_low: Uint256, quotient_high: Uint256, remainder: Uint256
+) {
+    alloc_locals;
+
+    // Compute a * b (512 bits).
+    let (ab_low, ab_high) = uint256_mul(a, b);
+
+    // Guess the quotient and remainder of (a * b) / d.
+    local quotient_low: Uint256;
+    local quotient_high: Uint256;
+    local remainder: Uint256;
+
+    %{
+        a = (ids.a.high << 128) + ids.a.low
+        b = (ids.b.high << 128) + ids.b.low
+        div = (ids.div.high << 128) + ids.div.low
+        quotient, remainder = divmod(a * b, div)
+
+        ids.quotient_low.low = quotient & ((1 << 128) - 1)
+        ids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)
+        ids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)
+        ids.quotient_high.high = quotient >> 384
+        ids.remainder.low = remainder & ((1 << 128) - 1)
+        ids.remainder.high = remainder >> 128
+    %}
+
+    // Compute x = quotient * div + remainder.
+    uint256_check(quotient_high);
+    let (quotient_mod10, quotient_mod11) = uint256_mul(quotient_high, div);
+    uint256_check(quotient_low);
+    let (quotient_mod00, quotient_mod01) = uint256_mul(quotient_low, div);
+    // Since x should equal a * b, the high 256 bits must be zero.
+    assert quotient_mod11 = Uint256(0, 0);
+
+    // The low 256 bits of x must be ab_low.
+    uint256_check(remainder);
+    let (x0, carry0) = uint256_add(quotient_mod00, remainder);
+    assert x0 = ab_low;
+
+    let (x1, carry1) = uint256_add(quotient_mod01, quotient_mod10);
+    assert carry1 = 0;
+    let (x1, carry2) = uint256_add(x1, Uint256(low=carry0, high=0));
+    assert carry2 = 0;
+
+    assert x1 = ab_high;
+
+    // Verify that 0 <= remainder < div.
+    let (is_valid) = uint256_lt(remainder, div);
+    assert is_valid = 1;
+
+    return (quotient_low=quotient_low, quotient_high=quotient_high, remainder=remainder);
+}
+
+func main{range_check_ptr: felt}() {
+    let (quotient_low, quotient_high, remainder) = uint256_mul_div_mod(
+        Uint256(89, 72), Uint256(3, 7), Uint256(107, 114)
+    );
+    assert quotient_low = Uint256(143276786071974089879315624181797141668, 4);
+    assert quotient_high = Uint256(0, 0);
+    assert remainder = Uint256(322372768661941702228460154409043568767, 101);
+
+    let (quotient_low, quotient_high, remainder) = uint256_mul_div_mod(
+        Uint256(1, 340282366920938463463374607431768211455),
+        Uint256(1, 340282366920938463463374607431768211455),
+        Uint256(5, 0),
+    );
+    assert quotient_low = Uint256(0, 136112946768375385385349842972707284582);
+    assert quotient_high = Uint256(
+        272225893536750770770699685945414569165, 68056473384187692692674921486353642290
+    );
+    assert remainder = Uint256(1, 0);
+
+    return ();
+}
@@ -0,0 +1,51 @@
+%builtins range_check
+
+from starkware.cairo.common.math import assert_nn_le
+from starkware.cairo.common.uint256 import Uint256, uint256_le, uint256_lt, uint256_mul
+
+// Returns the floor value of the square root of a uint256 integer, as a felt.
+func uint256_sqrt{range_check_ptr}(n: Uint256) -> (res: felt) {
+    alloc_locals;
+    local root: felt;
+
+    %{
+        from starkware.python.math_utils import isqrt
+        n = (ids.n.high << 128) + ids.n.low
+        root = isqrt(n)
+        assert 0 <= root < 2 ** 128
+        ids.root = root
+    %}
+
+    // Verify that 0 <= root < 2**128.
+    assert_nn_le(root, 2 ** 128 - 1);
+
+    // Verify that root**2 <= n.
+    let (root_squared, root_squared_high) = uint256_mul(Uint256(root, 0), Uint256(root, 0));
+    let (check_lower_bound) = uint256_le(root_squared, n);
+    assert check_lower_bound = 1;
+
+    // Verify that n < (root + 1)**2, unless root + 1 == 2**128.
+    if (root == 2 ** 128 - 1) {
+        return (res=root);
+    }
+    let (next_root_squared, next_root_squared_high) = uint256_mul(Uint256(root + 1, 0), Uint256(root + 1, 0));
+    let (check_upper_bound) = uint256_lt(n, next_root_squared);
+    assert check_upper_bound = 1;
+
+    return (res=root);
+}
+
+func main{range_check_ptr: felt}() {
+    let (root) = uint256_sqrt(Uint256(17, 7));
+    assert root = 48805497317890012913;
+
+    let (root) = uint256_sqrt(Uint256(0, 0));
+    assert root = 0;
+
+    let (root) = uint256_sqrt(
+        Uint256(340282366920938463463374607431768211455, 340282366920938463463374607431768211455)
+    );
+    assert root = 340282366920938463463374607431768211455;
+
+    return ();
+}
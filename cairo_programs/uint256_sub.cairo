@@ -0,0 +1,47 @@
+%builtins range_check
+
+from starkware.cairo.common.uint256 import Uint256, uint256_add, uint256_check
+
+// Computes (a - b) % 2**256, guessing the result in a hint.
+func uint256_sub{range_check_ptr}(a: Uint256, b: Uint256) -> (res: Uint256) {
+    alloc_locals;
+    local res: Uint256;
+    %{
+        def split(num: int, num_bits_shift: int = 128, length: int = 2):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int = 128) -> int:
+            limbs = (z.low, z.high)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack(ids.a)
+        b = pack(ids.b)
+        res = (a - b)%2**256
+        res_split = split(res)
+        ids.res.low = res_split[0]
+        ids.res.high = res_split[1]
+    %}
+    uint256_check(res);
+    let (sum, carry) = uint256_add(res, b);
+    assert sum = a;
+    return (res=res);
+}
+
+func main{range_check_ptr: felt}() {
+    let (res) = uint256_sub(Uint256(4, 3), Uint256(1, 2));
+    assert res = Uint256(3, 1);
+
+    let (res) = uint256_sub(Uint256(1, 2), Uint256(4, 3));
+    assert res = Uint256(
+        340282366920938463463374607431768211453, 340282366920938463463374607431768211454
+    );
+
+    let (res) = uint256_sub(Uint256(5, 2), Uint256(5, 2));
+    assert res = Uint256(0, 0);
+
+    return ();
+}
@@ -0,0 +1,170 @@
+%builtins range_check
+
+const SHIFT = 2 ** 128;
+const HALF_SHIFT = 2 ** 127;
+
+// Represents an integer in the range [0, 2^384).
+struct Uint384 {
+    // The low 128 bits of the value.
+    d0: felt,
+    // The middle 128 bits of the value.
+    d1: felt,
+    // The high 128 bits of the value.
+    d2: felt,
+}
+
+// Splits a field element in the range [0, 2^224) to its low 128-bit and high 96-bit parts.
+func split_128{range_check_ptr}(a: felt) -> (low: felt, high: felt) {
+    alloc_locals;
+    local low: felt;
+    local high: felt;
+    %{
+        ids.low = ids.a & ((1<<128) - 1)
+        ids.high = ids.a >> 128
+    %}
+    assert [range_check_ptr] = low;
+    assert [range_check_ptr + 1] = high;
+    let range_check_ptr = range_check_ptr + 2;
+    assert a = low + high * SHIFT;
+    return (low=low, high=high);
+}
+
+// Adds two integers. Returns the result as a 384-bit integer and the (1-bit) carry.
+// Doesn't verify that the result is a proper Uint384.
+func add_no_uint384_check{range_check_ptr}(a: Uint384, b: Uint384) -> (
+    res: Uint384, carry: felt
+) {
+    alloc_locals;
+    local carry_d0: felt;
+    local carry_d1: felt;
+    local carry_d2: felt;
+    %{
+        sum_d0 = ids.a.d0 + ids.b.d0
+        ids.carry_d0 = 1 if sum_d0 >= ids.SHIFT else 0
+        sum_d1 = ids.a.d1 + ids.b.d1 + ids.carry_d0
+        ids.carry_d1 = 1 if sum_d1 >= ids.SHIFT else 0
+        sum_d2 = ids.a.d2 + ids.b.d2 + ids.carry_d1
+        ids.carry_d2 = 1 if sum_d2 >= ids.SHIFT else 0
+    %}
+    // Verify that the carries are bits.
+    assert carry_d0 * carry_d0 = carry_d0;
+    assert carry_d1 * carry_d1 = carry_d1;
+    assert carry_d2 * carry_d2 = carry_d2;
+
+    let d0 = a.d0 + b.d0 - carry_d0 * SHIFT;
+    let d1 = a.d1 + b.d1 + carry_d0 - carry_d1 * SHIFT;
+    let d2 = a.d2 + b.d2 + carry_d1 - carry_d2 * SHIFT;
+    return (res=Uint384(d0, d1, d2), carry=carry_d2);
+}
+
+// Divides an integer by another one. Returns the quotient and the remainder.
+func unsigned_div_rem{range_check_ptr}(a: Uint384, div: Uint384) -> (
+    quotient: Uint384, remainder: Uint384
+) {
+    alloc_locals;
+    local quotient: Uint384;
+    local remainder: Uint384;
+    %{
+        def split(num: int, num_bits_shift: int, length: int):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack(ids.a, num_bits_shift = 128)
+        div = pack(ids.div, num_bits_shift = 128)
+        quotient, remainder = divmod(a, div)
+
+        quotient_split = split(quotient, num_bits_shift=128, length=3)
+        assert len(quotient_split) == 3
+
+        ids.quotient.d0 = quotient_split[0]
+        ids.quotient.d1 = quotient_split[1]
+        ids.quotient.d2 = quotient_split[2]
+
+        remainder_split = split(remainder, num_bits_shift=128, length=3)
+        ids.remainder.d0 = remainder_split[0]
+        ids.remainder.d1 = remainder_split[1]
+        ids.remainder.d2 = remainder_split[2]
+    %}
+    return (quotient=quotient, remainder=remainder);
+}
+
+// Returns the floor of the square root of an integer.
+func sqrt{range_check_ptr}(a: Uint384) -> (res: Uint384) {
+    alloc_locals;
+    local root: Uint384;
+    %{
+        from starkware.python.math_utils import isqrt
+
+        def split(num: int, num_bits_shift: int, length: int):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack(ids.a, num_bits_shift=128)
+        root = isqrt(a)
+        assert 0 <= root < 2 ** 192
+        root_split = split(root, num_bits_shift=128, length=3)
+        ids.root.d0 = root_split[0]
+        ids.root.d1 = root_split[1]
+        ids.root.d2 = root_split[2]
+    %}
+    return (res=root);
+}
+
+// Returns 1 if the signed integer is nonnegative.
+func signed_nn{range_check_ptr}(a: Uint384) -> (res: felt) {
+    %{ memory[ap] = 1 if 0 <= (ids.a.d2 % PRIME) < 2 ** 127 else 0 %}
+    jmp non_negative if [ap] != 0, ap++;
+    assert [range_check_ptr] = a.d2 - HALF_SHIFT;
+    let range_check_ptr = range_check_ptr + 1;
+    return (res=0);
+
+    non_negative:
+    assert [range_check_ptr] = a.d2 + HALF_SHIFT;
+    let range_check_ptr = range_check_ptr + 1;
+    return (res=1);
+}
+
+func main{range_check_ptr: felt}() {
+    let (low, high) = split_128(2 ** 200 + 7);
+    assert low = 7;
+    assert high = 2 ** 72;
+
+    let (res, carry) = add_no_uint384_check(Uint384(340282366920938463463374607431768211455, 340282366920938463463374607431768211455, 3), Uint384(1, 0, 4));
+    assert res = Uint384(0, 0, 8);
+    assert carry = 0;
+
+    let (quotient, remainder) = unsigned_div_rem(
+        Uint384(83434123481193248, 82349321849739284, 839243219401320423),
+        Uint384(9283430921839492319493, 313248123482483248, 3790328402913840),
+    );
+    assert quotient = Uint384(221, 0, 0);
+    assert remainder = Uint384(
+        340282366920936411825224315027446796751,
+        340282366920938463394229121463989152931,
+        1580642357361782,
+    );
+
+    let (root) = sqrt(Uint384(1, 2, 1));
+    assert root = Uint384(1, 1, 0);
+
+    let (res) = signed_nn(Uint384(1, 1, HALF_SHIFT - 1));
+    assert res = 1;
+    let (res) = signed_nn(Uint384(1, 1, HALF_SHIFT));
+    assert res = 0;
+
+    return ();
+}
@@ -0,0 +1,79 @@
+%builtins range_check
+
+struct Uint384 {
+    d0: felt,
+    d1: felt,
+    d2: felt,
+}
+
+// Represents an integer in the range [0, 2^768).
+struct Uint768 {
+    d0: felt,
+    d1: felt,
+    d2: felt,
+    d3: felt,
+    d4: felt,
+    d5: felt,
+}
+
+// Divides a 768-bit integer by a 384-bit one. Returns the quotient and the remainder.
+func unsigned_div_rem_uint768_by_uint384{range_check_ptr}(a: Uint768, div: Uint384) -> (
+    quotient: Uint768, remainder: Uint384
+) {
+    alloc_locals;
+    local quotient: Uint768;
+    local remainder: Uint384;
+    %{
+        def split(num: int, num_bits_shift: int, length: int):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        def pack_extended(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2, z.d3, z.d4, z.d5)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack_extended(ids.a, num_bits_shift = 128)
+        div = pack(ids.div, num_bits_shift = 128)
+
+        quotient, remainder = divmod(a, div)
+
+        quotient_split = split(quotient, num_bits_shift=128, length=6)
+
+        ids.quotient.d0 = quotient_split[0]
+        ids.quotient.d1 = quotient_split[1]
+        ids.quotient.d2 = quotient_split[2]
+        ids.quotient.d3 = quotient_split[3]
+        ids.quotient.d4 = quotient_split[4]
+        ids.quotient.d5 = quotient_split[5]
+
+        remainder_split = split(remainder, num_bits_shift=128, length=3)
+        ids.remainder.d0 = remainder_split[0]
+        ids.remainder.d1 = remainder_split[1]
+        ids.remainder.d2 = remainder_split[2]
+    %}
+    return (quotient=quotient, remainder=remainder);
+}
+
+func main{range_check_ptr: felt}() {
+    // (2**640 + 5) // (2**256 + 3)
+    let (quotient, remainder) = unsigned_div_rem_uint768_by_uint384(
+        Uint768(5, 0, 0, 0, 0, 1), Uint384(3, 0, 1)
+    );
+    assert quotient = Uint768(0, 340282366920938463463374607431768211453, 340282366920938463463374607431768211455, 0, 0, 0);
+    assert remainder = Uint384(5, 9, 0);
+
+    let (quotient, remainder) = unsigned_div_rem_uint768_by_uint384(
+        Uint768(7, 0, 0, 0, 0, 0), Uint384(2, 0, 0)
+    );
+    assert quotient = Uint768(3, 0, 0, 0, 0, 0);
+    assert remainder = Uint384(1, 0, 0);
+
+    return ();
+}
@@ -0,0 +1,189 @@
+use crate::{
+    air_public_input::serialize_felt_hex, vm::errors::air_public_input_errors::PublicInputError,
+};
+use felt::Felt;
+use serde::Serialize;
+use std::collections::HashMap;
+
+// Witness data of a single builtin instance. Each builtin serializes its instances with its own
+// field names, as expected by the prover.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum PrivateInput {
+    Value(PrivateInputValue),
+    Pair(PrivateInputPair),
+    EcOp(PrivateInputEcOp),
+    KeccakState(PrivateInputKeccakState),
+    PoseidonState(PrivateInputPoseidonState),
+    Signature(PrivateInputSignature),
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrivateInputValue {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub value: Felt,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrivateInputPair {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub x: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub y: Felt,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrivateInputEcOp {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub p_x: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub p_y: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub m: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub q_x: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub q_y: Felt,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrivateInputKeccakState {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s0: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s1: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s2: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s3: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s4: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s5: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s6: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s7: Felt,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrivateInputPoseidonState {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s0: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s1: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s2: Felt,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrivateInputSignature {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub pubkey: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub msg: Felt,
+    pub signature_input: SignatureInput,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SignatureInput {
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub r: Felt,
+    // Inverse of the signature's s modulo the curve order
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub w: Felt,
+}
+
+/// Maps each builtin name to the private inputs of its instances.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AirPrivateInput(pub HashMap<&'static str, Vec<PrivateInput>>);
+
+// The private input file, as expected by the prover
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AirPrivateInputSerializable {
+    pub trace_path: String,
+    pub memory_path: String,
+    #[serde(flatten)]
+    pub builtins: HashMap<&'static str, Vec<PrivateInput>>,
+}
+
+impl AirPrivateInput {
+    pub fn to_serializable(
+        &self,
+        trace_path: String,
+        memory_path: String,
+    ) -> AirPrivateInputSerializable {
+        AirPrivateInputSerializable {
+            trace_path,
+            memory_path,
+            builtins: self.0.clone(),
+        }
+    }
+}
+
+impl AirPrivateInputSerializable {
+    pub fn serialize_json(&self) -> Result<String, PublicInputError> {
+        serde_json::to_string_pretty(&self).map_err(|e| PublicInputError::Serde(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_air_private_input() {
+        let air_private_input = AirPrivateInput(HashMap::from([
+            (
+                "range_check",
+                vec![PrivateInput::Value(PrivateInputValue {
+                    index: 0,
+                    value: Felt::new(10),
+                })],
+            ),
+            (
+                "pedersen",
+                vec![PrivateInput::Pair(PrivateInputPair {
+                    index: 1,
+                    x: Felt::new(1),
+                    y: Felt::new(255),
+                })],
+            ),
+            (
+                "ecdsa",
+                vec![PrivateInput::Signature(PrivateInputSignature {
+                    index: 0,
+                    pubkey: Felt::new(2),
+                    msg: Felt::new(3),
+                    signature_input: SignatureInput {
+                        r: Felt::new(4),
+                        w: Felt::new(5),
+                    },
+                })],
+            ),
+        ]));
+        let json: serde_json::Value = serde_json::from_str(
+            &air_private_input
+                .to_serializable("trace.bin".to_string(), "memory.bin".to_string())
+                .serialize_json()
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(json["trace_path"], "trace.bin");
+        assert_eq!(json["memory_path"], "memory.bin");
+        assert_eq!(json["range_check"][0]["index"], 0);
+        assert_eq!(json["range_check"][0]["value"], "0xa");
+        assert_eq!(json["pedersen"][0]["index"], 1);
+        assert_eq!(json["pedersen"][0]["x"], "0x1");
+        assert_eq!(json["pedersen"][0]["y"], "0xff");
+        assert_eq!(json["ecdsa"][0]["pubkey"], "0x2");
+        assert_eq!(json["ecdsa"][0]["signature_input"]["w"], "0x5");
+    }
+}
@@ -0,0 +1,217 @@
+use crate::vm::{
+    errors::air_public_input_errors::PublicInputError, trace::trace_entry::RelocatedTraceEntry,
+};
+use felt::Felt;
+use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PublicMemoryEntry {
+    pub address: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub value: Felt,
+    pub page: usize,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemorySegmentAddresses {
+    pub begin_addr: usize,
+    pub stop_ptr: usize,
+}
+
+impl From<(usize, usize)> for MemorySegmentAddresses {
+    fn from(addresses: (usize, usize)) -> Self {
+        let (begin_addr, stop_ptr) = addresses;
+        MemorySegmentAddresses {
+            begin_addr,
+            stop_ptr,
+        }
+    }
+}
+
+// Public input of the Cairo AIR, as expected by the prover (the same data cairo-lang writes
+// when running with `--air_public_input`).
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PublicInput {
+    pub layout: String,
+    pub rc_min: isize,
+    pub rc_max: isize,
+    pub n_steps: usize,
+    pub memory_segments: HashMap<String, MemorySegmentAddresses>,
+    pub public_memory: Vec<PublicMemoryEntry>,
+}
+
+impl PublicInput {
+    /// Builds the public input from the relocated memory and trace of a finished run.
+    /// `public_memory_addresses` are (relocated address, page id) pairs, and
+    /// `memory_segment_addresses` maps each builtin name to its relocated (base, stop_ptr).
+    pub fn new(
+        memory: &[Option<Felt>],
+        layout: &str,
+        public_memory_addresses: &[(usize, usize)],
+        memory_segment_addresses: HashMap<&str, (usize, usize)>,
+        trace: &[RelocatedTraceEntry],
+        rc_limits: (isize, isize),
+    ) -> Result<Self, PublicInputError> {
+        let memory_entry = |&(address, page): &(usize, usize)| -> Result<_, PublicInputError> {
+            let value = memory
+                .get(address)
+                .and_then(|value| value.as_ref())
+                .ok_or(PublicInputError::MemoryNotFound(address))?;
+            Ok(PublicMemoryEntry {
+                address,
+                value: value.clone(),
+                page,
+            })
+        };
+        let public_memory = public_memory_addresses
+            .iter()
+            .map(memory_entry)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let initial_entry = trace.first().ok_or(PublicInputError::EmptyTrace)?;
+        let final_entry = trace.last().ok_or(PublicInputError::EmptyTrace)?;
+
+        let mut memory_segments: HashMap<String, MemorySegmentAddresses> = memory_segment_addresses
+            .into_iter()
+            .map(|(name, addresses)| (name.to_string(), addresses.into()))
+            .collect();
+        memory_segments.insert(
+            "program".to_string(),
+            (initial_entry.pc, final_entry.pc).into(),
+        );
+        memory_segments.insert(
+            "execution".to_string(),
+            (initial_entry.ap, final_entry.ap).into(),
+        );
+
+        Ok(PublicInput {
+            layout: layout.to_string(),
+            rc_min: rc_limits.0,
+            rc_max: rc_limits.1,
+            n_steps: trace.len(),
+            memory_segments,
+            public_memory,
+        })
+    }
+
+    pub fn serialize_json(&self) -> Result<String, PublicInputError> {
+        serde_json::to_string_pretty(&self).map_err(|e| PublicInputError::Serde(e.to_string()))
+    }
+}
+
+pub(crate) fn serialize_felt_hex<S: Serializer>(
+    value: &Felt,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("0x{}", value.to_str_radix(16)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace() -> Vec<RelocatedTraceEntry> {
+        vec![
+            RelocatedTraceEntry {
+                pc: 1,
+                ap: 4,
+                fp: 4,
+            },
+            RelocatedTraceEntry {
+                pc: 3,
+                ap: 5,
+                fp: 4,
+            },
+        ]
+    }
+
+    #[test]
+    fn new_public_input() {
+        let memory = vec![None, Some(Felt::new(10)), Some(Felt::new(20)), None];
+        let public_input = PublicInput::new(
+            &memory,
+            "small",
+            &[(1, 0), (2, 0)],
+            HashMap::from([("output", (6, 8))]),
+            &trace(),
+            (10, 20),
+        )
+        .unwrap();
+
+        assert_eq!(public_input.layout, "small");
+        assert_eq!((public_input.rc_min, public_input.rc_max), (10, 20));
+        assert_eq!(public_input.n_steps, 2);
+        assert_eq!(
+            public_input.memory_segments,
+            HashMap::from([
+                ("program".to_string(), (1, 3).into()),
+                ("execution".to_string(), (4, 5).into()),
+                ("output".to_string(), (6, 8).into()),
+            ])
+        );
+        assert_eq!(
+            public_input.public_memory,
+            vec![
+                PublicMemoryEntry {
+                    address: 1,
+                    value: Felt::new(10),
+                    page: 0
+                },
+                PublicMemoryEntry {
+                    address: 2,
+                    value: Felt::new(20),
+                    page: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn new_public_input_missing_memory() {
+        let memory = vec![None, Some(Felt::new(10))];
+        assert!(matches!(
+            PublicInput::new(
+                &memory,
+                "small",
+                &[(1, 0), (3, 0)],
+                HashMap::new(),
+                &trace(),
+                (10, 20)
+            ),
+            Err(PublicInputError::MemoryNotFound(3))
+        ));
+    }
+
+    #[test]
+    fn new_public_input_empty_trace() {
+        assert!(matches!(
+            PublicInput::new(&[], "small", &[], HashMap::new(), &[], (10, 20)),
+            Err(PublicInputError::EmptyTrace)
+        ));
+    }
+
+    #[test]
+    fn serialize_public_input() {
+        let memory = vec![None, Some(Felt::new(255))];
+        let public_input = PublicInput::new(
+            &memory,
+            "plain",
+            &[(1, 0)],
+            HashMap::new(),
+            &trace(),
+            (0, 1),
+        )
+        .unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&public_input.serialize_json().unwrap()).unwrap();
+
+        assert_eq!(json["layout"], "plain");
+        assert_eq!(json["n_steps"], 2);
+        assert_eq!(json["memory_segments"]["program"]["begin_addr"], 1);
+        assert_eq!(json["memory_segments"]["execution"]["stop_ptr"], 5);
+        assert_eq!(json["public_memory"][0]["address"], 1);
+        assert_eq!(json["public_memory"][0]["value"], "0xff");
+        assert_eq!(json["public_memory"][0]["page"], 0);
+    }
+}
@@ -1,11 +1,14 @@
 use crate::{
     hint_processor::hint_processor_definition::HintProcessor,
-    types::program::Program,
+    types::{
+        layout::{CairoLayout, CairoLayoutParams},
+        program::Program,
+    },
     vm::{
         errors::{
             cairo_run_errors::CairoRunError, runner_errors::RunnerError, vm_exception::VmException,
         },
-        runners::cairo_runner::CairoRunner,
+        runners::{cairo_pie::CairoPie, cairo_runner::CairoRunner},
         security::verify_secure_runner,
         trace::trace_entry::RelocatedTraceEntry,
         vm_core::VirtualMachine,
@@ -25,6 +28,10 @@ pub struct CairoRunConfig<'a> {
     pub layout: &'a str,
     pub proof_mode: bool,
     pub secure_run: Option<bool>,
+    /// Parameters of the layout, only used (and required) by the "dynamic" layout
+    pub dynamic_layout_params: Option<CairoLayoutParams>,
+    /// Input of the program, available to hints as `program_input`
+    pub program_input: Option<serde_json::Value>,
 }
 
 impl<'a> Default for CairoRunConfig<'a> {
@@ -36,15 +43,36 @@ impl<'a> Default for CairoRunConfig<'a> {
             layout: "plain",
             proof_mode: false,
             secure_run: None,
+            dynamic_layout_params: None,
+            program_input: None,
         }
     }
 }
 
+fn new_cairo_runner(
+    program: &Program,
+    cairo_run_config: &CairoRunConfig,
+    proof_mode: bool,
+) -> Result<CairoRunner, RunnerError> {
+    match (
+        cairo_run_config.layout,
+        &cairo_run_config.dynamic_layout_params,
+    ) {
+        ("dynamic", Some(params)) => Ok(CairoRunner::new_with_layout(
+            program,
+            CairoLayout::dynamic_instance(params)?,
+            proof_mode,
+        )),
+        (layout, Some(_)) => Err(RunnerError::UnexpectedLayoutParams(layout.to_string())),
+        (layout, None) => CairoRunner::new(program, layout, proof_mode),
+    }
+}
+
 pub fn cairo_run(
     path: &Path,
     cairo_run_config: &CairoRunConfig,
     hint_executor: &mut dyn HintProcessor,
-) -> Result<CairoRunner, CairoRunError> {
+) -> Result<(CairoRunner, VirtualMachine), CairoRunError> {
     let program = match Program::from_file(path, Some(cairo_run_config.entrypoint)) {
         Ok(program) => program,
         Err(error) => return Err(CairoRunError::Program(error)),
@@ -54,11 +82,11 @@ pub fn cairo_run(
         .secure_run
         .unwrap_or(!cairo_run_config.proof_mode);
 
-    let mut cairo_runner = CairoRunner::new(
-        &program,
-        cairo_run_config.layout,
-        cairo_run_config.proof_mode,
-    )?;
+    let mut cairo_runner =
+        new_cairo_runner(&program, cairo_run_config, cairo_run_config.proof_mode)?;
+    if let Some(program_input) = &cairo_run_config.program_input {
+        cairo_runner.set_program_input(program_input.clone());
+    }
     let mut vm = VirtualMachine::new(cairo_run_config.trace_enabled);
     let end = cairo_runner.initialize(&mut vm)?;
 
@@ -81,7 +109,47 @@ pub fn cairo_run(
         write_output(&mut cairo_runner, &mut vm)?;
     }
 
-    Ok(cairo_runner)
+    Ok((cairo_runner, vm))
+}
+
+/// Re-executes a CairoPie, checking that the run reaches the final state it claims.
+/// The layout, trace and secure run options of the config are honored, proof mode is not
+/// supported.
+pub fn cairo_run_from_cairo_pie(
+    cairo_pie: &CairoPie,
+    cairo_run_config: &CairoRunConfig,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<(CairoRunner, VirtualMachine), CairoRunError> {
+    let program = Program::from(&cairo_pie.metadata.program);
+    let secure_run = cairo_run_config.secure_run.unwrap_or(true);
+
+    let mut cairo_runner = new_cairo_runner(&program, cairo_run_config, false)?;
+    if let Some(program_input) = &cairo_run_config.program_input {
+        cairo_runner.set_program_input(program_input.clone());
+    }
+    let mut vm = VirtualMachine::new(cairo_run_config.trace_enabled);
+    let end = cairo_runner.initialize_from_cairo_pie(&mut vm, cairo_pie)?;
+
+    cairo_runner
+        .run_until_pc(end, &mut vm, hint_executor)
+        .map_err(|err| VmException::from_vm_error(&cairo_runner, &vm, err))?;
+    cairo_runner.end_run(false, false, &mut vm, hint_executor)?;
+
+    vm.verify_auto_deductions()?;
+    cairo_runner.read_return_values(&mut vm)?;
+    if secure_run {
+        verify_secure_runner(&cairo_runner, true, &mut vm)?;
+    }
+    cairo_runner.relocate(&mut vm)?;
+    cairo_pie
+        .check_compatibility(&cairo_runner.get_cairo_pie(&vm)?)
+        .map_err(RunnerError::from)?;
+
+    if cairo_run_config.print_output {
+        write_output(&mut cairo_runner, &mut vm)?;
+    }
+
+    Ok((cairo_runner, vm))
 }
 
 pub fn write_output(
@@ -166,11 +234,17 @@ mod tests {
     use super::*;
     use crate::{
         hint_processor::{
-            builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::BuiltinHintProcessor,
+                program_input_utils::get_program_input,
+            },
             hint_processor_definition::HintProcessor,
         },
+        types::relocatable::MaybeRelocatable,
         utils::test_utils::*,
+        vm::errors::cairo_pie_errors::CairoPieError,
     };
+    use assert_matches::assert_matches;
     use std::io::Read;
 
     fn run_test_program(
@@ -327,4 +401,212 @@ mod tests {
             .is_ok());
         assert!(vm.trace.is_none());
     }
+
+    fn valid_program_b_cairo_pie() -> CairoPie {
+        let config = CairoRunConfig {
+            layout: "small",
+            ..Default::default()
+        };
+        let (cairo_runner, vm) = cairo_run(
+            Path::new("cairo_programs/manually_compiled/valid_program_b.json"),
+            &config,
+            &mut BuiltinHintProcessor::new_empty(),
+        )
+        .unwrap();
+        cairo_runner.get_cairo_pie(&vm).unwrap()
+    }
+
+    #[test]
+    fn cairo_run_dynamic_layout() {
+        let params = serde_json::from_str(
+            r#"{
+                "cpu_component_step": 1,
+                "rc_units": 16,
+                "memory_units_per_step": 8,
+                "public_memory_fraction": 4,
+                "uses_output_builtin": true,
+                "uses_range_check_builtin": true,
+                "range_check_ratio": 8
+            }"#,
+        )
+        .unwrap();
+        let config = CairoRunConfig {
+            layout: "dynamic",
+            dynamic_layout_params: Some(params),
+            ..Default::default()
+        };
+        assert!(cairo_run(
+            Path::new("cairo_programs/manually_compiled/valid_program_b.json"),
+            &config,
+            &mut BuiltinHintProcessor::new_empty(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn cairo_run_dynamic_layout_without_params() {
+        let config = CairoRunConfig {
+            layout: "dynamic",
+            ..Default::default()
+        };
+        assert!(matches!(
+            cairo_run(
+                Path::new("cairo_programs/manually_compiled/valid_program_b.json"),
+                &config,
+                &mut BuiltinHintProcessor::new_empty(),
+            ),
+            Err(CairoRunError::Runner(
+                RunnerError::MissingDynamicLayoutParams
+            ))
+        ));
+    }
+
+    #[test]
+    fn cairo_run_layout_params_without_dynamic_layout() {
+        let config = CairoRunConfig {
+            layout: "small",
+            dynamic_layout_params: serde_json::from_str(
+                r#"{ "cpu_component_step": 1, "rc_units": 16, "memory_units_per_step": 8, "public_memory_fraction": 4 }"#,
+            )
+            .unwrap(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            cairo_run(
+                Path::new("cairo_programs/manually_compiled/valid_program_b.json"),
+                &config,
+                &mut BuiltinHintProcessor::new_empty(),
+            ),
+            Err(CairoRunError::Runner(RunnerError::UnexpectedLayoutParams(layout))) if layout == "small"
+        ));
+    }
+
+    #[test]
+    fn cairo_run_with_program_input() {
+        let config = CairoRunConfig {
+            layout: "small",
+            program_input: Some(serde_json::json!({"n": 5})),
+            ..Default::default()
+        };
+        let (cairo_runner, _) = cairo_run(
+            Path::new("cairo_programs/manually_compiled/valid_program_b.json"),
+            &config,
+            &mut BuiltinHintProcessor::new_empty(),
+        )
+        .unwrap();
+        assert_eq!(
+            get_program_input(&cairo_runner.exec_scopes).unwrap(),
+            &serde_json::json!({"n": 5})
+        );
+    }
+
+    #[test]
+    fn cairo_run_from_cairo_pie_valid() {
+        let cairo_pie = valid_program_b_cairo_pie();
+        let config = CairoRunConfig {
+            layout: "small",
+            ..Default::default()
+        };
+        let (cairo_runner, vm) =
+            cairo_run_from_cairo_pie(&cairo_pie, &config, &mut BuiltinHintProcessor::new_empty())
+                .unwrap();
+        assert_eq!(cairo_runner.get_cairo_pie(&vm).unwrap(), cairo_pie);
+    }
+
+    #[test]
+    fn cairo_run_from_cairo_pie_tampered_memory() {
+        let mut cairo_pie = valid_program_b_cairo_pie();
+        // Change the value written to the output segment
+        let output_segment = cairo_pie.metadata.builtin_segments["output"].index as usize;
+        let cell = cairo_pie
+            .memory
+            .0
+            .iter_mut()
+            .find(|((segment_index, _), _)| *segment_index == output_segment)
+            .unwrap();
+        cell.1 = MaybeRelocatable::from(Felt::new(8));
+        let config = CairoRunConfig {
+            layout: "small",
+            ..Default::default()
+        };
+        assert!(cairo_run_from_cairo_pie(
+            &cairo_pie,
+            &config,
+            &mut BuiltinHintProcessor::new_empty()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn cairo_run_from_cairo_pie_missing_output() {
+        let mut cairo_pie = valid_program_b_cairo_pie();
+        let output_segment = cairo_pie.metadata.builtin_segments["output"].index as usize;
+        cairo_pie
+            .memory
+            .0
+            .retain(|((segment_index, _), _)| *segment_index != output_segment);
+        cairo_pie
+            .metadata
+            .builtin_segments
+            .get_mut("output")
+            .unwrap()
+            .size = 0;
+        let config = CairoRunConfig {
+            layout: "small",
+            ..Default::default()
+        };
+        let error =
+            cairo_run_from_cairo_pie(&cairo_pie, &config, &mut BuiltinHintProcessor::new_empty())
+                .err()
+                .unwrap();
+        assert_eq!(
+            error.to_string(),
+            CairoPieError::BuiltinStopPointerMismatch("output".to_string(), 1, 0).to_string()
+        );
+    }
+
+    #[test]
+    fn initialize_from_cairo_pie_non_empty_ret_pc_segment() {
+        let mut cairo_pie = valid_program_b_cairo_pie();
+        cairo_pie.metadata.ret_pc_segment.size = 1;
+        let program = Program::from(&cairo_pie.metadata.program);
+        let mut cairo_runner = cairo_runner!(program, "small");
+        let mut vm = vm!();
+        assert_matches!(
+            cairo_runner.initialize_from_cairo_pie(&mut vm, &cairo_pie),
+            Err(RunnerError::CairoPie(CairoPieError::SegmentSizeMismatch(name, 0, 1)))
+                if name == "ret_pc"
+        );
+    }
+
+    #[test]
+    fn initialize_from_cairo_pie_execution_segment_too_small() {
+        let mut cairo_pie = valid_program_b_cairo_pie();
+        let execution_segment = cairo_pie.metadata.execution_segment.index as usize;
+        cairo_pie
+            .memory
+            .0
+            .retain(|((segment_index, _), _)| *segment_index != execution_segment);
+        cairo_pie.metadata.execution_segment.size = 0;
+        let program = Program::from(&cairo_pie.metadata.program);
+        let mut cairo_runner = cairo_runner!(program, "small");
+        let mut vm = vm!();
+        // The initial stack is written to the execution segment
+        assert_matches!(
+            cairo_runner.initialize_from_cairo_pie(&mut vm, &cairo_pie),
+            Err(RunnerError::CairoPie(CairoPieError::SegmentSizeMismatch(name, 4, 0)))
+                if name == "execution"
+        );
+    }
+
+    #[test]
+    fn initialize_from_cairo_pie_wrong_layout() {
+        let cairo_pie = valid_program_b_cairo_pie();
+        let program = Program::from(&cairo_pie.metadata.program);
+        let mut cairo_runner = cairo_runner!(program, "plain");
+        let mut vm = vm!();
+        assert!(cairo_runner
+            .initialize_from_cairo_pie(&mut vm, &cairo_pie)
+            .is_err());
+    }
 }
@@ -0,0 +1,462 @@
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::{
+            bootloader::{
+                fact_topologies::{configure_fact_topologies, write_fact_topologies_file},
+                types::{BootloaderInput, FactTopology, PackedOutput},
+            },
+            hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+            program_input_utils::get_program_input,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{
+        errors::hint_errors::HintError, runners::builtin_runner::OutputBuiltinState,
+        runners::cairo_runner::CairoArg, vm_core::VirtualMachine,
+    },
+};
+use felt::Felt;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.bootloader.objects import BootloaderInput
+    bootloader_input = BootloaderInput.Schema().load(program_input)
+
+    ids.simple_bootloader_output_start = segments.add()
+
+    # Change output builtin state to a different segment in preparation for calling the
+    # simple bootloader.
+    output_builtin_state = output_builtin.get_state()
+    output_builtin.new_state(base=ids.simple_bootloader_output_start)
+%}
+*/
+pub fn prepare_simple_bootloader_output_segment(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let program_input = get_program_input(exec_scopes)?;
+    let bootloader_input = BootloaderInput::deserialize(program_input)
+        .map_err(|err| HintError::InvalidBootloaderInput(err.to_string()))?;
+    exec_scopes.insert_value("bootloader_input", bootloader_input);
+
+    let simple_bootloader_output_start = vm.add_memory_segment();
+    insert_value_from_var_name(
+        "simple_bootloader_output_start",
+        simple_bootloader_output_start,
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+
+    let output_builtin = vm.get_output_builtin()?;
+    exec_scopes.insert_value("output_builtin_state", output_builtin.get_state());
+    output_builtin.new_state(simple_bootloader_output_start.segment_index as usize);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{ simple_bootloader_input = bootloader_input %}
+*/
+pub fn prepare_simple_bootloader_input(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+    let simple_bootloader_input = exec_scopes
+        .get_ref::<BootloaderInput>("bootloader_input")?
+        .simple_bootloader_input
+        .clone();
+    exec_scopes.insert_value("simple_bootloader_input", simple_bootloader_input);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # Restore the bootloader's output builtin state.
+    output_builtin.set_state(output_builtin_state)
+%}
+*/
+pub fn restore_bootloader_output(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+) -> Result<(), HintError> {
+    let output_builtin_state: OutputBuiltinState = exec_scopes.get("output_builtin_state")?;
+    vm.get_output_builtin()?.set_state(output_builtin_state);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.bootloader.objects import BootloaderConfig
+    bootloader_config: BootloaderConfig = bootloader_input.bootloader_config
+
+    ids.bootloader_config = segments.gen_arg(
+        [
+            bootloader_config.simple_bootloader_program_hash,
+            len(bootloader_config.supported_cairo_verifier_program_hashes),
+            bootloader_config.supported_cairo_verifier_program_hashes,
+        ],
+    )
+%}
+*/
+pub fn load_bootloader_config(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let bootloader_config = &exec_scopes
+        .get_ref::<BootloaderInput>("bootloader_input")?
+        .bootloader_config;
+    let verifier_hashes = &bootloader_config.supported_cairo_verifier_program_hashes;
+    let bootloader_config = vm.segments.gen_cairo_arg(&CairoArg::Composed(vec![
+        CairoArg::Single(MaybeRelocatable::from(
+            &bootloader_config.simple_bootloader_program_hash,
+        )),
+        CairoArg::Single(Felt::from(verifier_hashes.len()).into()),
+        CairoArg::Array(verifier_hashes.iter().map(MaybeRelocatable::from).collect()),
+    ]))?;
+    insert_value_from_var_name(
+        "bootloader_config",
+        bootloader_config,
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    Ok(())
+}
+
+/*
+Implements hint:
+%{ output_start = ids.output_ptr %}
+*/
+pub fn save_output_pointer(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+    exec_scopes.insert_value("output_start", output_ptr);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{ packed_outputs = bootloader_input.packed_outputs %}
+*/
+pub fn save_packed_outputs(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+    let packed_outputs = exec_scopes
+        .get_ref::<BootloaderInput>("bootloader_input")?
+        .packed_outputs
+        .clone();
+    exec_scopes.insert_value("packed_outputs", packed_outputs);
+    Ok(())
+}
+
+/// Returns the fact topologies of the plain outputs packed in the bootloader output, given the
+/// fact topologies of the tasks.
+pub fn compute_fact_topologies(
+    packed_outputs: &[PackedOutput],
+    fact_topologies: &[FactTopology],
+) -> Result<Vec<FactTopology>, HintError> {
+    if packed_outputs.len() != fact_topologies.len() {
+        return Err(HintError::InvalidFactTopology(format!(
+            "got {} packed outputs for {} tasks",
+            packed_outputs.len(),
+            fact_topologies.len()
+        )));
+    }
+    packed_outputs
+        .iter()
+        .zip(fact_topologies)
+        .map(|(packed_output, fact_topology)| match packed_output {
+            PackedOutput::Plain => Ok(fact_topology.clone()),
+            PackedOutput::Composite { .. } => Err(HintError::CompositePackedOutput),
+        })
+        .collect()
+}
+
+/*
+Implements hint:
+%{
+    from typing import List
+
+    from starkware.cairo.bootloaders.bootloader.utils import compute_fact_topologies
+    from starkware.cairo.bootloaders.fact_topology import FactTopology
+    from starkware.cairo.bootloaders.simple_bootloader.utils import (
+        configure_fact_topologies,
+        write_to_fact_topologies_file,
+    )
+
+    # Compute the fact topologies of the plain packed outputs based on packed_outputs and
+    # fact_topologies of the inner tasks.
+    plain_fact_topologies: List[FactTopology] = compute_fact_topologies(
+        packed_outputs=packed_outputs, fact_topologies=fact_topologies,
+    )
+
+    # Configure the memory pages in the output builtin, based on plain_fact_topologies.
+    configure_fact_topologies(
+        fact_topologies=plain_fact_topologies, output_start=output_start,
+        output_builtin=output_builtin,
+    )
+
+    # Dump fact topologies to a json file.
+    if bootloader_input.fact_topologies_path is not None:
+        write_to_fact_topologies_file(
+            fact_topologies_path=bootloader_input.fact_topologies_path,
+            fact_topologies=plain_fact_topologies,
+        )
+%}
+*/
+pub fn bootloader_compute_fact_topologies(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+) -> Result<(), HintError> {
+    let plain_fact_topologies = compute_fact_topologies(
+        exec_scopes.get_ref::<Vec<PackedOutput>>("packed_outputs")?,
+        exec_scopes.get_ref::<Vec<FactTopology>>("fact_topologies")?,
+    )?;
+    configure_fact_topologies(
+        &plain_fact_topologies,
+        exec_scopes.get("output_start")?,
+        vm.get_output_builtin()?,
+    )?;
+    if let Some(path) = &exec_scopes
+        .get_ref::<BootloaderInput>("bootloader_input")?
+        .simple_bootloader_input
+        .fact_topologies_path
+    {
+        write_fact_topologies_file(path, &plain_fact_topologies)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                bootloader::types::{BootloaderConfig, SimpleBootloaderInput},
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::relocatable::Relocatable,
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            runners::{
+                builtin_runner::{OutputBuiltinRunner, OUTPUT_BUILTIN_NAME},
+                cairo_pie::PublicMemoryPage,
+            },
+            vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+        },
+    };
+    use assert_matches::assert_matches;
+    use std::any::Any;
+
+    fn bootloader_input(packed_outputs: Vec<PackedOutput>) -> BootloaderInput {
+        BootloaderInput {
+            simple_bootloader_input: SimpleBootloaderInput {
+                tasks: vec![],
+                fact_topologies_path: None,
+                single_page: false,
+            },
+            bootloader_config: BootloaderConfig {
+                simple_bootloader_program_hash: Felt::new(10),
+                supported_cairo_verifier_program_hashes: vec![Felt::new(11), Felt::new(12)],
+            },
+            packed_outputs,
+        }
+    }
+
+    fn vm_with_output_builtin() -> VirtualMachine {
+        let mut vm = vm!();
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments);
+        vm.builtin_runners = vec![(OUTPUT_BUILTIN_NAME, output_builtin.into())];
+        vm
+    }
+
+    #[test]
+    fn prepare_output_segment_and_restore_output() {
+        let mut vm = vm_with_output_builtin();
+        vm.segments.add();
+        vm.run_context.fp = 1;
+        vm.get_output_builtin()
+            .unwrap()
+            .add_page(1, (0, 0).into(), 2)
+            .unwrap();
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value(
+            "program_input",
+            serde_json::json!({
+                "tasks": [],
+                "bootloader_config": {
+                    "simple_bootloader_program_hash": "0xa",
+                    "supported_cairo_verifier_program_hashes": ["0xb", "0xc"]
+                },
+                "packed_outputs": []
+            }),
+        );
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["simple_bootloader_output_start"],
+                hint_code::BOOTLOADER_PREPARE_SIMPLE_BOOTLOADER_OUTPUT_SEGMENT,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 0), (2, 0))];
+        assert_eq!(
+            exec_scopes
+                .get::<BootloaderInput>("bootloader_input")
+                .unwrap(),
+            bootloader_input(vec![])
+        );
+        let state = vm.get_output_builtin().unwrap().get_state();
+        assert_eq!(state.base, 2);
+        assert!(state.pages.is_empty());
+
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::BOOTLOADER_RESTORE_BOOTLOADER_OUTPUT,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        let state = vm.get_output_builtin().unwrap().get_state();
+        assert_eq!(state.base, 0);
+        assert_eq!(
+            state.pages,
+            HashMap::from([(1, PublicMemoryPage { start: 0, size: 2 })])
+        );
+    }
+
+    #[test]
+    fn prepare_simple_bootloader_input_from_bootloader_input() {
+        let mut vm = vm!();
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("bootloader_input", bootloader_input(vec![]));
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::BOOTLOADER_PREPARE_SIMPLE_BOOTLOADER_INPUT,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            exec_scopes
+                .get::<SimpleBootloaderInput>("simple_bootloader_input")
+                .unwrap(),
+            bootloader_input(vec![]).simple_bootloader_input
+        );
+    }
+
+    #[test]
+    fn load_bootloader_config_valid() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 1), 0)];
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("bootloader_input", bootloader_input(vec![]));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["bootloader_config"],
+                hint_code::BOOTLOADER_LOAD_BOOTLOADER_CONFIG,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![
+            vm.segments.memory,
+            ((1, 0), (3, 0)),
+            ((2, 0), 11),
+            ((2, 1), 12),
+            ((3, 0), 10),
+            ((3, 1), 2),
+            ((3, 2), (2, 0))
+        ];
+    }
+
+    #[test]
+    fn compute_fact_topologies_of_plain_outputs() {
+        let mut vm = vm_with_output_builtin();
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value(
+            "bootloader_input",
+            bootloader_input(vec![PackedOutput::Plain, PackedOutput::Plain]),
+        );
+        exec_scopes.insert_value("output_start", Relocatable::from((0, 2)));
+        exec_scopes.insert_value(
+            "fact_topologies",
+            vec![
+                FactTopology {
+                    tree_structure: vec![1, 0],
+                    page_sizes: vec![2],
+                },
+                FactTopology {
+                    tree_structure: vec![1, 0],
+                    page_sizes: vec![1],
+                },
+            ],
+        );
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::BOOTLOADER_SAVE_PACKED_OUTPUTS,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::BOOTLOADER_COMPUTE_FACT_TOPOLOGIES,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            vm.get_output_builtin().unwrap().get_state().pages,
+            HashMap::from([
+                (1, PublicMemoryPage { start: 4, size: 2 }),
+                (2, PublicMemoryPage { start: 8, size: 1 }),
+            ])
+        );
+    }
+
+    #[test]
+    fn compute_fact_topologies_composite_output() {
+        let packed_outputs = [PackedOutput::Composite {
+            outputs: vec![],
+            subtasks: vec![],
+        }];
+        let fact_topologies = [FactTopology {
+            tree_structure: vec![1, 0],
+            page_sizes: vec![0],
+        }];
+        assert_matches!(
+            compute_fact_topologies(&packed_outputs, &fact_topologies),
+            Err(HintError::CompositePackedOutput)
+        );
+    }
+}
@@ -0,0 +1,867 @@
+use crate::{
+    any_box,
+    hint_processor::{
+        builtin_hint_processor::{
+            bootloader::{
+                fact_topologies::get_task_fact_topology,
+                types::{FactTopology, Task},
+            },
+            hint_utils::{
+                get_ptr_from_var_name, get_relocatable_from_var_name, insert_value_from_var_name,
+            },
+            program_input_utils::PROGRAM_INPUT,
+        },
+        hint_processor_definition::HintReference,
+    },
+    program_hash::compute_program_hash_chain,
+    serde::deserialize_program::ApTracking,
+    types::{
+        exec_scope::ExecutionScopes,
+        program::Program,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        runners::{
+            builtin_runner::{
+                OutputBuiltinState, BITWISE_BUILTIN_NAME, EC_OP_BUILTIN_NAME, HASH_BUILTIN_NAME,
+                KECCAK_BUILTIN_NAME, OUTPUT_BUILTIN_NAME, POSEIDON_BUILTIN_NAME,
+                RANGE_CHECK_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME,
+            },
+            cairo_pie::{BuiltinAdditionalData, CairoPie},
+        },
+        vm_core::VirtualMachine,
+    },
+};
+use felt::Felt;
+use std::{any::Any, collections::HashMap};
+
+/// The builtins the bootloader passes to its tasks, in the order of its `BuiltinData` struct.
+pub const ALL_BUILTINS: [&str; 8] = [
+    OUTPUT_BUILTIN_NAME,
+    HASH_BUILTIN_NAME,
+    RANGE_CHECK_BUILTIN_NAME,
+    SIGNATURE_BUILTIN_NAME,
+    BITWISE_BUILTIN_NAME,
+    EC_OP_BUILTIN_NAME,
+    KECCAK_BUILTIN_NAME,
+    POSEIDON_BUILTIN_NAME,
+];
+
+// Offset of the builtin list in the ProgramHeader struct, after data_length,
+// bootloader_version, program_main and n_builtins
+const PROGRAM_HEADER_BUILTINS_OFFSET: usize = 4;
+
+/*
+Implements hint:
+%{ ids.program_data_ptr = program_data_base = segments.add() %}
+*/
+pub fn allocate_program_data_segment(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let program_data_base = vm.add_memory_segment();
+    insert_value_from_var_name(
+        "program_data_ptr",
+        program_data_base,
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    exec_scopes.insert_value("program_data_base", program_data_base);
+    Ok(())
+}
+
+/// Writes the header of `program` at `program_header`, followed by its bytecode. Returns the
+/// address of the bytecode and the size of the header and bytecode together.
+pub fn load_program(
+    vm: &mut VirtualMachine,
+    program: &Program,
+    program_header: Relocatable,
+) -> Result<(Relocatable, usize), HintError> {
+    let main = program.main.ok_or_else(|| {
+        HintError::InvalidBootloaderInput("the task program has no main entrypoint".to_string())
+    })?;
+    if !program.custom_builtins.is_empty() {
+        return Err(HintError::InvalidBootloaderInput(
+            "the bootloader can't run task programs that use custom builtins".to_string(),
+        ));
+    }
+    let header_size = PROGRAM_HEADER_BUILTINS_OFFSET + program.builtins.len();
+    // The data length doesn't count itself
+    vm.insert_value(
+        program_header,
+        Felt::from(header_size - 1 + program.data.len()),
+    )?;
+    vm.insert_value(program_header + 2_usize, Felt::from(main))?;
+    vm.insert_value(program_header + 3_usize, Felt::from(program.builtins.len()))?;
+    let builtins = program
+        .builtins
+        .iter()
+        .map(|builtin| MaybeRelocatable::from(Felt::from_bytes_be(builtin.as_bytes())))
+        .collect();
+    vm.load_data(
+        &(program_header + PROGRAM_HEADER_BUILTINS_OFFSET).into(),
+        &builtins,
+    )?;
+
+    let program_address = program_header + header_size;
+    vm.load_data(&program_address.into(), &program.data)?;
+    Ok((program_address, header_size + program.data.len()))
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.utils import load_program
+
+    # Call load_program to load the program header and code to memory.
+    program_address, program_data_size = load_program(
+        task=task, memory=memory, program_header=ids.program_header,
+        builtins_offset=ids.ProgramHeader.builtin_list)
+    segments.finalize(program_data_base.segment_index, program_data_size)
+%}
+*/
+pub fn load_program_hint(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let program_data_base: Relocatable = exec_scopes.get("program_data_base")?;
+    let program = exec_scopes.get_ref::<Task>("task")?.get_program();
+    let program_header = get_ptr_from_var_name("program_header", vm, ids_data, ap_tracking)?;
+
+    let (program_address, program_data_size) = load_program(vm, &program, program_header)?;
+    vm.segments
+        .segment_sizes
+        .insert(program_data_base.segment_index as usize, program_data_size);
+    exec_scopes.insert_value("program_address", program_address);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # Validate hash.
+    from starkware.cairo.bootloaders.hash_program import compute_program_hash_chain
+
+    assert memory[ids.output_ptr + 1] == compute_program_hash_chain(task.get_program()), \
+      'Computed hash does not match input.'
+%}
+*/
+pub fn validate_hash(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let program = exec_scopes.get_ref::<Task>("task")?.get_program();
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+
+    let program_hash = vm.get_integer(output_ptr + 1_usize)?;
+    let computed_hash = compute_program_hash_chain(&program, 0)?;
+    if program_hash.as_ref() != &computed_hash {
+        return Err(HintError::ProgramHashMismatch(
+            computed_hash,
+            program_hash.into_owned(),
+        ));
+    }
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # Sanity check.
+    assert ids.program_address == program_address
+%}
+*/
+pub fn assert_program_address(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let program_address: Relocatable = exec_scopes.get("program_address")?;
+    let ids_program_address = get_ptr_from_var_name("program_address", vm, ids_data, ap_tracking)?;
+    if ids_program_address != program_address {
+        return Err(HintError::ProgramAddressMismatch(
+            program_address,
+            ids_program_address,
+        ));
+    }
+    Ok(())
+}
+
+/// Copies the memory of a CairoPie into the VM, relocating its segments: the program goes to
+/// `program_address`, the execution to `execution_segment_address`, and the return fp and pc to
+/// `ret_fp` and `ret_pc`. The builtin segments are the ones already at the start of the
+/// execution segment, and every other segment of the CairoPie gets a new segment.
+pub fn load_cairo_pie(
+    vm: &mut VirtualMachine,
+    cairo_pie: &CairoPie,
+    program_address: Relocatable,
+    execution_segment_address: Relocatable,
+    ret_fp: Relocatable,
+    ret_pc: Relocatable,
+) -> Result<(), HintError> {
+    let metadata = &cairo_pie.metadata;
+    let mut segment_offsets = HashMap::from([
+        (metadata.program_segment.index, program_address),
+        (metadata.execution_segment.index, execution_segment_address),
+        (metadata.ret_fp_segment.index, ret_fp),
+        (metadata.ret_pc_segment.index, ret_pc),
+    ]);
+
+    let pie_memory: HashMap<(usize, usize), &MaybeRelocatable> = cairo_pie
+        .memory
+        .0
+        .iter()
+        .map(|(addr, value)| (*addr, value))
+        .collect();
+    for (index, builtin) in metadata.program.builtins.iter().enumerate() {
+        let builtin_start =
+            match pie_memory.get(&(metadata.execution_segment.index as usize, index)) {
+                Some(MaybeRelocatable::RelocatableValue(start)) if start.offset == 0 => start,
+                _ => {
+                    return Err(HintError::InvalidBootloaderInput(format!(
+                    "the CairoPie doesn't start its execution with the {builtin} builtin address"
+                )))
+                }
+            };
+        segment_offsets.insert(
+            builtin_start.segment_index,
+            vm.get_relocatable(execution_segment_address + index)?,
+        );
+    }
+    for segment in metadata.extra_segments.iter() {
+        segment_offsets.insert(segment.index, vm.add_memory_segment());
+    }
+
+    let relocate_address = |addr: Relocatable| -> Result<Relocatable, HintError> {
+        segment_offsets
+            .get(&addr.segment_index)
+            .map(|base| *base + addr.offset)
+            .ok_or_else(|| {
+                HintError::InvalidBootloaderInput(format!(
+                    "the CairoPie uses segment {}, which isn't described in its metadata",
+                    addr.segment_index
+                ))
+            })
+    };
+
+    // Signatures are added before memory is loaded, as the builtin checks the signed messages
+    // when they are written
+    if let Some(BuiltinAdditionalData::Signature(signatures)) =
+        cairo_pie.additional_data.get("ecdsa_builtin")
+    {
+        let signatures = signatures
+            .iter()
+            .map(|(addr, signature)| Ok((relocate_address(*addr)?, signature.clone())))
+            .collect::<Result<_, HintError>>()?;
+        vm.get_signature_builtin()?
+            .extend_additional_data(&BuiltinAdditionalData::Signature(signatures))
+            .map_err(VirtualMachineError::RunnerError)?;
+    }
+
+    for ((segment_index, offset), value) in cairo_pie.memory.0.iter() {
+        let addr = relocate_address(Relocatable::from((*segment_index as isize, *offset)))?;
+        let value = match value {
+            MaybeRelocatable::RelocatableValue(value) => relocate_address(*value)?.into(),
+            value => value.clone(),
+        };
+        vm.insert_value(addr, value)?;
+    }
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.objects import (
+        CairoPieTask,
+        RunProgramTask,
+        Task,
+    )
+    from starkware.cairo.bootloaders.simple_bootloader.utils import (
+        load_cairo_pie,
+        prepare_output_runner,
+    )
+
+    assert isinstance(task, Task)
+    n_builtins = len(task.get_program().builtins)
+    new_task_locals = {}
+    if isinstance(task, RunProgramTask):
+        new_task_locals['program_input'] = task.program_input
+        new_task_locals['WITH_BOOTLOADER'] = True
+
+        vm_load_program(task.program, program_address)
+    elif isinstance(task, CairoPieTask):
+        ret_pc = ids.ret_pc_label.instruction_offset_ - ids.call_task.instruction_offset_ + pc
+        load_cairo_pie(
+            task=task.cairo_pie, memory=memory, segments=segments,
+            program_address=program_address, execution_segment_address= ap - n_builtins,
+            builtin_runners=builtin_runners, ret_fp=fp, ret_pc=ret_pc)
+    else:
+        raise NotImplementedError(f'Unexpected task type: {type(task).__name__}.')
+
+    output_runner_data = prepare_output_runner(
+        task=task,
+        output_builtin=output_builtin,
+        output_ptr=ids.pre_execution_builtin_ptrs.output)
+    vm_enter_scope(new_task_locals)
+%}
+*/
+pub fn call_task(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let task: Task = exec_scopes.get("task")?;
+    let n_builtins = task.builtins().len();
+    exec_scopes.insert_value("n_builtins", n_builtins);
+
+    let mut new_task_locals: HashMap<String, Box<dyn Any>> = HashMap::new();
+    let output_runner_data = match &task {
+        Task::RunProgram {
+            program,
+            program_input,
+        } => {
+            let program_address: Relocatable = exec_scopes.get("program_address")?;
+            vm.load_program_hints(program.clone(), program_address);
+            new_task_locals.insert(PROGRAM_INPUT.to_string(), any_box!(program_input.clone()));
+            new_task_locals.insert("WITH_BOOTLOADER".to_string(), any_box!(true));
+
+            // Gives the task an output builtin state of its own
+            let pre_execution_builtin_ptrs =
+                get_ptr_from_var_name("pre_execution_builtin_ptrs", vm, ids_data, ap_tracking)?;
+            let output_ptr = vm.get_relocatable(pre_execution_builtin_ptrs)?;
+            let output_builtin = vm.get_output_builtin()?;
+            let output_builtin_state = output_builtin.get_state();
+            output_builtin.new_state(output_ptr.segment_index as usize);
+            Some(output_builtin_state)
+        }
+        Task::CairoPie(cairo_pie) => {
+            let program_address: Relocatable = exec_scopes.get("program_address")?;
+            // The task returns to ret_pc_label, right after the call instruction that follows
+            // this hint
+            let ret_pc = vm.get_pc() + vm.decode_current_instruction()?.size();
+            let execution_segment_address = vm.get_ap().sub_usize(n_builtins)?;
+            load_cairo_pie(
+                vm,
+                cairo_pie,
+                program_address,
+                execution_segment_address,
+                vm.get_fp(),
+                ret_pc,
+            )?;
+            None
+        }
+    };
+    exec_scopes.insert_value("output_runner_data", output_runner_data);
+    exec_scopes.enter_scope(new_task_locals);
+    Ok(())
+}
+
+/// Writes the builtin pointers after running a task at `return_builtins_addr`, in the order of
+/// `ALL_BUILTINS`. The pointers of the builtins used by the task are the ones it returned at
+/// `used_builtins_addr`, and the others are the same as before the task.
+pub fn write_return_builtins(
+    vm: &mut VirtualMachine,
+    return_builtins_addr: Relocatable,
+    used_builtins: &[&str],
+    used_builtins_addr: Relocatable,
+    pre_execution_builtins_addr: Relocatable,
+    task: &Task,
+) -> Result<(), HintError> {
+    let mut used_builtin_offset = 0;
+    for (index, builtin) in ALL_BUILTINS.iter().enumerate() {
+        let pre_execution_ptr = vm.get_relocatable(pre_execution_builtins_addr + index)?;
+        let return_ptr = if used_builtins.contains(builtin) {
+            let return_ptr = vm.get_relocatable(used_builtins_addr + used_builtin_offset)?;
+            used_builtin_offset += 1;
+
+            if let Task::CairoPie(cairo_pie) = task {
+                let builtin_size = cairo_pie
+                    .metadata
+                    .builtin_segments
+                    .get(*builtin)
+                    .map(|segment| segment.size);
+                if builtin_size != return_ptr.sub(&pre_execution_ptr).ok() {
+                    return Err(HintError::BuiltinUsageMismatch(builtin.to_string()));
+                }
+            }
+            return_ptr
+        } else {
+            pre_execution_ptr
+        };
+        vm.insert_value(return_builtins_addr + index, return_ptr)?;
+    }
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.utils import write_return_builtins
+
+    # Fill the values of all builtin pointers after executing the task.
+    builtins = task.get_program().builtins
+    write_return_builtins(
+        memory=memory, return_builtins_addr=ids.return_builtin_ptrs.address_,
+        used_builtins=builtins, used_builtins_addr=ids.used_builtins_addr,
+        pre_execution_builtins_addr=ids.pre_execution_builtin_ptrs.address_, task=task)
+
+    vm_enter_scope({'n_selected_builtins': n_builtins})
+%}
+*/
+pub fn write_return_builtins_hint(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let task = exec_scopes.get_ref::<Task>("task")?;
+    let return_builtins_addr =
+        get_relocatable_from_var_name("return_builtin_ptrs", vm, ids_data, ap_tracking)?;
+    let used_builtins_addr =
+        get_ptr_from_var_name("used_builtins_addr", vm, ids_data, ap_tracking)?;
+    let pre_execution_builtins_addr =
+        get_ptr_from_var_name("pre_execution_builtin_ptrs", vm, ids_data, ap_tracking)?;
+    write_return_builtins(
+        vm,
+        return_builtins_addr,
+        task.builtins(),
+        used_builtins_addr,
+        pre_execution_builtins_addr,
+        task,
+    )?;
+
+    let n_builtins: usize = exec_scopes.get("n_builtins")?;
+    exec_scopes.enter_scope(HashMap::from([(
+        "n_selected_builtins".to_string(),
+        any_box!(n_builtins),
+    )]));
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.utils import get_task_fact_topology
+
+    # Add the fact topology of the current task to 'fact_topologies'.
+    output_start = ids.pre_execution_builtin_ptrs.output
+    output_end = ids.return_builtin_ptrs.output
+    fact_topologies.append(get_task_fact_topology(
+        output_size=output_end - output_start,
+        task=task,
+        output_builtin=output_builtin,
+        output_runner_data=output_runner_data,
+    ))
+%}
+*/
+pub fn append_fact_topologies(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let pre_execution_builtin_ptrs =
+        get_ptr_from_var_name("pre_execution_builtin_ptrs", vm, ids_data, ap_tracking)?;
+    let return_builtin_ptrs =
+        get_relocatable_from_var_name("return_builtin_ptrs", vm, ids_data, ap_tracking)?;
+    // The output pointer is the first field of BuiltinData
+    let output_start = vm.get_relocatable(pre_execution_builtin_ptrs)?;
+    let output_end = vm.get_relocatable(return_builtin_ptrs)?;
+    let output_size = output_end.sub(&output_start)?;
+
+    let output_runner_data: Option<OutputBuiltinState> = exec_scopes.get("output_runner_data")?;
+    let fact_topology = get_task_fact_topology(
+        output_size,
+        output_start,
+        exec_scopes.get_ref::<Task>("task")?,
+        vm.get_output_builtin()?,
+        output_runner_data,
+    )?;
+    exec_scopes
+        .get_mut_ref::<Vec<FactTopology>>("fact_topologies")?
+        .push(fact_topology);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        relocatable,
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            runners::{
+                builtin_runner::OutputBuiltinRunner,
+                cairo_pie::{
+                    CairoPieMemory, CairoPieMetadata, CairoPieVersion, OutputBuiltinAdditionalData,
+                    StrippedProgram, CAIRO_PIE_VERSION,
+                },
+                cairo_runner::ExecutionResources,
+            },
+            vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+        },
+    };
+    use assert_matches::assert_matches;
+    use felt::PRIME_STR;
+
+    // A CairoPie of a program returning right away, which wrote 7 to its output
+    fn cairo_pie() -> CairoPie {
+        CairoPie {
+            metadata: CairoPieMetadata {
+                program: StrippedProgram {
+                    data: vec![Felt::new(0x208b7fff7fff7ffe_u64).into()],
+                    builtins: vec!["output"],
+                    main: 0,
+                    prime: PRIME_STR.to_string(),
+                },
+                program_segment: (0, 1).into(),
+                execution_segment: (1, 3).into(),
+                ret_fp_segment: (3, 0).into(),
+                ret_pc_segment: (4, 0).into(),
+                builtin_segments: HashMap::from([("output".to_string(), (2, 1).into())]),
+                extra_segments: vec![],
+            },
+            memory: CairoPieMemory(vec![
+                ((0, 0), Felt::new(0x208b7fff7fff7ffe_u64).into()),
+                ((1, 0), (2, 0).into()),
+                ((1, 1), (3, 0).into()),
+                ((1, 2), (4, 0).into()),
+                ((2, 0), Felt::new(7).into()),
+            ]),
+            execution_resources: ExecutionResources {
+                n_steps: 1,
+                n_memory_holes: 0,
+                builtin_instance_counter: HashMap::from([("output".to_string(), 1)]),
+            },
+            additional_data: HashMap::from([(
+                "output_builtin".to_string(),
+                BuiltinAdditionalData::Output(OutputBuiltinAdditionalData::default()),
+            )]),
+            version: CairoPieVersion {
+                cairo_pie: CAIRO_PIE_VERSION.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn load_program_of_task() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0))];
+        add_segments!(vm, 1);
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("program_data_base", relocatable!(2, 0));
+        exec_scopes.insert_value("task", Task::CairoPie(cairo_pie()));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["program_header"],
+                hint_code::EXECUTE_TASK_LOAD_PROGRAM,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![
+            vm.segments.memory,
+            ((2, 0), 5),
+            ((2, 2), 0),
+            ((2, 3), 1),
+            ((2, 5), 0x208b7fff7fff7ffe_u64)
+        ];
+        assert_eq!(
+            vm.get_integer(relocatable!(2, 4)).unwrap().as_ref(),
+            &Felt::from_bytes_be(b"output")
+        );
+        assert_eq!(vm.segments.segment_sizes.get(&2), Some(&6));
+        assert_matches!(
+            exec_scopes.get::<Relocatable>("program_address"),
+            Ok(address) if address == relocatable!(2, 5)
+        );
+    }
+
+    #[test]
+    fn load_program_with_custom_builtins() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0))];
+        add_segments!(vm, 1);
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("program_data_base", relocatable!(2, 0));
+        exec_scopes.insert_value(
+            "task",
+            Task::RunProgram {
+                program: program!(
+                    builtins = vec![OUTPUT_BUILTIN_NAME],
+                    custom_builtins = vec!["double".to_string()],
+                    main = Some(0),
+                ),
+                program_input: serde_json::Value::Null,
+            },
+        );
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["program_header"],
+                hint_code::EXECUTE_TASK_LOAD_PROGRAM,
+                &mut exec_scopes
+            ),
+            Err(HintError::InvalidBootloaderInput(_))
+        );
+    }
+
+    #[test]
+    fn validate_hash_of_task() {
+        let program_hash =
+            compute_program_hash_chain(&Program::from(&cairo_pie().metadata.program), 0).unwrap();
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0))];
+        add_segments!(vm, 1);
+        vm.insert_value(relocatable!(2, 1), program_hash).unwrap();
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("task", Task::CairoPie(cairo_pie()));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["output_ptr"],
+                hint_code::EXECUTE_TASK_VALIDATE_HASH,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_hash_mismatch() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0)), ((2, 1), 3)];
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("task", Task::CairoPie(cairo_pie()));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["output_ptr"],
+                hint_code::EXECUTE_TASK_VALIDATE_HASH,
+                &mut exec_scopes
+            ),
+            Err(HintError::ProgramHashMismatch(_, hash)) if hash == Felt::new(3)
+        );
+    }
+
+    #[test]
+    fn call_cairo_pie_task() {
+        let mut vm = vm!();
+        // call rel 5, followed by the output builtin pointer passed to the task
+        vm.segments = segments![
+            ((0, 0), 0x1104800180018000_u64),
+            ((0, 1), 5),
+            ((1, 2), (2, 0))
+        ];
+        add_segments!(vm, 2);
+        vm.run_context.pc = relocatable!(0, 0);
+        vm.run_context.ap = 3;
+        vm.run_context.fp = 0;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("task", Task::CairoPie(cairo_pie()));
+        exec_scopes.insert_value("program_address", relocatable!(3, 5));
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::EXECUTE_TASK_CALL_TASK,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![
+            vm.segments.memory,
+            ((3, 5), 0x208b7fff7fff7ffe_u64),
+            ((1, 2), (2, 0)),
+            ((1, 3), (1, 0)),
+            ((1, 4), (0, 2)),
+            ((2, 0), 7)
+        ];
+        assert_eq!(exec_scopes.data.len(), 2);
+        assert_matches!(
+            exec_scopes.data[0]
+                .get("n_builtins")
+                .unwrap()
+                .downcast_ref::<usize>(),
+            Some(1)
+        );
+        assert_matches!(
+            exec_scopes.data[0]
+                .get("output_runner_data")
+                .unwrap()
+                .downcast_ref::<Option<OutputBuiltinState>>(),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn call_run_program_task_with_hints() {
+        let mut program = Program::from(&cairo_pie().metadata.program);
+        program.hints = HashMap::from([(0, vec![])]);
+        let mut vm = vm!();
+        vm.builtin_runners
+            .push((OUTPUT_BUILTIN_NAME, OutputBuiltinRunner::new(true).into()));
+        vm.segments = segments![((1, 0), (2, 0)), ((2, 0), (3, 0))];
+        add_segments!(vm, 2);
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value(
+            "task",
+            Task::RunProgram {
+                program: program.clone(),
+                program_input: serde_json::Value::Null,
+            },
+        );
+        exec_scopes.insert_value("program_address", relocatable!(4, 5));
+        assert_matches!(
+            run_hint!(
+                vm,
+                non_continuous_ids_data![("pre_execution_builtin_ptrs", 0)],
+                hint_code::EXECUTE_TASK_CALL_TASK,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        // The hints of the task run from the address it was loaded at
+        assert_eq!(vm.programs_to_load, vec![(program, relocatable!(4, 5))]);
+        assert_eq!(vm.get_output_builtin().unwrap().base(), 3);
+    }
+
+    fn write_return_builtins_ids() -> HashMap<String, HintReference> {
+        HashMap::from([
+            (
+                "return_builtin_ptrs".to_string(),
+                HintReference::new_simple(-10),
+            ),
+            (
+                "used_builtins_addr".to_string(),
+                HintReference::new_simple(-2),
+            ),
+            (
+                "pre_execution_builtin_ptrs".to_string(),
+                HintReference::new_simple(-1),
+            ),
+        ])
+    }
+
+    // Memory of a task which used its output builtin up to `output_end`: the builtins it returned
+    // are at (2, 0), and the pointers before the task, (4, 10 * i), at (3, 0)
+    fn vm_after_task(output_end: usize) -> VirtualMachine {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 8), (2, 0)), ((1, 9), (3, 0))];
+        add_segments!(vm, 3);
+        vm.insert_value(relocatable!(2, 0), relocatable!(4, output_end))
+            .unwrap();
+        for index in 0..ALL_BUILTINS.len() {
+            vm.insert_value(relocatable!(3, index), relocatable!(4, 10 * index))
+                .unwrap();
+        }
+        vm.run_context.fp = 10;
+        vm
+    }
+
+    #[test]
+    fn write_return_builtins_of_cairo_pie() {
+        let mut vm = vm_after_task(1);
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("task", Task::CairoPie(cairo_pie()));
+        exec_scopes.insert_value("n_builtins", 1_usize);
+        assert_matches!(
+            run_hint!(
+                vm,
+                write_return_builtins_ids(),
+                hint_code::EXECUTE_TASK_WRITE_RETURN_BUILTINS,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            vm.get_relocatable(relocatable!(1, 0)).unwrap(),
+            relocatable!(4, 1)
+        );
+        for index in 1..ALL_BUILTINS.len() {
+            assert_eq!(
+                vm.get_relocatable(relocatable!(1, index)).unwrap(),
+                relocatable!(4, 10 * index)
+            );
+        }
+        assert_eq!(exec_scopes.data.len(), 2);
+        assert_matches!(exec_scopes.get::<usize>("n_selected_builtins"), Ok(1));
+    }
+
+    #[test]
+    fn write_return_builtins_usage_mismatch() {
+        let mut vm = vm_after_task(2);
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("task", Task::CairoPie(cairo_pie()));
+        exec_scopes.insert_value("n_builtins", 1_usize);
+        assert_matches!(
+            run_hint!(
+                vm,
+                write_return_builtins_ids(),
+                hint_code::EXECUTE_TASK_WRITE_RETURN_BUILTINS,
+                &mut exec_scopes
+            ),
+            Err(HintError::BuiltinUsageMismatch(builtin)) if builtin == OUTPUT_BUILTIN_NAME
+        );
+    }
+
+    #[test]
+    fn append_fact_topology_of_cairo_pie() {
+        let mut vm = vm!();
+        // pre_execution_builtin_ptrs = (3, 0), return_builtin_ptrs.output = (2, 3)
+        vm.segments = segments![((1, 0), (3, 0)), ((1, 1), (2, 3))];
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments);
+        vm.builtin_runners = vec![(OUTPUT_BUILTIN_NAME, output_builtin.into())];
+        add_segments!(vm, 1);
+        vm.insert_value(relocatable!(3, 0), relocatable!(2, 0))
+            .unwrap();
+        vm.run_context.fp = 2;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("task", Task::CairoPie(cairo_pie()));
+        exec_scopes.insert_value("output_runner_data", None::<OutputBuiltinState>);
+        exec_scopes.insert_value("fact_topologies", Vec::<FactTopology>::new());
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["pre_execution_builtin_ptrs", "return_builtin_ptrs"],
+                hint_code::EXECUTE_TASK_APPEND_FACT_TOPOLOGIES,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            exec_scopes
+                .get::<Vec<FactTopology>>("fact_topologies")
+                .unwrap(),
+            vec![FactTopology {
+                tree_structure: vec![1, 0],
+                page_sizes: vec![3],
+            }]
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    hint_processor::builtin_hint_processor::bootloader::types::{FactTopology, Task},
+    types::relocatable::Relocatable,
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        runners::{
+            builtin_runner::{OutputBuiltinRunner, OutputBuiltinState},
+            cairo_pie::{Attributes, BuiltinAdditionalData, Pages},
+        },
+    },
+};
+use std::{fs::File, path::Path};
+
+pub const GPS_FACT_TOPOLOGY: &str = "gps_fact_topology";
+
+// Bound on the values of a tree structure, and on its length
+const MAX_TREE_STRUCTURE_VALUE: usize = 1 << 30;
+const MAX_TREE_STRUCTURE_LEN: usize = 10;
+
+/// Returns the fact topology of a task whose output of `output_size` cells starts at
+/// `output_start`.
+/// A task run from its program wrote its pages to the output builtin, which is restored to
+/// `output_runner_data` afterwards. The pages of a CairoPie are read from its additional data.
+pub fn get_task_fact_topology(
+    output_size: usize,
+    output_start: Relocatable,
+    task: &Task,
+    output_builtin: &mut OutputBuiltinRunner,
+    output_runner_data: Option<OutputBuiltinState>,
+) -> Result<FactTopology, HintError> {
+    match task {
+        Task::RunProgram { .. } => {
+            let output_runner_data = output_runner_data.ok_or_else(|| {
+                HintError::InvalidFactTopology(
+                    "the output builtin state wasn't saved before running the task".to_string(),
+                )
+            })?;
+            let state = output_builtin.get_state();
+            // Pages are given relative to the start of the task's output
+            let pages = state
+                .pages
+                .into_iter()
+                .map(|(page_id, mut page)| {
+                    page.start = page.start.checked_sub(output_start.offset).ok_or_else(|| {
+                        HintError::InvalidFactTopology(format!(
+                            "page {page_id} starts before the output of the task"
+                        ))
+                    })?;
+                    Ok((page_id, page))
+                })
+                .collect::<Result<Pages, HintError>>()?;
+            let fact_topology =
+                get_fact_topology_from_additional_data(output_size, &pages, &state.attributes)?;
+            output_builtin.set_state(output_runner_data);
+            Ok(fact_topology)
+        }
+        Task::CairoPie(cairo_pie) => match cairo_pie.additional_data.get("output_builtin") {
+            Some(BuiltinAdditionalData::Output(data)) => {
+                get_fact_topology_from_additional_data(output_size, &data.pages, &data.attributes)
+            }
+            _ => Err(HintError::InvalidFactTopology(
+                "the CairoPie has no output builtin data".to_string(),
+            )),
+        },
+    }
+}
+
+/// Builds the fact topology of an output from its pages and its `gps_fact_topology` attribute.
+/// Outputs without the attribute must have a single page, page 0.
+pub fn get_fact_topology_from_additional_data(
+    output_size: usize,
+    pages: &Pages,
+    attributes: &Attributes,
+) -> Result<FactTopology, HintError> {
+    let tree_structure = match attributes.get(GPS_FACT_TOPOLOGY) {
+        Some(tree_structure) => {
+            if tree_structure.len() % 2 != 0
+                || tree_structure.is_empty()
+                || tree_structure.len() > MAX_TREE_STRUCTURE_LEN
+            {
+                return Err(HintError::InvalidFactTopology(format!(
+                    "invalid tree structure length {}",
+                    tree_structure.len()
+                )));
+            }
+            if tree_structure
+                .iter()
+                .any(|value| *value >= MAX_TREE_STRUCTURE_VALUE)
+            {
+                return Err(HintError::InvalidFactTopology(
+                    "tree structure values must be below 2**30".to_string(),
+                ));
+            }
+            tree_structure.clone()
+        }
+        None => {
+            if !pages.is_empty() {
+                return Err(HintError::InvalidFactTopology(format!(
+                    "additional pages cannot be used since the '{GPS_FACT_TOPOLOGY}' attribute is not specified"
+                )));
+            }
+            vec![1, 0]
+        }
+    };
+    Ok(FactTopology {
+        tree_structure,
+        page_sizes: get_page_sizes_from_pages(output_size, pages)?,
+    })
+}
+
+/// Returns the sizes of the pages of an output, starting with page 0, which holds everything
+/// before page 1. Pages must be numbered from 1 and cover the rest of the output contiguously.
+pub fn get_page_sizes_from_pages(
+    output_size: usize,
+    pages: &Pages,
+) -> Result<Vec<usize>, HintError> {
+    let mut page_ids: Vec<&usize> = pages.keys().collect();
+    page_ids.sort();
+
+    let mut page_sizes = vec![output_size];
+    let mut expected_page_start = None;
+    for (expected_page_id, page_id) in (1..).zip(page_ids) {
+        if *page_id != expected_page_id {
+            return Err(HintError::InvalidFactTopology(format!(
+                "expected page id {expected_page_id}, found {page_id}"
+            )));
+        }
+        let page = &pages[page_id];
+        match expected_page_start {
+            None if page.start <= output_size => page_sizes[0] = page.start,
+            Some(expected_page_start) if page.start == expected_page_start => (),
+            _ => {
+                return Err(HintError::InvalidFactTopology(format!(
+                    "invalid start {} for page {page_id}",
+                    page.start
+                )))
+            }
+        }
+        if page.size == 0 || page.size > output_size {
+            return Err(HintError::InvalidFactTopology(format!(
+                "invalid size {} for page {page_id}",
+                page.size
+            )));
+        }
+        expected_page_start = Some(page.start + page.size);
+        page_sizes.push(page.size);
+    }
+    if matches!(expected_page_start, Some(page_end) if page_end != output_size) {
+        return Err(HintError::InvalidFactTopology(
+            "pages must cover the entire program output".to_string(),
+        ));
+    }
+    Ok(page_sizes)
+}
+
+/// Adds the pages of the tasks run by the bootloader to the output builtin, given their fact
+/// topologies. The output of each task starts after the 2 cells the bootloader writes for it,
+/// and the pages are numbered from 1, as page 0 holds the bootloader output.
+pub fn configure_fact_topologies(
+    fact_topologies: &[FactTopology],
+    mut output_start: Relocatable,
+    output_builtin: &mut OutputBuiltinRunner,
+) -> Result<(), HintError> {
+    let mut page_id = 1;
+    for fact_topology in fact_topologies {
+        output_start += 2;
+        for page_size in fact_topology.page_sizes.iter() {
+            output_builtin
+                .add_page(page_id, output_start, *page_size)
+                .map_err(VirtualMachineError::RunnerError)?;
+            page_id += 1;
+            output_start += *page_size;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the fact topologies as `{"fact_topologies": [...]}` to the file at `path`.
+pub fn write_fact_topologies_file(
+    path: &Path,
+    fact_topologies: &[FactTopology],
+) -> Result<(), HintError> {
+    let file = File::create(path).map_err(|err| HintError::FactTopologiesFile(err.to_string()))?;
+    serde_json::to_writer_pretty(
+        file,
+        &serde_json::json!({ "fact_topologies": fact_topologies }),
+    )
+    .map_err(|err| HintError::FactTopologiesFile(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::runners::cairo_pie::PublicMemoryPage;
+    use assert_matches::assert_matches;
+    use std::collections::HashMap;
+
+    #[test]
+    fn fact_topology_without_attribute() {
+        assert_eq!(
+            get_fact_topology_from_additional_data(5, &HashMap::new(), &HashMap::new()).unwrap(),
+            FactTopology {
+                tree_structure: vec![1, 0],
+                page_sizes: vec![5],
+            }
+        );
+    }
+
+    #[test]
+    fn fact_topology_with_pages() {
+        let pages = HashMap::from([
+            (1, PublicMemoryPage { start: 2, size: 3 }),
+            (2, PublicMemoryPage { start: 5, size: 4 }),
+        ]);
+        let attributes = HashMap::from([(GPS_FACT_TOPOLOGY.to_string(), vec![3, 2, 0, 2])]);
+        assert_eq!(
+            get_fact_topology_from_additional_data(9, &pages, &attributes).unwrap(),
+            FactTopology {
+                tree_structure: vec![3, 2, 0, 2],
+                page_sizes: vec![2, 3, 4],
+            }
+        );
+    }
+
+    #[test]
+    fn page_sizes_not_covering_output() {
+        let pages = HashMap::from([(1, PublicMemoryPage { start: 2, size: 3 })]);
+        assert_matches!(
+            get_page_sizes_from_pages(6, &pages),
+            Err(HintError::InvalidFactTopology(_))
+        );
+    }
+
+    #[test]
+    fn page_sizes_missing_page() {
+        let pages = HashMap::from([(2, PublicMemoryPage { start: 2, size: 3 })]);
+        assert_matches!(
+            get_page_sizes_from_pages(5, &pages),
+            Err(HintError::InvalidFactTopology(_))
+        );
+    }
+
+    #[test]
+    fn pages_without_attribute() {
+        let pages = HashMap::from([(1, PublicMemoryPage { start: 0, size: 3 })]);
+        assert_matches!(
+            get_fact_topology_from_additional_data(3, &pages, &HashMap::new()),
+            Err(HintError::InvalidFactTopology(_))
+        );
+    }
+
+    #[test]
+    fn configure_pages_of_tasks() {
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.new_state(2);
+        let fact_topologies = [
+            FactTopology {
+                tree_structure: vec![1, 0],
+                page_sizes: vec![3],
+            },
+            FactTopology {
+                tree_structure: vec![2, 1, 0, 2],
+                page_sizes: vec![1, 4],
+            },
+        ];
+        configure_fact_topologies(&fact_topologies, (2, 1).into(), &mut output_builtin).unwrap();
+        assert_eq!(
+            output_builtin.get_state().pages,
+            HashMap::from([
+                (1, PublicMemoryPage { start: 3, size: 3 }),
+                (2, PublicMemoryPage { start: 8, size: 1 }),
+                (3, PublicMemoryPage { start: 9, size: 4 }),
+            ])
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Hints of StarkWare's bootloader and simple bootloader, which run a list of tasks (compiled
+//! programs or CairoPies) and output their program hashes and outputs, so that all of them
+//! can be proven at once.
+//!
+//! The task list is read from the `program_input` variable of the execution scopes.
+
+pub mod bootloader_hints;
+pub mod execute_task_hints;
+pub mod fact_topologies;
+pub mod select_builtins_hints;
+pub mod simple_bootloader_hints;
+pub mod types;
@@ -0,0 +1,157 @@
+use crate::{
+    any_box,
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::Felt;
+use num_traits::ToPrimitive;
+use std::{any::Any, collections::HashMap};
+
+/*
+Implements hint:
+%{ vm_enter_scope({'n_selected_builtins': ids.n_selected_builtins}) %}
+*/
+pub fn select_builtins_enter_scope(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let n_selected_builtins =
+        get_integer_from_var_name("n_selected_builtins", vm, ids_data, ap_tracking)?
+            .to_usize()
+            .ok_or(HintError::BigintToUsizeFail)?;
+    exec_scopes.enter_scope(HashMap::from([(
+        "n_selected_builtins".to_string(),
+        any_box!(n_selected_builtins),
+    )]));
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # A builtin should be selected iff its encoding appears in the selected encodings list
+    # and the list wasn't exhausted.
+    # Note that testing inclusion by a single comparison is possible since the lists are sorted.
+    ids.select_builtin = int(
+      n_selected_builtins > 0 and memory[ids.selected_encodings] == memory[ids.all_encodings])
+    if ids.select_builtin:
+      n_selected_builtins = n_selected_builtins - 1
+%}
+*/
+pub fn select_builtin(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let n_selected_builtins: usize = exec_scopes.get("n_selected_builtins")?;
+    let select_builtin = n_selected_builtins > 0 && {
+        let selected_encodings =
+            get_ptr_from_var_name("selected_encodings", vm, ids_data, ap_tracking)?;
+        let all_encodings = get_ptr_from_var_name("all_encodings", vm, ids_data, ap_tracking)?;
+        vm.get_integer(selected_encodings)? == vm.get_integer(all_encodings)?
+    };
+    insert_value_from_var_name(
+        "select_builtin",
+        Felt::from(select_builtin as usize),
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    if select_builtin {
+        exec_scopes.insert_value("n_selected_builtins", n_selected_builtins - 1);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::relocatable::MaybeRelocatable,
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+        },
+    };
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn enter_scope_with_n_selected_builtins() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 3)];
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["n_selected_builtins"],
+                hint_code::SELECT_BUILTINS_ENTER_SCOPE,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(exec_scopes.data.len(), 2);
+        assert_matches!(exec_scopes.get::<usize>("n_selected_builtins"), Ok(3));
+    }
+
+    #[test]
+    fn select_matching_builtin() {
+        let mut vm = vm!();
+        // selected_encodings = (2, 0), all_encodings = (2, 1)
+        vm.segments = segments![((1, 0), (2, 0)), ((1, 1), (2, 1)), ((2, 0), 7), ((2, 1), 7)];
+        vm.run_context.fp = 3;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("n_selected_builtins", 2_usize);
+        let ids_data = ids_data!["selected_encodings", "all_encodings", "select_builtin"];
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::INNER_SELECT_BUILTINS_SELECT_BUILTIN,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 2), 1)];
+        assert_matches!(exec_scopes.get::<usize>("n_selected_builtins"), Ok(1));
+    }
+
+    #[test]
+    fn skip_builtin_when_all_are_selected() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0)), ((1, 1), (2, 1))];
+        vm.run_context.fp = 3;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("n_selected_builtins", 0_usize);
+        let ids_data = ids_data!["selected_encodings", "all_encodings", "select_builtin"];
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::INNER_SELECT_BUILTINS_SELECT_BUILTIN,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 2), 0)];
+        assert_matches!(exec_scopes.get::<usize>("n_selected_builtins"), Ok(0));
+    }
+}
@@ -0,0 +1,338 @@
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::{
+            bootloader::{
+                execute_task_hints::ALL_BUILTINS,
+                fact_topologies::{configure_fact_topologies, write_fact_topologies_file},
+                types::{FactTopology, SimpleBootloaderInput, TaskSpec},
+            },
+            hint_utils::{
+                get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+            },
+            program_input_utils::get_program_input,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::Felt;
+use num_traits::ToPrimitive;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.objects import SimpleBootloaderInput
+    simple_bootloader_input = SimpleBootloaderInput.Schema().load(program_input)
+%}
+*/
+pub fn load_simple_bootloader_input(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+    let program_input = get_program_input(exec_scopes)?;
+    let simple_bootloader_input = SimpleBootloaderInput::deserialize(program_input)
+        .map_err(|err| HintError::InvalidBootloaderInput(err.to_string()))?;
+    exec_scopes.insert_value("simple_bootloader_input", simple_bootloader_input);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    n_tasks = len(simple_bootloader_input.tasks)
+    memory[ids.output_ptr] = n_tasks
+
+    # Task range checks are located right after simple bootloader validation range checks, and
+    # this is validated later in this function.
+    ids.task_range_check_ptr = ids.range_check_ptr + ids.BuiltinData.SIZE * n_tasks
+
+    # A list of fact_toplogies that instruct how to generate the fact from the program output
+    # for each task.
+    fact_topologies = []
+%}
+*/
+pub fn prepare_task_range_checks(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let n_tasks = exec_scopes
+        .get_ref::<SimpleBootloaderInput>("simple_bootloader_input")?
+        .tasks
+        .len();
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+    vm.insert_value(output_ptr, Felt::from(n_tasks))?;
+
+    // BuiltinData has a field per builtin
+    let range_check_ptr = get_ptr_from_var_name("range_check_ptr", vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name(
+        "task_range_check_ptr",
+        range_check_ptr + ALL_BUILTINS.len() * n_tasks,
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    exec_scopes.insert_value("fact_topologies", Vec::<FactTopology>::new());
+    Ok(())
+}
+
+/*
+Implements hint:
+%{ tasks = simple_bootloader_input.tasks %}
+*/
+pub fn set_tasks_variable(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+    let tasks = exec_scopes
+        .get_ref::<SimpleBootloaderInput>("simple_bootloader_input")?
+        .tasks
+        .clone();
+    exec_scopes.insert_value("tasks", tasks);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.objects import Task
+
+    # Pass current task to execute_task.
+    task_id = len(simple_bootloader_input.tasks) - ids.n_tasks
+    task = simple_bootloader_input.tasks[task_id].load_task()
+%}
+*/
+pub fn set_current_task(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let tasks: &Vec<TaskSpec> = &exec_scopes
+        .get_ref::<SimpleBootloaderInput>("simple_bootloader_input")?
+        .tasks;
+    let n_tasks = get_integer_from_var_name("n_tasks", vm, ids_data, ap_tracking)?;
+    let task = n_tasks
+        .to_usize()
+        .and_then(|n_tasks| tasks.len().checked_sub(n_tasks))
+        .and_then(|task_id| tasks.get(task_id))
+        .ok_or_else(|| {
+            HintError::InvalidBootloaderInput(format!(
+                "{n_tasks} tasks left out of {}",
+                tasks.len()
+            ))
+        })?
+        .load_task()?;
+    exec_scopes.insert_value("task", task);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # Dump fact topologies to a json file.
+    from starkware.cairo.bootloaders.simple_bootloader.utils import (
+        configure_fact_topologies,
+        write_to_fact_topologies_file,
+    )
+
+    # The task-related output is prefixed by a single word that contains the number of tasks.
+    tasks_output_start = output_builtin.base + 1
+
+    if not simple_bootloader_input.single_page:
+        # Configure the memory pages in the output builtin, based on fact_topologies.
+        configure_fact_topologies(
+            fact_topologies=fact_topologies, output_start=tasks_output_start,
+            output_builtin=output_builtin,
+        )
+
+    if simple_bootloader_input.fact_topologies_path is not None:
+        write_to_fact_topologies_file(
+            fact_topologies_path=simple_bootloader_input.fact_topologies_path,
+            fact_topologies=fact_topologies,
+        )
+%}
+*/
+pub fn simple_bootloader_configure_fact_topologies(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+) -> Result<(), HintError> {
+    let simple_bootloader_input =
+        exec_scopes.get_ref::<SimpleBootloaderInput>("simple_bootloader_input")?;
+    let fact_topologies = exec_scopes.get_ref::<Vec<FactTopology>>("fact_topologies")?;
+
+    let output_builtin = vm.get_output_builtin()?;
+    let tasks_output_start = Relocatable::from((output_builtin.base() as isize, 1));
+    if !simple_bootloader_input.single_page {
+        configure_fact_topologies(fact_topologies, tasks_output_start, output_builtin)?;
+    }
+    if let Some(path) = &simple_bootloader_input.fact_topologies_path {
+        write_fact_topologies_file(path, fact_topologies)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::relocatable::MaybeRelocatable,
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            runners::{
+                builtin_runner::{OutputBuiltinRunner, OUTPUT_BUILTIN_NAME},
+                cairo_pie::PublicMemoryPage,
+            },
+            vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+        },
+    };
+    use assert_matches::assert_matches;
+    use std::{any::Any, path::PathBuf};
+
+    fn simple_bootloader_input(n_tasks: usize) -> SimpleBootloaderInput {
+        SimpleBootloaderInput {
+            tasks: (0..n_tasks)
+                .map(|i| TaskSpec::CairoPiePath(PathBuf::from(format!("task_{i}.zip"))))
+                .collect(),
+            fact_topologies_path: None,
+            single_page: false,
+        }
+    }
+
+    #[test]
+    fn load_input_from_program_input() {
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value(
+            "program_input",
+            serde_json::json!({"tasks": [{"type": "CairoPiePath", "path": "task_0.zip"}]}),
+        );
+        let mut vm = vm!();
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::SIMPLE_BOOTLOADER_LOAD_INPUT,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            exec_scopes
+                .get::<SimpleBootloaderInput>("simple_bootloader_input")
+                .unwrap(),
+            simple_bootloader_input(1)
+        );
+    }
+
+    #[test]
+    fn load_input_without_program_input() {
+        let mut vm = vm!();
+        assert_matches!(
+            run_hint!(vm, HashMap::new(), hint_code::SIMPLE_BOOTLOADER_LOAD_INPUT),
+            Err(HintError::VariableNotInScopeError(name)) if name == "program_input"
+        );
+    }
+
+    #[test]
+    fn prepare_task_range_checks_valid() {
+        let mut vm = vm!();
+        // output_ptr = (2, 0), range_check_ptr = (3, 0)
+        vm.segments = segments![((1, 0), (2, 0)), ((1, 1), (3, 0))];
+        add_segments!(vm, 2);
+        vm.run_context.fp = 3;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("simple_bootloader_input", simple_bootloader_input(2));
+        let ids_data = ids_data!["output_ptr", "range_check_ptr", "task_range_check_ptr"];
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SIMPLE_BOOTLOADER_PREPARE_TASK_RANGE_CHECKS,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((2, 0), 2), ((1, 2), (3, 16))];
+        assert_eq!(
+            exec_scopes
+                .get::<Vec<FactTopology>>("fact_topologies")
+                .unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn set_current_task_missing_cairo_pie() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 2)];
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("simple_bootloader_input", simple_bootloader_input(3));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["n_tasks"],
+                hint_code::SIMPLE_BOOTLOADER_SET_CURRENT_TASK,
+                &mut exec_scopes
+            ),
+            Err(HintError::InvalidBootloaderInput(message)) if message.contains("task_1.zip")
+        );
+    }
+
+    #[test]
+    fn set_current_task_out_of_range() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 4)];
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("simple_bootloader_input", simple_bootloader_input(3));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["n_tasks"],
+                hint_code::SIMPLE_BOOTLOADER_SET_CURRENT_TASK,
+                &mut exec_scopes
+            ),
+            Err(HintError::InvalidBootloaderInput(_))
+        );
+    }
+
+    #[test]
+    fn configure_fact_topologies_of_tasks() {
+        let mut vm = vm!();
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments);
+        vm.builtin_runners = vec![(OUTPUT_BUILTIN_NAME, output_builtin.into())];
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("simple_bootloader_input", simple_bootloader_input(1));
+        exec_scopes.insert_value(
+            "fact_topologies",
+            vec![FactTopology {
+                tree_structure: vec![1, 0],
+                page_sizes: vec![3],
+            }],
+        );
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::SIMPLE_BOOTLOADER_CONFIGURE_FACT_TOPOLOGIES,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        // The output starts with the number of tasks, followed by 2 cells for the task
+        assert_eq!(
+            vm.get_output_builtin().unwrap().get_state().pages,
+            HashMap::from([(1, PublicMemoryPage { start: 3, size: 3 })])
+        );
+    }
+}
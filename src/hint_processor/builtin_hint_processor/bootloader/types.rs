@@ -0,0 +1,328 @@
+//! Inputs of the bootloaders, read from the `program_input` of the run.
+//!
+//! Tagged values (tasks and packed outputs) are deserialized through `serde_json::Value`, as
+//! serde's internally tagged enums don't support the `arbitrary_precision` numbers of
+//! compiled programs.
+
+use crate::{
+    serde::deserialize_program::{deserialize_felt_hex, parse_program_json, ProgramJson},
+    types::program::Program,
+    vm::{errors::hint_errors::HintError, runners::cairo_pie::CairoPie},
+};
+use felt::Felt;
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::path::PathBuf;
+
+/// A task run by the simple bootloader.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Task {
+    /// A compiled program, along with its own program input.
+    RunProgram {
+        program: Program,
+        program_input: serde_json::Value,
+    },
+    CairoPie(CairoPie),
+}
+
+impl Task {
+    pub fn get_program(&self) -> Program {
+        match self {
+            Task::RunProgram { program, .. } => program.clone(),
+            Task::CairoPie(cairo_pie) => Program::from(&cairo_pie.metadata.program),
+        }
+    }
+
+    pub fn builtins(&self) -> &[&'static str] {
+        match self {
+            Task::RunProgram { program, .. } => &program.builtins,
+            Task::CairoPie(cairo_pie) => &cairo_pie.metadata.program.builtins,
+        }
+    }
+}
+
+/// A task as given in the bootloader input. CairoPies are only read from their file when the
+/// bootloader reaches them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TaskSpec {
+    RunProgram {
+        program: Box<Program>,
+        program_input: serde_json::Value,
+    },
+    CairoPiePath(PathBuf),
+}
+
+impl TaskSpec {
+    pub fn load_task(&self) -> Result<Task, HintError> {
+        match self {
+            TaskSpec::RunProgram {
+                program,
+                program_input,
+            } => Ok(Task::RunProgram {
+                program: program.as_ref().clone(),
+                program_input: program_input.clone(),
+            }),
+            TaskSpec::CairoPiePath(path) => CairoPie::read_zip_file(path)
+                .map(Task::CairoPie)
+                .map_err(|err| {
+                    HintError::InvalidBootloaderInput(format!(
+                        "failed to read CairoPie {}: {err}",
+                        path.display()
+                    ))
+                }),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TaskSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RunProgramTaskJson {
+            program: ProgramJson,
+            #[serde(default)]
+            program_input: serde_json::Value,
+        }
+
+        #[derive(Deserialize)]
+        struct CairoPiePathJson {
+            path: PathBuf,
+        }
+
+        let (task_type, value) = split_type_tag(serde_json::Value::deserialize(deserializer)?)?;
+        match task_type.as_str() {
+            "RunProgramTask" => {
+                let task: RunProgramTaskJson =
+                    serde_json::from_value(value).map_err(de::Error::custom)?;
+                Ok(TaskSpec::RunProgram {
+                    program: Box::new(
+                        parse_program_json(task.program, Some("main"))
+                            .map_err(de::Error::custom)?,
+                    ),
+                    program_input: task.program_input,
+                })
+            }
+            "CairoPiePath" => {
+                let task: CairoPiePathJson =
+                    serde_json::from_value(value).map_err(de::Error::custom)?;
+                Ok(TaskSpec::CairoPiePath(task.path))
+            }
+            _ => Err(de::Error::custom(format!("unknown task type {task_type}"))),
+        }
+    }
+}
+
+/// Input of the simple bootloader.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SimpleBootloaderInput {
+    pub tasks: Vec<TaskSpec>,
+    #[serde(default)]
+    pub fact_topologies_path: Option<PathBuf>,
+    /// Whether the whole output goes to a single memory page, instead of a page per task.
+    #[serde(default)]
+    pub single_page: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct BootloaderConfig {
+    #[serde(deserialize_with = "deserialize_felt_hex")]
+    pub simple_bootloader_program_hash: Felt,
+    #[serde(deserialize_with = "deserialize_felt_hex_list")]
+    pub supported_cairo_verifier_program_hashes: Vec<Felt>,
+}
+
+/// Describes how the output of a task is packed in the output of the bootloader.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PackedOutput {
+    /// The output of a task run by the bootloader.
+    Plain,
+    /// The output of a Cairo verifier, which packs the outputs of its subtasks.
+    Composite {
+        outputs: Vec<Felt>,
+        subtasks: Vec<PackedOutput>,
+    },
+}
+
+impl<'de> Deserialize<'de> for PackedOutput {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct CompositePackedOutputJson {
+            #[serde(deserialize_with = "deserialize_felt_hex_list")]
+            outputs: Vec<Felt>,
+            subtasks: Vec<PackedOutput>,
+        }
+
+        let (output_type, value) = split_type_tag(serde_json::Value::deserialize(deserializer)?)?;
+        match output_type.as_str() {
+            "PlainPackedOutput" => Ok(PackedOutput::Plain),
+            "CompositePackedOutput" => {
+                let output: CompositePackedOutputJson =
+                    serde_json::from_value(value).map_err(de::Error::custom)?;
+                Ok(PackedOutput::Composite {
+                    outputs: output.outputs,
+                    subtasks: output.subtasks,
+                })
+            }
+            _ => Err(de::Error::custom(format!(
+                "unknown packed output type {output_type}"
+            ))),
+        }
+    }
+}
+
+/// Input of the bootloader: a simple bootloader input, along with the configuration of the
+/// bootloader and the packing of the task outputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BootloaderInput {
+    pub simple_bootloader_input: SimpleBootloaderInput,
+    pub bootloader_config: BootloaderConfig,
+    pub packed_outputs: Vec<PackedOutput>,
+}
+
+impl<'de> Deserialize<'de> for BootloaderInput {
+    // The fields of the simple bootloader input sit next to the others, but can't be flattened
+    // because of arbitrary_precision
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct BootloaderInputJson {
+            bootloader_config: BootloaderConfig,
+            packed_outputs: Vec<PackedOutput>,
+        }
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let input = BootloaderInputJson::deserialize(&value).map_err(de::Error::custom)?;
+        Ok(BootloaderInput {
+            simple_bootloader_input: SimpleBootloaderInput::deserialize(&value)
+                .map_err(de::Error::custom)?,
+            bootloader_config: input.bootloader_config,
+            packed_outputs: input.packed_outputs,
+        })
+    }
+}
+
+/// Describes how to build the fact of a task from its output: the output is split into pages
+/// of `page_sizes`, which are then merged into a tree following `tree_structure`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct FactTopology {
+    pub tree_structure: Vec<usize>,
+    pub page_sizes: Vec<usize>,
+}
+
+fn split_type_tag<E: de::Error>(
+    mut value: serde_json::Value,
+) -> Result<(String, serde_json::Value), E> {
+    let tag = value
+        .as_object_mut()
+        .and_then(|object| object.remove("type"))
+        .ok_or_else(|| de::Error::missing_field("type"))?;
+    match tag {
+        serde_json::Value::String(tag) => Ok((tag, value)),
+        _ => Err(de::Error::custom("expected type to be a string")),
+    }
+}
+
+fn deserialize_felt_hex_list<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Felt>, D::Error> {
+    #[derive(Deserialize)]
+    struct FeltHex(#[serde(deserialize_with = "deserialize_felt_hex")] Felt);
+
+    let felts: Vec<FeltHex> = Vec::deserialize(d)?;
+    Ok(felts.into_iter().map(|FeltHex(felt)| felt).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    const PROGRAM: &str = r#"{
+        "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+        "builtins": ["output"],
+        "data": ["0x208b7fff7fff7ffe"],
+        "identifiers": {"__main__.main": {"type": "function", "pc": 0, "decorators": []}},
+        "hints": {},
+        "reference_manager": {"references": []},
+        "attributes": [],
+        "debug_info": null
+    }"#;
+
+    #[test]
+    fn deserialize_simple_bootloader_input() {
+        let input = format!(
+            r#"{{
+                "tasks": [
+                    {{"type": "RunProgramTask", "program": {PROGRAM}, "program_input": {{"n": 3}}}},
+                    {{"type": "CairoPiePath", "path": "fibonacci.zip"}}
+                ],
+                "single_page": true
+            }}"#
+        );
+        let input: SimpleBootloaderInput = serde_json::from_str(&input).unwrap();
+        assert_eq!(input.tasks.len(), 2);
+        assert_matches!(
+            &input.tasks[0],
+            TaskSpec::RunProgram { program, program_input }
+                if program.main == Some(0) && program.builtins == vec!["output"]
+                    && program_input == &serde_json::json!({"n": 3})
+        );
+        assert_eq!(
+            input.tasks[1],
+            TaskSpec::CairoPiePath(PathBuf::from("fibonacci.zip"))
+        );
+        assert_eq!(input.fact_topologies_path, None);
+        assert!(input.single_page);
+    }
+
+    #[test]
+    fn deserialize_bootloader_input() {
+        let input = r#"{
+            "tasks": [],
+            "fact_topologies_path": "fact_topologies.json",
+            "bootloader_config": {
+                "simple_bootloader_program_hash": "0x10",
+                "supported_cairo_verifier_program_hashes": ["0x1", "0x2"]
+            },
+            "packed_outputs": [
+                {"type": "PlainPackedOutput"},
+                {"type": "CompositePackedOutput", "outputs": ["0x3"], "subtasks": [
+                    {"type": "PlainPackedOutput"}
+                ]}
+            ]
+        }"#;
+        let input: BootloaderInput = serde_json::from_str(input).unwrap();
+        assert_eq!(
+            input.simple_bootloader_input,
+            SimpleBootloaderInput {
+                tasks: vec![],
+                fact_topologies_path: Some(PathBuf::from("fact_topologies.json")),
+                single_page: false,
+            }
+        );
+        assert_eq!(
+            input.bootloader_config,
+            BootloaderConfig {
+                simple_bootloader_program_hash: Felt::new(16),
+                supported_cairo_verifier_program_hashes: vec![Felt::new(1), Felt::new(2)],
+            }
+        );
+        assert_eq!(
+            input.packed_outputs,
+            vec![
+                PackedOutput::Plain,
+                PackedOutput::Composite {
+                    outputs: vec![Felt::new(3)],
+                    subtasks: vec![PackedOutput::Plain],
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_task_unknown_type() {
+        let task = r#"{"type": "UnknownTask", "path": "fibonacci.zip"}"#;
+        assert!(serde_json::from_str::<TaskSpec>(task).is_err());
+    }
+
+    #[test]
+    fn load_missing_cairo_pie() {
+        let task = TaskSpec::CairoPiePath(PathBuf::from("missing_cairo_pie.zip"));
+        assert_matches!(task.load_task(), Err(HintError::InvalidBootloaderInput(_)));
+    }
+}
@@ -4,6 +4,22 @@ use crate::{
             blake2s_utils::{
                 blake2s_add_uint256, blake2s_add_uint256_bigend, compute_blake2s, finalize_blake2s,
             },
+            bootloader::{
+                bootloader_hints::{
+                    bootloader_compute_fact_topologies, load_bootloader_config,
+                    prepare_simple_bootloader_input, prepare_simple_bootloader_output_segment,
+                    restore_bootloader_output, save_output_pointer, save_packed_outputs,
+                },
+                execute_task_hints::{
+                    allocate_program_data_segment, append_fact_topologies, assert_program_address,
+                    call_task, load_program_hint, validate_hash, write_return_builtins_hint,
+                },
+                select_builtins_hints::{select_builtin, select_builtins_enter_scope},
+                simple_bootloader_hints::{
+                    load_simple_bootloader_input, prepare_task_range_checks, set_current_task,
+                    set_tasks_variable, simple_bootloader_configure_fact_topologies,
+                },
+            },
             cairo_keccak::keccak_hints::{
                 block_permutation, cairo_keccak_finalize, compare_bytes_in_word_nondet,
                 compare_keccak_full_rate_in_bytes_nondet, keccak_write_args,
@@ -14,15 +30,24 @@ use crate::{
             },
             find_element_hint::{find_element, search_sorted_lower},
             hint_code,
+            hint_interpreter::{
+                execute_compiled_python_subset_hint, parse_python_subset_hint, Statement,
+            },
             keccak_utils::{unsafe_keccak, unsafe_keccak_finalize},
             math_utils::*,
             memcpy_hint_utils::{
                 add_segment, enter_scope, exit_scope, memcpy_continue_copying, memcpy_enter_scope,
             },
             memset_utils::{memset_continue_loop, memset_enter_scope},
+            output_builtin_hints::add_onchain_data_pages,
+            poseidon_utils::{n_greater_than_10, n_greater_than_2},
             pow_utils::pow,
             secp::{
                 bigint_utils::{bigint_to_uint256, nondet_bigint3},
+                ec_recover::{
+                    ec_recover_divmod_n_packed, ec_recover_product_div_m, ec_recover_product_mod,
+                    ec_recover_sub_a_b,
+                },
                 ec_utils::{
                     compute_doubling_slope, compute_slope, ec_double_assign_new_x,
                     ec_double_assign_new_y, ec_mul_inner, ec_negate, fast_ec_add_assign_new_x,
@@ -32,6 +57,9 @@ use crate::{
                     is_zero_assign_scope_variables, is_zero_nondet, is_zero_pack, reduce,
                     verify_zero,
                 },
+                secp_utils::{
+                    import_secp256r1_alpha, import_secp256r1_n, import_secp256r1_p, SECP256K1,
+                },
                 signature::{div_mod_n_packed_divmod, div_mod_n_safe_div, get_point_from_x},
             },
             segments::{relocate_segment, temporary_array},
@@ -45,8 +73,15 @@ use crate::{
                 squash_dict_inner_next_key, squash_dict_inner_skip_loop,
                 squash_dict_inner_used_accesses_assert,
             },
+            syscall_handler::{execute_syscall_hint, NoSyscallHandler, SyscallHandler},
             uint256_utils::{
-                split_64, uint256_add, uint256_signed_nn, uint256_sqrt, uint256_unsigned_div_rem,
+                split_128, split_64, uint256_add, uint256_expanded_unsigned_div_rem,
+                uint256_mul_div_mod, uint256_signed_nn, uint256_sqrt, uint256_sqrt_felt,
+                uint256_sub, uint256_unsigned_div_rem,
+            },
+            uint384_utils::{
+                add_no_uint384_check, uint384_signed_nn, uint384_sqrt, uint384_unsigned_div_rem,
+                uint768_unsigned_div_rem,
             },
             usort::{
                 usort_body, usort_enter_scope, verify_multiplicity_assert,
@@ -54,31 +89,57 @@ use crate::{
             },
         },
         hint_processor_definition::{HintProcessor, HintReference},
+        hint_processor_utils::normalize_hint_code,
     },
     serde::deserialize_program::ApTracking,
     types::exec_scope::ExecutionScopes,
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
 };
 use felt::Felt;
-use std::{any::Any, collections::HashMap, rc::Rc};
+use num_bigint::BigInt;
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 #[cfg(feature = "skip_next_instruction_hint")]
 use crate::hint_processor::builtin_hint_processor::skip_next_instruction::skip_next_instruction;
 
 pub struct HintProcessorData {
-    pub code: String,
+    // Private so that it can't change after the code the hint is matched by was resolved
+    code: String,
     pub ap_tracking: ApTracking,
     pub ids_data: HashMap<String, HintReference>,
+    // The code the hint is matched by: its normalized code, or the current version of the hint if
+    // it's a historical variant
+    pub(crate) known_code: String,
+    // The statements of the hint if its syntax is part of the Python subset of the interpreter,
+    // which runs the hints that aren't implemented
+    pub(crate) python_subset_statements: Option<Vec<Statement>>,
 }
 
 impl HintProcessorData {
-    pub fn new_default(code: String, ids_data: HashMap<String, HintReference>) -> Self {
+    pub fn new(
+        code: String,
+        ap_tracking: ApTracking,
+        ids_data: HashMap<String, HintReference>,
+    ) -> Self {
+        let known_code = known_hint_code(&code);
+        let python_subset_statements = parse_python_subset_hint(&known_code);
         HintProcessorData {
             code,
-            ap_tracking: ApTracking::default(),
+            ap_tracking,
             ids_data,
+            known_code,
+            python_subset_statements,
         }
     }
+
+    pub fn new_default(code: String, ids_data: HashMap<String, HintReference>) -> Self {
+        HintProcessorData::new(code, ApTracking::default(), ids_data)
+    }
+
+    /// The code of the hint, as written in the program.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
 }
 
 #[allow(clippy::type_complexity)]
@@ -96,21 +157,29 @@ pub struct HintFunc(
 );
 pub struct BuiltinHintProcessor {
     pub extra_hints: HashMap<String, Rc<HintFunc>>,
+    /// Serves the syscall hints of StarkNet contracts. Defaults to a `NoSyscallHandler`, failing
+    /// every syscall.
+    pub syscall_handler: Rc<RefCell<dyn SyscallHandler>>,
 }
 impl BuiltinHintProcessor {
     pub fn new_empty() -> Self {
-        BuiltinHintProcessor {
-            extra_hints: HashMap::new(),
-        }
+        BuiltinHintProcessor::new(HashMap::new())
     }
 
     pub fn new(extra_hints: HashMap<String, Rc<HintFunc>>) -> Self {
-        BuiltinHintProcessor { extra_hints }
+        BuiltinHintProcessor {
+            extra_hints,
+            syscall_handler: Rc::new(RefCell::new(NoSyscallHandler)),
+        }
     }
 
     pub fn add_hint(&mut self, hint_code: String, hint_func: Rc<HintFunc>) {
         self.extra_hints.insert(hint_code, hint_func);
     }
+
+    pub fn set_syscall_handler(&mut self, syscall_handler: Rc<RefCell<dyn SyscallHandler>>) {
+        self.syscall_handler = syscall_handler;
+    }
 }
 
 impl HintProcessor for BuiltinHintProcessor {
@@ -124,8 +193,30 @@ impl HintProcessor for BuiltinHintProcessor {
         let hint_data = hint_data
             .downcast_ref::<HintProcessorData>()
             .ok_or(HintError::WrongHintData)?;
+        match self.execute_implemented_hint(vm, exec_scopes, hint_data, constants) {
+            Err(HintError::UnknownHint(_)) => {
+                execute_compiled_python_subset_hint(vm, exec_scopes, hint_data, constants)
+            }
+            result => result,
+        }
+    }
+}
 
-        if let Some(hint_func) = self.extra_hints.get(&hint_data.code) {
+impl BuiltinHintProcessor {
+    // Runs the hint if it's implemented in Rust, either as a custom hint or as a builtin one,
+    // and returns HintError::UnknownHint otherwise
+    pub(crate) fn execute_implemented_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &HintProcessorData,
+        constants: &HashMap<String, Felt>,
+    ) -> Result<(), HintError> {
+        if let Some(hint_func) = self
+            .extra_hints
+            .get(&hint_data.code)
+            .or_else(|| self.extra_hints.get(&hint_data.known_code))
+        {
             return hint_func.0(
                 vm,
                 exec_scopes,
@@ -135,7 +226,7 @@ impl HintProcessor for BuiltinHintProcessor {
             );
         }
 
-        match &*hint_data.code {
+        match &*hint_data.known_code {
             hint_code::ADD_SEGMENT => add_segment(vm),
             hint_code::IS_NN => is_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking),
             hint_code::IS_NN_OUT_OF_RANGE => {
@@ -151,6 +242,9 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::ASSERT_LE_FELT_EXCLUDED_2 => assert_le_felt_excluded_2(exec_scopes),
             hint_code::ASSERT_LE_FELT_EXCLUDED_1 => assert_le_felt_excluded_1(vm, exec_scopes),
             hint_code::ASSERT_LE_FELT_EXCLUDED_0 => assert_le_felt_excluded_0(vm, exec_scopes),
+            hint_code::ASSERT_LE_FELT_V_0_8 => {
+                assert_le_felt_v_0_8(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::IS_LE_FELT => is_le_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking),
             hint_code::ASSERT_250_BITS => {
                 assert_250_bit(vm, &hint_data.ids_data, &hint_data.ap_tracking)
@@ -165,6 +259,16 @@ impl HintProcessor for BuiltinHintProcessor {
             }
             hint_code::ASSERT_NN => assert_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking),
             hint_code::SQRT => sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::IS_QUAD_RESIDUE => {
+                is_quad_residue(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::GET_FELT_BIT_LENGTH => {
+                get_felt_bitlength(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::SPLIT_XX => split_xx(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::IS_ADDR_BOUNDED => {
+                is_addr_bounded(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+            }
             hint_code::ASSERT_NOT_ZERO => {
                 assert_not_zero(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
@@ -235,9 +339,18 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::BLAKE2S_COMPUTE => {
                 compute_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
-            hint_code::VERIFY_ZERO => {
-                verify_zero(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
-            }
+            hint_code::VERIFY_ZERO => verify_zero(
+                vm,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+                &SECP256K1.p,
+            ),
+            hint_code::VERIFY_ZERO_EXTERNAL_SECP => verify_zero(
+                vm,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+                &exec_scopes.get::<BigInt>("SECP_P")?,
+            ),
             hint_code::NONDET_BIGINT3 => nondet_bigint3(
                 vm,
                 exec_scopes,
@@ -250,8 +363,18 @@ impl HintProcessor for BuiltinHintProcessor {
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.p,
             ),
+            hint_code::REDUCE_EXTERNAL_SECP => {
+                let secp_p = exec_scopes.get::<BigInt>("SECP_P")?;
+                reduce(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    &secp_p,
+                )
+            }
             hint_code::BLAKE2S_FINALIZE => {
                 finalize_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
@@ -320,15 +443,41 @@ impl HintProcessor for BuiltinHintProcessor {
             }
             hint_code::UINT256_ADD => uint256_add(vm, &hint_data.ids_data, &hint_data.ap_tracking),
             hint_code::SPLIT_64 => split_64(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::SPLIT_128 => split_128(vm, &hint_data.ids_data, &hint_data.ap_tracking),
             hint_code::UINT256_SQRT => {
                 uint256_sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::UINT256_SQRT_FELT => {
+                uint256_sqrt_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::UINT256_SIGNED_NN => {
                 uint256_signed_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
             hint_code::UINT256_UNSIGNED_DIV_REM => {
                 uint256_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::UINT256_EXPANDED_UNSIGNED_DIV_REM => {
+                uint256_expanded_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::UINT256_MUL_DIV_MOD => {
+                uint256_mul_div_mod(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::UINT256_SUB => uint256_sub(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::UINT384_UNSIGNED_DIV_REM => {
+                uint384_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::ADD_NO_UINT384_CHECK => {
+                add_no_uint384_check(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::UINT384_SQRT => {
+                uint384_sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::UINT384_SIGNED_NN => {
+                uint384_signed_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::UNSIGNED_DIV_REM_UINT768_BY_UINT384 => {
+                uint768_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::BIGINT_TO_UINT256 => {
                 bigint_to_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
             }
@@ -337,56 +486,114 @@ impl HintProcessor for BuiltinHintProcessor {
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.p,
             ),
+            hint_code::IS_ZERO_PACK_EXTERNAL_SECP | hint_code::IS_ZERO_PACK_EXTERNAL_SECP_V2 => {
+                let secp_p = exec_scopes.get::<BigInt>("SECP_P")?;
+                is_zero_pack(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    &secp_p,
+                )
+            }
             hint_code::IS_ZERO_NONDET => is_zero_nondet(vm, exec_scopes),
             hint_code::IS_ZERO_ASSIGN_SCOPE_VARS => {
-                is_zero_assign_scope_variables(exec_scopes, constants)
+                is_zero_assign_scope_variables(exec_scopes, &SECP256K1.p)
+            }
+            hint_code::IS_ZERO_ASSIGN_SCOPE_VARS_EXTERNAL_SECP => {
+                let secp_p = exec_scopes.get::<BigInt>("SECP_P")?;
+                is_zero_assign_scope_variables(exec_scopes, &secp_p)
             }
             hint_code::DIV_MOD_N_PACKED_DIVMOD => div_mod_n_packed_divmod(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.n,
             ),
-            hint_code::DIV_MOD_N_SAFE_DIV => div_mod_n_safe_div(exec_scopes, constants),
+            hint_code::DIV_MOD_N_PACKED_DIVMOD_EXTERNAL_N => {
+                let n = exec_scopes.get::<BigInt>("N")?;
+                div_mod_n_packed_divmod(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    &n,
+                )
+            }
+            hint_code::DIV_MOD_N_SAFE_DIV => div_mod_n_safe_div(exec_scopes),
             hint_code::GET_POINT_FROM_X => get_point_from_x(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.p,
+                &SECP256K1.beta,
             ),
             hint_code::EC_NEGATE => ec_negate(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.p,
             ),
             hint_code::EC_DOUBLE_SCOPE => compute_doubling_slope(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.p,
+                &SECP256K1.alpha,
             ),
+            hint_code::EC_DOUBLE_SCOPE_EXTERNAL_CONSTS => {
+                let secp_p = exec_scopes.get::<BigInt>("SECP_P")?;
+                let alpha = exec_scopes.get::<BigInt>("ALPHA")?;
+                compute_doubling_slope(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    &secp_p,
+                    &alpha,
+                )
+            }
             hint_code::COMPUTE_SLOPE => compute_slope(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.p,
             ),
+            hint_code::COMPUTE_SLOPE_EXTERNAL_SECP => {
+                let secp_p = exec_scopes.get::<BigInt>("SECP_P")?;
+                compute_slope(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    &secp_p,
+                )
+            }
             hint_code::EC_DOUBLE_ASSIGN_NEW_X => ec_double_assign_new_x(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.p,
             ),
-            hint_code::EC_DOUBLE_ASSIGN_NEW_Y => ec_double_assign_new_y(exec_scopes, constants),
+            hint_code::EC_DOUBLE_ASSIGN_NEW_X_EXTERNAL_SECP => {
+                let secp_p = exec_scopes.get::<BigInt>("SECP_P")?;
+                ec_double_assign_new_x(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    &secp_p,
+                )
+            }
+            hint_code::EC_DOUBLE_ASSIGN_NEW_Y => ec_double_assign_new_y(exec_scopes),
             hint_code::KECCAK_WRITE_ARGS => {
                 keccak_write_args(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
@@ -422,12 +629,44 @@ impl HintProcessor for BuiltinHintProcessor {
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
+                &SECP256K1.p,
             ),
-            hint_code::FAST_EC_ADD_ASSIGN_NEW_Y => fast_ec_add_assign_new_y(exec_scopes, constants),
+            hint_code::FAST_EC_ADD_ASSIGN_NEW_X_EXTERNAL_SECP => {
+                let secp_p = exec_scopes.get::<BigInt>("SECP_P")?;
+                fast_ec_add_assign_new_x(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    &secp_p,
+                )
+            }
+            hint_code::FAST_EC_ADD_ASSIGN_NEW_Y => fast_ec_add_assign_new_y(exec_scopes),
             hint_code::EC_MUL_INNER => {
                 ec_mul_inner(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::IMPORT_SECP256R1_P => import_secp256r1_p(exec_scopes),
+            hint_code::IMPORT_SECP256R1_ALPHA => import_secp256r1_alpha(exec_scopes),
+            hint_code::IMPORT_SECP256R1_N => import_secp256r1_n(exec_scopes),
+            hint_code::EC_RECOVER_DIV_MOD_N_PACKED => ec_recover_divmod_n_packed(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            ),
+            hint_code::EC_RECOVER_SUB_A_B => {
+                ec_recover_sub_a_b(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::EC_RECOVER_PRODUCT_MOD => {
+                ec_recover_product_mod(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::EC_RECOVER_PRODUCT_DIV_M => ec_recover_product_div_m(exec_scopes),
+            hint_code::NONDET_N_GREATER_THAN_10 => {
+                n_greater_than_10(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::NONDET_N_GREATER_THAN_2 => {
+                n_greater_than_2(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::RELOCATE_SEGMENT => {
                 relocate_segment(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
@@ -437,6 +676,105 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::VERIFY_ECDSA_SIGNATURE => {
                 verify_ecdsa_signature(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::ADD_ONCHAIN_DATA_PAGES => {
+                add_onchain_data_pages(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::STORAGE_READ
+            | hint_code::STORAGE_WRITE
+            | hint_code::GET_CALLER_ADDRESS
+            | hint_code::GET_CONTRACT_ADDRESS
+            | hint_code::EMIT_EVENT
+            | hint_code::CALL_CONTRACT
+            | hint_code::LIBRARY_CALL => {
+                // The handler is borrowed until the syscall returns, so that a handler that runs
+                // other contracts with this hint processor gets an error instead of a panic
+                let mut syscall_handler = self.syscall_handler.try_borrow_mut().map_err(|_| {
+                    HintError::SyscallFailed(
+                        "the syscall handler is already serving a syscall".to_string(),
+                    )
+                })?;
+                execute_syscall_hint(
+                    &mut *syscall_handler,
+                    vm,
+                    &hint_data.known_code,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                )
+            }
+            hint_code::SIMPLE_BOOTLOADER_LOAD_INPUT => load_simple_bootloader_input(exec_scopes),
+            hint_code::SIMPLE_BOOTLOADER_PREPARE_TASK_RANGE_CHECKS => prepare_task_range_checks(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            ),
+            hint_code::SIMPLE_BOOTLOADER_SET_TASKS_VARIABLE => set_tasks_variable(exec_scopes),
+            hint_code::SIMPLE_BOOTLOADER_SET_CURRENT_TASK => {
+                set_current_task(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::SIMPLE_BOOTLOADER_CONFIGURE_FACT_TOPOLOGIES => {
+                simple_bootloader_configure_fact_topologies(vm, exec_scopes)
+            }
+            hint_code::EXECUTE_TASK_ALLOCATE_PROGRAM_DATA_SEGMENT => allocate_program_data_segment(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            ),
+            hint_code::EXECUTE_TASK_LOAD_PROGRAM => {
+                load_program_hint(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::EXECUTE_TASK_VALIDATE_HASH => {
+                validate_hash(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::EXECUTE_TASK_ASSERT_PROGRAM_ADDRESS => {
+                assert_program_address(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::EXECUTE_TASK_CALL_TASK => {
+                call_task(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::EXECUTE_TASK_WRITE_RETURN_BUILTINS => write_return_builtins_hint(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            ),
+            hint_code::EXECUTE_TASK_APPEND_FACT_TOPOLOGIES => {
+                append_fact_topologies(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::SELECT_BUILTINS_ENTER_SCOPE => select_builtins_enter_scope(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            ),
+            hint_code::INNER_SELECT_BUILTINS_SELECT_BUILTIN => {
+                select_builtin(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::BOOTLOADER_PREPARE_SIMPLE_BOOTLOADER_OUTPUT_SEGMENT => {
+                prepare_simple_bootloader_output_segment(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                )
+            }
+            hint_code::BOOTLOADER_PREPARE_SIMPLE_BOOTLOADER_INPUT => {
+                prepare_simple_bootloader_input(exec_scopes)
+            }
+            hint_code::BOOTLOADER_RESTORE_BOOTLOADER_OUTPUT => {
+                restore_bootloader_output(vm, exec_scopes)
+            }
+            hint_code::BOOTLOADER_LOAD_BOOTLOADER_CONFIG => {
+                load_bootloader_config(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::BOOTLOADER_SAVE_OUTPUT_POINTER => {
+                save_output_pointer(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::BOOTLOADER_SAVE_PACKED_OUTPUTS => save_packed_outputs(exec_scopes),
+            hint_code::BOOTLOADER_COMPUTE_FACT_TOPOLOGIES => {
+                bootloader_compute_fact_topologies(vm, exec_scopes)
+            }
             #[cfg(feature = "skip_next_instruction_hint")]
             hint_code::SKIP_NEXT_INSTRUCTION => skip_next_instruction(vm),
             code => Err(HintError::UnknownHint(code.to_string())),
@@ -444,6 +782,16 @@ impl HintProcessor for BuiltinHintProcessor {
     }
 }
 
+// Returns the code under which the hint is known: its normalized code, or the current version of
+// the hint if it's a historical variant
+fn known_hint_code(code: &str) -> String {
+    let normalized_code = normalize_hint_code(code);
+    hint_code::HINT_CODE_VARIANTS
+        .iter()
+        .find(|(variant, _)| *variant == normalized_code)
+        .map_or(normalized_code, |(_, current)| current.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -455,6 +803,7 @@ mod tests {
         utils::test_utils::*,
         vm::{
             errors::{exec_scope_errors::ExecScopeError, memory_errors::MemoryError},
+            runners::builtin_runner::RangeCheckBuiltinRunner,
             vm_core::VirtualMachine,
             vm_memory::memory::Memory,
         },
@@ -525,6 +874,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_hint_with_different_whitespace() {
+        let hint_code = "\r\n    from starkware.cairo.common.math_utils import assert_integer\r\n    assert_integer(ids.a)  \r\n    assert 0 <= ids.a % PRIME < range_check_builtin.bound, f'a = {ids.a} is out of range.'\r\n";
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 1;
+        vm.segments = segments![((1, 0), (-1))];
+        let ids_data = ids_data!["a"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::AssertNNValueOutOfRange(x)) if x == Felt::new(-1)
+        );
+    }
+
+    #[test]
+    fn known_hint_code_of_variants() {
+        assert_eq!(
+            known_hint_code(hint_code::VERIFY_ZERO),
+            hint_code::VERIFY_ZERO
+        );
+        assert_eq!(
+            known_hint_code(&format!("  {}\n", hint_code::ADD_SEGMENT)),
+            hint_code::ADD_SEGMENT
+        );
+        for (variant, current) in hint_code::HINT_CODE_VARIANTS {
+            assert_eq!(known_hint_code(variant), current);
+            assert_eq!(normalize_hint_code(variant), variant);
+        }
+    }
+
+    #[test]
+    fn python_subset_hints_are_parsed_when_compiled() {
+        let hint_data =
+            HintProcessorData::new_default("x = 1\nids.y = x".to_string(), HashMap::new());
+        assert_eq!(
+            hint_data
+                .python_subset_statements
+                .map(|statements| statements.len()),
+            Some(2)
+        );
+        let hint_data =
+            HintProcessorData::new_default("import math\nids.y = 1".to_string(), HashMap::new());
+        assert_eq!(hint_data.python_subset_statements, None);
+    }
+
     #[test]
     fn memcpy_enter_scope_valid() {
         let hint_code = "vm_enter_scope({'n': ids.len})";
@@ -63,6 +63,16 @@ pub(crate) const ASSERT_LE_FELT_EXCLUDED_0: &str = "memory[ap] = 1 if excluded !
 pub(crate) const ASSERT_LE_FELT_EXCLUDED_1: &str = "memory[ap] = 1 if excluded != 1 else 0";
 pub(crate) const ASSERT_LE_FELT_EXCLUDED_2: &str = "assert excluded == 2";
 
+pub(crate) const ASSERT_LE_FELT_V_0_8: &str = r#"from starkware.cairo.common.math_utils import assert_integer
+assert_integer(ids.a)
+assert_integer(ids.b)
+a = ids.a % PRIME
+b = ids.b % PRIME
+assert a <= b, f'a = {a} is not less than or equal to b = {b}.'
+
+ids.small_inputs = int(
+    a < range_check_builtin.bound and (b - a) < range_check_builtin.bound)"#;
+
 pub(crate) const ASSERT_LT_FELT: &str = r#"from starkware.cairo.common.math_utils import assert_integer
 assert_integer(ids.a)
 assert_integer(ids.b)
@@ -81,12 +91,22 @@ assert value < ids.UPPER_BOUND, f'{value} is outside of the range [0, 2**250).'
 # Calculation for the assertion.
 ids.high, ids.low = divmod(ids.value, ids.SHIFT)"#;
 
+pub(crate) const IS_ADDR_BOUNDED: &str = r#"# Verify the assumptions on the relationship between 2**250, ADDR_BOUND and PRIME.
+ADDR_BOUND = ids.ADDR_BOUND % PRIME
+assert (2**250 < ADDR_BOUND <= 2**251) and (2 * 2**250 < PRIME) and (
+        ADDR_BOUND * 2 > PRIME), \
+    'normalize_address() cannot be used with the current constants.'
+ids.is_small = 1 if ids.addr < ADDR_BOUND else 0"#;
+
 pub(crate) const SPLIT_INT: &str = r#"memory[ids.output] = res = (int(ids.value) % PRIME) % ids.base
 assert res < ids.bound, f'split_int(): Limb {res} is out of range.'"#;
 
 pub(crate) const SPLIT_64: &str = r#"ids.low = ids.a & ((1<<64) - 1)
 ids.high = ids.a >> 64"#;
 
+pub(crate) const SPLIT_128: &str = r#"ids.low = ids.a & ((1<<128) - 1)
+ids.high = ids.a >> 128"#;
+
 pub(crate) const SPLIT_FELT: &str = r#"from starkware.cairo.common.math_utils import assert_integer
 assert ids.MAX_HIGH < 2**128 and ids.MAX_LOW < 2**128
 assert PRIME - 1 == ids.MAX_HIGH * 2**128 + ids.MAX_LOW
@@ -100,6 +120,30 @@ assert value < 2 ** 250, f"value={value} is outside of the range [0, 2**250)."
 assert 2 ** 250 < PRIME
 ids.root = isqrt(value)"#;
 
+pub(crate) const IS_QUAD_RESIDUE: &str = r#"from starkware.crypto.signature.signature import FIELD_PRIME
+from starkware.python.math_utils import div_mod, is_quad_residue, sqrt
+
+x = ids.x
+if is_quad_residue(x, FIELD_PRIME):
+    ids.y = sqrt(x, FIELD_PRIME)
+else:
+    ids.y = sqrt(div_mod(x, 3, FIELD_PRIME), FIELD_PRIME)"#;
+
+pub(crate) const GET_FELT_BIT_LENGTH: &str = r#"x = ids.x
+ids.bit_length = x.bit_length()"#;
+
+pub(crate) const SPLIT_XX: &str = r#"PRIME = 2**255 - 19
+II = pow(2, (PRIME - 1) // 4, PRIME)
+
+xx = ids.xx.low + (ids.xx.high<<128)
+x = pow(xx, (PRIME + 3) // 8, PRIME)
+if (x * x - xx) % PRIME != 0:
+    x = (x * II) % PRIME
+if x % 2 != 0:
+    x = PRIME - x
+ids.x.low = x & ((1<<128)-1)
+ids.x.high = x >> 128"#;
+
 pub(crate) const UNSIGNED_DIV_REM: &str = r#"from starkware.cairo.common.math_utils import assert_integer
 assert_integer(ids.div)
 assert 0 < ids.div <= PRIME // range_check_builtin.bound, \
@@ -283,6 +327,12 @@ assert 0 <= root < 2 ** 128
 ids.root.low = root
 ids.root.high = 0"#;
 
+pub(crate) const UINT256_SQRT_FELT: &str = r#"from starkware.python.math_utils import isqrt
+n = (ids.n.high << 128) + ids.n.low
+root = isqrt(n)
+assert 0 <= root < 2 ** 128
+ids.root = root"#;
+
 pub(crate) const UINT256_SIGNED_NN: &str =
     "memory[ap] = 1 if 0 <= (ids.a.high % PRIME) < 2 ** 127 else 0";
 
@@ -295,6 +345,137 @@ ids.quotient.high = quotient >> 128
 ids.remainder.low = remainder & ((1 << 128) - 1)
 ids.remainder.high = remainder >> 128"#;
 
+pub(crate) const UINT256_EXPANDED_UNSIGNED_DIV_REM: &str = r#"a = (ids.a.high << 128) + ids.a.low
+div = (ids.div.b23 << 128) + ids.div.b01
+quotient, remainder = divmod(a, div)
+
+ids.quotient.low = quotient & ((1 << 128) - 1)
+ids.quotient.high = quotient >> 128
+ids.remainder.low = remainder & ((1 << 128) - 1)
+ids.remainder.high = remainder >> 128"#;
+
+pub(crate) const UINT256_MUL_DIV_MOD: &str = r#"a = (ids.a.high << 128) + ids.a.low
+b = (ids.b.high << 128) + ids.b.low
+div = (ids.div.high << 128) + ids.div.low
+quotient, remainder = divmod(a * b, div)
+
+ids.quotient_low.low = quotient & ((1 << 128) - 1)
+ids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)
+ids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)
+ids.quotient_high.high = quotient >> 384
+ids.remainder.low = remainder & ((1 << 128) - 1)
+ids.remainder.high = remainder >> 128"#;
+
+pub(crate) const UINT256_SUB: &str = r#"def split(num: int, num_bits_shift: int = 128, length: int = 2):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int = 128) -> int:
+    limbs = (z.low, z.high)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack(ids.a)
+b = pack(ids.b)
+res = (a - b)%2**256
+res_split = split(res)
+ids.res.low = res_split[0]
+ids.res.high = res_split[1]"#;
+
+pub(crate) const UINT384_UNSIGNED_DIV_REM: &str = r#"def split(num: int, num_bits_shift: int, length: int):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack(ids.a, num_bits_shift = 128)
+div = pack(ids.div, num_bits_shift = 128)
+quotient, remainder = divmod(a, div)
+
+quotient_split = split(quotient, num_bits_shift=128, length=3)
+assert len(quotient_split) == 3
+
+ids.quotient.d0 = quotient_split[0]
+ids.quotient.d1 = quotient_split[1]
+ids.quotient.d2 = quotient_split[2]
+
+remainder_split = split(remainder, num_bits_shift=128, length=3)
+ids.remainder.d0 = remainder_split[0]
+ids.remainder.d1 = remainder_split[1]
+ids.remainder.d2 = remainder_split[2]"#;
+
+pub(crate) const ADD_NO_UINT384_CHECK: &str = r#"sum_d0 = ids.a.d0 + ids.b.d0
+ids.carry_d0 = 1 if sum_d0 >= ids.SHIFT else 0
+sum_d1 = ids.a.d1 + ids.b.d1 + ids.carry_d0
+ids.carry_d1 = 1 if sum_d1 >= ids.SHIFT else 0
+sum_d2 = ids.a.d2 + ids.b.d2 + ids.carry_d1
+ids.carry_d2 = 1 if sum_d2 >= ids.SHIFT else 0"#;
+
+pub(crate) const UINT384_SQRT: &str = r#"from starkware.python.math_utils import isqrt
+
+def split(num: int, num_bits_shift: int, length: int):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack(ids.a, num_bits_shift=128)
+root = isqrt(a)
+assert 0 <= root < 2 ** 192
+root_split = split(root, num_bits_shift=128, length=3)
+ids.root.d0 = root_split[0]
+ids.root.d1 = root_split[1]
+ids.root.d2 = root_split[2]"#;
+
+pub(crate) const UINT384_SIGNED_NN: &str =
+    "memory[ap] = 1 if 0 <= (ids.a.d2 % PRIME) < 2 ** 127 else 0";
+
+pub(crate) const UNSIGNED_DIV_REM_UINT768_BY_UINT384: &str = r#"def split(num: int, num_bits_shift: int, length: int):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+def pack_extended(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2, z.d3, z.d4, z.d5)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack_extended(ids.a, num_bits_shift = 128)
+div = pack(ids.div, num_bits_shift = 128)
+
+quotient, remainder = divmod(a, div)
+
+quotient_split = split(quotient, num_bits_shift=128, length=6)
+
+ids.quotient.d0 = quotient_split[0]
+ids.quotient.d1 = quotient_split[1]
+ids.quotient.d2 = quotient_split[2]
+ids.quotient.d3 = quotient_split[3]
+ids.quotient.d4 = quotient_split[4]
+ids.quotient.d5 = quotient_split[5]
+
+remainder_split = split(remainder, num_bits_shift=128, length=3)
+ids.remainder.d0 = remainder_split[0]
+ids.remainder.d1 = remainder_split[1]
+ids.remainder.d2 = remainder_split[2]"#;
+
 pub(crate) const USORT_ENTER_SCOPE: &str =
     "vm_enter_scope(dict(__usort_max_size = globals().get('__usort_max_size')))";
 pub(crate) const USORT_BODY: &str = r#"from collections import defaultdict
@@ -535,6 +716,110 @@ pub(crate) const FAST_EC_ADD_ASSIGN_NEW_Y: &str =
 
 pub(crate) const EC_MUL_INNER: &str = r#"memory[ap] = (ids.scalar % PRIME) % 2"#;
 
+pub(crate) const IMPORT_SECP256R1_P: &str =
+    "from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P";
+
+pub(crate) const IMPORT_SECP256R1_ALPHA: &str =
+    "from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_ALPHA as ALPHA";
+
+pub(crate) const IMPORT_SECP256R1_N: &str =
+    "from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_N as N";
+
+pub(crate) const VERIFY_ZERO_EXTERNAL_SECP: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+q, r = divmod(pack(ids.val, PRIME), SECP_P)
+assert r == 0, f"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}."
+ids.q = q % PRIME"#;
+
+pub(crate) const REDUCE_EXTERNAL_SECP: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+value = pack(ids.x, PRIME) % SECP_P"#;
+
+pub(crate) const IS_ZERO_PACK_EXTERNAL_SECP: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+x = pack(ids.x, PRIME) % SECP_P"#;
+
+pub(crate) const IS_ZERO_PACK_EXTERNAL_SECP_V2: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+x = pack(ids.x, PRIME) % SECP_P"#;
+
+pub(crate) const IS_ZERO_ASSIGN_SCOPE_VARS_EXTERNAL_SECP: &str = r#"from starkware.python.math_utils import div_mod
+
+value = x_inv = div_mod(1, x, SECP_P)"#;
+
+pub(crate) const DIV_MOD_N_PACKED_DIVMOD_EXTERNAL_N: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+value = res = div_mod(a, b, N)"#;
+
+pub(crate) const EC_DOUBLE_SCOPE_EXTERNAL_CONSTS: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import ec_double_slope
+
+# Compute the slope.
+x = pack(ids.point.x, PRIME)
+y = pack(ids.point.y, PRIME)
+value = slope = ec_double_slope(point=(x, y), alpha=ALPHA, p=SECP_P)"#;
+
+pub(crate) const COMPUTE_SLOPE_EXTERNAL_SECP: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import line_slope
+
+# Compute the slope.
+x0 = pack(ids.point0.x, PRIME)
+y0 = pack(ids.point0.y, PRIME)
+x1 = pack(ids.point1.x, PRIME)
+y1 = pack(ids.point1.y, PRIME)
+value = slope = line_slope(point1=(x0, y0), point2=(x1, y1), p=SECP_P)"#;
+
+pub(crate) const EC_DOUBLE_ASSIGN_NEW_X_EXTERNAL_SECP: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+slope = pack(ids.slope, PRIME)
+x = pack(ids.point.x, PRIME)
+y = pack(ids.point.y, PRIME)
+
+value = new_x = (pow(slope, 2, SECP_P) - 2 * x) % SECP_P"#;
+
+pub(crate) const FAST_EC_ADD_ASSIGN_NEW_X_EXTERNAL_SECP: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+slope = pack(ids.slope, PRIME)
+x0 = pack(ids.point0.x, PRIME)
+x1 = pack(ids.point1.x, PRIME)
+y0 = pack(ids.point0.y, PRIME)
+
+value = new_x = (pow(slope, 2, SECP_P) - x0 - x1) % SECP_P"#;
+
+pub(crate) const EC_RECOVER_DIV_MOD_N_PACKED: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+N = pack(ids.n, PRIME)
+x = pack(ids.x, PRIME) % N
+s = pack(ids.s, PRIME) % N
+value = res = div_mod(x, s, N)"#;
+
+pub(crate) const EC_RECOVER_SUB_A_B: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+
+value = res = a - b"#;
+
+pub(crate) const EC_RECOVER_PRODUCT_MOD: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+product = a * b
+m = pack(ids.m, PRIME)
+
+value = res = product % m"#;
+
+pub(crate) const EC_RECOVER_PRODUCT_DIV_M: &str = "value = k = product // m";
+
+pub(crate) const NONDET_N_GREATER_THAN_10: &str =
+    "memory[ap] = to_felt_or_relocatable(ids.n >= 10)";
+
+pub(crate) const NONDET_N_GREATER_THAN_2: &str = "memory[ap] = to_felt_or_relocatable(ids.n >= 2)";
+
 pub(crate) const RELOCATE_SEGMENT: &str =
     r#"memory.add_relocation_rule(src_ptr=ids.src_ptr, dest_ptr=ids.dest_ptr)"#;
 
@@ -543,3 +828,296 @@ pub(crate) const VERIFY_ECDSA_SIGNATURE: &str =
     r#"ecdsa_builtin.add_signature(ids.ecdsa_ptr.address_, (ids.signature_r, ids.signature_s))"#;
 #[cfg(feature = "skip_next_instruction_hint")]
 pub(crate) const SKIP_NEXT_INSTRUCTION: &str = "skip_next_instruction()";
+
+pub(crate) const ADD_ONCHAIN_DATA_PAGES: &str = r#"from starkware.python.math_utils import div_ceil
+onchain_data_start = ids.da_start
+onchain_data_size = ids.output_ptr - onchain_data_start
+
+max_page_size = 3800
+n_pages = div_ceil(onchain_data_size, max_page_size)
+for i in range(n_pages):
+    start_offset = i * max_page_size
+    output_builtin.add_page(
+        page_id=1 + i,
+        page_start=onchain_data_start + start_offset,
+        page_size=min(onchain_data_size - start_offset, max_page_size),
+    )
+# Set the tree structure to a root with two children:
+# * A leaf which represents the main part
+# * An inner node for the onchain data part (which contains n_pages children).
+#
+# This is encoded using the following sequence:
+output_builtin.add_attribute('gps_fact_topology', [
+    # Push 1 + n_pages pages (all of the pages).
+    1 + n_pages,
+    # Create a parent node for the last n_pages.
+    n_pages,
+    # Don't push additional pages.
+    0,
+    # Take the first page (the main part) and the node that was created (onchain data)
+    # and use them to construct the root of the fact tree.
+    2,
+])"#;
+
+pub(crate) const STORAGE_READ: &str =
+    "syscall_handler.storage_read(segments=segments, syscall_ptr=ids.syscall_ptr)";
+pub(crate) const STORAGE_WRITE: &str =
+    "syscall_handler.storage_write(segments=segments, syscall_ptr=ids.syscall_ptr)";
+pub(crate) const GET_CALLER_ADDRESS: &str =
+    "syscall_handler.get_caller_address(segments=segments, syscall_ptr=ids.syscall_ptr)";
+pub(crate) const GET_CONTRACT_ADDRESS: &str =
+    "syscall_handler.get_contract_address(segments=segments, syscall_ptr=ids.syscall_ptr)";
+pub(crate) const EMIT_EVENT: &str =
+    "syscall_handler.emit_event(segments=segments, syscall_ptr=ids.syscall_ptr)";
+pub(crate) const CALL_CONTRACT: &str =
+    "syscall_handler.call_contract(segments=segments, syscall_ptr=ids.syscall_ptr)";
+pub(crate) const LIBRARY_CALL: &str =
+    "syscall_handler.library_call(segments=segments, syscall_ptr=ids.syscall_ptr)";
+
+pub(crate) const SIMPLE_BOOTLOADER_LOAD_INPUT: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.objects import SimpleBootloaderInput
+simple_bootloader_input = SimpleBootloaderInput.Schema().load(program_input)"#;
+
+pub(crate) const SIMPLE_BOOTLOADER_PREPARE_TASK_RANGE_CHECKS: &str = r#"n_tasks = len(simple_bootloader_input.tasks)
+memory[ids.output_ptr] = n_tasks
+
+# Task range checks are located right after simple bootloader validation range checks, and
+# this is validated later in this function.
+ids.task_range_check_ptr = ids.range_check_ptr + ids.BuiltinData.SIZE * n_tasks
+
+# A list of fact_toplogies that instruct how to generate the fact from the program output
+# for each task.
+fact_topologies = []"#;
+
+pub(crate) const SIMPLE_BOOTLOADER_SET_TASKS_VARIABLE: &str =
+    "tasks = simple_bootloader_input.tasks";
+
+pub(crate) const SIMPLE_BOOTLOADER_SET_CURRENT_TASK: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.objects import Task
+
+# Pass current task to execute_task.
+task_id = len(simple_bootloader_input.tasks) - ids.n_tasks
+task = simple_bootloader_input.tasks[task_id].load_task()"#;
+
+pub(crate) const SIMPLE_BOOTLOADER_CONFIGURE_FACT_TOPOLOGIES: &str = r#"# Dump fact topologies to a json file.
+from starkware.cairo.bootloaders.simple_bootloader.utils import (
+    configure_fact_topologies,
+    write_to_fact_topologies_file,
+)
+
+# The task-related output is prefixed by a single word that contains the number of tasks.
+tasks_output_start = output_builtin.base + 1
+
+if not simple_bootloader_input.single_page:
+    # Configure the memory pages in the output builtin, based on fact_topologies.
+    configure_fact_topologies(
+        fact_topologies=fact_topologies, output_start=tasks_output_start,
+        output_builtin=output_builtin,
+    )
+
+if simple_bootloader_input.fact_topologies_path is not None:
+    write_to_fact_topologies_file(
+        fact_topologies_path=simple_bootloader_input.fact_topologies_path,
+        fact_topologies=fact_topologies,
+    )"#;
+
+pub(crate) const EXECUTE_TASK_ALLOCATE_PROGRAM_DATA_SEGMENT: &str =
+    "ids.program_data_ptr = program_data_base = segments.add()";
+
+pub(crate) const EXECUTE_TASK_LOAD_PROGRAM: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.utils import load_program
+
+# Call load_program to load the program header and code to memory.
+program_address, program_data_size = load_program(
+    task=task, memory=memory, program_header=ids.program_header,
+    builtins_offset=ids.ProgramHeader.builtin_list)
+segments.finalize(program_data_base.segment_index, program_data_size)"#;
+
+pub(crate) const EXECUTE_TASK_VALIDATE_HASH: &str = r#"# Validate hash.
+from starkware.cairo.bootloaders.hash_program import compute_program_hash_chain
+
+assert memory[ids.output_ptr + 1] == compute_program_hash_chain(task.get_program()), \
+  'Computed hash does not match input.'"#;
+
+pub(crate) const EXECUTE_TASK_ASSERT_PROGRAM_ADDRESS: &str = r#"# Sanity check.
+assert ids.program_address == program_address"#;
+
+pub(crate) const EXECUTE_TASK_CALL_TASK: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.objects import (
+    CairoPieTask,
+    RunProgramTask,
+    Task,
+)
+from starkware.cairo.bootloaders.simple_bootloader.utils import (
+    load_cairo_pie,
+    prepare_output_runner,
+)
+
+assert isinstance(task, Task)
+n_builtins = len(task.get_program().builtins)
+new_task_locals = {}
+if isinstance(task, RunProgramTask):
+    new_task_locals['program_input'] = task.program_input
+    new_task_locals['WITH_BOOTLOADER'] = True
+
+    vm_load_program(task.program, program_address)
+elif isinstance(task, CairoPieTask):
+    ret_pc = ids.ret_pc_label.instruction_offset_ - ids.call_task.instruction_offset_ + pc
+    load_cairo_pie(
+        task=task.cairo_pie, memory=memory, segments=segments,
+        program_address=program_address, execution_segment_address= ap - n_builtins,
+        builtin_runners=builtin_runners, ret_fp=fp, ret_pc=ret_pc)
+else:
+    raise NotImplementedError(f'Unexpected task type: {type(task).__name__}.')
+
+output_runner_data = prepare_output_runner(
+    task=task,
+    output_builtin=output_builtin,
+    output_ptr=ids.pre_execution_builtin_ptrs.output)
+vm_enter_scope(new_task_locals)"#;
+
+pub(crate) const EXECUTE_TASK_WRITE_RETURN_BUILTINS: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.utils import write_return_builtins
+
+# Fill the values of all builtin pointers after executing the task.
+builtins = task.get_program().builtins
+write_return_builtins(
+    memory=memory, return_builtins_addr=ids.return_builtin_ptrs.address_,
+    used_builtins=builtins, used_builtins_addr=ids.used_builtins_addr,
+    pre_execution_builtins_addr=ids.pre_execution_builtin_ptrs.address_, task=task)
+
+vm_enter_scope({'n_selected_builtins': n_builtins})"#;
+
+pub(crate) const EXECUTE_TASK_APPEND_FACT_TOPOLOGIES: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.utils import get_task_fact_topology
+
+# Add the fact topology of the current task to 'fact_topologies'.
+output_start = ids.pre_execution_builtin_ptrs.output
+output_end = ids.return_builtin_ptrs.output
+fact_topologies.append(get_task_fact_topology(
+    output_size=output_end - output_start,
+    task=task,
+    output_builtin=output_builtin,
+    output_runner_data=output_runner_data,
+))"#;
+
+pub(crate) const SELECT_BUILTINS_ENTER_SCOPE: &str =
+    "vm_enter_scope({'n_selected_builtins': ids.n_selected_builtins})";
+
+pub(crate) const INNER_SELECT_BUILTINS_SELECT_BUILTIN: &str = r#"# A builtin should be selected iff its encoding appears in the selected encodings list
+# and the list wasn't exhausted.
+# Note that testing inclusion by a single comparison is possible since the lists are sorted.
+ids.select_builtin = int(
+  n_selected_builtins > 0 and memory[ids.selected_encodings] == memory[ids.all_encodings])
+if ids.select_builtin:
+  n_selected_builtins = n_selected_builtins - 1"#;
+
+pub(crate) const BOOTLOADER_PREPARE_SIMPLE_BOOTLOADER_OUTPUT_SEGMENT: &str = r#"from starkware.cairo.bootloaders.bootloader.objects import BootloaderInput
+bootloader_input = BootloaderInput.Schema().load(program_input)
+
+ids.simple_bootloader_output_start = segments.add()
+
+# Change output builtin state to a different segment in preparation for calling the
+# simple bootloader.
+output_builtin_state = output_builtin.get_state()
+output_builtin.new_state(base=ids.simple_bootloader_output_start)"#;
+
+pub(crate) const BOOTLOADER_PREPARE_SIMPLE_BOOTLOADER_INPUT: &str =
+    "simple_bootloader_input = bootloader_input";
+
+pub(crate) const BOOTLOADER_RESTORE_BOOTLOADER_OUTPUT: &str = r#"# Restore the bootloader's output builtin state.
+output_builtin.set_state(output_builtin_state)"#;
+
+pub(crate) const BOOTLOADER_LOAD_BOOTLOADER_CONFIG: &str = r#"from starkware.cairo.bootloaders.bootloader.objects import BootloaderConfig
+bootloader_config: BootloaderConfig = bootloader_input.bootloader_config
+
+ids.bootloader_config = segments.gen_arg(
+    [
+        bootloader_config.simple_bootloader_program_hash,
+        len(bootloader_config.supported_cairo_verifier_program_hashes),
+        bootloader_config.supported_cairo_verifier_program_hashes,
+    ],
+)"#;
+
+pub(crate) const BOOTLOADER_SAVE_OUTPUT_POINTER: &str = "output_start = ids.output_ptr";
+
+pub(crate) const BOOTLOADER_SAVE_PACKED_OUTPUTS: &str =
+    "packed_outputs = bootloader_input.packed_outputs";
+
+pub(crate) const BOOTLOADER_COMPUTE_FACT_TOPOLOGIES: &str = r#"from typing import List
+
+from starkware.cairo.bootloaders.bootloader.utils import compute_fact_topologies
+from starkware.cairo.bootloaders.fact_topology import FactTopology
+from starkware.cairo.bootloaders.simple_bootloader.utils import (
+    configure_fact_topologies,
+    write_to_fact_topologies_file,
+)
+
+# Compute the fact topologies of the plain packed outputs based on packed_outputs and
+# fact_topologies of the inner tasks.
+plain_fact_topologies: List[FactTopology] = compute_fact_topologies(
+    packed_outputs=packed_outputs, fact_topologies=fact_topologies,
+)
+
+# Configure the memory pages in the output builtin, based on plain_fact_topologies.
+configure_fact_topologies(
+    fact_topologies=plain_fact_topologies, output_start=output_start,
+    output_builtin=output_builtin,
+)
+
+# Dump fact topologies to a json file.
+if bootloader_input.fact_topologies_path is not None:
+    write_to_fact_topologies_file(
+        fact_topologies_path=bootloader_input.fact_topologies_path,
+        fact_topologies=plain_fact_topologies,
+    )"#;
+
+// Hints written differently by previous versions of cairo-lang, which are implemented by the
+// hints they are mapped to in HINT_CODE_VARIANTS
+
+pub(crate) const BLAKE2S_FINALIZE_V2: &str = r#"# Add dummy pairs of input and output.
+from starkware.cairo.common.cairo_blake2s.blake2s_utils import IV, blake2s_compress
+
+_n_packed_instances = int(ids.N_PACKED_INSTANCES)
+assert 0 <= _n_packed_instances < 20
+_blake2s_input_chunk_size_felts = int(ids.BLAKE2S_INPUT_CHUNK_SIZE_FELTS)
+assert 0 <= _blake2s_input_chunk_size_felts < 100
+
+message = [0] * _blake2s_input_chunk_size_felts
+modified_iv = [IV[0] ^ 0x01010020] + IV[1:]
+output = blake2s_compress(
+    message=message,
+    h=modified_iv,
+    t0=0,
+    t1=0,
+    f0=0xffffffff,
+    f1=0,
+)
+padding = (modified_iv + message + [0, 0xffffffff] + output) * (_n_packed_instances - 1)
+segments.write_arg(ids.blake2s_ptr_end, padding)"#;
+
+pub(crate) const VERIFY_ZERO_V2: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import SECP_P
+q, r = divmod(pack(ids.val, PRIME), SECP_P)
+assert r == 0, f"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}."
+ids.q = q % PRIME"#;
+
+pub(crate) const NONDET_BIGINT3_V2: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import split
+segments.write_arg(ids.res.address_, split(value))"#;
+
+pub(crate) const IS_ZERO_PACK_V2: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack
+x = pack(ids.x, PRIME) % SECP_P"#;
+
+pub(crate) const IS_ZERO_INT: &str = "memory[ap] = int(x == 0)";
+
+pub(crate) const BLOCK_PERMUTATION_V2: &str = r#"from starkware.cairo.common.keccak_utils.keccak_utils import keccak_func
+_keccak_state_size_felts = int(ids.KECCAK_STATE_SIZE_FELTS)
+assert 0 <= _keccak_state_size_felts < 100
+
+output_values = keccak_func(memory.get_range(
+    ids.keccak_ptr - _keccak_state_size_felts, _keccak_state_size_felts))
+segments.write_arg(ids.keccak_ptr, output_values)"#;
+
+/// Pairs of a historical variant of a hint, and the hint of the current version of cairo-lang
+/// that implements it.
+pub(crate) const HINT_CODE_VARIANTS: [(&str, &str); 6] = [
+    (BLAKE2S_FINALIZE_V2, BLAKE2S_FINALIZE),
+    (VERIFY_ZERO_V2, VERIFY_ZERO),
+    (NONDET_BIGINT3_V2, NONDET_BIGINT3),
+    (IS_ZERO_PACK_V2, IS_ZERO_PACK),
+    (IS_ZERO_INT, IS_ZERO_NONDET),
+    (BLOCK_PERMUTATION_V2, BLOCK_PERMUTATION),
+];
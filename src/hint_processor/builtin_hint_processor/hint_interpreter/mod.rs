@@ -0,0 +1,1002 @@
+//! Interpreter for hints written in a small subset of Python, which runs the hints that aren't
+//! implemented by the `BuiltinHintProcessor`.
+//!
+//! The subset covers the one-line hints that are common in Cairo code, such as
+//! `ids.x = ids.y * 2 % PRIME` or `memory[ap] = to_felt_or_relocatable(ids.a < ids.b)`:
+//! * Assignments, including tuple and augmented assignments, and `assert` statements.
+//! * Integer literals, arithmetic, bitwise, comparison and boolean operators, with Python's
+//!   semantics on integers of up to 4096 bits, and conditional expressions.
+//! * `ids.<name>` for the felt and pointer ids of the hint and the constants of the program,
+//!   `memory[<address>]`, `ap`, `fp`, `pc`, `PRIME`, `True` and `False`.
+//! * Variables of the current execution scope, which assignments to plain names create.
+//! * The functions `to_felt_or_relocatable`, `divmod`, `pow`, `abs`, `min`, `max`, `int`
+//!   and `segments.add`.
+//!
+//! Values written to memory are reduced modulo the prime. Hints outside the subset, including
+//! those calling other functions or using variables that aren't in scope, are left unknown.
+
+mod parser;
+
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::{
+            builtin_hint_processor_definition::HintProcessorData,
+            hint_utils::{get_maybe_relocatable_from_var_name, insert_value_from_var_name},
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{
+        errors::{hint_errors::HintError, memory_errors::MemoryError},
+        vm_core::VirtualMachine,
+    },
+};
+use felt::{Felt, PRIME_STR};
+use lazy_static::lazy_static;
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{Num, Signed, ToPrimitive, Zero};
+pub(crate) use parser::Statement;
+use parser::{parse_hint, BinOp, CmpOp, Expr, UnaryOp};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+lazy_static! {
+    static ref PRIME: BigInt = BigInt::from_str_radix(&PRIME_STR[2..], 16).unwrap();
+}
+
+// Names with a meaning of their own, which can't be assigned to
+const RESERVED_NAMES: [&str; 9] = [
+    "ids", "memory", "segments", "ap", "fp", "pc", "PRIME", "True", "False",
+];
+
+// Functions the hints can call, besides `segments.add`
+const FUNCTIONS: [&str; 7] = [
+    "to_felt_or_relocatable",
+    "int",
+    "abs",
+    "divmod",
+    "pow",
+    "min",
+    "max",
+];
+
+// Size limit of the ints computed by the hints, which would otherwise be able to exhaust the
+// memory with a single `**` or `<<`
+const MAX_INT_BITS: u64 = 4096;
+
+/// A Python value: either an int or a relocatable address, or a tuple of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Value {
+    Int(BigInt),
+    Relocatable(Relocatable),
+    Tuple(Vec<Value>),
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Int(BigInt::from(value as u8))
+    }
+}
+
+impl From<MaybeRelocatable> for Value {
+    fn from(value: MaybeRelocatable) -> Self {
+        match value {
+            MaybeRelocatable::Int(value) => Value::Int(value.to_biguint().into()),
+            MaybeRelocatable::RelocatableValue(value) => Value::Relocatable(value),
+        }
+    }
+}
+
+impl Value {
+    fn to_int(&self) -> Result<&BigInt, HintError> {
+        match self {
+            Value::Int(value) => Ok(value),
+            value => Err(interpreter_error(format!("expected an int, got {value:?}"))),
+        }
+    }
+
+    fn to_maybe_relocatable(&self) -> Result<MaybeRelocatable, HintError> {
+        match self {
+            Value::Int(value) => Ok(Felt::from(value.mod_floor(&PRIME)).into()),
+            Value::Relocatable(value) => Ok((*value).into()),
+            Value::Tuple(_) => Err(interpreter_error(
+                "tuples can't be written to memory".to_string(),
+            )),
+        }
+    }
+
+    fn is_true(&self) -> bool {
+        match self {
+            Value::Int(value) => !value.is_zero(),
+            Value::Relocatable(_) => true,
+            Value::Tuple(values) => !values.is_empty(),
+        }
+    }
+}
+
+fn interpreter_error(message: String) -> HintError {
+    HintError::HintInterpreter(message)
+}
+
+/// Runs `code` as a hint written in the Python subset of the interpreter. Returns
+/// `HintError::UnknownHint` if the code isn't part of the subset.
+pub fn execute_python_subset_hint(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
+    code: &str,
+) -> Result<(), HintError> {
+    let statements = parse_hint(code).map_err(|_| HintError::UnknownHint(code.to_string()))?;
+    execute_statements(
+        vm,
+        exec_scopes,
+        ids_data,
+        ap_tracking,
+        constants,
+        code,
+        &statements,
+    )
+}
+
+/// Parses `code` as a hint written in the Python subset of the interpreter, or returns `None` if
+/// its syntax isn't part of the subset. Hints are parsed once, when they are compiled.
+pub(crate) fn parse_python_subset_hint(code: &str) -> Option<Vec<Statement>> {
+    parse_hint(code).ok()
+}
+
+/// Runs a compiled hint in the Python subset of the interpreter, using the statements parsed when
+/// it was compiled. Returns `HintError::UnknownHint` if the code isn't part of the subset.
+pub(crate) fn execute_compiled_python_subset_hint(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt>,
+) -> Result<(), HintError> {
+    let statements = hint_data
+        .python_subset_statements
+        .as_deref()
+        .ok_or_else(|| HintError::UnknownHint(hint_data.known_code.clone()))?;
+    execute_statements(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        constants,
+        &hint_data.known_code,
+        statements,
+    )
+}
+
+// Checks that the statements are supported before running them, so that unsupported hints
+// don't run partially
+fn execute_statements(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
+    code: &str,
+    statements: &[Statement],
+) -> Result<(), HintError> {
+    let mut support_check = SupportCheck {
+        exec_scopes,
+        ids_data,
+        constants,
+        assigned: HashSet::new(),
+    };
+    if !statements
+        .iter()
+        .all(|statement| support_check.statement(statement))
+    {
+        return Err(HintError::UnknownHint(code.to_string()));
+    }
+    let mut interpreter = Interpreter {
+        vm,
+        exec_scopes,
+        ids_data,
+        ap_tracking,
+        constants,
+    };
+    for statement in statements.iter() {
+        interpreter.execute(statement)?;
+    }
+    Ok(())
+}
+
+struct Interpreter<'a> {
+    vm: &'a mut VirtualMachine,
+    exec_scopes: &'a mut ExecutionScopes,
+    ids_data: &'a HashMap<String, HintReference>,
+    ap_tracking: &'a ApTracking,
+    constants: &'a HashMap<String, Felt>,
+}
+
+impl Interpreter<'_> {
+    fn execute(&mut self, statement: &Statement) -> Result<(), HintError> {
+        match statement {
+            Statement::Assign { targets, value } => {
+                let value = self.eval(value)?;
+                if let [target] = targets.as_slice() {
+                    return self.assign(target, value);
+                }
+                let values = match value {
+                    Value::Tuple(values) if values.len() == targets.len() => values,
+                    value => {
+                        return Err(interpreter_error(format!(
+                            "can't unpack {value:?} into {} values",
+                            targets.len()
+                        )))
+                    }
+                };
+                for (target, value) in targets.iter().zip(values) {
+                    self.assign(target, value)?;
+                }
+                Ok(())
+            }
+            Statement::AugAssign { target, op, value } => {
+                let current = self.eval(target)?;
+                let value = self.eval(value)?;
+                let value = binary_op(current, *op, value)?;
+                self.assign(target, value)
+            }
+            Statement::Assert { test, message } => {
+                if self.eval(test)?.is_true() {
+                    return Ok(());
+                }
+                Err(HintError::AssertionFailed(
+                    message
+                        .clone()
+                        .unwrap_or_else(|| "Assertion failed".to_string()),
+                ))
+            }
+        }
+    }
+
+    fn assign(&mut self, target: &Expr, value: Value) -> Result<(), HintError> {
+        match target {
+            Expr::Name(name) if !RESERVED_NAMES.contains(&name.as_str()) => {
+                match value {
+                    Value::Int(value) => self.exec_scopes.insert_value(name, value),
+                    Value::Relocatable(value) => self.exec_scopes.insert_value(name, value),
+                    Value::Tuple(_) => {
+                        return Err(interpreter_error(format!(
+                            "tuples can't be assigned to {name}"
+                        )))
+                    }
+                }
+                Ok(())
+            }
+            Expr::Attribute(base, name) if is_ids(base) => insert_value_from_var_name(
+                name,
+                value.to_maybe_relocatable()?,
+                self.vm,
+                self.ids_data,
+                self.ap_tracking,
+            ),
+            Expr::Subscript(base, address) if is_name(base, "memory") => {
+                let address = self.eval_address(address)?;
+                self.vm
+                    .insert_value(address, value.to_maybe_relocatable()?)
+                    .map_err(HintError::Memory)
+            }
+            target => Err(interpreter_error(format!("can't assign to {target:?}"))),
+        }
+    }
+
+    fn eval_address(&mut self, address: &Expr) -> Result<Relocatable, HintError> {
+        match self.eval(address)? {
+            Value::Relocatable(address) => Ok(address),
+            value => Err(interpreter_error(format!(
+                "expected a memory address, got {value:?}"
+            ))),
+        }
+    }
+
+    fn eval(&mut self, expr: &Expr) -> Result<Value, HintError> {
+        match expr {
+            Expr::Int(value) => Ok(Value::Int(value.clone())),
+            Expr::Name(name) => self.eval_name(name),
+            Expr::Attribute(base, name) if is_ids(base) => self.eval_ids(name),
+            Expr::Subscript(base, address) if is_name(base, "memory") => {
+                let address = self.eval_address(address)?;
+                self.vm
+                    .get_maybe(&address)
+                    .map(Value::from)
+                    .ok_or(HintError::Memory(MemoryError::UnknownMemoryCell(address)))
+            }
+            Expr::Call(function, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| self.eval(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.call(function, args)
+            }
+            Expr::Tuple(exprs) => Ok(Value::Tuple(
+                exprs
+                    .iter()
+                    .map(|expr| self.eval(expr))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Expr::UnaryOp(op, operand) => {
+                let operand = self.eval(operand)?;
+                match op {
+                    UnaryOp::Not => Ok(Value::from(!operand.is_true())),
+                    UnaryOp::Pos => Ok(Value::Int(operand.to_int()?.clone())),
+                    UnaryOp::Neg => Ok(Value::Int(-operand.to_int()?.clone())),
+                    UnaryOp::Invert => Ok(Value::Int(-operand.to_int()?.clone() - 1)),
+                }
+            }
+            Expr::BinOp(left, op, right) => {
+                let left = self.eval(left)?;
+                let right = self.eval(right)?;
+                binary_op(left, *op, right)
+            }
+            Expr::And(left, right) => {
+                let left = self.eval(left)?;
+                if !left.is_true() {
+                    return Ok(left);
+                }
+                self.eval(right)
+            }
+            Expr::Or(left, right) => {
+                let left = self.eval(left)?;
+                if left.is_true() {
+                    return Ok(left);
+                }
+                self.eval(right)
+            }
+            Expr::Compare(first, comparisons) => {
+                let mut left = self.eval(first)?;
+                for (op, right) in comparisons.iter() {
+                    let right = self.eval(right)?;
+                    if !compare(&left, *op, &right)? {
+                        return Ok(Value::from(false));
+                    }
+                    left = right;
+                }
+                Ok(Value::from(true))
+            }
+            Expr::IfExp { test, body, orelse } => {
+                if self.eval(test)?.is_true() {
+                    self.eval(body)
+                } else {
+                    self.eval(orelse)
+                }
+            }
+            expr => Err(interpreter_error(format!(
+                "unsupported expression {expr:?}"
+            ))),
+        }
+    }
+
+    fn eval_name(&self, name: &str) -> Result<Value, HintError> {
+        match name {
+            "PRIME" => return Ok(Value::Int(PRIME.clone())),
+            "True" => return Ok(Value::from(true)),
+            "False" => return Ok(Value::from(false)),
+            "ap" => return Ok(Value::Relocatable(self.vm.get_ap())),
+            "fp" => return Ok(Value::Relocatable(self.vm.get_fp())),
+            "pc" => return Ok(Value::Relocatable(self.vm.get_pc())),
+            _ => {}
+        }
+        if let Some(variable) = self.exec_scopes.get_local_variables()?.get(name) {
+            return variable_value(variable.as_ref())
+                .ok_or_else(|| interpreter_error(format!("{name} is not an int or an address")));
+        }
+        match self.get_constant(name) {
+            Some(value) => Ok(value),
+            None => Err(HintError::VariableNotInScopeError(name.to_string())),
+        }
+    }
+
+    fn eval_ids(&self, name: &str) -> Result<Value, HintError> {
+        if self.ids_data.contains_key(name) {
+            return get_maybe_relocatable_from_var_name(
+                name,
+                self.vm,
+                self.ids_data,
+                self.ap_tracking,
+            )
+            .map(Value::from);
+        }
+        self.get_constant(name)
+            .ok_or_else(|| HintError::UnknownIdentifier(name.to_string()))
+    }
+
+    fn get_constant(&self, name: &str) -> Option<Value> {
+        get_constant(self.constants, name).map(|value| Value::Int(value.to_biguint().into()))
+    }
+
+    fn call(&mut self, function: &Expr, args: Vec<Value>) -> Result<Value, HintError> {
+        let name = match function {
+            Expr::Name(name) => name.as_str(),
+            Expr::Attribute(base, name) if is_name(base, "segments") && name == "add" => {
+                check_arg_count("segments.add", &args, 0)?;
+                return Ok(Value::Relocatable(self.vm.add_memory_segment()));
+            }
+            function => {
+                return Err(interpreter_error(format!(
+                    "unsupported function {function:?}"
+                )))
+            }
+        };
+        match name {
+            "to_felt_or_relocatable" => {
+                check_arg_count(name, &args, 1)?;
+                match &args[0] {
+                    Value::Int(value) => Ok(Value::Int(value.mod_floor(&PRIME))),
+                    value => Ok(value.clone()),
+                }
+            }
+            "int" => {
+                check_arg_count(name, &args, 1)?;
+                Ok(Value::Int(args[0].to_int()?.clone()))
+            }
+            "abs" => {
+                check_arg_count(name, &args, 1)?;
+                Ok(Value::Int(args[0].to_int()?.abs()))
+            }
+            "divmod" => {
+                check_arg_count(name, &args, 2)?;
+                let (a, b) = (args[0].to_int()?, args[1].to_int()?);
+                if b.is_zero() {
+                    return Err(division_by_zero());
+                }
+                let (q, r) = a.div_mod_floor(b);
+                Ok(Value::Tuple(vec![Value::Int(q), Value::Int(r)]))
+            }
+            "pow" => match args.as_slice() {
+                [base, exponent] => binary_op(base.clone(), BinOp::Pow, exponent.clone()),
+                [base, exponent, modulus] => {
+                    let (base, exponent, modulus) =
+                        (base.to_int()?, exponent.to_int()?, modulus.to_int()?);
+                    if modulus.is_zero() {
+                        return Err(interpreter_error(
+                            "pow() 3rd argument cannot be 0".to_string(),
+                        ));
+                    }
+                    if exponent.is_negative() {
+                        return Err(interpreter_error(
+                            "negative exponents are not supported".to_string(),
+                        ));
+                    }
+                    // Rounds like Python, with the sign of the modulus
+                    Ok(Value::Int(base.modpow(exponent, modulus)))
+                }
+                _ => Err(interpreter_error(
+                    "pow expects 2 or 3 arguments".to_string(),
+                )),
+            },
+            "min" | "max" => {
+                let mut values = match args.as_slice() {
+                    [Value::Tuple(values)] => values.iter(),
+                    args => args.iter(),
+                };
+                let mut result = values
+                    .next()
+                    .ok_or_else(|| interpreter_error(format!("{name} expects an argument")))?;
+                for value in values {
+                    let op = if name == "min" { CmpOp::Lt } else { CmpOp::Gt };
+                    if compare(value, op, result)? {
+                        result = value;
+                    }
+                }
+                Ok(result.clone())
+            }
+            name => Err(interpreter_error(format!("unsupported function {name}"))),
+        }
+    }
+}
+
+/// Returns the constant called `name`. Constants are given by their full path, and are looked up
+/// by their name, which must not be ambiguous.
+pub(crate) fn get_constant<'a>(
+    constants: &'a HashMap<String, Felt>,
+    name: &str,
+) -> Option<&'a Felt> {
+    let suffix = format!(".{name}");
+    let mut matches = constants
+        .iter()
+        .filter(|(path, _)| *path == name || path.ends_with(&suffix));
+    match (matches.next(), matches.next()) {
+        (Some((_, value)), None) => Some(value),
+        _ => None,
+    }
+}
+
+// Hints are only run if all of their code is supported, so that the other ones are left to other
+// hint processors instead of failing halfway through their execution
+struct SupportCheck<'a> {
+    exec_scopes: &'a ExecutionScopes,
+    ids_data: &'a HashMap<String, HintReference>,
+    constants: &'a HashMap<String, Felt>,
+    // Variables assigned by the previous statements of the hint
+    assigned: HashSet<&'a str>,
+}
+
+impl<'a> SupportCheck<'a> {
+    fn statement(&mut self, statement: &'a Statement) -> bool {
+        match statement {
+            Statement::Assign { targets, value } => {
+                self.expr(value) && targets.iter().all(|target| self.target(target))
+            }
+            Statement::AugAssign { target, value, .. } => {
+                self.expr(target) && self.expr(value) && self.target(target)
+            }
+            Statement::Assert { test, .. } => self.expr(test),
+        }
+    }
+
+    fn target(&mut self, target: &'a Expr) -> bool {
+        match target {
+            Expr::Name(name) if !RESERVED_NAMES.contains(&name.as_str()) => {
+                self.assigned.insert(name);
+                true
+            }
+            Expr::Attribute(base, name) => is_ids(base) && self.is_single_value_ids(name),
+            Expr::Subscript(base, address) => is_name(base, "memory") && self.expr(address),
+            _ => false,
+        }
+    }
+
+    fn expr(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Int(_) => true,
+            Expr::Name(name) => self.is_known(name),
+            Expr::Attribute(base, name) => is_ids(base) && self.is_single_value_ids(name),
+            Expr::Subscript(base, address) => is_name(base, "memory") && self.expr(address),
+            Expr::Call(function, args) => {
+                let supported_function = match function.as_ref() {
+                    Expr::Name(name) => FUNCTIONS.contains(&name.as_str()),
+                    Expr::Attribute(base, name) => is_name(base, "segments") && name == "add",
+                    _ => false,
+                };
+                supported_function && args.iter().all(|arg| self.expr(arg))
+            }
+            Expr::Tuple(exprs) => exprs.iter().all(|expr| self.expr(expr)),
+            Expr::UnaryOp(_, operand) => self.expr(operand),
+            Expr::BinOp(left, _, right) | Expr::And(left, right) | Expr::Or(left, right) => {
+                self.expr(left) && self.expr(right)
+            }
+            Expr::Compare(first, comparisons) => {
+                self.expr(first) && comparisons.iter().all(|(_, expr)| self.expr(expr))
+            }
+            Expr::IfExp { test, body, orelse } => {
+                self.expr(test) && self.expr(body) && self.expr(orelse)
+            }
+        }
+    }
+
+    // Whether `ids.name` is a felt or a pointer, or a constant. Structs are proxies of several
+    // cells in Python, which the interpreter doesn't implement
+    fn is_single_value_ids(&self, name: &str) -> bool {
+        self.ids_data.get(name).map_or(true, |reference| {
+            reference.cairo_type.as_deref().map_or(true, |cairo_type| {
+                cairo_type == "felt" || cairo_type.ends_with('*')
+            })
+        })
+    }
+
+    // Whether `name` is a value the interpreter can evaluate
+    fn is_known(&self, name: &str) -> bool {
+        let is_scope_value = || {
+            self.exec_scopes
+                .get_local_variables()
+                .ok()
+                .and_then(|variables| variables.get(name))
+                .map_or(false, |variable| {
+                    variable_value(variable.as_ref()).is_some()
+                })
+        };
+        matches!(name, "PRIME" | "True" | "False" | "ap" | "fp" | "pc")
+            || self.assigned.contains(name)
+            || is_scope_value()
+            || get_constant(self.constants, name).is_some()
+    }
+}
+
+fn is_name(expr: &Expr, expected: &str) -> bool {
+    matches!(expr, Expr::Name(name) if name == expected)
+}
+
+fn is_ids(expr: &Expr) -> bool {
+    is_name(expr, "ids")
+}
+
+fn check_arg_count(name: &str, args: &[Value], count: usize) -> Result<(), HintError> {
+    if args.len() != count {
+        return Err(interpreter_error(format!(
+            "{name} expects {count} arguments, got {}",
+            args.len()
+        )));
+    }
+    Ok(())
+}
+
+fn division_by_zero() -> HintError {
+    interpreter_error("division by zero".to_string())
+}
+
+fn int_too_large() -> HintError {
+    interpreter_error(format!("ints can't be larger than {MAX_INT_BITS} bits"))
+}
+
+// Reads the scope variables written by the interpreter or by other hints
+fn variable_value(variable: &dyn std::any::Any) -> Option<Value> {
+    if let Some(value) = variable.downcast_ref::<BigInt>() {
+        Some(Value::Int(value.clone()))
+    } else if let Some(value) = variable.downcast_ref::<Felt>() {
+        Some(Value::Int(value.to_biguint().into()))
+    } else if let Some(value) = variable.downcast_ref::<BigUint>() {
+        Some(Value::Int(value.clone().into()))
+    } else if let Some(value) = variable.downcast_ref::<usize>() {
+        Some(Value::Int((*value).into()))
+    } else if let Some(value) = variable.downcast_ref::<u64>() {
+        Some(Value::Int((*value).into()))
+    } else if let Some(value) = variable.downcast_ref::<bool>() {
+        Some(Value::from(*value))
+    } else if let Some(value) = variable.downcast_ref::<Relocatable>() {
+        Some(Value::Relocatable(*value))
+    } else {
+        variable
+            .downcast_ref::<MaybeRelocatable>()
+            .map(|value| Value::from(value.clone()))
+    }
+}
+
+fn binary_op(left: Value, op: BinOp, right: Value) -> Result<Value, HintError> {
+    let (a, b) = match (left, op, right) {
+        (Value::Int(a), _, Value::Int(b)) => (a, b),
+        (Value::Relocatable(a), BinOp::Add, Value::Int(b))
+        | (Value::Int(b), BinOp::Add, Value::Relocatable(a)) => {
+            return add_offset(a, &b).map(Value::Relocatable)
+        }
+        (Value::Relocatable(a), BinOp::Sub, Value::Int(b)) => {
+            return add_offset(a, &-b).map(Value::Relocatable)
+        }
+        (Value::Relocatable(a), BinOp::Sub, Value::Relocatable(b))
+            if a.segment_index == b.segment_index =>
+        {
+            return Ok(Value::Int(BigInt::from(a.offset) - BigInt::from(b.offset)))
+        }
+        (left, op, right) => {
+            return Err(interpreter_error(format!(
+                "unsupported operands {left:?} {op:?} {right:?}"
+            )))
+        }
+    };
+    let result = match op {
+        BinOp::Add => a + b,
+        BinOp::Sub => a - b,
+        BinOp::Mul => a * b,
+        BinOp::Div => {
+            return Err(interpreter_error(
+                "true division is not supported, use // instead".to_string(),
+            ))
+        }
+        BinOp::FloorDiv | BinOp::Mod if b.is_zero() => return Err(division_by_zero()),
+        BinOp::FloorDiv => a.div_floor(&b),
+        BinOp::Mod => a.mod_floor(&b),
+        BinOp::Pow => {
+            let exponent = b
+                .to_u32()
+                .ok_or_else(|| interpreter_error(format!("unsupported exponent {b}")))?;
+            // |a| ** exponent is at least 2 ** ((a.bits() - 1) * exponent)
+            if a.bits().saturating_sub(1) * u64::from(exponent) > MAX_INT_BITS {
+                return Err(int_too_large());
+            }
+            a.pow(exponent)
+        }
+        BinOp::LShift | BinOp::RShift => {
+            let shift = b
+                .to_usize()
+                .ok_or_else(|| interpreter_error(format!("unsupported shift count {b}")))?;
+            if op == BinOp::RShift {
+                a >> shift
+            } else if a.is_zero() {
+                a
+            } else if shift as u64 + a.bits() > MAX_INT_BITS {
+                return Err(int_too_large());
+            } else {
+                a << shift
+            }
+        }
+        BinOp::BitAnd => a & b,
+        BinOp::BitOr => a | b,
+        BinOp::BitXor => a ^ b,
+    };
+    if result.bits() > MAX_INT_BITS {
+        return Err(int_too_large());
+    }
+    Ok(Value::Int(result))
+}
+
+fn add_offset(address: Relocatable, offset: &BigInt) -> Result<Relocatable, HintError> {
+    (BigInt::from(address.offset) + offset)
+        .to_usize()
+        .map(|offset| Relocatable::from((address.segment_index, offset)))
+        .ok_or_else(|| interpreter_error(format!("invalid offset for {address}")))
+}
+
+fn compare(left: &Value, op: CmpOp, right: &Value) -> Result<bool, HintError> {
+    let ordering = match (left, right) {
+        (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+        (Value::Relocatable(a), Value::Relocatable(b)) if a.segment_index == b.segment_index => {
+            Some(a.offset.cmp(&b.offset))
+        }
+        _ => None,
+    };
+    match (op, ordering) {
+        (CmpOp::Eq, ordering) => Ok(ordering == Some(Ordering::Equal)),
+        (CmpOp::NotEq, ordering) => Ok(ordering != Some(Ordering::Equal)),
+        (CmpOp::Lt, Some(ordering)) => Ok(ordering.is_lt()),
+        (CmpOp::LtE, Some(ordering)) => Ok(ordering.is_le()),
+        (CmpOp::Gt, Some(ordering)) => Ok(ordering.is_gt()),
+        (CmpOp::GtE, Some(ordering)) => Ok(ordering.is_ge()),
+        (op, None) => Err(interpreter_error(format!(
+            "can't compare {left:?} {op:?} {right:?}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::builtin_hint_processor_definition::{
+                BuiltinHintProcessor, HintProcessorData,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        utils::test_utils::*,
+        vm::vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+    };
+    use assert_matches::assert_matches;
+    use std::any::Any;
+
+    #[test]
+    fn run_arithmetic_hint() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 5)];
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["y", "x"];
+        assert_matches!(
+            run_hint!(vm, ids_data, "ids.x = (ids.y * 2 - 11) % PRIME"),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 1), (-1))];
+    }
+
+    #[test]
+    fn run_comparison_into_memory() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 3), ((1, 1), 4)];
+        vm.run_context.fp = 2;
+        vm.run_context.ap = 2;
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["a", "b"],
+                "memory[ap] = to_felt_or_relocatable(ids.a < ids.b)"
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 2), 1)];
+    }
+
+    #[test]
+    fn run_bitwise_hint_with_constant() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        // a = 2**130 + 7
+        vm.insert_value(
+            Relocatable::from((1, 0)),
+            Felt::from(BigInt::from(1) << 130_usize) + Felt::new(7),
+        )
+        .unwrap();
+        vm.run_context.fp = 2;
+        let constants = HashMap::from([(
+            "starkware.cairo.common.uint256.SHIFT".to_string(),
+            Felt::new(1_u128 << 127),
+        )]);
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["a", "res"],
+                "ids.res = (ids.a >> 128) | ids.SHIFT",
+                &mut exec_scopes,
+                &constants
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            vm.get_integer(Relocatable::from((1, 1))).unwrap().as_ref(),
+            &Felt::new((1_u128 << 127) + 4)
+        );
+    }
+
+    #[test]
+    fn run_multiple_statements_with_scope_variables() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 17)];
+        vm.run_context.fp = 3;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("base", Felt::new(5));
+        let code = "ids.q, ids.r = divmod(ids.a, base)\nn = ids.q + ids.r\nn += 10";
+        assert_matches!(
+            run_hint!(vm, ids_data!["a", "q", "r"], code, &mut exec_scopes),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 1), 3), ((1, 2), 2)];
+        assert_matches!(exec_scopes.get::<BigInt>("n"), Ok(n) if n == BigInt::from(15));
+    }
+
+    #[test]
+    fn run_segments_add() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        assert_matches!(
+            run_hint!(vm, HashMap::new(), "memory[ap] = segments.add()"),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 0), (2, 0))];
+    }
+
+    #[test]
+    fn run_relocatable_arithmetic() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 3)), ((1, 1), (2, 7))];
+        vm.run_context.fp = 3;
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data!["start", "end", "size"],
+                "ids.size = ids.end - ids.start; memory[fp] = ids.start + 1"
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 2), 4), ((1, 3), (2, 4))];
+    }
+
+    #[test]
+    fn run_failing_assert() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 3)];
+        vm.run_context.fp = 1;
+        assert_matches!(
+            run_hint!(vm, ids_data!["a"], "assert ids.a % 2 == 0, 'a must be even'"),
+            Err(HintError::AssertionFailed(message)) if message == "a must be even"
+        );
+    }
+
+    #[test]
+    fn run_division_by_zero() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 3), ((1, 1), 0)];
+        vm.run_context.fp = 3;
+        assert_matches!(
+            run_hint!(vm, ids_data!["a", "b", "c"], "ids.c = ids.a // ids.b"),
+            Err(HintError::HintInterpreter(_))
+        );
+    }
+
+    #[test]
+    fn huge_ints_are_rejected() {
+        let mut vm = vm!();
+        for code in [
+            "x = 1 << (1 << 40)",
+            "x = 3 ** 4294967295",
+            "x = 2 ** 4000 * 2 ** 4000",
+        ] {
+            assert_matches!(
+                run_hint!(vm, HashMap::new(), code),
+                Err(HintError::HintInterpreter(message)) if message == "ints can't be larger than 4096 bits"
+            );
+        }
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                "x = 1 ** 4294967295 + (2 ** 4000 >> (1 << 40)) + (0 << (1 << 40))",
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(exec_scopes.get::<BigInt>("x").unwrap(), BigInt::from(1));
+    }
+
+    #[test]
+    fn python_semantics_of_ints() {
+        let mut vm = vm!();
+        let mut exec_scopes = ExecutionScopes::new();
+        let code = "a = -7 // 2; b = -7 % 2; c = -1 >> 1; d = ~5; e = pow(3, 4, 5); f = 2 ** 3 ** 2; g = 0 or 5 and 6; h = max(3, -1, 8)";
+        assert_matches!(
+            run_hint!(vm, HashMap::new(), code, &mut exec_scopes),
+            Ok(())
+        );
+        for (name, value) in [
+            ("a", -4),
+            ("b", 1),
+            ("c", -1),
+            ("d", -6),
+            ("e", 1),
+            ("f", 512),
+            ("g", 6),
+            ("h", 8),
+        ] {
+            assert_eq!(
+                exec_scopes.get::<BigInt>(name).unwrap(),
+                BigInt::from(value),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_variable() {
+        let mut vm = vm!();
+        assert_matches!(
+            run_hint!(vm, HashMap::new(), "x = y + 1"),
+            Err(HintError::UnknownHint(_))
+        );
+        assert_matches!(
+            run_hint!(vm, HashMap::new(), "y = 1\nx = y + 1\nx += y"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn code_outside_subset_is_unknown() {
+        let mut vm = vm!();
+        let code = "import math\nids.x = math.floor(1)";
+        assert_matches!(
+            run_hint!(vm, HashMap::new(), code),
+            Err(HintError::UnknownHint(hint)) if hint == code
+        );
+        // Calls to other functions are rejected before anything runs
+        let mut exec_scopes = ExecutionScopes::new();
+        let code = "x = 1\ny = sqrt(x)";
+        assert_matches!(
+            run_hint!(vm, HashMap::new(), code, &mut exec_scopes),
+            Err(HintError::UnknownHint(hint)) if hint == code
+        );
+        assert!(exec_scopes.get::<BigInt>("x").is_err());
+    }
+
+    #[test]
+    fn struct_ids_are_unknown() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 3), ((1, 1), 4), ((1, 2), (1, 0))];
+        vm.run_context.fp = 3;
+        vm.run_context.ap = 3;
+        let mut ids_data = ids_data!["point", "ptr"];
+        ids_data.get_mut("point").unwrap().cairo_type =
+            Some("starkware.cairo.common.ec_point.EcPoint".to_string());
+        ids_data.get_mut("ptr").unwrap().cairo_type =
+            Some("starkware.cairo.common.ec_point.EcPoint*".to_string());
+        let code = "memory[ap] = ids.point";
+        assert_matches!(
+            run_hint!(vm, ids_data.clone(), code),
+            Err(HintError::UnknownHint(hint)) if hint == code
+        );
+        assert_matches!(
+            run_hint!(vm, ids_data.clone(), "ids.point = 1"),
+            Err(HintError::UnknownHint(_))
+        );
+        assert_matches!(run_hint!(vm, ids_data, "memory[ap] = ids.ptr"), Ok(()));
+        check_memory![vm.segments.memory, ((1, 3), (1, 0))];
+    }
+}
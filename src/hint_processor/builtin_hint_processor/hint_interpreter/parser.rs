@@ -0,0 +1,710 @@
+//! Tokenizer and parser of the Python subset understood by the hint interpreter.
+//!
+//! The grammar is a strict subset of Python's, so a hint is either parsed with its Python meaning
+//! or rejected:
+//!
+//! ```text
+//! hint      := statement ((NEWLINE | ';') statement)*
+//! statement := "assert" expr ["," STRING]
+//!            | targets "=" exprs
+//!            | target AUG_OP expr
+//! expr      := or_expr ["if" or_expr "else" expr]
+//! or_expr   := and_expr ("or" and_expr)*
+//! and_expr  := not_expr ("and" not_expr)*
+//! not_expr  := "not" not_expr | comparison
+//! comparison:= bit_or (CMP_OP bit_or)*
+//! bit_or    := bit_xor ("|" bit_xor)*, and so on down to shifts, sums and products
+//! factor    := ("-" | "+" | "~") factor | power
+//! power     := primary ["**" factor]
+//! primary   := atom ("." NAME | "[" expr "]" | "(" [exprs] ")")*
+//! atom      := NAME | NUMBER | "(" exprs ")"
+//! ```
+
+use num_bigint::BigInt;
+use num_traits::Num;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Name(String),
+    Int(BigInt),
+    Str(String),
+    Op(&'static str),
+    Newline,
+}
+
+// Longest operators first, so that they are matched before their prefixes
+const OPERATORS: [&str; 41] = [
+    "**=", "//=", "<<=", ">>=", "**", "//", "<<", ">>", "<=", ">=", "==", "!=", "+=", "-=", "*=",
+    "%=", "&=", "|=", "^=", "+", "-", "*", "/", "%", "&", "|", "^", "~", "<", ">", "=", "(", ")",
+    "[", "]", ",", ".", ";", ":", "{", "}",
+];
+
+fn tokenize(code: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut depth = 0_usize;
+    let mut chars = code.char_indices().peekable();
+    let mut line_start = true;
+
+    while let Some(&(start, c)) = chars.peek() {
+        if line_start && depth == 0 {
+            line_start = false;
+            // Hints are dedented, and the subset has no blocks
+            if c == ' ' || c == '\t' {
+                let rest = &code[start..];
+                let first = rest.trim_start_matches([' ', '\t']).chars().next();
+                if !matches!(first, None | Some('\n') | Some('#')) {
+                    return Err("unexpected indentation".to_string());
+                }
+            }
+        }
+        match c {
+            '\n' => {
+                chars.next();
+                if depth == 0 {
+                    if !matches!(tokens.last(), None | Some(Token::Newline)) {
+                        tokens.push(Token::Newline);
+                    }
+                    line_start = true;
+                }
+            }
+            ' ' | '\t' | '\r' => {
+                chars.next();
+            }
+            '\\' => {
+                chars.next();
+                match chars.next() {
+                    Some((_, '\n')) => {}
+                    _ => return Err("unexpected character after line continuation".to_string()),
+                }
+            }
+            '#' => {
+                while matches!(chars.peek(), Some(&(_, c)) if c != '\n') {
+                    chars.next();
+                }
+            }
+            '\'' | '"' => {
+                chars.next();
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, quote)) if quote == c => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, escaped @ ('\\' | '\'' | '"'))) => string.push(escaped),
+                            Some((_, 'n')) => string.push('\n'),
+                            _ => return Err("unsupported escape sequence".to_string()),
+                        },
+                        Some((_, '\n')) | None => return Err("unterminated string".to_string()),
+                        Some((_, c)) => string.push(c),
+                    }
+                }
+                tokens.push(Token::Str(string));
+            }
+            c if c.is_ascii_digit() => {
+                let mut end = start;
+                while let Some(&(index, c)) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    end = index + c.len_utf8();
+                    chars.next();
+                }
+                tokens.push(Token::Int(parse_int(&code[start..end])?));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = start;
+                while let Some(&(index, c)) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    end = index + c.len_utf8();
+                    chars.next();
+                }
+                let name = &code[start..end];
+                // String prefixes, as in f-strings, aren't supported
+                if matches!(chars.peek(), Some(&(_, '\'' | '"'))) {
+                    return Err(format!("unsupported string prefix {name}"));
+                }
+                tokens.push(Token::Name(name.to_string()));
+            }
+            _ => {
+                let op = OPERATORS
+                    .iter()
+                    .find(|op| code[start..].starts_with(*op))
+                    .ok_or_else(|| format!("unexpected character {c}"))?;
+                for _ in 0..op.len() {
+                    chars.next();
+                }
+                match *op {
+                    "(" | "[" | "{" => depth += 1,
+                    ")" | "]" | "}" => {
+                        depth = depth
+                            .checked_sub(1)
+                            .ok_or_else(|| format!("unmatched {op}"))?
+                    }
+                    _ => {}
+                }
+                tokens.push(Token::Op(op));
+            }
+        }
+    }
+    if depth != 0 {
+        return Err("unclosed bracket".to_string());
+    }
+    Ok(tokens)
+}
+
+fn parse_int(literal: &str) -> Result<BigInt, String> {
+    let digits = literal.replace('_', "");
+    let lowercase = digits.to_ascii_lowercase();
+    let (digits, radix) = if let Some(hex) = lowercase.strip_prefix("0x") {
+        (hex, 16)
+    } else if let Some(octal) = lowercase.strip_prefix("0o") {
+        (octal, 8)
+    } else if let Some(binary) = lowercase.strip_prefix("0b") {
+        (binary, 2)
+    } else if lowercase.len() > 1 && lowercase.starts_with('0') {
+        // Python rejects leading zeros in decimal literals
+        return Err(format!("invalid literal {literal}"));
+    } else {
+        (lowercase.as_str(), 10)
+    };
+    if digits.is_empty() || literal.ends_with('_') || literal.contains("__") {
+        return Err(format!("invalid literal {literal}"));
+    }
+    BigInt::from_str_radix(digits, radix).map_err(|_| format!("invalid literal {literal}"))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    FloorDiv,
+    Mod,
+    Pow,
+    LShift,
+    RShift,
+    BitAnd,
+    BitOr,
+    BitXor,
+}
+
+impl BinOp {
+    fn from_op(op: &str) -> Option<BinOp> {
+        Some(match op {
+            "+" => BinOp::Add,
+            "-" => BinOp::Sub,
+            "*" => BinOp::Mul,
+            "/" => BinOp::Div,
+            "//" => BinOp::FloorDiv,
+            "%" => BinOp::Mod,
+            "**" => BinOp::Pow,
+            "<<" => BinOp::LShift,
+            ">>" => BinOp::RShift,
+            "&" => BinOp::BitAnd,
+            "|" => BinOp::BitOr,
+            "^" => BinOp::BitXor,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum UnaryOp {
+    Neg,
+    Pos,
+    Invert,
+    Not,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CmpOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtE,
+    Gt,
+    GtE,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Expr {
+    Int(BigInt),
+    Name(String),
+    Attribute(Box<Expr>, String),
+    Subscript(Box<Expr>, Box<Expr>),
+    Call(Box<Expr>, Vec<Expr>),
+    Tuple(Vec<Expr>),
+    UnaryOp(UnaryOp, Box<Expr>),
+    BinOp(Box<Expr>, BinOp, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(Box<Expr>, Vec<(CmpOp, Expr)>),
+    IfExp {
+        test: Box<Expr>,
+        body: Box<Expr>,
+        orelse: Box<Expr>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Statement {
+    /// `a, b = c, d` has the targets `[a, b]` and the value `(c, d)`.
+    Assign {
+        targets: Vec<Expr>,
+        value: Expr,
+    },
+    AugAssign {
+        target: Expr,
+        op: BinOp,
+        value: Expr,
+    },
+    Assert {
+        test: Expr,
+        message: Option<String>,
+    },
+}
+
+// Names of Python keywords which aren't part of the subset, and can't be used as variables
+const KEYWORDS: [&str; 27] = [
+    "as", "async", "await", "break", "class", "continue", "def", "del", "elif", "except",
+    "finally", "for", "from", "global", "import", "in", "is", "lambda", "nonlocal", "pass",
+    "raise", "return", "try", "while", "with", "yield", "None",
+];
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek_op(&self) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Op(op)) => Some(op),
+            _ => None,
+        }
+    }
+
+    fn eat_op(&mut self, op: &str) -> bool {
+        if self.peek_op() == Some(op) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_op(&mut self, op: &str) -> Result<(), String> {
+        if self.eat_op(op) {
+            Ok(())
+        } else {
+            Err(format!("expected {op}"))
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Name(name)) if name == keyword) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn statements(&mut self) -> Result<Vec<Statement>, String> {
+        let mut statements = Vec::new();
+        while self.peek().is_some() {
+            statements.push(self.statement()?);
+            match self.next() {
+                None | Some(Token::Newline) => {}
+                Some(Token::Op(";")) => {
+                    if self.peek() == Some(&Token::Newline) {
+                        self.position += 1;
+                    }
+                }
+                Some(token) => return Err(format!("unexpected token {token:?}")),
+            }
+        }
+        if statements.is_empty() {
+            return Err("empty hint".to_string());
+        }
+        Ok(statements)
+    }
+
+    fn statement(&mut self) -> Result<Statement, String> {
+        if self.eat_keyword("assert") {
+            let test = self.expr()?;
+            let message = if self.eat_op(",") {
+                match self.next() {
+                    Some(Token::Str(message)) => Some(message),
+                    _ => return Err("expected an assertion message".to_string()),
+                }
+            } else {
+                None
+            };
+            return Ok(Statement::Assert { test, message });
+        }
+
+        let targets = self.exprs()?;
+        let op = self.peek_op().ok_or("expected an assignment")?;
+        self.position += 1;
+        if op == "=" {
+            let targets = match targets {
+                Expr::Tuple(targets) => targets,
+                target => vec![target],
+            };
+            for target in targets.iter() {
+                check_target(target)?;
+            }
+            let value = self.exprs()?;
+            if self.peek_op() == Some("=") {
+                return Err("chained assignments are not supported".to_string());
+            }
+            return Ok(Statement::Assign { targets, value });
+        }
+        let op = op
+            .strip_suffix('=')
+            .and_then(BinOp::from_op)
+            .ok_or_else(|| format!("unexpected operator {op}"))?;
+        check_target(&targets)?;
+        Ok(Statement::AugAssign {
+            target: targets,
+            op,
+            value: self.expr()?,
+        })
+    }
+
+    // A comma-separated list of expressions, which makes a tuple if there is more than one
+    fn exprs(&mut self) -> Result<Expr, String> {
+        let first = self.expr()?;
+        if self.peek_op() != Some(",") {
+            return Ok(first);
+        }
+        let mut exprs = vec![first];
+        while self.eat_op(",") {
+            if matches!(self.peek_op(), Some(")" | "=")) || self.peek().is_none() {
+                break;
+            }
+            exprs.push(self.expr()?);
+        }
+        Ok(Expr::Tuple(exprs))
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let body = self.or_expr()?;
+        if !self.eat_keyword("if") {
+            return Ok(body);
+        }
+        let test = self.or_expr()?;
+        if !self.eat_keyword("else") {
+            return Err("expected else".to_string());
+        }
+        Ok(Expr::IfExp {
+            test: Box::new(test),
+            body: Box::new(body),
+            orelse: Box::new(self.expr()?),
+        })
+    }
+
+    fn or_expr(&mut self) -> Result<Expr, String> {
+        let mut expr = self.and_expr()?;
+        while self.eat_keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn and_expr(&mut self) -> Result<Expr, String> {
+        let mut expr = self.not_expr()?;
+        while self.eat_keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.not_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn not_expr(&mut self) -> Result<Expr, String> {
+        if self.eat_keyword("not") {
+            return Ok(Expr::UnaryOp(UnaryOp::Not, Box::new(self.not_expr()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let left = self.binary(0)?;
+        let mut comparisons = Vec::new();
+        loop {
+            let op = match self.peek_op() {
+                Some("==") => CmpOp::Eq,
+                Some("!=") => CmpOp::NotEq,
+                Some("<") => CmpOp::Lt,
+                Some("<=") => CmpOp::LtE,
+                Some(">") => CmpOp::Gt,
+                Some(">=") => CmpOp::GtE,
+                _ => break,
+            };
+            self.position += 1;
+            comparisons.push((op, self.binary(0)?));
+        }
+        if comparisons.is_empty() {
+            Ok(left)
+        } else {
+            Ok(Expr::Compare(Box::new(left), comparisons))
+        }
+    }
+
+    // Binary operators by increasing precedence, all of them left-associative
+    const BINARY_LEVELS: [&'static [&'static str]; 6] = [
+        &["|"],
+        &["^"],
+        &["&"],
+        &["<<", ">>"],
+        &["+", "-"],
+        &["*", "/", "//", "%"],
+    ];
+
+    fn binary(&mut self, level: usize) -> Result<Expr, String> {
+        let Some(ops) = Self::BINARY_LEVELS.get(level) else {
+            return self.factor();
+        };
+        let mut expr = self.binary(level + 1)?;
+        while let Some(op) = self.peek_op().filter(|op| ops.contains(op)) {
+            self.position += 1;
+            let op = BinOp::from_op(op).ok_or_else(|| format!("unexpected operator {op}"))?;
+            expr = Expr::BinOp(Box::new(expr), op, Box::new(self.binary(level + 1)?));
+        }
+        Ok(expr)
+    }
+
+    fn factor(&mut self) -> Result<Expr, String> {
+        let op = match self.peek_op() {
+            Some("-") => UnaryOp::Neg,
+            Some("+") => UnaryOp::Pos,
+            Some("~") => UnaryOp::Invert,
+            _ => return self.power(),
+        };
+        self.position += 1;
+        Ok(Expr::UnaryOp(op, Box::new(self.factor()?)))
+    }
+
+    fn power(&mut self) -> Result<Expr, String> {
+        let base = self.primary()?;
+        if self.eat_op("**") {
+            // Right-associative, and binds tighter than a unary operator on its left only
+            return Ok(Expr::BinOp(
+                Box::new(base),
+                BinOp::Pow,
+                Box::new(self.factor()?),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        let mut expr = self.atom()?;
+        loop {
+            if self.eat_op(".") {
+                match self.next() {
+                    Some(Token::Name(name)) => expr = Expr::Attribute(Box::new(expr), name),
+                    _ => return Err("expected an attribute name".to_string()),
+                }
+            } else if self.eat_op("[") {
+                let index = self.exprs()?;
+                self.expect_op("]")?;
+                expr = Expr::Subscript(Box::new(expr), Box::new(index));
+            } else if self.eat_op("(") {
+                let args = if self.eat_op(")") {
+                    vec![]
+                } else {
+                    let args = match self.exprs()? {
+                        Expr::Tuple(args) => args,
+                        arg => vec![arg],
+                    };
+                    self.expect_op(")")?;
+                    args
+                };
+                expr = Expr::Call(Box::new(expr), args);
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn atom(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Int(value)) => Ok(Expr::Int(value)),
+            Some(Token::Name(name)) => {
+                if KEYWORDS.contains(&name.as_str())
+                    || ["assert", "if", "else", "and", "or", "not"].contains(&name.as_str())
+                {
+                    return Err(format!("unsupported keyword {name}"));
+                }
+                Ok(Expr::Name(name))
+            }
+            Some(Token::Op("(")) => {
+                if self.eat_op(")") {
+                    return Ok(Expr::Tuple(vec![]));
+                }
+                let expr = self.exprs()?;
+                self.expect_op(")")?;
+                Ok(expr)
+            }
+            token => Err(format!("unexpected token {token:?}")),
+        }
+    }
+}
+
+fn check_target(target: &Expr) -> Result<(), String> {
+    match target {
+        Expr::Name(_) | Expr::Attribute(..) | Expr::Subscript(..) => Ok(()),
+        _ => Err("invalid assignment target".to_string()),
+    }
+}
+
+/// Parses the code of a hint, returning why it isn't part of the subset otherwise.
+pub(crate) fn parse_hint(code: &str) -> Result<Vec<Statement>, String> {
+    let mut tokens = tokenize(code)?;
+    while tokens.last() == Some(&Token::Newline) {
+        tokens.pop();
+    }
+    Parser {
+        tokens,
+        position: 0,
+    }
+    .statements()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    fn name(name: &str) -> Expr {
+        Expr::Name(name.to_string())
+    }
+
+    fn ids(name: &str) -> Expr {
+        Expr::Attribute(Box::new(Expr::Name("ids".to_string())), name.to_string())
+    }
+
+    fn int(value: i64) -> Expr {
+        Expr::Int(BigInt::from(value))
+    }
+
+    fn bin_op(left: Expr, op: BinOp, right: Expr) -> Expr {
+        Expr::BinOp(Box::new(left), op, Box::new(right))
+    }
+
+    #[test]
+    fn parse_precedence() {
+        assert_eq!(
+            parse_hint("ids.x = ids.y * 2 % PRIME + -1 ** 2").unwrap(),
+            vec![Statement::Assign {
+                targets: vec![ids("x")],
+                value: bin_op(
+                    bin_op(
+                        bin_op(ids("y"), BinOp::Mul, int(2)),
+                        BinOp::Mod,
+                        name("PRIME")
+                    ),
+                    BinOp::Add,
+                    Expr::UnaryOp(UnaryOp::Neg, Box::new(bin_op(int(1), BinOp::Pow, int(2))))
+                ),
+            }]
+        );
+        assert_eq!(
+            parse_hint("ids.res = (ids.a >> 128) & 0xff | 1").unwrap(),
+            vec![Statement::Assign {
+                targets: vec![ids("res")],
+                value: bin_op(
+                    bin_op(
+                        bin_op(ids("a"), BinOp::RShift, int(128)),
+                        BinOp::BitAnd,
+                        int(255)
+                    ),
+                    BinOp::BitOr,
+                    int(1)
+                ),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_statements() {
+        assert_eq!(
+            parse_hint(
+                "# Split a\nids.q, ids.r = divmod(ids.a, 2)\nassert 0 <= ids.q < 10, 'too big'\nx += 1; memory[ap] = 1 if not x else 0\n"
+            )
+            .unwrap(),
+            vec![
+                Statement::Assign {
+                    targets: vec![ids("q"), ids("r")],
+                    value: Expr::Call(Box::new(name("divmod")), vec![ids("a"), int(2)]),
+                },
+                Statement::Assert {
+                    test: Expr::Compare(
+                        Box::new(int(0)),
+                        vec![(CmpOp::LtE, ids("q")), (CmpOp::Lt, int(10))]
+                    ),
+                    message: Some("too big".to_string()),
+                },
+                Statement::AugAssign {
+                    target: name("x"),
+                    op: BinOp::Add,
+                    value: int(1),
+                },
+                Statement::Assign {
+                    targets: vec![Expr::Subscript(Box::new(name("memory")), Box::new(name("ap")))],
+                    value: Expr::IfExp {
+                        test: Box::new(Expr::UnaryOp(UnaryOp::Not, Box::new(name("x")))),
+                        body: Box::new(int(1)),
+                        orelse: Box::new(int(0)),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_multiline_expression() {
+        assert_eq!(
+            parse_hint("ids.x = (\n    ids.a +\n    0b10_01)").unwrap(),
+            vec![Statement::Assign {
+                targets: vec![ids("x")],
+                value: bin_op(ids("a"), BinOp::Add, int(9)),
+            }]
+        );
+    }
+
+    #[test]
+    fn reject_code_outside_subset() {
+        for code in [
+            "random_invalid_code",
+            "vm_enter_scope()",
+            "if ids.a:\n    ids.b = 1",
+            "from starkware.cairo.common.math_utils import as_int",
+            "ids.a = [x for x in range(3)]",
+            "ids.a = f'{x}'",
+            "ids.a = b = 1",
+            "ids.a + 1 = 2",
+            "ids.a = 'string'",
+            "ids.a = 1 if ids.b",
+            "ids.a = (1",
+            "ids.a = 01",
+            "ids.a = None",
+            "",
+        ] {
+            assert_matches!(parse_hint(code), Err(_), "{code}");
+        }
+    }
+}
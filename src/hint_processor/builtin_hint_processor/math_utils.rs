@@ -2,12 +2,12 @@ use crate::{
     any_box,
     hint_processor::{
         builtin_hint_processor::hint_utils::{
-            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
-            insert_value_into_ap,
+            get_integer_from_var_name, get_ptr_from_var_name, get_relocatable_from_var_name,
+            insert_value_from_var_name, insert_value_into_ap,
         },
         hint_processor_definition::HintReference,
     },
-    math_utils::isqrt,
+    math_utils::{div_mod, isqrt, sqrt_prime},
     serde::deserialize_program::ApTracking,
     types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
     vm::{
@@ -16,7 +16,7 @@ use crate::{
     },
 };
 use felt::{Felt, PRIME_STR};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use num_integer::Integer;
 use num_traits::One;
 use num_traits::{Num, Signed, Zero};
@@ -163,6 +163,38 @@ pub fn assert_le_felt_excluded_0(
     }
 }
 
+/*
+Implements hint:
+%{
+    from starkware.cairo.common.math_utils import assert_integer
+    assert_integer(ids.a)
+    assert_integer(ids.b)
+    a = ids.a % PRIME
+    b = ids.b % PRIME
+    assert a <= b, f'a = {a} is not less than or equal to b = {b}.'
+
+    ids.small_inputs = int(
+        a < range_check_builtin.bound and (b - a) < range_check_builtin.bound)
+%}
+*/
+pub fn assert_le_felt_v_0_8(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b = get_integer_from_var_name("b", vm, ids_data, ap_tracking)?;
+    if a > b {
+        return Err(HintError::NonLeFelt(a.into_owned(), b.into_owned()));
+    }
+    let range_check_builtin = vm.get_range_check_builtin()?;
+    let small_inputs = match &range_check_builtin._bound {
+        Some(bound) if a.as_ref() < bound && b.as_ref() - a.as_ref() < *bound => Felt::one(),
+        _ => Felt::zero(),
+    };
+    insert_value_from_var_name("small_inputs", small_inputs, vm, ids_data, ap_tracking)
+}
+
 //Implements hint:from starkware.cairo.common.math_cmp import is_le_felt
 //    memory[ap] = 0 if (ids.a % PRIME) <= (ids.b % PRIME) else 1
 pub fn is_le_felt(
@@ -525,6 +557,153 @@ pub fn assert_lt_felt(
     Ok(())
 }
 
+/*
+Implements hint:
+%{
+    from starkware.crypto.signature.signature import FIELD_PRIME
+    from starkware.python.math_utils import div_mod, is_quad_residue, sqrt
+
+    x = ids.x
+    if is_quad_residue(x, FIELD_PRIME):
+        ids.y = sqrt(x, FIELD_PRIME)
+    else:
+        ids.y = sqrt(div_mod(x, 3, FIELD_PRIME), FIELD_PRIME)
+%}
+*/
+pub fn is_quad_residue(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let x = get_integer_from_var_name("x", vm, ids_data, ap_tracking)?;
+    let prime = BigUint::from_str_radix(&PRIME_STR[2..], 16)
+        .map_err(|_| VirtualMachineError::CouldntParsePrime(PRIME_STR.to_string()))?;
+    //Main logic
+    //3 isn't a quadratic residue modulo FIELD_PRIME, so x / 3 is one when x isn't
+    let y = match sqrt_prime(&x.to_biguint(), &prime) {
+        Some(y) => y,
+        None => {
+            let x_div_3 = div_mod(
+                &x.to_bigint(),
+                &BigInt::from(3),
+                &BigInt::from(prime.clone()),
+            );
+            sqrt_prime(&x_div_3.to_biguint().unwrap_or_default(), &prime).ok_or_else(|| {
+                HintError::AssertionFailed(format!("{x} / 3 is not a quadratic residue"))
+            })?
+        }
+    };
+    insert_value_from_var_name("y", Felt::new(y), vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hint:
+%{
+    x = ids.x
+    ids.bit_length = x.bit_length()
+%}
+*/
+pub fn get_felt_bitlength(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let x = get_integer_from_var_name("x", vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("bit_length", Felt::new(x.bits()), vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hint:
+%{
+    # Verify the assumptions on the relationship between 2**250, ADDR_BOUND and PRIME.
+    ADDR_BOUND = ids.ADDR_BOUND % PRIME
+    assert (2**250 < ADDR_BOUND <= 2**251) and (2 * 2**250 < PRIME) and (
+            ADDR_BOUND * 2 > PRIME), \
+        'normalize_address() cannot be used with the current constants.'
+    ids.is_small = 1 if ids.addr < ADDR_BOUND else 0
+%}
+*/
+pub fn is_addr_bounded(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
+) -> Result<(), HintError> {
+    const ADDR_BOUND: &str = "starkware.starknet.common.storage.ADDR_BOUND";
+
+    let addr_bound = constants
+        .get(ADDR_BOUND)
+        .ok_or(HintError::MissingConstant(ADDR_BOUND))?
+        .to_biguint();
+    let addr = get_integer_from_var_name("addr", vm, ids_data, ap_tracking)?;
+    let prime = BigUint::from_str_radix(&PRIME_STR[2..], 16)
+        .map_err(|_| VirtualMachineError::CouldntParsePrime(PRIME_STR.to_string()))?;
+
+    //Main logic
+    //Verify the assumptions on the relationship between 2**250, ADDR_BOUND and PRIME
+    let two_pow_250 = BigUint::one().shl(250_u32);
+    if !(two_pow_250 < addr_bound
+        && addr_bound <= BigUint::one().shl(251_u32)
+        && &two_pow_250 * 2_u32 < prime
+        && &addr_bound * 2_u32 > prime)
+    {
+        return Err(HintError::AssertionFailed(String::from(
+            "normalize_address() cannot be used with the current constants.",
+        )));
+    }
+    //ids.is_small = 1 if ids.addr < ADDR_BOUND else 0
+    let is_small = if addr.to_biguint() < addr_bound {
+        Felt::one()
+    } else {
+        Felt::zero()
+    };
+    insert_value_from_var_name("is_small", is_small, vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hint:
+%{
+    PRIME = 2**255 - 19
+    II = pow(2, (PRIME - 1) // 4, PRIME)
+
+    xx = ids.xx.low + (ids.xx.high<<128)
+    x = pow(xx, (PRIME + 3) // 8, PRIME)
+    if (x * x - xx) % PRIME != 0:
+        x = (x * II) % PRIME
+    if x % 2 != 0:
+        x = PRIME - x
+    ids.x.low = x & ((1<<128)-1)
+    ids.x.high = x >> 128
+%}
+*/
+pub fn split_xx(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let xx_addr = get_relocatable_from_var_name("xx", vm, ids_data, ap_tracking)?;
+    let x_addr = get_relocatable_from_var_name("x", vm, ids_data, ap_tracking)?;
+    let xx_low = vm.get_integer(xx_addr)?.to_biguint();
+    let xx_high = vm.get_integer(xx_addr + 1_usize)?.to_biguint();
+
+    //Main logic
+    //The square root is computed modulo the prime of curve25519, 2**255 - 19
+    let prime = BigUint::one().shl(255_u32) - 19_u32;
+    let ii = BigUint::from(2_u32).modpow(&((&prime - 1_u32) / 4_u32), &prime);
+    let xx = (xx_high.shl(128_u32) + xx_low).mod_floor(&prime);
+    let mut x = xx.modpow(&((&prime + 3_u32) / 8_u32), &prime);
+    if (&x * &x).mod_floor(&prime) != xx {
+        x = (x * ii).mod_floor(&prime);
+    }
+    if x.is_odd() {
+        x = &prime - x;
+    }
+
+    vm.insert_value(x_addr, Felt::new(&x & BigUint::from(u128::MAX)))?;
+    vm.insert_value(x_addr + 1_usize, Felt::new(x.shr(128_u32)))
+        .map_err(HintError::Memory)
+}
+
 fn div_prime_by_bound(bound: Felt) -> Result<Felt, VirtualMachineError> {
     let prime = BigUint::from_str_radix(&PRIME_STR[2..], 16)
         .map_err(|_| VirtualMachineError::CouldntParsePrime(PRIME_STR.to_string()))?;
@@ -541,6 +720,7 @@ mod tests {
         any_box,
         hint_processor::builtin_hint_processor::{
             builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+            hint_code,
             hint_code::ASSERT_LE_FELT,
         },
         hint_processor::hint_processor_definition::HintProcessor,
@@ -1877,4 +2057,226 @@ mod tests {
             )) if x == "b" && y == (1,2).into()
         );
     }
+
+    #[test]
+    fn run_assert_le_felt_v_0_8_small_inputs() {
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 3;
+        //Insert ids into memory
+        vm.segments = segments![((1, 0), 1), ((1, 1), 2)];
+        let ids_data = ids_data!["a", "b", "small_inputs"];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::ASSERT_LE_FELT_V_0_8),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 2), 1)];
+    }
+
+    #[test]
+    fn run_assert_le_felt_v_0_8_big_inputs() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 3;
+        vm.segments = segments![
+            ((1, 0), 1),
+            (
+                (1, 1),
+                (
+                    "1606938044258990275541962092341162602522202993782792835301376",
+                    10
+                )
+            )
+        ];
+        let ids_data = ids_data!["a", "b", "small_inputs"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::ASSERT_LE_FELT_V_0_8),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 2), 0)];
+    }
+
+    #[test]
+    fn run_assert_le_felt_v_0_8_assert_fails() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 3;
+        vm.segments = segments![((1, 0), 3), ((1, 1), 2)];
+        let ids_data = ids_data!["a", "b", "small_inputs"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::ASSERT_LE_FELT_V_0_8),
+            Err(HintError::NonLeFelt(a, b)) if a == Felt::new(3) && b == Felt::new(2)
+        );
+    }
+
+    #[test]
+    fn run_is_quad_residue_ok() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 2;
+        vm.segments = segments![((1, 0), 4)];
+        let ids_data = ids_data!["x", "y"];
+        assert_matches!(run_hint!(vm, ids_data, hint_code::IS_QUAD_RESIDUE), Ok(()));
+        check_memory![vm.segments.memory, ((1, 1), 2)];
+    }
+
+    #[test]
+    fn run_is_quad_residue_not_residue() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        //3 isn't a quadratic residue, so the root of 3 / 3 is written
+        vm.segments = segments![((1, 0), 3)];
+        let ids_data = ids_data!["x", "y"];
+        assert_matches!(run_hint!(vm, ids_data, hint_code::IS_QUAD_RESIDUE), Ok(()));
+        check_memory![vm.segments.memory, ((1, 1), 1)];
+    }
+
+    #[test]
+    fn run_is_quad_residue_zero() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.segments = segments![((1, 0), 0)];
+        let ids_data = ids_data!["x", "y"];
+        assert_matches!(run_hint!(vm, ids_data, hint_code::IS_QUAD_RESIDUE), Ok(()));
+        check_memory![vm.segments.memory, ((1, 1), 0)];
+    }
+
+    #[test]
+    fn run_get_felt_bitlength_ok() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        //x = 2**250 + 1
+        vm.segments = segments![(
+            (1, 0),
+            (
+                "1809251394333065553493296640760748560207343510400633813116524750123642650625",
+                10
+            )
+        )];
+        let ids_data = ids_data!["x", "bit_length"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::GET_FELT_BIT_LENGTH),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 1), 251)];
+    }
+
+    #[test]
+    fn run_get_felt_bitlength_zero() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.segments = segments![((1, 0), 0)];
+        let ids_data = ids_data!["x", "bit_length"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::GET_FELT_BIT_LENGTH),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 1), 0)];
+    }
+
+    fn addr_bound_constants(addr_bound: Felt) -> HashMap<String, Felt> {
+        HashMap::from([(
+            "starkware.starknet.common.storage.ADDR_BOUND".to_string(),
+            addr_bound,
+        )])
+    }
+
+    #[test]
+    fn run_is_addr_bounded_small() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.segments = segments![((1, 0), 1)];
+        let ids_data = ids_data!["addr", "is_small"];
+        let constants = addr_bound_constants(Felt::one().shl(251_u32) - Felt::new(256));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::IS_ADDR_BOUNDED,
+                exec_scopes_ref!(),
+                &constants
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 1), 1)];
+    }
+
+    #[test]
+    fn run_is_addr_bounded_big() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        //addr = 2**251
+        vm.segments = segments![(
+            (1, 0),
+            (
+                "3618502788666131106986593281521497120414687020801267626233049500247285301248",
+                10
+            )
+        )];
+        let ids_data = ids_data!["addr", "is_small"];
+        let constants = addr_bound_constants(Felt::one().shl(251_u32) - Felt::new(256));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::IS_ADDR_BOUNDED,
+                exec_scopes_ref!(),
+                &constants
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 1), 0)];
+    }
+
+    #[test]
+    fn run_is_addr_bounded_invalid_addr_bound() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.segments = segments![((1, 0), 1)];
+        let ids_data = ids_data!["addr", "is_small"];
+        let constants = addr_bound_constants(Felt::one().shl(250_u32));
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::IS_ADDR_BOUNDED,
+                exec_scopes_ref!(),
+                &constants
+            ),
+            Err(HintError::AssertionFailed(message))
+                if message == "normalize_address() cannot be used with the current constants."
+        );
+    }
+
+    #[test]
+    fn run_is_addr_bounded_missing_constant() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.segments = segments![((1, 0), 1)];
+        let ids_data = ids_data!["addr", "is_small"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::IS_ADDR_BOUNDED),
+            Err(HintError::MissingConstant(
+                "starkware.starknet.common.storage.ADDR_BOUND"
+            ))
+        );
+    }
+
+    #[test]
+    fn run_split_xx_ok() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 4;
+        //Insert ids.xx into memory
+        vm.segments = segments![
+            ((1, 0), ("96041728533064653893136611586985700281", 10)),
+            ((1, 1), 447909743051131415)
+        ];
+        let ids_data = non_continuous_ids_data![("xx", -4), ("x", -2)];
+        assert_matches!(run_hint!(vm, ids_data, hint_code::SPLIT_XX), Ok(()));
+        check_memory![
+            vm.segments.memory,
+            ((1, 2), ("340282366908592784562140039541644754648", 10)),
+            ((1, 3), ("170141183460469231731687303715884105727", 10))
+        ];
+    }
 }
@@ -1,17 +1,22 @@
 pub mod blake2s_hash;
 pub mod blake2s_utils;
+pub mod bootloader;
 pub mod builtin_hint_processor_definition;
 pub mod cairo_keccak;
 pub mod dict_hint_utils;
 pub mod dict_manager;
 pub mod find_element_hint;
 pub mod hint_code;
+pub mod hint_interpreter;
 pub mod hint_utils;
 pub mod keccak_utils;
 pub mod math_utils;
 pub mod memcpy_hint_utils;
 pub mod memset_utils;
+pub mod output_builtin_hints;
+pub mod poseidon_utils;
 pub mod pow_utils;
+pub mod program_input_utils;
 pub mod secp;
 pub mod segments;
 pub mod set;
@@ -21,5 +26,8 @@ pub mod signature;
 #[cfg_attr(docsrs, doc(cfg(feature = "skip_next_instruction_hint")))]
 pub mod skip_next_instruction;
 pub mod squash_dict_utils;
+pub mod syscall_handler;
+pub mod syscall_request;
 pub mod uint256_utils;
+pub mod uint384_utils;
 pub mod usort;
@@ -0,0 +1,147 @@
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::get_ptr_from_var_name,
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
+};
+use num_integer::Integer;
+use std::collections::HashMap;
+
+const MAX_PAGE_SIZE: usize = 3800;
+
+/*
+Implements hint:
+%{
+    from starkware.python.math_utils import div_ceil
+    onchain_data_start = ids.da_start
+    onchain_data_size = ids.output_ptr - onchain_data_start
+
+    max_page_size = 3800
+    n_pages = div_ceil(onchain_data_size, max_page_size)
+    for i in range(n_pages):
+        start_offset = i * max_page_size
+        output_builtin.add_page(
+            page_id=1 + i,
+            page_start=onchain_data_start + start_offset,
+            page_size=min(onchain_data_size - start_offset, max_page_size),
+        )
+    ...
+    output_builtin.add_attribute('gps_fact_topology', [1 + n_pages, n_pages, 0, 2])
+%}
+*/
+pub fn add_onchain_data_pages(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let onchain_data_start = get_ptr_from_var_name("da_start", vm, ids_data, ap_tracking)?;
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+    let onchain_data_size = output_ptr.sub(&onchain_data_start)?;
+
+    let output_builtin = vm.get_output_builtin()?;
+    let n_pages = Integer::div_ceil(&onchain_data_size, &MAX_PAGE_SIZE);
+    for i in 0..n_pages {
+        let start_offset = i * MAX_PAGE_SIZE;
+        output_builtin
+            .add_page(
+                1 + i,
+                onchain_data_start + start_offset,
+                (onchain_data_size - start_offset).min(MAX_PAGE_SIZE),
+            )
+            .map_err(VirtualMachineError::RunnerError)?;
+    }
+    // The fact tree has the main part as a leaf and the onchain data pages under an inner node
+    output_builtin
+        .add_attribute("gps_fact_topology", vec![1 + n_pages, n_pages, 0, 2])
+        .map_err(VirtualMachineError::RunnerError)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code::ADD_ONCHAIN_DATA_PAGES,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            runners::{
+                builtin_runner::{OutputBuiltinRunner, OUTPUT_BUILTIN_NAME},
+                cairo_pie::PublicMemoryPage,
+            },
+            vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+        },
+    };
+    use assert_matches::assert_matches;
+    use std::any::Any;
+
+    #[test]
+    fn add_onchain_data_pages_valid() {
+        let mut vm = vm!();
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments);
+        vm.builtin_runners = vec![(OUTPUT_BUILTIN_NAME, output_builtin.into())];
+        // da_start = (0, 2), output_ptr = (0, 2 + 2 * MAX_PAGE_SIZE + 10)
+        vm.segments = segments![((1, 0), (0, 2)), ((1, 1), (0, 7612))];
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["da_start", "output_ptr"];
+        assert_matches!(run_hint!(vm, ids_data, ADD_ONCHAIN_DATA_PAGES), Ok(()));
+
+        let state = vm.get_output_builtin().unwrap().get_state();
+        assert_eq!(
+            state.pages,
+            HashMap::from([
+                (
+                    1,
+                    PublicMemoryPage {
+                        start: 2,
+                        size: 3800
+                    }
+                ),
+                (
+                    2,
+                    PublicMemoryPage {
+                        start: 3802,
+                        size: 3800
+                    }
+                ),
+                (
+                    3,
+                    PublicMemoryPage {
+                        start: 7602,
+                        size: 10
+                    }
+                ),
+            ])
+        );
+        assert_eq!(
+            state.attributes,
+            HashMap::from([("gps_fact_topology".to_string(), vec![4, 3, 0, 2])])
+        );
+    }
+
+    #[test]
+    fn add_onchain_data_pages_no_output_builtin() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (0, 2)), ((1, 1), (0, 4))];
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["da_start", "output_ptr"];
+        assert_matches!(
+            run_hint!(vm, ids_data, ADD_ONCHAIN_DATA_PAGES),
+            Err(HintError::Internal(VirtualMachineError::NoOutputBuiltin))
+        );
+    }
+}
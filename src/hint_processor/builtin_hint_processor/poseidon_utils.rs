@@ -0,0 +1,142 @@
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_integer_from_var_name, insert_value_into_ap},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::Felt;
+use std::collections::HashMap;
+
+// The hints of poseidon_hash_many, which absorbs its elements by chunks of 10 while it can, and
+// then by chunks of 2, before the padding. The hash itself is computed by the poseidon builtin,
+// or by crate::poseidon_hash::poseidon_hash_many outside the VM.
+
+/*
+Implements hint:
+%{ memory[ap] = to_felt_or_relocatable(ids.n >= 10) %}
+*/
+pub fn n_greater_than_10(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    n_greater_than(vm, ids_data, ap_tracking, 10)
+}
+
+/*
+Implements hint:
+%{ memory[ap] = to_felt_or_relocatable(ids.n >= 2) %}
+*/
+pub fn n_greater_than_2(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    n_greater_than(vm, ids_data, ap_tracking, 2)
+}
+
+// Writes 1 into memory[ap] if ids.n >= bound, and 0 otherwise
+fn n_greater_than(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    bound: u32,
+) -> Result<(), HintError> {
+    let n = get_integer_from_var_name("n", vm, ids_data, ap_tracking)?;
+    let value = Felt::new((n.as_ref() >= &Felt::new(bound)) as u8);
+    insert_value_into_ap(vm, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{errors::memory_errors::MemoryError, vm_memory::memory::Memory},
+    };
+    use assert_matches::assert_matches;
+    use std::any::Any;
+
+    #[test]
+    fn run_n_greater_than_10_true() {
+        let mut vm = vm!();
+        //Initialize fp and ap
+        vm.run_context.fp = 1;
+        vm.run_context.ap = 3;
+        vm.segments = segments![((1, 0), 21)];
+        let ids_data = ids_data!["n"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::NONDET_N_GREATER_THAN_10),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 3), 1)];
+    }
+
+    #[test]
+    fn run_n_greater_than_10_false() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.run_context.ap = 3;
+        vm.segments = segments![((1, 0), 9)];
+        let ids_data = ids_data!["n"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::NONDET_N_GREATER_THAN_10),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 3), 0)];
+    }
+
+    #[test]
+    fn run_n_greater_than_2_true() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.run_context.ap = 3;
+        vm.segments = segments![((1, 0), 2)];
+        let ids_data = ids_data!["n"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::NONDET_N_GREATER_THAN_2),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 3), 1)];
+    }
+
+    #[test]
+    fn run_n_greater_than_2_false() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.run_context.ap = 3;
+        vm.segments = segments![((1, 0), 1)];
+        let ids_data = ids_data!["n"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::NONDET_N_GREATER_THAN_2),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 3), 0)];
+    }
+
+    #[test]
+    fn run_n_greater_than_2_n_not_integer() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.run_context.ap = 3;
+        vm.segments = segments![((1, 0), (1, 2))];
+        let ids_data = ids_data!["n"];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::NONDET_N_GREATER_THAN_2),
+            Err(HintError::IdentifierNotInteger(name, addr))
+                if name == "n" && addr == (1, 0).into()
+        );
+    }
+}
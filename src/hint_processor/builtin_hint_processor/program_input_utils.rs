@@ -0,0 +1,189 @@
+//! Helpers to read the input of the program from hints.
+//!
+//! As in cairo-lang, the JSON given with `--program_input` is available to the hints of the main
+//! scope as the `program_input` variable.
+
+use crate::{
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::Felt;
+use serde::de::DeserializeOwned;
+
+pub const PROGRAM_INPUT: &str = "program_input";
+
+/// Returns the input of the program.
+pub fn get_program_input(exec_scopes: &ExecutionScopes) -> Result<&serde_json::Value, HintError> {
+    exec_scopes.get_ref::<serde_json::Value>(PROGRAM_INPUT)
+}
+
+fn get_program_input_field<'a>(
+    exec_scopes: &'a ExecutionScopes,
+    key: &str,
+) -> Result<&'a serde_json::Value, HintError> {
+    get_program_input(exec_scopes)?
+        .get(key)
+        .ok_or_else(|| HintError::MissingProgramInputKey(key.to_string()))
+}
+
+/// Returns the value of `key` in the program input, deserialized as `T`.
+pub fn get_program_input_value<T: DeserializeOwned>(
+    exec_scopes: &ExecutionScopes,
+    key: &str,
+) -> Result<T, HintError> {
+    T::deserialize(get_program_input_field(exec_scopes, key)?)
+        .map_err(|err| HintError::InvalidProgramInput(key.to_string(), err.to_string()))
+}
+
+// Felts are given either as JSON numbers, which may be negative or exceed 64 bits, or as hex
+// strings
+fn felt_from_json(key: &str, value: &serde_json::Value) -> Result<Felt, HintError> {
+    let felt = match value {
+        serde_json::Value::Number(number) => Felt::parse_bytes(number.to_string().as_bytes(), 10),
+        serde_json::Value::String(string) => string
+            .strip_prefix("0x")
+            .and_then(|hex| Felt::parse_bytes(hex.as_bytes(), 16)),
+        _ => None,
+    };
+    felt.ok_or_else(|| {
+        HintError::InvalidProgramInput(key.to_string(), format!("expected a felt, got {value}"))
+    })
+}
+
+/// Returns the value of `key` in the program input as a felt.
+pub fn get_program_input_felt(exec_scopes: &ExecutionScopes, key: &str) -> Result<Felt, HintError> {
+    felt_from_json(key, get_program_input_field(exec_scopes, key)?)
+}
+
+/// Returns the value of `key` in the program input as a list of felts.
+pub fn get_program_input_felts(
+    exec_scopes: &ExecutionScopes,
+    key: &str,
+) -> Result<Vec<Felt>, HintError> {
+    match get_program_input_field(exec_scopes, key)? {
+        serde_json::Value::Array(values) => values
+            .iter()
+            .map(|value| felt_from_json(key, value))
+            .collect(),
+        value => Err(HintError::InvalidProgramInput(
+            key.to_string(),
+            format!("expected a list, got {value}"),
+        )),
+    }
+}
+
+fn gen_json_arg(
+    vm: &mut VirtualMachine,
+    key: &str,
+    value: &serde_json::Value,
+) -> Result<MaybeRelocatable, HintError> {
+    match value {
+        serde_json::Value::Array(values) => {
+            let args = values
+                .iter()
+                .map(|value| gen_json_arg(vm, key, value))
+                .collect::<Result<Vec<_>, _>>()?;
+            let base = vm.add_memory_segment();
+            vm.load_data(&base.into(), &args)?;
+            Ok(base.into())
+        }
+        value => felt_from_json(key, value).map(MaybeRelocatable::from),
+    }
+}
+
+/// Writes the value of `key` in the program input to memory, as `segments.gen_arg` does: felts
+/// are returned as they are, while lists are written into a new segment, whose base is returned.
+/// Nested lists are written into segments of their own.
+pub fn gen_program_input_arg(
+    vm: &mut VirtualMachine,
+    exec_scopes: &ExecutionScopes,
+    key: &str,
+) -> Result<MaybeRelocatable, HintError> {
+    gen_json_arg(vm, key, get_program_input_field(exec_scopes, key)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{relocatable, types::relocatable::Relocatable, utils::test_utils::*};
+    use assert_matches::assert_matches;
+
+    fn exec_scopes_with_input(program_input: serde_json::Value) -> ExecutionScopes {
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value(PROGRAM_INPUT, program_input);
+        exec_scopes
+    }
+
+    #[test]
+    fn get_program_input_missing() {
+        assert_matches!(
+            get_program_input(&ExecutionScopes::new()),
+            Err(HintError::VariableNotInScopeError(name)) if name == PROGRAM_INPUT
+        );
+    }
+
+    #[test]
+    fn get_typed_value() {
+        let exec_scopes = exec_scopes_with_input(serde_json::from_str(r#"{"n": 10}"#).unwrap());
+        assert_matches!(get_program_input_value::<u64>(&exec_scopes, "n"), Ok(10));
+        assert_matches!(
+            get_program_input_value::<String>(&exec_scopes, "n"),
+            Err(HintError::InvalidProgramInput(key, _)) if key == "n"
+        );
+        assert_matches!(
+            get_program_input_value::<u64>(&exec_scopes, "m"),
+            Err(HintError::MissingProgramInputKey(key)) if key == "m"
+        );
+    }
+
+    #[test]
+    fn get_felts() {
+        let exec_scopes = exec_scopes_with_input(
+            serde_json::from_str(
+                r#"{"big": 3618502788666131213697322783095070105623107215331596699973092056135872020480, "list": [1, -1, "0x10"], "name": "fib"}"#,
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            get_program_input_felt(&exec_scopes, "big").unwrap(),
+            Felt::new(-1)
+        );
+        assert_eq!(
+            get_program_input_felts(&exec_scopes, "list").unwrap(),
+            vec![Felt::new(1), Felt::new(-1), Felt::new(16)]
+        );
+        assert_matches!(
+            get_program_input_felt(&exec_scopes, "name"),
+            Err(HintError::InvalidProgramInput(key, _)) if key == "name"
+        );
+        assert_matches!(
+            get_program_input_felts(&exec_scopes, "big"),
+            Err(HintError::InvalidProgramInput(key, _)) if key == "big"
+        );
+    }
+
+    #[test]
+    fn gen_nested_list_arg() {
+        let mut vm = vm!();
+        let exec_scopes =
+            exec_scopes_with_input(serde_json::json!({"n": 3, "lists": [[1, 2], [], 5]}));
+        assert_matches!(
+            gen_program_input_arg(&mut vm, &exec_scopes, "n"),
+            Ok(n) if n == MaybeRelocatable::from(Felt::new(3))
+        );
+        // The inner lists get their segments before the outer one
+        assert_matches!(
+            gen_program_input_arg(&mut vm, &exec_scopes, "lists"),
+            Ok(MaybeRelocatable::RelocatableValue(base)) if base == relocatable!(2, 0)
+        );
+        check_memory![
+            vm.segments.memory,
+            ((0, 0), 1),
+            ((0, 1), 2),
+            ((2, 0), (0, 0)),
+            ((2, 1), (1, 0)),
+            ((2, 2), 5)
+        ];
+        assert_eq!(vm.segments.num_segments(), 3);
+    }
+}
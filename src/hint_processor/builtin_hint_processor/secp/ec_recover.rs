@@ -0,0 +1,330 @@
+use crate::{
+    hint_processor::hint_processor_definition::HintReference,
+    math_utils::div_mod,
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
+};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::collections::HashMap;
+
+use super::{bigint_utils::BigInt3, secp_utils::pack};
+
+/* Implements hint:
+%{
+    from starkware.cairo.common.cairo_secp.secp_utils import pack
+    from starkware.python.math_utils import div_mod, safe_div
+
+    N = pack(ids.n, PRIME)
+    x = pack(ids.x, PRIME) % N
+    s = pack(ids.s, PRIME) % N
+    value = res = div_mod(x, s, N)
+%}
+*/
+pub fn ec_recover_divmod_n_packed(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let n = pack(BigInt3::from_var_name("n", vm, ids_data, ap_tracking)?);
+    if n.is_zero() {
+        return Err(VirtualMachineError::DividedByZero.into());
+    }
+    let x = pack(BigInt3::from_var_name("x", vm, ids_data, ap_tracking)?).mod_floor(&n);
+    let s = pack(BigInt3::from_var_name("s", vm, ids_data, ap_tracking)?).mod_floor(&n);
+    // n comes from the program, so it may not be prime
+    if !s.gcd(&n).is_one() {
+        return Err(HintError::AssertionFailed(format!(
+            "{s} is not invertible modulo {n}"
+        )));
+    }
+
+    let value = div_mod(&x, &s, &n);
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("res", value);
+    Ok(())
+}
+
+/* Implements hint:
+%{
+    from starkware.cairo.common.cairo_secp.secp_utils import pack
+    from starkware.python.math_utils import div_mod, safe_div
+
+    a = pack(ids.a, PRIME)
+    b = pack(ids.b, PRIME)
+
+    value = res = a - b
+%}
+*/
+pub fn ec_recover_sub_a_b(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a = pack(BigInt3::from_var_name("a", vm, ids_data, ap_tracking)?);
+    let b = pack(BigInt3::from_var_name("b", vm, ids_data, ap_tracking)?);
+
+    let value = a - b;
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("res", value);
+    Ok(())
+}
+
+/* Implements hint:
+%{
+    from starkware.cairo.common.cairo_secp.secp_utils import pack
+    from starkware.python.math_utils import div_mod, safe_div
+
+    a = pack(ids.a, PRIME)
+    b = pack(ids.b, PRIME)
+    product = a * b
+    m = pack(ids.m, PRIME)
+
+    value = res = product % m
+%}
+*/
+pub fn ec_recover_product_mod(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a = pack(BigInt3::from_var_name("a", vm, ids_data, ap_tracking)?);
+    let b = pack(BigInt3::from_var_name("b", vm, ids_data, ap_tracking)?);
+    let m = pack(BigInt3::from_var_name("m", vm, ids_data, ap_tracking)?);
+    if m.is_zero() {
+        return Err(VirtualMachineError::DividedByZero.into());
+    }
+
+    let product = a * b;
+    let value = product.mod_floor(&m);
+    exec_scopes.insert_value("product", product);
+    exec_scopes.insert_value("m", m);
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("res", value);
+    Ok(())
+}
+
+/* Implements hint:
+%{ value = k = product // m %}
+*/
+pub fn ec_recover_product_div_m(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+    let product = exec_scopes.get_ref::<BigInt>("product")?;
+    let m = exec_scopes.get_ref::<BigInt>("m")?;
+    if m.is_zero() {
+        return Err(VirtualMachineError::DividedByZero.into());
+    }
+
+    let value = product.div_floor(m);
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("k", value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{errors::memory_errors::MemoryError, vm_memory::memory::Memory},
+    };
+    use assert_matches::assert_matches;
+    use std::any::Any;
+
+    #[test]
+    fn run_ec_recover_divmod_n_packed_ok() {
+        let mut vm = vm!();
+        vm.run_context.fp = 9;
+        let ids_data = non_continuous_ids_data![("n", -9), ("x", -6), ("s", -3)];
+        vm.segments = segments![
+            ((1, 0), 177),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 100),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 7),
+            ((1, 7), 0),
+            ((1, 8), 0)
+        ];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::EC_RECOVER_DIV_MOD_N_PACKED,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_scope!(
+            &exec_scopes,
+            [("value", BigInt::from(166)), ("res", BigInt::from(166))]
+        );
+    }
+
+    #[test]
+    fn run_ec_recover_divmod_n_packed_n_is_zero() {
+        let mut vm = vm!();
+        vm.run_context.fp = 9;
+        let ids_data = non_continuous_ids_data![("n", -9), ("x", -6), ("s", -3)];
+        vm.segments = segments![
+            ((1, 0), 0),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 100),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 7),
+            ((1, 7), 0),
+            ((1, 8), 0)
+        ];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::EC_RECOVER_DIV_MOD_N_PACKED),
+            Err(HintError::Internal(VirtualMachineError::DividedByZero))
+        );
+    }
+
+    #[test]
+    fn run_ec_recover_divmod_n_packed_s_not_invertible() {
+        let mut vm = vm!();
+        vm.run_context.fp = 9;
+        let ids_data = non_continuous_ids_data![("n", -9), ("x", -6), ("s", -3)];
+        vm.segments = segments![
+            ((1, 0), 10),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 3),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 4),
+            ((1, 7), 0),
+            ((1, 8), 0)
+        ];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::EC_RECOVER_DIV_MOD_N_PACKED),
+            Err(HintError::AssertionFailed(message)) if message == "4 is not invertible modulo 10"
+        );
+    }
+
+    #[test]
+    fn run_ec_recover_sub_a_b_ok() {
+        let mut vm = vm!();
+        vm.run_context.fp = 6;
+        let ids_data = non_continuous_ids_data![("a", -6), ("b", -3)];
+        vm.segments = segments![
+            ((1, 0), 0),
+            ((1, 1), 1),
+            ((1, 2), 0),
+            ((1, 3), 1),
+            ((1, 4), 0),
+            ((1, 5), 0)
+        ];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::EC_RECOVER_SUB_A_B,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        // 2**86 - 1
+        check_scope!(
+            &exec_scopes,
+            [
+                ("value", bigint_str!("77371252455336267181195263")),
+                ("res", bigint_str!("77371252455336267181195263"))
+            ]
+        );
+    }
+
+    #[test]
+    fn run_ec_recover_product_mod_ok() {
+        let mut vm = vm!();
+        vm.run_context.fp = 9;
+        let ids_data = non_continuous_ids_data![("a", -9), ("b", -6), ("m", -3)];
+        vm.segments = segments![
+            ((1, 0), 60),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 2),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 100),
+            ((1, 7), 0),
+            ((1, 8), 0)
+        ];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::EC_RECOVER_PRODUCT_MOD,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_scope!(
+            &exec_scopes,
+            [
+                ("product", BigInt::from(120)),
+                ("m", BigInt::from(100)),
+                ("value", BigInt::from(20)),
+                ("res", BigInt::from(20))
+            ]
+        );
+    }
+
+    #[test]
+    fn run_ec_recover_product_div_m_ok() {
+        let mut vm = vm!();
+        let mut exec_scopes = scope![("product", BigInt::from(250)), ("m", BigInt::from(100))];
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::EC_RECOVER_PRODUCT_DIV_M,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_scope!(
+            &exec_scopes,
+            [("value", BigInt::from(2)), ("k", BigInt::from(2))]
+        );
+    }
+
+    #[test]
+    fn run_ec_recover_product_div_m_missing_product() {
+        let mut vm = vm!();
+        let mut exec_scopes = scope![("m", BigInt::from(100))];
+        assert_matches!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::EC_RECOVER_PRODUCT_DIV_M,
+                &mut exec_scopes
+            ),
+            Err(HintError::VariableNotInScopeError(name)) if name == "product"
+        );
+    }
+}
@@ -1,9 +1,6 @@
 use crate::{
     hint_processor::{
-        builtin_hint_processor::{
-            hint_utils::{insert_value_from_var_name, insert_value_into_ap},
-            secp::secp_utils::SECP_REM,
-        },
+        builtin_hint_processor::hint_utils::{insert_value_from_var_name, insert_value_into_ap},
         hint_processor_definition::HintReference,
     },
     math_utils::div_mod,
@@ -15,7 +12,7 @@ use felt::Felt;
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::{One, Zero};
-use std::{collections::HashMap, ops::Shl};
+use std::collections::HashMap;
 
 use super::{bigint_utils::BigInt3, secp_utils::pack};
 
@@ -28,22 +25,16 @@ Implements hint:
     assert r == 0, f"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}."
     ids.q = q % PRIME
 %}
+The variant without the SECP_P import, which uses the SECP_P of the scope, is also implemented.
 */
 pub fn verify_zero(
     vm: &mut VirtualMachine,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, Felt>,
+    secp_p: &BigInt,
 ) -> Result<(), HintError> {
-    #[allow(deprecated)]
-    let secp_p = BigInt::one().shl(256_u32)
-        - constants
-            .get(SECP_REM)
-            .ok_or(HintError::MissingConstant(SECP_REM))?
-            .to_bigint();
-
     let val = pack(BigInt3::from_var_name("val", vm, ids_data, ap_tracking)?);
-    let (q, r) = val.div_rem(&secp_p);
+    let (q, r) = val.div_rem(secp_p);
     if !r.is_zero() {
         return Err(HintError::SecpVerifyZero(val));
     }
@@ -58,23 +49,17 @@ Implements hint:
 
     value = pack(ids.x, PRIME) % SECP_P
 %}
+The variant without the SECP_P import, which uses the SECP_P of the scope, is also implemented.
 */
 pub fn reduce(
     vm: &mut VirtualMachine,
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, Felt>,
+    secp_p: &BigInt,
 ) -> Result<(), HintError> {
-    #[allow(deprecated)]
-    let secp_p = num_bigint::BigInt::one().shl(256_u32)
-        - constants
-            .get(SECP_REM)
-            .ok_or(HintError::MissingConstant(SECP_REM))?
-            .to_bigint();
-
     let value = pack(BigInt3::from_var_name("x", vm, ids_data, ap_tracking)?);
-    exec_scopes.insert_value("value", value.mod_floor(&secp_p));
+    exec_scopes.insert_value("value", value.mod_floor(secp_p));
     Ok(())
 }
 
@@ -85,23 +70,17 @@ Implements hint:
 
     x = pack(ids.x, PRIME) % SECP_P
 %}
+The variant without the SECP_P import, which uses the SECP_P of the scope, is also implemented.
 */
 pub fn is_zero_pack(
     vm: &mut VirtualMachine,
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, Felt>,
+    secp_p: &BigInt,
 ) -> Result<(), HintError> {
-    #[allow(deprecated)]
-    let secp_p = BigInt::one().shl(256_u32)
-        - constants
-            .get(SECP_REM)
-            .ok_or(HintError::MissingConstant(SECP_REM))?
-            .to_bigint();
-
     let x_packed = pack(BigInt3::from_var_name("x", vm, ids_data, ap_tracking)?);
-    let x = x_packed.mod_floor(&secp_p);
+    let x = x_packed.mod_floor(secp_p);
     exec_scopes.insert_value("x", x);
     Ok(())
 }
@@ -137,22 +116,16 @@ Implements hint:
 
     value = x_inv = div_mod(1, x, SECP_P)
 %}
+The variant without the SECP_P import, which uses the SECP_P of the scope, is also implemented.
 */
 pub fn is_zero_assign_scope_variables(
     exec_scopes: &mut ExecutionScopes,
-    constants: &HashMap<String, Felt>,
+    secp_p: &BigInt,
 ) -> Result<(), HintError> {
-    #[allow(deprecated)]
-    let secp_p = BigInt::one().shl(256_u32)
-        - constants
-            .get(SECP_REM)
-            .ok_or(HintError::MissingConstant(SECP_REM))?
-            .to_bigint();
-
     //Get `x` variable from vm scope
     let x = exec_scopes.get::<BigInt>("x")?;
 
-    let value = div_mod(&BigInt::one(), &x, &secp_p);
+    let value = div_mod(&BigInt::one(), &x, secp_p);
     exec_scopes.insert_value("value", value.clone());
     exec_scopes.insert_value("x_inv", value);
     Ok(())
@@ -165,8 +138,10 @@ mod tests {
     use crate::{
         any_box,
         hint_processor::{
-            builtin_hint_processor::builtin_hint_processor_definition::{
-                BuiltinHintProcessor, HintProcessorData,
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+                secp::secp_utils::SECP256R1,
             },
             hint_processor_definition::HintProcessor,
         },
@@ -194,25 +169,7 @@ mod tests {
         vm.segments = segments![((1, 4), 0), ((1, 5), 0), ((1, 6), 0)];
         //Execute the hint
         assert_matches!(
-            run_hint!(
-                vm,
-                ids_data,
-                hint_code,
-                exec_scopes_ref!(),
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
-            ),
+            run_hint!(vm, ids_data, hint_code, exec_scopes_ref!()),
             Ok(())
         );
         //Check hint memory inserts
@@ -220,6 +177,20 @@ mod tests {
         check_memory![vm.segments.memory, ((1, 9), 0)];
     }
 
+    #[test]
+    fn run_verify_zero_v2_ok() {
+        let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import SECP_P\nq, r = divmod(pack(ids.val, PRIME), SECP_P)\nassert r == 0, f\"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}.\"\nids.q = q % PRIME";
+        let mut vm = vm_with_range_check!();
+        run_context!(vm, 0, 9, 9);
+        let ids_data = non_continuous_ids_data![("val", -5), ("q", 0)];
+        vm.segments = segments![((1, 4), 0), ((1, 5), 0), ((1, 6), 0)];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code, exec_scopes_ref!()),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 9), 0)];
+    }
+
     #[test]
     fn run_verify_zero_error() {
         let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack\n\nq, r = divmod(pack(ids.val, PRIME), SECP_P)\nassert r == 0, f\"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}.\"\nids.q = q % PRIME";
@@ -236,20 +207,7 @@ mod tests {
                 vm,
                 ids_data,
                 hint_code,
-                exec_scopes_ref!(),
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
+                exec_scopes_ref!()
             ),
             Err(HintError::SecpVerifyZero(x)) if x == bigint_str!(
                 "897946605976106752944343961220884287276604954404454400"
@@ -275,20 +233,7 @@ mod tests {
                 vm,
                 ids_data,
                 hint_code,
-                exec_scopes_ref!(),
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
+                exec_scopes_ref!()
             ),
             Err(HintError::Memory(
                 MemoryError::InconsistentMemory(
@@ -322,28 +267,7 @@ mod tests {
 
         let mut exec_scopes = ExecutionScopes::new();
         //Execute the hint
-        assert_matches!(
-            run_hint!(
-                vm,
-                ids_data,
-                hint_code,
-                &mut exec_scopes,
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
-            ),
-            Ok(())
-        );
+        assert_matches!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
 
         //Check 'value' is defined in the vm scope
         assert_matches!(
@@ -372,26 +296,57 @@ mod tests {
                 vm,
                 ids_data,
                 hint_code,
-                exec_scopes_ref!(),
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
+                exec_scopes_ref!()
             ),
             Err(HintError::IdentifierHasNoMember(x, y
             )) if x == "x" && y == "d0"
         );
     }
 
+    #[test]
+    fn run_reduce_external_secp_ok() {
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("x", -3)];
+        //ids.x = 2**256 + 1
+        vm.segments = segments![
+            ((1, 0), 1),
+            ((1, 1), 0),
+            ((1, 2), ("19342813113834066795298816", 10))
+        ];
+        let mut exec_scopes = scope![("SECP_P", SECP256R1.p.clone())];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::REDUCE_EXTERNAL_SECP,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_scope!(
+            &exec_scopes,
+            [(
+                "value",
+                bigint_str!("26959946660873538059280334323183841250350249843923952699046031785986")
+            )]
+        );
+    }
+
+    #[test]
+    fn run_reduce_external_secp_missing_secp_p() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("x", -3)];
+        vm.segments = segments![((1, 0), 1), ((1, 1), 0), ((1, 2), 0)];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::REDUCE_EXTERNAL_SECP),
+            Err(HintError::VariableNotInScopeError(name)) if name == "SECP_P"
+        );
+    }
+
     #[test]
     fn run_is_zero_pack_ok() {
         let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack\n\nx = pack(ids.x, PRIME) % SECP_P";
@@ -412,28 +367,7 @@ mod tests {
         let mut exec_scopes = ExecutionScopes::new();
 
         //Execute the hint
-        assert_matches!(
-            run_hint!(
-                vm,
-                ids_data,
-                hint_code,
-                &mut exec_scopes,
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
-            ),
-            Ok(())
-        );
+        assert_matches!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
 
         //Check 'x' is defined in the vm scope
         check_scope!(
@@ -466,20 +400,7 @@ mod tests {
                 vm,
                 ids_data,
                 hint_code,
-                exec_scopes_ref!(),
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
+                exec_scopes_ref!()
             ),
             Err(HintError::IdentifierHasNoMember(x, y
             )) if x == "x" && y == "d0"
@@ -602,25 +523,7 @@ mod tests {
         );
         //Execute the hint
         assert_matches!(
-            run_hint!(
-                vm,
-                HashMap::new(),
-                hint_code,
-                &mut exec_scopes,
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
-            ),
+            run_hint!(vm, HashMap::new(), hint_code, &mut exec_scopes),
             Ok(())
         );
 
@@ -652,20 +555,7 @@ mod tests {
                 vm,
                 HashMap::new(),
                 hint_code,
-                exec_scopes_ref!(),
-                &[(
-                    SECP_REM,
-                    Felt::one().shl(32_u32)
-                        + Felt::one().shl(9_u32)
-                        + Felt::one().shl(8_u32)
-                        + Felt::one().shl(7_u32)
-                        + Felt::one().shl(6_u32)
-                        + Felt::one().shl(4_u32)
-                        + Felt::one()
-                )]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
+                exec_scopes_ref!()
             ),
             Err(HintError::VariableNotInScopeError(x)) if x == *"x".to_string()
         );
@@ -1,7 +1,8 @@
-use crate::vm::errors::hint_errors::HintError;
+use crate::{types::exec_scope::ExecutionScopes, vm::errors::hint_errors::HintError};
 use felt::Felt;
-
-use num_traits::Zero;
+use lazy_static::lazy_static;
+use num_bigint::BigInt;
+use num_traits::{Num, Zero};
 use std::collections::HashMap;
 use std::ops::Shl;
 
@@ -18,6 +19,84 @@ pub const P1: &str = "starkware.cairo.common.cairo_secp.constants.P1";
 pub const P2: &str = "starkware.cairo.common.cairo_secp.constants.P2";
 pub const SECP_REM: &str = "starkware.cairo.common.cairo_secp.constants.SECP_REM";
 
+/// The parameters of a curve y^2 = x^3 + alpha * x + beta over the integers modulo `p`, whose
+/// group of points has order `n`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecpCurve {
+    pub p: BigInt,
+    pub n: BigInt,
+    pub alpha: BigInt,
+    pub beta: BigInt,
+}
+
+fn from_dec_str(value: &str) -> BigInt {
+    BigInt::from_str_radix(value, 10).expect("curve parameters are decimal numbers")
+}
+
+lazy_static! {
+    /// secp256k1, whose parameters are imported by the hints from
+    /// `starkware.cairo.common.cairo_secp.secp_utils`.
+    pub static ref SECP256K1: SecpCurve = SecpCurve {
+        //SECP_P = 2**256 - 2**32 - 2**9 - 2**8 - 2**7 - 2**6 - 2**4 - 1
+        p: from_dec_str(
+            "115792089237316195423570985008687907853269984665640564039457584007908834671663"
+        ),
+        //N = 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141
+        n: from_dec_str(
+            "115792089237316195423570985008687907852837564279074904382605163141518161494337"
+        ),
+        alpha: BigInt::zero(),
+        beta: BigInt::from(7),
+    };
+    /// secp256r1 (NIST P-256), whose parameters are imported by the hints from
+    /// `starkware.cairo.common.cairo_secp.secp256r1_utils`.
+    pub static ref SECP256R1: SecpCurve = SecpCurve {
+        //SECP256R1_P = 2**256 - 2**224 + 2**192 + 2**96 - 1
+        p: from_dec_str(
+            "115792089210356248762697446949407573530086143415290314195533631308867097853951"
+        ),
+        //SECP256R1_N = 0xFFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551
+        n: from_dec_str(
+            "115792089210356248762697446949407573529996955224135760342422259061068512044369"
+        ),
+        //SECP256R1_ALPHA = SECP256R1_P - 3
+        alpha: from_dec_str(
+            "115792089210356248762697446949407573530086143415290314195533631308867097853948"
+        ),
+        //SECP256R1_B = 0x5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B
+        beta: from_dec_str(
+            "41058363725152142129326129780047268409114441015993725554835256314039467401291"
+        ),
+    };
+}
+
+/*
+Implements hint:
+%{ from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P %}
+*/
+pub fn import_secp256r1_p(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+    exec_scopes.insert_value("SECP_P", SECP256R1.p.clone());
+    Ok(())
+}
+
+/*
+Implements hint:
+%{ from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_ALPHA as ALPHA %}
+*/
+pub fn import_secp256r1_alpha(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+    exec_scopes.insert_value("ALPHA", SECP256R1.alpha.clone());
+    Ok(())
+}
+
+/*
+Implements hint:
+%{ from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_N as N %}
+*/
+pub fn import_secp256r1_n(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+    exec_scopes.insert_value("N", SECP256R1.n.clone());
+    Ok(())
+}
+
 /*
 Takes a 256-bit integer and returns its canonical representation as:
 d0 + BASE * d1 + BASE**2 * d2,
@@ -67,11 +146,23 @@ mod tests {
     use std::borrow::Cow;
 
     use super::*;
-    use crate::utils::test_utils::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        utils::test_utils::*,
+        vm::vm_core::VirtualMachine,
+    };
     use assert_matches::assert_matches;
     use felt::felt_str;
     use num_bigint::BigUint;
     use num_traits::One;
+    use std::{any::Any, collections::HashMap};
 
     #[test]
     fn secp_split() {
@@ -165,4 +256,52 @@ mod tests {
             bigint_str!("7737125245533626718119526477371252455336267181195264773712524553362")
         );
     }
+
+    #[test]
+    fn curve_generators_are_on_their_curves() {
+        let generators = [
+            (
+                &*SECP256K1,
+                "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+                "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            ),
+            (
+                &*SECP256R1,
+                "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+                "4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+            ),
+        ];
+        for (curve, x, y) in generators {
+            let x = BigInt::from_str_radix(x, 16).unwrap();
+            let y = BigInt::from_str_radix(y, 16).unwrap();
+            assert_eq!(
+                y.pow(2) % &curve.p,
+                (x.pow(3) + &curve.alpha * &x + &curve.beta) % &curve.p
+            );
+        }
+    }
+
+    #[test]
+    fn run_import_secp256r1_hints() {
+        let mut vm = vm!();
+        let mut exec_scopes = ExecutionScopes::new();
+        for hint in [
+            hint_code::IMPORT_SECP256R1_P,
+            hint_code::IMPORT_SECP256R1_ALPHA,
+            hint_code::IMPORT_SECP256R1_N,
+        ] {
+            assert_matches!(
+                run_hint!(vm, HashMap::new(), hint, &mut exec_scopes),
+                Ok(())
+            );
+        }
+        check_scope!(
+            &exec_scopes,
+            [
+                ("SECP_P", SECP256R1.p.clone()),
+                ("ALPHA", SECP256R1.alpha.clone()),
+                ("N", SECP256R1.n.clone())
+            ]
+        );
+    }
 }
@@ -1,9 +1,6 @@
 use crate::{
     hint_processor::{
-        builtin_hint_processor::{
-            hint_utils::get_integer_from_var_name,
-            secp::secp_utils::{pack, BASE_86, BETA, N0, N1, N2, SECP_REM},
-        },
+        builtin_hint_processor::{hint_utils::get_integer_from_var_name, secp::secp_utils::pack},
         hint_processor_definition::HintReference,
     },
     math_utils::{div_mod, safe_div_bigint},
@@ -12,14 +9,9 @@ use crate::{
     vm::errors::hint_errors::HintError,
     vm::vm_core::VirtualMachine,
 };
-use felt::Felt;
 use num_bigint::BigInt;
 use num_integer::Integer;
-use num_traits::One;
-use std::{
-    collections::HashMap,
-    ops::{Shl, Shr},
-};
+use std::{collections::HashMap, ops::Shr};
 
 use super::bigint_utils::BigInt3;
 
@@ -30,40 +22,21 @@ from starkware.python.math_utils import div_mod, safe_div
 a = pack(ids.a, PRIME)
 b = pack(ids.b, PRIME)
 value = res = div_mod(a, b, N)
+
+The variant without the N import, which uses the N of the scope, is also implemented.
 */
 pub fn div_mod_n_packed_divmod(
     vm: &mut VirtualMachine,
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, Felt>,
+    n: &BigInt,
 ) -> Result<(), HintError> {
     let a = pack(BigInt3::from_var_name("a", vm, ids_data, ap_tracking)?);
     let b = pack(BigInt3::from_var_name("b", vm, ids_data, ap_tracking)?);
 
-    #[allow(deprecated)]
-    let n = {
-        let base = constants
-            .get(BASE_86)
-            .ok_or(HintError::MissingConstant(BASE_86))?
-            .to_bigint();
-        let n0 = constants
-            .get(N0)
-            .ok_or(HintError::MissingConstant(N0))?
-            .to_bigint();
-        let n1 = constants
-            .get(N1)
-            .ok_or(HintError::MissingConstant(N1))?
-            .to_bigint();
-        let n2 = constants
-            .get(N2)
-            .ok_or(HintError::MissingConstant(N2))?
-            .to_bigint();
-
-        (n2 * &base * &base) | (n1 * base) | n0
-    };
-
-    let value = div_mod(&a, &b, &n);
+    let value = div_mod(&a, &b, n);
+    exec_scopes.insert_value("N", n.clone());
     exec_scopes.insert_value("a", a);
     exec_scopes.insert_value("b", b);
     exec_scopes.insert_value("value", value.clone());
@@ -73,73 +46,51 @@ pub fn div_mod_n_packed_divmod(
 
 // Implements hint:
 // value = k = safe_div(res * b - a, N)
-pub fn div_mod_n_safe_div(
-    exec_scopes: &mut ExecutionScopes,
-    constants: &HashMap<String, Felt>,
-) -> Result<(), HintError> {
+pub fn div_mod_n_safe_div(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
     let a = exec_scopes.get_ref::<BigInt>("a")?;
     let b = exec_scopes.get_ref::<BigInt>("b")?;
     let res = exec_scopes.get_ref::<BigInt>("res")?;
+    let n = exec_scopes.get_ref::<BigInt>("N")?;
 
-    #[allow(deprecated)]
-    let n = {
-        let base = constants
-            .get(BASE_86)
-            .ok_or(HintError::MissingConstant(BASE_86))?
-            .to_bigint();
-        let n0 = constants
-            .get(N0)
-            .ok_or(HintError::MissingConstant(N0))?
-            .to_bigint();
-        let n1 = constants
-            .get(N1)
-            .ok_or(HintError::MissingConstant(N1))?
-            .to_bigint();
-        let n2 = constants
-            .get(N2)
-            .ok_or(HintError::MissingConstant(N2))?
-            .to_bigint();
-
-        n2 * &base * &base + n1 * base + n0
-    };
-
-    let value = safe_div_bigint(&(res * b - a), &n)?;
+    let value = safe_div_bigint(&(res * b - a), n)?;
 
     exec_scopes.insert_value("value", value);
     Ok(())
 }
 
+/* Implements hint:
+from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack
+
+x_cube_int = pack(ids.x_cube, PRIME) % SECP_P
+y_square_int = (x_cube_int + ids.BETA) % SECP_P
+y = pow(y_square_int, (SECP_P + 1) // 4, SECP_P)
+
+# We need to decide whether to take y or SECP_P - y.
+if ids.v % 2 == y % 2:
+    value = y
+else:
+    value = (-y) % SECP_P
+
+ids.BETA is the beta of the curve the hint is run for, which the program declares as a constant.
+*/
 pub fn get_point_from_x(
     vm: &mut VirtualMachine,
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, Felt>,
+    secp_p: &BigInt,
+    beta: &BigInt,
 ) -> Result<(), HintError> {
-    #[allow(deprecated)]
-    let beta = constants
-        .get(BETA)
-        .ok_or(HintError::MissingConstant(BETA))?
-        .to_bigint();
-    #[allow(deprecated)]
-    let secp_p = BigInt::one().shl(256_u32)
-        - constants
-            .get(SECP_REM)
-            .ok_or(HintError::MissingConstant(SECP_REM))?
-            .to_bigint();
-
     let x_cube_int =
-        pack(BigInt3::from_var_name("x_cube", vm, ids_data, ap_tracking)?).mod_floor(&secp_p);
-    //.mod_floor(&BigInt::from_biguint(num_bigint::Sign::Plus, secp_p.clone()))
-    //.to_biguint().ok_or(VirtualMachineError::BigIntToBigUintFail)?;
-    let y_cube_int = (x_cube_int + beta).mod_floor(&secp_p);
+        pack(BigInt3::from_var_name("x_cube", vm, ids_data, ap_tracking)?).mod_floor(secp_p);
+    let y_cube_int = (x_cube_int + beta).mod_floor(secp_p);
     // Divide by 4
-    let mut y = y_cube_int.modpow(&(&secp_p + 1_u32).shr(2_u32), &secp_p);
+    let mut y = y_cube_int.modpow(&(secp_p + 1_u32).shr(2_u32), secp_p);
 
     #[allow(deprecated)]
     let v = get_integer_from_var_name("v", vm, ids_data, ap_tracking)?.to_biguint();
     if v.is_even() != y.is_even() {
-        y = &secp_p - y;
+        y = secp_p - y;
     }
     exec_scopes.insert_value("value", y);
     Ok(())
@@ -148,6 +99,7 @@ pub fn get_point_from_x(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hint_processor::builtin_hint_processor::secp::secp_utils::{SECP256K1, SECP256R1};
     use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
     use crate::{
         any_box,
@@ -166,8 +118,9 @@ mod tests {
         },
     };
     use assert_matches::assert_matches;
-    use num_traits::Zero;
-    use std::{any::Any, ops::Shl};
+    use felt::Felt;
+    use num_traits::{One, Zero};
+    use std::any::Any;
 
     #[test]
     fn safe_div_ok() {
@@ -185,20 +138,8 @@ mod tests {
         vm.run_context.fp = 3;
         let ids_data = non_continuous_ids_data![("a", -3), ("b", 0)];
         let mut exec_scopes = ExecutionScopes::new();
-        let constants = [
-            (BASE_86, Felt::one().shl(86_u32)),
-            (N0, Felt::new(10428087374290690730508609u128)),
-            (N1, Felt::new(77371252455330678278691517u128)),
-            (N2, Felt::new(19342813113834066795298815u128)),
-        ]
-        .into_iter()
-        .map(|(k, v)| (k.to_string(), v))
-        .collect();
-        assert_matches!(
-            run_hint!(vm, ids_data, hint_code, &mut exec_scopes, &constants),
-            Ok(())
-        );
-        assert_matches!(div_mod_n_safe_div(&mut exec_scopes, &constants), Ok(()));
+        assert_matches!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+        assert_matches!(div_mod_n_safe_div(&mut exec_scopes), Ok(()));
     }
 
     #[test]
@@ -206,21 +147,11 @@ mod tests {
         let mut exec_scopes = scope![
             ("a", BigInt::zero()),
             ("b", BigInt::one()),
-            ("res", BigInt::one())
+            ("res", BigInt::one()),
+            ("N", SECP256K1.n.clone())
         ];
         assert_matches!(
-            div_mod_n_safe_div(
-                &mut exec_scopes,
-                &[
-                    (BASE_86, Felt::one().shl(86_u32)),
-                    (N0, Felt::new(10428087374290690730508609u128)),
-                    (N1, Felt::new(77371252455330678278691517u128)),
-                    (N2, Felt::new(19342813113834066795298815u128)),
-                ]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
-            ),
+            div_mod_n_safe_div(&mut exec_scopes),
             Err(
                 HintError::Internal(VirtualMachineError::SafeDivFailBigInt(
                     x,
@@ -243,28 +174,7 @@ mod tests {
         vm.run_context.fp = 1;
         let ids_data = non_continuous_ids_data![("v", -1), ("x_cube", 0)];
         assert_matches!(
-            run_hint!(
-                vm,
-                ids_data,
-                hint_code,
-                exec_scopes_ref!(),
-                &[
-                    (BETA, Felt::new(7)),
-                    (
-                        SECP_REM,
-                        Felt::one().shl(32_u32)
-                            + Felt::one().shl(9_u32)
-                            + Felt::one().shl(8_u32)
-                            + Felt::one().shl(7_u32)
-                            + Felt::one().shl(6_u32)
-                            + Felt::one().shl(4_u32)
-                            + Felt::one()
-                    ),
-                ]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
-            ),
+            run_hint!(vm, ids_data, hint_code, exec_scopes_ref!()),
             Ok(())
         )
     }
@@ -283,31 +193,7 @@ mod tests {
         vm.run_context.fp = 2;
 
         let ids_data = ids_data!["v", "x_cube"];
-        assert_matches!(
-            run_hint!(
-                vm,
-                ids_data,
-                hint_code,
-                &mut exec_scopes,
-                &[
-                    (BETA, Felt::new(7)),
-                    (
-                        SECP_REM,
-                        Felt::one().shl(32_u32)
-                            + Felt::one().shl(9_u32)
-                            + Felt::one().shl(8_u32)
-                            + Felt::one().shl(7_u32)
-                            + Felt::one().shl(6_u32)
-                            + Felt::one().shl(4_u32)
-                            + Felt::one()
-                    ),
-                ]
-                .into_iter()
-                .map(|(k, v)| (k.to_string(), v))
-                .collect()
-            ),
-            Ok(())
-        );
+        assert_matches!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
 
         check_scope!(
             &exec_scopes,
@@ -319,4 +205,31 @@ mod tests {
             )]
         );
     }
+
+    #[test]
+    fn get_point_from_x_secp256r1() {
+        let mut vm = vm!();
+        let mut exec_scopes = ExecutionScopes::new();
+        // x_cube + beta = 4, so y is 2 or p - 2, and v selects the even one
+        let x_cube = (BigInt::from(4) - &SECP256R1.beta).mod_floor(&SECP256R1.p);
+        let limb = |i: usize| Felt::from((&x_cube >> (86 * i)) & BigInt::from((1_u128 << 86) - 1));
+        vm.segments = segments![((1, 0), 0)];
+        for i in 0..3 {
+            vm.insert_value((1, 1 + i).into(), limb(i)).unwrap();
+        }
+        vm.run_context.fp = 1;
+        let ids_data = non_continuous_ids_data![("v", -1), ("x_cube", 0)];
+        assert_matches!(
+            get_point_from_x(
+                &mut vm,
+                &mut exec_scopes,
+                &ids_data,
+                &ApTracking::default(),
+                &SECP256R1.p,
+                &SECP256R1.beta,
+            ),
+            Ok(())
+        );
+        check_scope!(&exec_scopes, [("value", BigInt::from(2))]);
+    }
 }
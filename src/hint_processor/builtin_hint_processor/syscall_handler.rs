@@ -0,0 +1,437 @@
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::{
+            hint_code,
+            hint_utils::get_ptr_from_var_name,
+            syscall_request::{
+                CallContractRequest, CallContractResponse, EmitEventRequest,
+                GetCallerAddressRequest, GetCallerAddressResponse, GetContractAddressRequest,
+                GetContractAddressResponse, LibraryCallRequest, StorageReadRequest,
+                StorageReadResponse, StorageWriteRequest, SyscallRequest, SyscallResponse,
+            },
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::Felt;
+use num_traits::Zero;
+use std::collections::HashMap;
+
+/// Serves the syscalls of StarkNet contracts, made through the `syscall_handler.<syscall>`
+/// hints. The requests are read from the syscall structs in memory, and the responses written
+/// back to them.
+pub trait SyscallHandler {
+    fn storage_read(
+        &mut self,
+        request: StorageReadRequest,
+    ) -> Result<StorageReadResponse, HintError>;
+
+    fn storage_write(&mut self, request: StorageWriteRequest) -> Result<(), HintError>;
+
+    fn get_caller_address(
+        &mut self,
+        request: GetCallerAddressRequest,
+    ) -> Result<GetCallerAddressResponse, HintError>;
+
+    fn get_contract_address(
+        &mut self,
+        request: GetContractAddressRequest,
+    ) -> Result<GetContractAddressResponse, HintError>;
+
+    fn emit_event(&mut self, request: EmitEventRequest) -> Result<(), HintError>;
+
+    fn call_contract(
+        &mut self,
+        request: CallContractRequest,
+    ) -> Result<CallContractResponse, HintError>;
+
+    fn library_call(
+        &mut self,
+        request: LibraryCallRequest,
+    ) -> Result<CallContractResponse, HintError>;
+}
+
+/// Default syscall handler of the `BuiltinHintProcessor`, for programs that aren't StarkNet
+/// contracts: every syscall fails.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoSyscallHandler;
+
+impl NoSyscallHandler {
+    fn unsupported<T>(syscall: &str) -> Result<T, HintError> {
+        Err(HintError::SyscallFailed(format!(
+            "{syscall} needs a syscall handler, none was set"
+        )))
+    }
+}
+
+impl SyscallHandler for NoSyscallHandler {
+    fn storage_read(
+        &mut self,
+        _request: StorageReadRequest,
+    ) -> Result<StorageReadResponse, HintError> {
+        Self::unsupported("storage_read")
+    }
+
+    fn storage_write(&mut self, _request: StorageWriteRequest) -> Result<(), HintError> {
+        Self::unsupported("storage_write")
+    }
+
+    fn get_caller_address(
+        &mut self,
+        _request: GetCallerAddressRequest,
+    ) -> Result<GetCallerAddressResponse, HintError> {
+        Self::unsupported("get_caller_address")
+    }
+
+    fn get_contract_address(
+        &mut self,
+        _request: GetContractAddressRequest,
+    ) -> Result<GetContractAddressResponse, HintError> {
+        Self::unsupported("get_contract_address")
+    }
+
+    fn emit_event(&mut self, _request: EmitEventRequest) -> Result<(), HintError> {
+        Self::unsupported("emit_event")
+    }
+
+    fn call_contract(
+        &mut self,
+        _request: CallContractRequest,
+    ) -> Result<CallContractResponse, HintError> {
+        Self::unsupported("call_contract")
+    }
+
+    fn library_call(
+        &mut self,
+        _request: LibraryCallRequest,
+    ) -> Result<CallContractResponse, HintError> {
+        Self::unsupported("library_call")
+    }
+}
+
+/// Syscall handler of a single contract, keeping its storage and events in memory. As there
+/// are no other contracts, contract and library calls fail.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InMemorySyscallHandler {
+    pub contract_address: Felt,
+    pub caller_address: Felt,
+    /// Storage of the contract, whose keys that were never written hold zero.
+    pub storage: HashMap<Felt, Felt>,
+    /// Events emitted by the contract, in order.
+    pub events: Vec<EmitEventRequest>,
+}
+
+impl InMemorySyscallHandler {
+    pub fn new(contract_address: Felt, caller_address: Felt) -> Self {
+        InMemorySyscallHandler {
+            contract_address,
+            caller_address,
+            ..Default::default()
+        }
+    }
+}
+
+impl SyscallHandler for InMemorySyscallHandler {
+    fn storage_read(
+        &mut self,
+        request: StorageReadRequest,
+    ) -> Result<StorageReadResponse, HintError> {
+        Ok(StorageReadResponse {
+            value: self
+                .storage
+                .get(&request.address)
+                .cloned()
+                .unwrap_or_else(Felt::zero),
+        })
+    }
+
+    fn storage_write(&mut self, request: StorageWriteRequest) -> Result<(), HintError> {
+        self.storage.insert(request.address, request.value);
+        Ok(())
+    }
+
+    fn get_caller_address(
+        &mut self,
+        _request: GetCallerAddressRequest,
+    ) -> Result<GetCallerAddressResponse, HintError> {
+        Ok(GetCallerAddressResponse {
+            caller_address: self.caller_address.clone(),
+        })
+    }
+
+    fn get_contract_address(
+        &mut self,
+        _request: GetContractAddressRequest,
+    ) -> Result<GetContractAddressResponse, HintError> {
+        Ok(GetContractAddressResponse {
+            contract_address: self.contract_address.clone(),
+        })
+    }
+
+    fn emit_event(&mut self, request: EmitEventRequest) -> Result<(), HintError> {
+        self.events.push(request);
+        Ok(())
+    }
+
+    fn call_contract(
+        &mut self,
+        _request: CallContractRequest,
+    ) -> Result<CallContractResponse, HintError> {
+        Err(HintError::SyscallFailed(
+            "InMemorySyscallHandler doesn't support contract calls".to_string(),
+        ))
+    }
+
+    fn library_call(
+        &mut self,
+        _request: LibraryCallRequest,
+    ) -> Result<CallContractResponse, HintError> {
+        Err(HintError::SyscallFailed(
+            "InMemorySyscallHandler doesn't support library calls".to_string(),
+        ))
+    }
+}
+
+/// Implements the hint
+/// `syscall_handler.<syscall>(segments=segments, syscall_ptr=ids.syscall_ptr)`
+/// for every syscall of `SyscallHandler`.
+pub fn execute_syscall_hint(
+    syscall_handler: &mut dyn SyscallHandler,
+    vm: &mut VirtualMachine,
+    hint_code: &str,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let syscall_ptr = get_ptr_from_var_name("syscall_ptr", vm, ids_data, ap_tracking)?;
+    match hint_code {
+        hint_code::STORAGE_READ => run_syscall(vm, syscall_ptr, |request| {
+            syscall_handler.storage_read(request)
+        }),
+        hint_code::STORAGE_WRITE => run_syscall(vm, syscall_ptr, |request| {
+            syscall_handler.storage_write(request)
+        }),
+        hint_code::GET_CALLER_ADDRESS => run_syscall(vm, syscall_ptr, |request| {
+            syscall_handler.get_caller_address(request)
+        }),
+        hint_code::GET_CONTRACT_ADDRESS => run_syscall(vm, syscall_ptr, |request| {
+            syscall_handler.get_contract_address(request)
+        }),
+        hint_code::EMIT_EVENT => run_syscall(vm, syscall_ptr, |request| {
+            syscall_handler.emit_event(request)
+        }),
+        hint_code::CALL_CONTRACT => run_syscall(vm, syscall_ptr, |request| {
+            syscall_handler.call_contract(request)
+        }),
+        hint_code::LIBRARY_CALL => run_syscall(vm, syscall_ptr, |request| {
+            syscall_handler.library_call(request)
+        }),
+        _ => Err(HintError::UnknownHint(hint_code.to_string())),
+    }
+}
+
+fn run_syscall<Request: SyscallRequest, Response: SyscallResponse>(
+    vm: &mut VirtualMachine,
+    syscall_ptr: Relocatable,
+    syscall: impl FnOnce(Request) -> Result<Response, HintError>,
+) -> Result<(), HintError> {
+    let request = Request::read(vm, syscall_ptr)?;
+    syscall(request)?.write(vm, syscall_ptr + Request::SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::builtin_hint_processor_definition::{
+                BuiltinHintProcessor, HintProcessorData,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+        },
+    };
+    use assert_matches::assert_matches;
+    use std::{any::Any, cell::RefCell, rc::Rc};
+
+    fn syscall_vm(syscall_name: &str) -> VirtualMachine {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0))];
+        vm.add_memory_segment();
+        vm.run_context.fp = 1;
+        vm.insert_value((2, 0).into(), Felt::from_bytes_be(syscall_name.as_bytes()))
+            .unwrap();
+        vm
+    }
+
+    #[test]
+    fn storage_write_and_read() {
+        let mut handler = InMemorySyscallHandler::new(Felt::new(1), Felt::new(2));
+        let mut vm = syscall_vm("StorageWrite");
+        vm.insert_value((2, 1).into(), Felt::new(5)).unwrap();
+        vm.insert_value((2, 2).into(), Felt::new(7)).unwrap();
+        let ids_data = ids_data!["syscall_ptr"];
+        assert_matches!(
+            execute_syscall_hint(
+                &mut handler,
+                &mut vm,
+                hint_code::STORAGE_WRITE,
+                &ids_data,
+                &ApTracking::new()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            handler.storage,
+            HashMap::from([(Felt::new(5), Felt::new(7))])
+        );
+
+        let mut vm = syscall_vm("StorageRead");
+        vm.insert_value((2, 1).into(), Felt::new(5)).unwrap();
+        assert_matches!(
+            execute_syscall_hint(
+                &mut handler,
+                &mut vm,
+                hint_code::STORAGE_READ,
+                &ids_data,
+                &ApTracking::new()
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((2, 2), 7)];
+    }
+
+    #[test]
+    fn syscalls_fail_without_handler() {
+        let mut vm = syscall_vm("StorageWrite");
+        vm.insert_value((2, 1).into(), Felt::new(5)).unwrap();
+        vm.insert_value((2, 2).into(), Felt::new(7)).unwrap();
+        let hint_data = HintProcessorData::new_default(
+            hint_code::STORAGE_WRITE.to_string(),
+            ids_data!["syscall_ptr"],
+        );
+        assert_matches!(
+            BuiltinHintProcessor::new_empty().execute_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &any_box!(hint_data),
+                &HashMap::new()
+            ),
+            Err(HintError::SyscallFailed(_))
+        );
+    }
+
+    #[test]
+    fn reentrant_syscall_fails() {
+        let handler = Rc::new(RefCell::new(InMemorySyscallHandler::default()));
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        hint_processor.set_syscall_handler(handler.clone());
+        // The handler is still serving a syscall when the hint runs
+        let _serving = handler.borrow_mut();
+
+        let mut vm = syscall_vm("GetContractAddress");
+        let hint_data = HintProcessorData::new_default(
+            hint_code::GET_CONTRACT_ADDRESS.to_string(),
+            ids_data!["syscall_ptr"],
+        );
+        assert_matches!(
+            hint_processor.execute_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &any_box!(hint_data),
+                &HashMap::new()
+            ),
+            Err(HintError::SyscallFailed(_))
+        );
+    }
+
+    #[test]
+    fn get_caller_address_invalid_selector() {
+        let mut vm = syscall_vm("GetContractAddress");
+        assert_matches!(
+            execute_syscall_hint(
+                &mut InMemorySyscallHandler::default(),
+                &mut vm,
+                hint_code::GET_CALLER_ADDRESS,
+                &ids_data!["syscall_ptr"],
+                &ApTracking::new()
+            ),
+            Err(HintError::InvalidSyscallSelector(name, _, _)) if name == "GetCallerAddress"
+        );
+    }
+
+    #[test]
+    fn call_contract_not_supported() {
+        let mut vm = syscall_vm("CallContract");
+        vm.insert_value((2, 1).into(), Felt::new(3)).unwrap();
+        vm.insert_value((2, 2).into(), Felt::new(4)).unwrap();
+        vm.insert_value((2, 3).into(), Felt::new(0)).unwrap();
+        vm.insert_value((2, 4).into(), Relocatable::from((2, 0)))
+            .unwrap();
+        assert_matches!(
+            execute_syscall_hint(
+                &mut InMemorySyscallHandler::default(),
+                &mut vm,
+                hint_code::CALL_CONTRACT,
+                &ids_data!["syscall_ptr"],
+                &ApTracking::new()
+            ),
+            Err(HintError::SyscallFailed(_))
+        );
+    }
+
+    #[test]
+    fn run_syscall_hints_with_builtin_hint_processor() {
+        let handler = Rc::new(RefCell::new(InMemorySyscallHandler::new(
+            Felt::new(1),
+            Felt::new(2),
+        )));
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        hint_processor.set_syscall_handler(handler.clone());
+
+        let mut vm = syscall_vm("GetContractAddress");
+        let hint_data = HintProcessorData::new_default(
+            hint_code::GET_CONTRACT_ADDRESS.to_string(),
+            ids_data!["syscall_ptr"],
+        );
+        assert_matches!(
+            hint_processor.execute_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &any_box!(hint_data),
+                &HashMap::new()
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((2, 1), 1)];
+
+        let mut vm = syscall_vm("StorageWrite");
+        vm.insert_value((2, 1).into(), Felt::new(5)).unwrap();
+        vm.insert_value((2, 2).into(), Felt::new(7)).unwrap();
+        let hint_data = HintProcessorData::new_default(
+            hint_code::STORAGE_WRITE.to_string(),
+            ids_data!["syscall_ptr"],
+        );
+        assert_matches!(
+            hint_processor.execute_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &any_box!(hint_data),
+                &HashMap::new()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            handler.borrow().storage,
+            HashMap::from([(Felt::new(5), Felt::new(7))])
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Typed views of the syscall structs that StarkNet contracts write at `syscall_ptr`.
+//!
+//! Each struct starts with the selector of its syscall, the short string of its name, followed
+//! by the request and then the response, which is filled in by the syscall handler.
+
+use crate::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::Felt;
+use num_traits::ToPrimitive;
+use std::borrow::Cow;
+
+pub trait SyscallRequest: Sized {
+    /// Name of the syscall, whose short string is the selector the struct starts with.
+    const SYSCALL_NAME: &'static str;
+    /// Size of the request, selector included. The response starts right after it.
+    const SIZE: usize;
+
+    /// Reads the fields of the request, which start at `ptr`, right after the selector.
+    fn read_fields(vm: &VirtualMachine, ptr: Relocatable) -> Result<Self, HintError>;
+
+    /// Reads the request of the syscall struct at `syscall_ptr`, checking its selector.
+    fn read(vm: &VirtualMachine, syscall_ptr: Relocatable) -> Result<Self, HintError> {
+        let selector = vm.get_integer(syscall_ptr)?;
+        if selector.as_ref() != &Felt::from_bytes_be(Self::SYSCALL_NAME.as_bytes()) {
+            return Err(HintError::InvalidSyscallSelector(
+                Self::SYSCALL_NAME.to_string(),
+                syscall_ptr,
+                selector.into_owned(),
+            ));
+        }
+        Self::read_fields(vm, syscall_ptr + 1_usize)
+    }
+}
+
+pub trait SyscallResponse {
+    /// Writes the response at `ptr`, right after the request.
+    fn write(self, vm: &mut VirtualMachine, ptr: Relocatable) -> Result<(), HintError>;
+}
+
+/// Response of the syscalls that write nothing back.
+impl SyscallResponse for () {
+    fn write(self, _vm: &mut VirtualMachine, _ptr: Relocatable) -> Result<(), HintError> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageReadRequest {
+    pub address: Felt,
+}
+
+impl SyscallRequest for StorageReadRequest {
+    const SYSCALL_NAME: &'static str = "StorageRead";
+    const SIZE: usize = 2;
+
+    fn read_fields(vm: &VirtualMachine, ptr: Relocatable) -> Result<Self, HintError> {
+        Ok(StorageReadRequest {
+            address: vm.get_integer(ptr)?.into_owned(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageReadResponse {
+    pub value: Felt,
+}
+
+impl SyscallResponse for StorageReadResponse {
+    fn write(self, vm: &mut VirtualMachine, ptr: Relocatable) -> Result<(), HintError> {
+        vm.insert_value(ptr, self.value)?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageWriteRequest {
+    pub address: Felt,
+    pub value: Felt,
+}
+
+impl SyscallRequest for StorageWriteRequest {
+    const SYSCALL_NAME: &'static str = "StorageWrite";
+    const SIZE: usize = 3;
+
+    fn read_fields(vm: &VirtualMachine, ptr: Relocatable) -> Result<Self, HintError> {
+        Ok(StorageWriteRequest {
+            address: vm.get_integer(ptr)?.into_owned(),
+            value: vm.get_integer(ptr + 1_usize)?.into_owned(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetCallerAddressRequest;
+
+impl SyscallRequest for GetCallerAddressRequest {
+    const SYSCALL_NAME: &'static str = "GetCallerAddress";
+    const SIZE: usize = 1;
+
+    fn read_fields(_vm: &VirtualMachine, _ptr: Relocatable) -> Result<Self, HintError> {
+        Ok(GetCallerAddressRequest)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetCallerAddressResponse {
+    pub caller_address: Felt,
+}
+
+impl SyscallResponse for GetCallerAddressResponse {
+    fn write(self, vm: &mut VirtualMachine, ptr: Relocatable) -> Result<(), HintError> {
+        vm.insert_value(ptr, self.caller_address)?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetContractAddressRequest;
+
+impl SyscallRequest for GetContractAddressRequest {
+    const SYSCALL_NAME: &'static str = "GetContractAddress";
+    const SIZE: usize = 1;
+
+    fn read_fields(_vm: &VirtualMachine, _ptr: Relocatable) -> Result<Self, HintError> {
+        Ok(GetContractAddressRequest)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetContractAddressResponse {
+    pub contract_address: Felt,
+}
+
+impl SyscallResponse for GetContractAddressResponse {
+    fn write(self, vm: &mut VirtualMachine, ptr: Relocatable) -> Result<(), HintError> {
+        vm.insert_value(ptr, self.contract_address)?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmitEventRequest {
+    pub keys: Vec<Felt>,
+    pub data: Vec<Felt>,
+}
+
+impl SyscallRequest for EmitEventRequest {
+    const SYSCALL_NAME: &'static str = "EmitEvent";
+    const SIZE: usize = 5;
+
+    fn read_fields(vm: &VirtualMachine, ptr: Relocatable) -> Result<Self, HintError> {
+        Ok(EmitEventRequest {
+            keys: read_felt_array(vm, ptr)?,
+            data: read_felt_array(vm, ptr + 2_usize)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallContractRequest {
+    pub contract_address: Felt,
+    pub function_selector: Felt,
+    pub calldata: Vec<Felt>,
+}
+
+impl SyscallRequest for CallContractRequest {
+    const SYSCALL_NAME: &'static str = "CallContract";
+    const SIZE: usize = 5;
+
+    fn read_fields(vm: &VirtualMachine, ptr: Relocatable) -> Result<Self, HintError> {
+        Ok(CallContractRequest {
+            contract_address: vm.get_integer(ptr)?.into_owned(),
+            function_selector: vm.get_integer(ptr + 1_usize)?.into_owned(),
+            calldata: read_felt_array(vm, ptr + 2_usize)?,
+        })
+    }
+}
+
+/// Request of a library call, which shares the layout of `CallContractRequest` with the class
+/// hash in place of the contract address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LibraryCallRequest {
+    pub class_hash: Felt,
+    pub function_selector: Felt,
+    pub calldata: Vec<Felt>,
+}
+
+impl SyscallRequest for LibraryCallRequest {
+    const SYSCALL_NAME: &'static str = "LibraryCall";
+    const SIZE: usize = 5;
+
+    fn read_fields(vm: &VirtualMachine, ptr: Relocatable) -> Result<Self, HintError> {
+        Ok(LibraryCallRequest {
+            class_hash: vm.get_integer(ptr)?.into_owned(),
+            function_selector: vm.get_integer(ptr + 1_usize)?.into_owned(),
+            calldata: read_felt_array(vm, ptr + 2_usize)?,
+        })
+    }
+}
+
+/// Response of both contract and library calls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallContractResponse {
+    pub retdata: Vec<Felt>,
+}
+
+impl SyscallResponse for CallContractResponse {
+    /// Writes the retdata to a new segment, and its size and start at `ptr`.
+    fn write(self, vm: &mut VirtualMachine, ptr: Relocatable) -> Result<(), HintError> {
+        let retdata = self
+            .retdata
+            .into_iter()
+            .map(MaybeRelocatable::from)
+            .collect::<Vec<_>>();
+        let retdata_ptr = vm.add_memory_segment();
+        vm.load_data(&retdata_ptr.into(), &retdata)?;
+        vm.insert_value(ptr, Felt::from(retdata.len()))?;
+        vm.insert_value(ptr + 1_usize, retdata_ptr)?;
+        Ok(())
+    }
+}
+
+/// Reads an array given by its length at `ptr` and its start at `ptr + 1`.
+fn read_felt_array(vm: &VirtualMachine, ptr: Relocatable) -> Result<Vec<Felt>, HintError> {
+    let len = vm
+        .get_integer(ptr)?
+        .to_usize()
+        .ok_or(HintError::BigintToUsizeFail)?;
+    let start = vm.get_relocatable(ptr + 1_usize)?;
+    Ok(vm
+        .get_integer_range(start, len)?
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+        },
+    };
+    use assert_matches::assert_matches;
+    use std::collections::HashMap;
+
+    #[test]
+    fn read_call_contract_request() {
+        let mut vm = vm!();
+        vm.segments = segments![
+            ((1, 1), 3),
+            ((1, 2), 4),
+            ((1, 3), 2),
+            ((1, 4), (2, 0)),
+            ((2, 0), 5),
+            ((2, 1), 6)
+        ];
+        vm.insert_value((1, 0).into(), Felt::from_bytes_be(b"CallContract"))
+            .unwrap();
+        assert_eq!(
+            CallContractRequest::read(&vm, (1, 0).into()).unwrap(),
+            CallContractRequest {
+                contract_address: Felt::new(3),
+                function_selector: Felt::new(4),
+                calldata: vec![Felt::new(5), Felt::new(6)],
+            }
+        );
+    }
+
+    #[test]
+    fn read_request_invalid_selector() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 1), ((1, 1), 3)];
+        assert_matches!(
+            StorageReadRequest::read(&vm, (1, 0).into()),
+            Err(HintError::InvalidSyscallSelector(name, ptr, selector))
+                if name == "StorageRead" && ptr == (1, 0).into() && selector == Felt::new(1)
+        );
+    }
+
+    #[test]
+    fn write_call_contract_response() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 0)];
+        CallContractResponse {
+            retdata: vec![Felt::new(7), Felt::new(8)],
+        }
+        .write(&mut vm, (1, 5).into())
+        .unwrap();
+        check_memory![
+            vm.segments.memory,
+            ((1, 5), 2),
+            ((1, 6), (2, 0)),
+            ((2, 0), 7),
+            ((2, 1), 8)
+        ];
+    }
+}
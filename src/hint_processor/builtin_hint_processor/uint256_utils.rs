@@ -6,9 +6,13 @@ use crate::{
     hint_processor::hint_processor_definition::HintReference,
     math_utils::isqrt,
     serde::deserialize_program::ApTracking,
-    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
 };
 use felt::Felt;
+use num_bigint::BigUint;
 use num_integer::div_rem;
 use num_traits::{One, Signed, Zero};
 use std::{
@@ -73,15 +77,36 @@ pub fn split_64(
     vm: &mut VirtualMachine,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    split_by_mask(vm, ids_data, ap_tracking, 64)
+}
+
+/*
+Implements hint:
+%{
+    ids.low = ids.a & ((1<<128) - 1)
+    ids.high = ids.a >> 128
+%}
+*/
+pub fn split_128(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    split_by_mask(vm, ids_data, ap_tracking, 128)
+}
+
+// Splits ids.a into the bits under the mask ((1 << bits) - 1) and the ones above it
+fn split_by_mask(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    bits: u32,
 ) -> Result<(), HintError> {
     let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
-    let mut digits = a.iter_u64_digits();
-    let low = Felt::new(digits.next().unwrap_or(0u64));
-    let high = if digits.len() <= 1 {
-        Felt::new(digits.next().unwrap_or(0u64))
-    } else {
-        a.as_ref().shr(64_u32)
-    };
+    let mask = (Felt::one() << bits) - Felt::one();
+    let low = a.as_ref() & &mask;
+    let high = a.as_ref().shr(bits);
     insert_value_from_var_name("high", high, vm, ids_data, ap_tracking)?;
     insert_value_from_var_name("low", low, vm, ids_data, ap_tracking)
 }
@@ -102,8 +127,38 @@ pub fn uint256_sqrt(
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
 ) -> Result<(), HintError> {
-    let n_addr = get_relocatable_from_var_name("n", vm, ids_data, ap_tracking)?;
+    let root = get_uint256_sqrt(vm, ids_data, ap_tracking)?;
     let root_addr = get_relocatable_from_var_name("root", vm, ids_data, ap_tracking)?;
+    vm.insert_value(root_addr, root)?;
+    vm.insert_value(root_addr + 1_i32, Felt::zero())
+        .map_err(HintError::Memory)
+}
+
+/*
+Implements hint:
+%{
+    from starkware.python.math_utils import isqrt
+    n = (ids.n.high << 128) + ids.n.low
+    root = isqrt(n)
+    assert 0 <= root < 2 ** 128
+    ids.root = root
+%}
+*/
+pub fn uint256_sqrt_felt(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let root = get_uint256_sqrt(vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("root", root, vm, ids_data, ap_tracking)
+}
+
+fn get_uint256_sqrt(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Felt, HintError> {
+    let n_addr = get_relocatable_from_var_name("n", vm, ids_data, ap_tracking)?;
     let n_low = vm.get_integer(n_addr)?;
     let n_high = vm.get_integer(n_addr + 1_usize)?;
     let n_low = n_low.as_ref();
@@ -114,21 +169,17 @@ pub fn uint256_sqrt(
     //n = (ids.n.high << 128) + ids.n.low
     //root = isqrt(n)
     //assert 0 <= root < 2 ** 128
-    //ids.root.low = root
-    //ids.root.high = 0
 
     #[allow(deprecated)]
     let root = isqrt(&(&n_high.to_biguint().shl(128_u32) + n_low.to_biguint()))?;
 
-    if root >= num_bigint::BigUint::one().shl(128_u32) {
+    if root >= BigUint::one().shl(128_u32) {
         return Err(HintError::AssertionFailed(format!(
             "assert 0 <= {} < 2 ** 128",
             &root
         )));
     }
-    vm.insert_value(root_addr, Felt::new(root))?;
-    vm.insert_value(root_addr + 1_i32, Felt::zero())
-        .map_err(HintError::Memory)
+    Ok(Felt::new(root))
 }
 
 /*
@@ -170,20 +221,51 @@ pub fn uint256_unsigned_div_rem(
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
 ) -> Result<(), HintError> {
-    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
-    let div_addr = get_relocatable_from_var_name("div", vm, ids_data, ap_tracking)?;
+    uint256_offseted_unsigned_div_rem(vm, ids_data, ap_tracking, 0, 1)
+}
+
+/*
+Implements hint:
+%{
+    a = (ids.a.high << 128) + ids.a.low
+    div = (ids.div.b23 << 128) + ids.div.b01
+    quotient, remainder = divmod(a, div)
+
+    ids.quotient.low = quotient & ((1 << 128) - 1)
+    ids.quotient.high = quotient >> 128
+    ids.remainder.low = remainder & ((1 << 128) - 1)
+    ids.remainder.high = remainder >> 128
+%}
+*/
+pub fn uint256_expanded_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    // ids.div is a Uint256Expand, whose members are B0, b01, b12, b23 and b3
+    uint256_offseted_unsigned_div_rem(vm, ids_data, ap_tracking, 1, 3)
+}
+
+// Runs the unsigned_div_rem hints, reading the low and high limbs of ids.div at the given offsets
+fn uint256_offseted_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    div_low_offset: usize,
+    div_high_offset: usize,
+) -> Result<(), HintError> {
+    let a = get_uint256_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let div = get_uint256_limbs_from_var_name(
+        "div",
+        vm,
+        ids_data,
+        ap_tracking,
+        div_low_offset,
+        div_high_offset,
+    )?;
     let quotient_addr = get_relocatable_from_var_name("quotient", vm, ids_data, ap_tracking)?;
     let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
 
-    let a_low = vm.get_integer(a_addr)?;
-    let a_high = vm.get_integer(a_addr + 1_usize)?;
-    let div_low = vm.get_integer(div_addr)?;
-    let div_high = vm.get_integer(div_addr + 1_usize)?;
-    let a_low = a_low.as_ref();
-    let a_high = a_high.as_ref();
-    let div_low = div_low.as_ref();
-    let div_high = div_high.as_ref();
-
     //Main logic
     //a = (ids.a.high << 128) + ids.a.low
     //div = (ids.div.high << 128) + ids.div.low
@@ -194,16 +276,16 @@ pub fn uint256_unsigned_div_rem(
     //ids.remainder.low = remainder & ((1 << 128) - 1)
     //ids.remainder.high = remainder >> 128
 
-    let a = &a_high.shl(128_usize) + a_low;
-    let div = &div_high.shl(128_usize) + div_low;
-    //a and div will always be positive numbers
-    //Then, Rust div_rem equals Python divmod
+    //a and div can exceed the field, so they are divided as integers
+    if div.is_zero() {
+        return Err(VirtualMachineError::DividedByZero.into());
+    }
     let (quotient, remainder) = div_rem(a, div);
-    let quotient_low = &quotient & &Felt::new(u128::MAX);
-    let quotient_high = quotient.shr(128);
+    let quotient_low = Felt::new(&quotient & BigUint::from(u128::MAX));
+    let quotient_high = Felt::new(quotient.shr(128_u32));
 
-    let remainder_low = &remainder & &Felt::new(u128::MAX);
-    let remainder_high = remainder.shr(128);
+    let remainder_low = Felt::new(&remainder & BigUint::from(u128::MAX));
+    let remainder_high = Felt::new(remainder.shr(128_u32));
 
     //Insert ids.quotient.low
     vm.insert_value(quotient_addr, quotient_low)?;
@@ -216,6 +298,128 @@ pub fn uint256_unsigned_div_rem(
     Ok(())
 }
 
+/*
+Implements hint:
+%{
+    a = (ids.a.high << 128) + ids.a.low
+    b = (ids.b.high << 128) + ids.b.low
+    div = (ids.div.high << 128) + ids.div.low
+    quotient, remainder = divmod(a * b, div)
+
+    ids.quotient_low.low = quotient & ((1 << 128) - 1)
+    ids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)
+    ids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)
+    ids.quotient_high.high = quotient >> 384
+    ids.remainder.low = remainder & ((1 << 128) - 1)
+    ids.remainder.high = remainder >> 128
+%}
+*/
+pub fn uint256_mul_div_mod(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a = get_uint256_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b = get_uint256_from_var_name("b", vm, ids_data, ap_tracking)?;
+    let div = get_uint256_from_var_name("div", vm, ids_data, ap_tracking)?;
+    let quotient_low_addr =
+        get_relocatable_from_var_name("quotient_low", vm, ids_data, ap_tracking)?;
+    let quotient_high_addr =
+        get_relocatable_from_var_name("quotient_high", vm, ids_data, ap_tracking)?;
+    let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
+
+    //Main logic
+    //The product can take up to 512 bits, so it is computed outside the field
+    if div.is_zero() {
+        return Err(VirtualMachineError::DividedByZero.into());
+    }
+    let (quotient, remainder) = div_rem(a * b, div);
+    let limb = |value: &BigUint, index: u32| {
+        Felt::new((value >> (128 * index)) & BigUint::from(u128::MAX))
+    };
+
+    //Insert ids.quotient_low.low, ids.quotient_low.high
+    vm.insert_value(quotient_low_addr, limb(&quotient, 0))?;
+    vm.insert_value(quotient_low_addr + 1_i32, limb(&quotient, 1))?;
+    //Insert ids.quotient_high.low, ids.quotient_high.high
+    vm.insert_value(quotient_high_addr, limb(&quotient, 2))?;
+    vm.insert_value(quotient_high_addr + 1_i32, limb(&quotient, 3))?;
+    //Insert ids.remainder.low, ids.remainder.high
+    vm.insert_value(remainder_addr, limb(&remainder, 0))?;
+    vm.insert_value(remainder_addr + 1_i32, limb(&remainder, 1))?;
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    def split(num: int, num_bits_shift: int = 128, length: int = 2):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int = 128) -> int:
+        limbs = (z.low, z.high)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack(ids.a)
+    b = pack(ids.b)
+    res = (a - b)%2**256
+    res_split = split(res)
+    ids.res.low = res_split[0]
+    ids.res.high = res_split[1]
+%}
+*/
+pub fn uint256_sub(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a = get_uint256_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b = get_uint256_from_var_name("b", vm, ids_data, ap_tracking)?;
+    let res_addr = get_relocatable_from_var_name("res", vm, ids_data, ap_tracking)?;
+
+    //Main logic
+    //res = (a - b)%2**256
+    let res = if a >= b {
+        a - b
+    } else {
+        (BigUint::one().shl(256_u32) + a) - b
+    };
+
+    //Insert ids.res.low, ids.res.high
+    vm.insert_value(res_addr, Felt::new(&res & BigUint::from(u128::MAX)))?;
+    vm.insert_value(res_addr + 1_i32, Felt::new(res.shr(128_u32)))
+        .map_err(HintError::Memory)
+}
+
+// Packs the Uint256 variable var_name as (high << 128) + low
+fn get_uint256_from_var_name(
+    var_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<BigUint, HintError> {
+    get_uint256_limbs_from_var_name(var_name, vm, ids_data, ap_tracking, 0, 1)
+}
+
+// Packs the limbs of var_name at the given offsets as (high << 128) + low
+fn get_uint256_limbs_from_var_name(
+    var_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    low_offset: usize,
+    high_offset: usize,
+) -> Result<BigUint, HintError> {
+    let addr = get_relocatable_from_var_name(var_name, vm, ids_data, ap_tracking)?;
+    let low = vm.get_integer(addr + low_offset)?;
+    let high = vm.get_integer(addr + high_offset)?;
+    Ok(high.to_biguint().shl(128_u32) + low.to_biguint())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -432,6 +636,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_split_128_ok() {
+        let hint_code = "ids.low = ids.a & ((1<<128) - 1)\nids.high = ids.a >> 128";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("a", -3), ("high", 1), ("low", 0)];
+        //Insert ids.a into memory
+        vm.segments = segments![((1, 7), ("4083388403051261561560495289181218537477", 10))];
+        //Execute the hint
+        assert_matches!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.low, ids.high
+        check_memory![vm.segments.memory, ((1, 10), 5), ((1, 11), 12)];
+    }
+
+    #[test]
+    fn run_uint256_sqrt_felt_ok() {
+        let hint_code = "from starkware.python.math_utils import isqrt\nn = (ids.n.high << 128) + ids.n.low\nroot = isqrt(n)\nassert 0 <= root < 2 ** 128\nids.root = root";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 5;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("n", -5), ("root", 0)];
+        vm.segments = segments![((1, 0), 17), ((1, 1), 7)];
+        //Execute the hint
+        assert_matches!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.root
+        check_memory![vm.segments.memory, ((1, 5), 48805497317890012913_u128)];
+        assert_eq!(vm.get_maybe(&Relocatable::from((1, 6))), None);
+    }
+
+    #[test]
+    fn run_uint256_sqrt_felt_assert_error() {
+        let hint_code = "from starkware.python.math_utils import isqrt\nn = (ids.n.high << 128) + ids.n.low\nroot = isqrt(n)\nassert 0 <= root < 2 ** 128\nids.root = root";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 5;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("n", -5), ("root", 0)];
+        vm.segments = segments![
+            ((1, 0), 0),
+            ((1, 1), ("340282366920938463463374607431768211458", 10))
+        ];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::AssertionFailed(x)) if x == *String::from(
+                "assert 0 <= 340282366920938463463374607431768211456 < 2 ** 128"
+            )
+        );
+    }
+
     #[test]
     fn run_signed_nn_ok_result_one() {
         let hint_code = "memory[ap] = 1 if 0 <= (ids.a.high % PRIME) < 2 ** 127 else 0";
@@ -557,4 +816,230 @@ mod tests {
                     z == MaybeRelocatable::from(Felt::new(10))
         );
     }
+
+    #[test]
+    fn run_unsigned_div_rem_divided_by_zero() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\ndiv = (ids.div.high << 128) + ids.div.low\nquotient, remainder = divmod(a, div)\n\nids.quotient.low = quotient & ((1 << 128) - 1)\nids.quotient.high = quotient >> 128\nids.remainder.low = remainder & ((1 << 128) - 1)\nids.remainder.high = remainder >> 128";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data =
+            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)];
+        //Insert ids into memory
+        vm.segments = segments![((1, 4), 89), ((1, 5), 72), ((1, 6), 0), ((1, 7), 0)];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::Internal(VirtualMachineError::DividedByZero))
+        );
+    }
+
+    #[test]
+    fn run_unsigned_div_rem_above_prime() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\ndiv = (ids.div.high << 128) + ids.div.low\nquotient, remainder = divmod(a, div)\n\nids.quotient.low = quotient & ((1 << 128) - 1)\nids.quotient.high = quotient >> 128\nids.remainder.low = remainder & ((1 << 128) - 1)\nids.remainder.high = remainder >> 128";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data =
+            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)];
+        //Insert ids into memory
+        //ids.a is (2^128 - 1) * 2^128, which is above the prime
+        vm.segments = segments![
+            ((1, 4), 0),
+            ((1, 5), ("340282366920938463463374607431768211455", 10)),
+            ((1, 6), 0),
+            ((1, 7), 1)
+        ];
+        //Execute the hint
+        assert_matches!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.quotient.low, ids.quotient.high, ids.remainder.low, ids.remainder.high
+        check_memory![
+            vm.segments.memory,
+            ((1, 10), ("340282366920938463463374607431768211455", 10)),
+            ((1, 11), 0),
+            ((1, 12), 0),
+            ((1, 13), 0)
+        ];
+    }
+
+    #[test]
+    fn run_expanded_unsigned_div_rem_ok() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\ndiv = (ids.div.b23 << 128) + ids.div.b01\nquotient, remainder = divmod(a, div)\n\nids.quotient.low = quotient & ((1 << 128) - 1)\nids.quotient.high = quotient >> 128\nids.remainder.low = remainder & ((1 << 128) - 1)\nids.remainder.high = remainder >> 128";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data =
+            non_continuous_ids_data![("a", -7), ("div", -5), ("quotient", 0), ("remainder", 2)];
+        //Insert ids into memory
+        //ids.div is a Uint256Expand of Uint256(3, 7), only b01 and b23 are read
+        vm.segments = segments![
+            ((1, 3), 89),
+            ((1, 4), 72),
+            ((1, 5), 0),
+            ((1, 6), 3),
+            ((1, 7), 0),
+            ((1, 8), 7),
+            ((1, 9), 0)
+        ];
+        //Execute the hint
+        assert_matches!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.quotient.low, ids.quotient.high, ids.remainder.low, ids.remainder.high
+        check_memory![
+            vm.segments.memory,
+            ((1, 10), 10),
+            ((1, 11), 0),
+            ((1, 12), 59),
+            ((1, 13), 2)
+        ];
+    }
+
+    #[test]
+    fn run_expanded_unsigned_div_rem_divided_by_zero() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\ndiv = (ids.div.b23 << 128) + ids.div.b01\nquotient, remainder = divmod(a, div)\n\nids.quotient.low = quotient & ((1 << 128) - 1)\nids.quotient.high = quotient >> 128\nids.remainder.low = remainder & ((1 << 128) - 1)\nids.remainder.high = remainder >> 128";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data =
+            non_continuous_ids_data![("a", -7), ("div", -5), ("quotient", 0), ("remainder", 2)];
+        //Insert ids into memory
+        //ids.div is a Uint256Expand of Uint256(0, 0)
+        vm.segments = segments![
+            ((1, 3), 89),
+            ((1, 4), 72),
+            ((1, 5), 0),
+            ((1, 6), 0),
+            ((1, 7), 0),
+            ((1, 8), 0),
+            ((1, 9), 0)
+        ];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::Internal(VirtualMachineError::DividedByZero))
+        );
+    }
+
+    #[test]
+    fn run_mul_div_mod_ok() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\nb = (ids.b.high << 128) + ids.b.low\ndiv = (ids.div.high << 128) + ids.div.low\nquotient, remainder = divmod(a * b, div)\n\nids.quotient_low.low = quotient & ((1 << 128) - 1)\nids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)\nids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)\nids.quotient_high.high = quotient >> 384\nids.remainder.low = remainder & ((1 << 128) - 1)\nids.remainder.high = remainder >> 128";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![
+            ("a", -8),
+            ("b", -6),
+            ("div", -4),
+            ("quotient_low", 0),
+            ("quotient_high", 2),
+            ("remainder", 4)
+        ];
+        //Insert ids into memory
+        vm.segments = segments![
+            ((1, 2), 1),
+            ((1, 3), ("340282366920938463463374607431768211455", 10)),
+            ((1, 4), 1),
+            ((1, 5), ("340282366920938463463374607431768211455", 10)),
+            ((1, 6), 5),
+            ((1, 7), 0)
+        ];
+        //Execute the hint
+        assert_matches!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.quotient_low, ids.quotient_high, ids.remainder
+        let expected = [
+            felt_str!("0"),
+            felt_str!("136112946768375385385349842972707284582"),
+            felt_str!("272225893536750770770699685945414569165"),
+            felt_str!("68056473384187692692674921486353642290"),
+            felt_str!("1"),
+            felt_str!("0"),
+        ];
+        for (offset, value) in expected.iter().enumerate() {
+            assert_eq!(
+                vm.get_integer(Relocatable::from((1, 10 + offset)))
+                    .unwrap()
+                    .as_ref(),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn run_mul_div_mod_divided_by_zero() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\nb = (ids.b.high << 128) + ids.b.low\ndiv = (ids.div.high << 128) + ids.div.low\nquotient, remainder = divmod(a * b, div)\n\nids.quotient_low.low = quotient & ((1 << 128) - 1)\nids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)\nids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)\nids.quotient_high.high = quotient >> 384\nids.remainder.low = remainder & ((1 << 128) - 1)\nids.remainder.high = remainder >> 128";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![
+            ("a", -8),
+            ("b", -6),
+            ("div", -4),
+            ("quotient_low", 0),
+            ("quotient_high", 2),
+            ("remainder", 4)
+        ];
+        //Insert ids into memory
+        vm.segments = segments![
+            ((1, 2), 89),
+            ((1, 3), 72),
+            ((1, 4), 3),
+            ((1, 5), 7),
+            ((1, 6), 0),
+            ((1, 7), 0)
+        ];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::Internal(VirtualMachineError::DividedByZero))
+        );
+    }
+
+    #[test]
+    fn run_uint256_sub_ok() {
+        let hint_code = "def split(num: int, num_bits_shift: int = 128, length: int = 2):\n    a = []\n    for _ in range(length):\n        a.append( num & ((1 << num_bits_shift) - 1) )\n        num = num >> num_bits_shift\n    return tuple(a)\n\ndef pack(z, num_bits_shift: int = 128) -> int:\n    limbs = (z.low, z.high)\n    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))\n\na = pack(ids.a)\nb = pack(ids.b)\nres = (a - b)%2**256\nres_split = split(res)\nids.res.low = res_split[0]\nids.res.high = res_split[1]";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("a", -4), ("b", -2), ("res", 0)];
+        //Insert ids into memory
+        vm.segments = segments![((1, 6), 4), ((1, 7), 3), ((1, 8), 1), ((1, 9), 2)];
+        //Execute the hint
+        assert_matches!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.res.low, ids.res.high
+        check_memory![vm.segments.memory, ((1, 10), 3), ((1, 11), 1)];
+    }
+
+    #[test]
+    fn run_uint256_sub_wraps_around() {
+        let hint_code = "def split(num: int, num_bits_shift: int = 128, length: int = 2):\n    a = []\n    for _ in range(length):\n        a.append( num & ((1 << num_bits_shift) - 1) )\n        num = num >> num_bits_shift\n    return tuple(a)\n\ndef pack(z, num_bits_shift: int = 128) -> int:\n    limbs = (z.low, z.high)\n    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))\n\na = pack(ids.a)\nb = pack(ids.b)\nres = (a - b)%2**256\nres_split = split(res)\nids.res.low = res_split[0]\nids.res.high = res_split[1]";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("a", -4), ("b", -2), ("res", 0)];
+        //Insert ids into memory
+        vm.segments = segments![((1, 6), 1), ((1, 7), 2), ((1, 8), 4), ((1, 9), 3)];
+        //Execute the hint
+        assert_matches!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.res.low, ids.res.high
+        assert_eq!(
+            vm.get_integer(Relocatable::from((1, 10))).unwrap().as_ref(),
+            &felt_str!("340282366920938463463374607431768211453")
+        );
+        assert_eq!(
+            vm.get_integer(Relocatable::from((1, 11))).unwrap().as_ref(),
+            &felt_str!("340282366920938463463374607431768211454")
+        );
+    }
 }
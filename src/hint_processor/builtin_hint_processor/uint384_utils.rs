@@ -0,0 +1,538 @@
+//! Hints of the `uint384` and `uint384_extension` libraries, whose integers are made of 128-bit
+//! limbs `d0`, `d1`, ..., from the least significant one.
+//!
+//! The `uint384_split_128` hint has the same code as the `split_128` hint of `uint256`, and is run
+//! by [`split_128`](super::uint256_utils::split_128).
+use crate::{
+    hint_processor::builtin_hint_processor::hint_utils::{
+        get_relocatable_from_var_name, insert_value_from_var_name, insert_value_into_ap,
+    },
+    hint_processor::hint_processor_definition::HintReference,
+    math_utils::isqrt,
+    serde::deserialize_program::ApTracking,
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
+};
+use felt::Felt;
+use num_bigint::BigUint;
+use num_integer::div_rem;
+use num_traits::{One, Signed, Zero};
+use std::{
+    collections::HashMap,
+    ops::{Shl, Shr},
+};
+
+/*
+Implements hint:
+%{
+    def split(num: int, num_bits_shift: int, length: int):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack(ids.a, num_bits_shift = 128)
+    div = pack(ids.div, num_bits_shift = 128)
+    quotient, remainder = divmod(a, div)
+
+    quotient_split = split(quotient, num_bits_shift=128, length=3)
+    assert len(quotient_split) == 3
+
+    ids.quotient.d0 = quotient_split[0]
+    ids.quotient.d1 = quotient_split[1]
+    ids.quotient.d2 = quotient_split[2]
+
+    remainder_split = split(remainder, num_bits_shift=128, length=3)
+    ids.remainder.d0 = remainder_split[0]
+    ids.remainder.d1 = remainder_split[1]
+    ids.remainder.d2 = remainder_split[2]
+%}
+*/
+pub fn uint384_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    uint_unsigned_div_rem(vm, ids_data, ap_tracking, 3)
+}
+
+/*
+Implements hint:
+%{
+    def split(num: int, num_bits_shift: int, length: int):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    def pack_extended(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2, z.d3, z.d4, z.d5)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack_extended(ids.a, num_bits_shift = 128)
+    div = pack(ids.div, num_bits_shift = 128)
+
+    quotient, remainder = divmod(a, div)
+
+    quotient_split = split(quotient, num_bits_shift=128, length=6)
+
+    ids.quotient.d0 = quotient_split[0]
+    ids.quotient.d1 = quotient_split[1]
+    ids.quotient.d2 = quotient_split[2]
+    ids.quotient.d3 = quotient_split[3]
+    ids.quotient.d4 = quotient_split[4]
+    ids.quotient.d5 = quotient_split[5]
+
+    remainder_split = split(remainder, num_bits_shift=128, length=3)
+    ids.remainder.d0 = remainder_split[0]
+    ids.remainder.d1 = remainder_split[1]
+    ids.remainder.d2 = remainder_split[2]
+%}
+*/
+pub fn uint768_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    uint_unsigned_div_rem(vm, ids_data, ap_tracking, 6)
+}
+
+// Divides ids.a, of a_limbs limbs, by the 3-limb ids.div, writing a quotient of a_limbs limbs
+fn uint_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    a_limbs: usize,
+) -> Result<(), HintError> {
+    let a = get_uint_from_var_name("a", a_limbs, vm, ids_data, ap_tracking)?;
+    let div = get_uint_from_var_name("div", 3, vm, ids_data, ap_tracking)?;
+
+    //Main logic
+    //a and div are nonnegative, so Rust div_rem equals Python divmod
+    if div.is_zero() {
+        return Err(VirtualMachineError::DividedByZero.into());
+    }
+    let (quotient, remainder) = div_rem(a, div);
+
+    insert_uint_into_var_name("quotient", &quotient, a_limbs, vm, ids_data, ap_tracking)?;
+    insert_uint_into_var_name("remainder", &remainder, 3, vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hint:
+%{
+    sum_d0 = ids.a.d0 + ids.b.d0
+    ids.carry_d0 = 1 if sum_d0 >= ids.SHIFT else 0
+    sum_d1 = ids.a.d1 + ids.b.d1 + ids.carry_d0
+    ids.carry_d1 = 1 if sum_d1 >= ids.SHIFT else 0
+    sum_d2 = ids.a.d2 + ids.b.d2 + ids.carry_d1
+    ids.carry_d2 = 1 if sum_d2 >= ids.SHIFT else 0
+%}
+*/
+pub fn add_no_uint384_check(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let shift = Felt::one() << 128_u32;
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b_addr = get_relocatable_from_var_name("b", vm, ids_data, ap_tracking)?;
+
+    let mut carry = Felt::zero();
+    for (i, carry_name) in ["carry_d0", "carry_d1", "carry_d2"].into_iter().enumerate() {
+        let a_limb = vm.get_integer(a_addr + i)?;
+        let b_limb = vm.get_integer(b_addr + i)?;
+        carry = if a_limb.as_ref() + b_limb.as_ref() + &carry >= shift {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+        insert_value_from_var_name(carry_name, carry.clone(), vm, ids_data, ap_tracking)?;
+    }
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.python.math_utils import isqrt
+
+    def split(num: int, num_bits_shift: int, length: int):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack(ids.a, num_bits_shift=128)
+    root = isqrt(a)
+    assert 0 <= root < 2 ** 192
+    root_split = split(root, num_bits_shift=128, length=3)
+    ids.root.d0 = root_split[0]
+    ids.root.d1 = root_split[1]
+    ids.root.d2 = root_split[2]
+%}
+*/
+pub fn uint384_sqrt(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a = get_uint_from_var_name("a", 3, vm, ids_data, ap_tracking)?;
+
+    //Main logic
+    //root = isqrt(a)
+    //assert 0 <= root < 2 ** 192
+    let root = isqrt(&a)?;
+    if root >= BigUint::one().shl(192_u32) {
+        return Err(HintError::AssertionFailed(format!(
+            "assert 0 <= {} < 2 ** 192",
+            &root
+        )));
+    }
+    insert_uint_into_var_name("root", &root, 3, vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hint:
+%{ memory[ap] = 1 if 0 <= (ids.a.d2 % PRIME) < 2 ** 127 else 0 %}
+*/
+pub fn uint384_signed_nn(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let a_d2 = vm.get_integer(a_addr + 2_usize)?;
+    //Main logic
+    //memory[ap] = 1 if 0 <= (ids.a.d2 % PRIME) < 2 ** 127 else 0
+    let result: Felt = if !a_d2.is_negative() && a_d2.as_ref() <= &Felt::new(i128::MAX) {
+        Felt::one()
+    } else {
+        Felt::zero()
+    };
+    insert_value_into_ap(vm, result)
+}
+
+// Packs the n_limbs 128-bit limbs of var_name as sum(limb << (128 * i))
+fn get_uint_from_var_name(
+    var_name: &str,
+    n_limbs: usize,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<BigUint, HintError> {
+    let addr = get_relocatable_from_var_name(var_name, vm, ids_data, ap_tracking)?;
+    let mut value = BigUint::zero();
+    for i in (0..n_limbs).rev() {
+        value = value.shl(128_u32) + vm.get_integer(addr + i)?.to_biguint();
+    }
+    Ok(value)
+}
+
+// Splits value into n_limbs 128-bit limbs and writes them into var_name
+fn insert_uint_into_var_name(
+    var_name: &str,
+    value: &BigUint,
+    n_limbs: usize,
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let addr = get_relocatable_from_var_name(var_name, vm, ids_data, ap_tracking)?;
+    let mask = BigUint::from(u128::MAX);
+    for i in 0..n_limbs {
+        let limb = value.shr(128 * i) & &mask;
+        vm.insert_value(addr + i, Felt::new(limb))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{errors::memory_errors::MemoryError, vm_memory::memory::Memory},
+    };
+    use assert_matches::assert_matches;
+    use std::any::Any;
+
+    #[test]
+    fn run_uint384_unsigned_div_rem_ok() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 12;
+        //Create hint_data
+        let ids_data =
+            non_continuous_ids_data![("a", -12), ("div", -9), ("quotient", -6), ("remainder", -3)];
+        //Insert ids into memory
+        vm.segments = segments![
+            //a
+            ((1, 0), 83434123481193248),
+            ((1, 1), 82349321849739284),
+            ((1, 2), 839243219401320423),
+            //div
+            ((1, 3), 9283430921839492319493),
+            ((1, 4), 313248123482483248),
+            ((1, 5), 3790328402913840)
+        ];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::UINT384_UNSIGNED_DIV_REM),
+            Ok(())
+        );
+        //Check hint memory inserts
+        check_memory![
+            vm.segments.memory,
+            //quotient
+            ((1, 6), 221),
+            ((1, 7), 0),
+            ((1, 8), 0),
+            //remainder
+            ((1, 9), ("340282366920936411825224315027446796751", 10)),
+            ((1, 10), ("340282366920938463394229121463989152931", 10)),
+            ((1, 11), ("1580642357361782", 10))
+        ];
+    }
+
+    #[test]
+    fn run_uint384_unsigned_div_rem_divide_by_zero() {
+        let mut vm = vm!();
+        vm.run_context.fp = 12;
+        let ids_data =
+            non_continuous_ids_data![("a", -12), ("div", -9), ("quotient", -6), ("remainder", -3)];
+        vm.segments = segments![
+            ((1, 0), 1),
+            ((1, 1), 2),
+            ((1, 2), 3),
+            ((1, 3), 0),
+            ((1, 4), 0),
+            ((1, 5), 0)
+        ];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::UINT384_UNSIGNED_DIV_REM),
+            Err(HintError::Internal(VirtualMachineError::DividedByZero))
+        );
+    }
+
+    #[test]
+    fn run_uint384_unsigned_div_rem_invalid_memory_insert() {
+        let mut vm = vm!();
+        vm.run_context.fp = 12;
+        let ids_data =
+            non_continuous_ids_data![("a", -12), ("div", -9), ("quotient", -6), ("remainder", -3)];
+        vm.segments = segments![
+            ((1, 0), 7),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 2),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 2)
+        ];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::UINT384_UNSIGNED_DIV_REM),
+            Err(HintError::Memory(
+                MemoryError::InconsistentMemory(
+                    x,
+                    y,
+                    z
+                )
+            )) if x == MaybeRelocatable::from((1, 6)) &&
+                    y == MaybeRelocatable::from(Felt::new(2)) &&
+                    z == MaybeRelocatable::from(Felt::new(3))
+        );
+    }
+
+    #[test]
+    fn run_uint384_split_128_ok() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["a", "low", "high"];
+        vm.segments = segments![(
+            (1, 0),
+            (
+                "3618502788666131106986593281521497120414687020801267626233049500247285301247",
+                10
+            )
+        )];
+        assert_matches!(run_hint!(vm, ids_data, hint_code::SPLIT_128), Ok(()));
+        check_memory![
+            vm.segments.memory,
+            ((1, 1), ("340282366920938463463374607431768211455", 10)),
+            ((1, 2), ("10633823966279326983230456482242756607", 10))
+        ];
+    }
+
+    #[test]
+    fn run_add_no_uint384_check_ok() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 9;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![
+            ("a", -9),
+            ("b", -6),
+            ("carry_d0", -3),
+            ("carry_d1", -2),
+            ("carry_d2", -1)
+        ];
+        vm.segments = segments![
+            //a
+            ((1, 0), ("340282366920938463463374607431768211455", 10)),
+            ((1, 1), ("340282366920938463463374607431768211455", 10)),
+            ((1, 2), 3),
+            //b
+            ((1, 3), 1),
+            ((1, 4), 0),
+            ((1, 5), 4)
+        ];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::ADD_NO_UINT384_CHECK),
+            Ok(())
+        );
+        //Check hint memory inserts
+        check_memory![vm.segments.memory, ((1, 6), 1), ((1, 7), 1), ((1, 8), 0)];
+    }
+
+    #[test]
+    fn run_uint384_sqrt_ok() {
+        let mut vm = vm!();
+        vm.run_context.fp = 6;
+        let ids_data = non_continuous_ids_data![("a", -6), ("root", -3)];
+        //a = 2**256 + 2 * 2**128 + 1 = (2**128 + 1)**2
+        vm.segments = segments![((1, 0), 1), ((1, 1), 2), ((1, 2), 1)];
+        assert_matches!(run_hint!(vm, ids_data, hint_code::UINT384_SQRT), Ok(()));
+        check_memory![vm.segments.memory, ((1, 3), 1), ((1, 4), 1), ((1, 5), 0)];
+    }
+
+    #[test]
+    fn run_uint384_sqrt_assert_error() {
+        let mut vm = vm!();
+        vm.run_context.fp = 6;
+        let ids_data = non_continuous_ids_data![("a", -6), ("root", -3)];
+        //a = 2**384 is out of range, as the limbs aren't range checked
+        vm.segments = segments![
+            ((1, 0), 0),
+            ((1, 1), 0),
+            ((1, 2), ("340282366920938463463374607431768211456", 10))
+        ];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::UINT384_SQRT),
+            Err(HintError::AssertionFailed(message))
+                if message == format!("assert 0 <= {} < 2 ** 192", BigUint::one().shl(192_u32))
+        );
+    }
+
+    #[test]
+    fn run_uint384_signed_nn_ok_positive() {
+        let mut vm = vm!();
+        //Initialize fp and ap
+        vm.run_context.fp = 3;
+        vm.run_context.ap = 4;
+        let ids_data = non_continuous_ids_data![("a", -3)];
+        vm.segments = segments![
+            ((1, 0), 1),
+            ((1, 1), 1),
+            ((1, 2), ("170141183460469231731687303715884105727", 10))
+        ];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::UINT384_SIGNED_NN),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 4), 1)];
+    }
+
+    #[test]
+    fn run_uint384_signed_nn_ok_negative() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        vm.run_context.ap = 4;
+        let ids_data = non_continuous_ids_data![("a", -3)];
+        vm.segments = segments![
+            ((1, 0), 1),
+            ((1, 1), 1),
+            ((1, 2), ("170141183460469231731687303715884105728", 10))
+        ];
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::UINT384_SIGNED_NN),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 4), 0)];
+    }
+
+    #[test]
+    fn run_uint768_unsigned_div_rem_ok() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 18;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![
+            ("a", -18),
+            ("div", -12),
+            ("quotient", -9),
+            ("remainder", -3)
+        ];
+        vm.segments = segments![
+            //a = 2**640 + 5
+            ((1, 0), 5),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 0),
+            ((1, 4), 0),
+            ((1, 5), 1),
+            //div = 2**256 + 3
+            ((1, 6), 3),
+            ((1, 7), 0),
+            ((1, 8), 1)
+        ];
+        //Execute the hint
+        assert_matches!(
+            run_hint!(vm, ids_data, hint_code::UNSIGNED_DIV_REM_UINT768_BY_UINT384),
+            Ok(())
+        );
+        //Check hint memory inserts
+        check_memory![
+            vm.segments.memory,
+            //quotient
+            ((1, 9), 0),
+            ((1, 10), ("340282366920938463463374607431768211453", 10)),
+            ((1, 11), ("340282366920938463463374607431768211455", 10)),
+            ((1, 12), 0),
+            ((1, 13), 0),
+            ((1, 14), 0),
+            //remainder
+            ((1, 15), 5),
+            ((1, 16), 9),
+            ((1, 17), 0)
+        ];
+    }
+}
@@ -0,0 +1,1655 @@
+use super::{
+    dict_manager::{DictManagerExecScope, DictSquashExecScope},
+    hints::{BinOpOperand, CellRef, DerefOrImmediate, Hint, Operation, ResOperand},
+};
+use crate::{
+    any_box,
+    hint_processor::hint_processor_definition::{HintProcessor, HintReference},
+    math_utils::{is_quad_residue, isqrt, sqrt_prime},
+    serde::deserialize_program::ApTracking,
+    types::{
+        exec_scope::ExecutionScopes,
+        instruction::Register,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
+};
+use felt::{Felt, PRIME_STR};
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{Num, One, ToPrimitive, Zero};
+use rand::Rng;
+use std::{any::Any, collections::HashMap};
+
+const DICT_MANAGER: &str = "dict_manager_exec_scope";
+const DICT_SQUASH: &str = "dict_squash_exec_scope";
+const EXCLUDED_ARC: &str = "excluded_arc";
+const CONSTANT_SEGMENT: &str = "constant_segment";
+// Size of a DictAccess: key, previous value and new value.
+const DICT_ACCESS_SIZE: usize = 3;
+// Python's `PRIME // 3 // 2**128 + 1` and `PRIME // 2 // 2**128 + 1`, used to split the arcs of
+// AssertLeFindSmallArcs into range checked limbs.
+const PRIME_OVER_3_HIGH: &str = "3544607988759775765608368578435044694";
+const PRIME_OVER_2_HIGH: &str = "5316911983139663648412552867652567041";
+// The `beta` coefficient of the STARK curve, y^2 = x^3 + x + beta.
+const STARK_CURVE_BETA: &str =
+    "3141592653589793238462643383279502884197169399375105820974944592307816406665";
+
+/// Executes the structured hints of Cairo 1 programs, as loaded by `Program::from_casm_file`.
+///
+/// Each hint is stored in the program as its JSON representation, which `compile_hint` parses
+/// back into a `Hint`. The cells referenced by the hint are resolved against the current `ap`
+/// and `fp`.
+#[derive(Debug, Default)]
+pub struct Cairo1HintProcessor {}
+
+impl Cairo1HintProcessor {
+    pub fn new() -> Self {
+        Cairo1HintProcessor {}
+    }
+
+    fn alloc_segment(&self, vm: &mut VirtualMachine, dst: &CellRef) -> Result<(), HintError> {
+        let segment = vm.add_memory_segment();
+        vm.insert_value(cell_ref_to_relocatable(dst, vm)?, segment)?;
+        Ok(())
+    }
+
+    fn test_less_than(
+        &self,
+        vm: &mut VirtualMachine,
+        lhs: &ResOperand,
+        rhs: &ResOperand,
+        dst: &CellRef,
+        or_equal: bool,
+    ) -> Result<(), HintError> {
+        let lhs = res_operand_get_val(vm, lhs)?;
+        let rhs = res_operand_get_val(vm, rhs)?;
+        let result = if or_equal { lhs <= rhs } else { lhs < rhs };
+        vm.insert_value(
+            cell_ref_to_relocatable(dst, vm)?,
+            Felt::from(result as usize),
+        )?;
+        Ok(())
+    }
+
+    fn wide_mul_128(
+        &self,
+        vm: &mut VirtualMachine,
+        lhs: &ResOperand,
+        rhs: &ResOperand,
+        high: &CellRef,
+        low: &CellRef,
+    ) -> Result<(), HintError> {
+        let product =
+            res_operand_get_val(vm, lhs)?.to_biguint() * res_operand_get_val(vm, rhs)?.to_biguint();
+        let (high_value, low_value) = product.div_rem(&pow2_128());
+        vm.insert_value(cell_ref_to_relocatable(high, vm)?, Felt::from(high_value))?;
+        vm.insert_value(cell_ref_to_relocatable(low, vm)?, Felt::from(low_value))?;
+        Ok(())
+    }
+
+    fn div_mod(
+        &self,
+        vm: &mut VirtualMachine,
+        lhs: &ResOperand,
+        rhs: &ResOperand,
+        quotient: &CellRef,
+        remainder: &CellRef,
+    ) -> Result<(), HintError> {
+        let lhs = res_operand_get_val(vm, lhs)?.to_biguint();
+        let rhs = res_operand_get_val(vm, rhs)?.to_biguint();
+        if rhs.is_zero() {
+            return Err(VirtualMachineError::DividedByZero.into());
+        }
+        let (quotient_value, remainder_value) = lhs.div_rem(&rhs);
+        vm.insert_value(
+            cell_ref_to_relocatable(quotient, vm)?,
+            Felt::from(quotient_value),
+        )?;
+        vm.insert_value(
+            cell_ref_to_relocatable(remainder, vm)?,
+            Felt::from(remainder_value),
+        )?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn uint256_div_mod(
+        &self,
+        vm: &mut VirtualMachine,
+        dividend0: &ResOperand,
+        dividend1: &ResOperand,
+        divisor0: &ResOperand,
+        divisor1: &ResOperand,
+        quotient0: &CellRef,
+        quotient1: &CellRef,
+        remainder0: &CellRef,
+        remainder1: &CellRef,
+    ) -> Result<(), HintError> {
+        let dividend = join_limbs(vm, &[dividend0, dividend1])?;
+        let divisor = join_limbs(vm, &[divisor0, divisor1])?;
+        if divisor.is_zero() {
+            return Err(VirtualMachineError::DividedByZero.into());
+        }
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        write_limbs(vm, quotient, &[quotient0, quotient1])?;
+        write_limbs(vm, remainder, &[remainder0, remainder1])
+    }
+
+    fn uint512_div_mod_by_uint256(
+        &self,
+        vm: &mut VirtualMachine,
+        dividend: &[&ResOperand],
+        divisor: &[&ResOperand],
+        quotient: &[&CellRef],
+        remainder: &[&CellRef],
+    ) -> Result<(), HintError> {
+        let dividend = join_limbs(vm, dividend)?;
+        let divisor = join_limbs(vm, divisor)?;
+        if divisor.is_zero() {
+            return Err(VirtualMachineError::DividedByZero.into());
+        }
+        let (quotient_value, remainder_value) = dividend.div_rem(&divisor);
+        write_limbs(vm, quotient_value, quotient)?;
+        write_limbs(vm, remainder_value, remainder)
+    }
+
+    fn square_root(
+        &self,
+        vm: &mut VirtualMachine,
+        value: &ResOperand,
+        dst: &CellRef,
+    ) -> Result<(), HintError> {
+        let value = res_operand_get_val(vm, value)?.to_biguint();
+        let root = isqrt(&value)?;
+        vm.insert_value(cell_ref_to_relocatable(dst, vm)?, Felt::from(root))?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn uint256_square_root(
+        &self,
+        vm: &mut VirtualMachine,
+        value_low: &ResOperand,
+        value_high: &ResOperand,
+        sqrt0: &CellRef,
+        sqrt1: &CellRef,
+        remainder_low: &CellRef,
+        remainder_high: &CellRef,
+        sqrt_mul_2_minus_remainder_ge_u128: &CellRef,
+    ) -> Result<(), HintError> {
+        let value = join_limbs(vm, &[value_low, value_high])?;
+        let root = isqrt(&value)?;
+        let remainder = &value - &root * &root;
+        let pow2_64 = BigUint::one() << 64_u32;
+        let (root1, root0) = root.div_rem(&pow2_64);
+        vm.insert_value(cell_ref_to_relocatable(sqrt0, vm)?, Felt::from(root0))?;
+        vm.insert_value(cell_ref_to_relocatable(sqrt1, vm)?, Felt::from(root1))?;
+        // The remainder is at most 2 * root, so the subtraction can't underflow
+        let ge_u128 = (&root << 1_u32) - &remainder >= pow2_128();
+        write_limbs(vm, remainder, &[remainder_low, remainder_high])?;
+        vm.insert_value(
+            cell_ref_to_relocatable(sqrt_mul_2_minus_remainder_ge_u128, vm)?,
+            Felt::from(ge_u128 as usize),
+        )?;
+        Ok(())
+    }
+
+    fn linear_split(
+        &self,
+        vm: &mut VirtualMachine,
+        value: &ResOperand,
+        scalar: &ResOperand,
+        max_x: &ResOperand,
+        x: &CellRef,
+        y: &CellRef,
+    ) -> Result<(), HintError> {
+        let value = res_operand_get_val(vm, value)?.to_biguint();
+        let scalar = res_operand_get_val(vm, scalar)?.to_biguint();
+        let max_x = res_operand_get_val(vm, max_x)?.to_biguint();
+        if scalar.is_zero() {
+            return Err(VirtualMachineError::DividedByZero.into());
+        }
+        let x_value = (&value / &scalar).min(max_x);
+        let y_value = value - &x_value * scalar;
+        vm.insert_value(cell_ref_to_relocatable(x, vm)?, Felt::from(x_value))?;
+        vm.insert_value(cell_ref_to_relocatable(y, vm)?, Felt::from(y_value))?;
+        Ok(())
+    }
+
+    /// Allocates a new dictionary and writes its segment to the segment arena's infos.
+    fn alloc_felt_252_dict(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        segment_arena_ptr: &ResOperand,
+    ) -> Result<(), HintError> {
+        let segment_arena_ptr = extract_relocatable(vm, segment_arena_ptr)?;
+        let n_dicts = vm
+            .get_integer(segment_arena_ptr.sub_usize(2)?)?
+            .to_usize()
+            .ok_or(HintError::BigintToUsizeFail)?;
+        let dict_infos_base = vm.get_relocatable(segment_arena_ptr.sub_usize(3)?)?;
+
+        if exec_scopes
+            .get_ref::<DictManagerExecScope>(DICT_MANAGER)
+            .is_err()
+        {
+            exec_scopes
+                .assign_or_update_variable(DICT_MANAGER, any_box!(DictManagerExecScope::default()));
+        }
+        let dict_manager = exec_scopes.get_mut_ref::<DictManagerExecScope>(DICT_MANAGER)?;
+        let new_dict_segment = dict_manager.new_default_dict(vm)?;
+        vm.insert_value(
+            dict_infos_base + DICT_ACCESS_SIZE * n_dicts,
+            new_dict_segment,
+        )?;
+        Ok(())
+    }
+
+    /// Writes the previous value of `key` to the new dictionary access.
+    fn felt_252_dict_entry_init(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_ptr: &ResOperand,
+        key: &ResOperand,
+    ) -> Result<(), HintError> {
+        let dict_ptr = extract_relocatable(vm, dict_ptr)?;
+        let key = res_operand_get_val(vm, key)?;
+        let dict_manager = exec_scopes.get_ref::<DictManagerExecScope>(DICT_MANAGER)?;
+        let prev_value = dict_manager
+            .get_from_tracker(dict_ptr, &key)?
+            .unwrap_or_else(|| DictManagerExecScope::DICT_DEFAULT_VALUE.into());
+        vm.insert_value(dict_ptr + 1_usize, prev_value)?;
+        Ok(())
+    }
+
+    /// Stores the new value of the key of the access that ends at `dict_ptr`.
+    fn felt_252_dict_entry_update(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_ptr: &ResOperand,
+        value: &ResOperand,
+    ) -> Result<(), HintError> {
+        let dict_ptr = extract_relocatable(vm, dict_ptr)?;
+        let key = vm
+            .get_integer(dict_ptr.sub_usize(DICT_ACCESS_SIZE)?)?
+            .into_owned();
+        let value = res_operand_get_maybe(vm, value)?;
+        let dict_manager = exec_scopes.get_mut_ref::<DictManagerExecScope>(DICT_MANAGER)?;
+        dict_manager.insert_to_tracker(dict_ptr, key, value)
+    }
+
+    fn felt_252_dict_read(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_ptr: &ResOperand,
+        key: &ResOperand,
+        value_dst: &CellRef,
+    ) -> Result<(), HintError> {
+        let dict_ptr = extract_relocatable(vm, dict_ptr)?;
+        let key = res_operand_get_val(vm, key)?;
+        let dict_manager = exec_scopes.get_ref::<DictManagerExecScope>(DICT_MANAGER)?;
+        let value = dict_manager
+            .get_from_tracker(dict_ptr, &key)?
+            .unwrap_or_else(|| DictManagerExecScope::DICT_DEFAULT_VALUE.into());
+        vm.insert_value(cell_ref_to_relocatable(value_dst, vm)?, value)?;
+        Ok(())
+    }
+
+    fn felt_252_dict_write(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_ptr: &ResOperand,
+        key: &ResOperand,
+        value: &ResOperand,
+    ) -> Result<(), HintError> {
+        let dict_ptr = extract_relocatable(vm, dict_ptr)?;
+        let key = res_operand_get_val(vm, key)?;
+        let value = res_operand_get_maybe(vm, value)?;
+        let dict_manager = exec_scopes.get_mut_ref::<DictManagerExecScope>(DICT_MANAGER)?;
+        let prev_value = dict_manager
+            .get_from_tracker(dict_ptr, &key)?
+            .unwrap_or_else(|| DictManagerExecScope::DICT_DEFAULT_VALUE.into());
+        vm.insert_value(dict_ptr + 1_usize, prev_value)?;
+        dict_manager.insert_to_tracker(dict_ptr + DICT_ACCESS_SIZE, key, value)
+    }
+
+    fn get_segment_arena_index(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_end_ptr: &ResOperand,
+        dict_index: &CellRef,
+    ) -> Result<(), HintError> {
+        let dict_end_ptr = extract_relocatable(vm, dict_end_ptr)?;
+        let dict_manager = exec_scopes.get_ref::<DictManagerExecScope>(DICT_MANAGER)?;
+        let index = dict_manager.get_dict_infos_index(dict_end_ptr)?;
+        vm.insert_value(cell_ref_to_relocatable(dict_index, vm)?, Felt::from(index))?;
+        Ok(())
+    }
+
+    /// Collects the accesses of each key of the dictionary to squash, and starts squashing
+    /// its smallest key.
+    #[allow(clippy::too_many_arguments)]
+    fn init_squash_data(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_accesses: &ResOperand,
+        ptr_diff: &ResOperand,
+        n_accesses: &ResOperand,
+        big_keys: &CellRef,
+        first_key: &CellRef,
+    ) -> Result<(), HintError> {
+        let dict_accesses = extract_relocatable(vm, dict_accesses)?;
+        let ptr_diff = res_operand_get_val(vm, ptr_diff)?
+            .to_usize()
+            .ok_or(HintError::BigintToUsizeFail)?;
+        let n_accesses = res_operand_get_val(vm, n_accesses)?
+            .to_usize()
+            .ok_or(HintError::BigintToUsizeFail)?;
+        if ptr_diff % DICT_ACCESS_SIZE != 0 {
+            return Err(HintError::PtrDiffNotDivisibleByDictAccessSize);
+        }
+
+        let mut squash = DictSquashExecScope::default();
+        for i in 0..n_accesses {
+            let key = vm
+                .get_integer(dict_accesses + DICT_ACCESS_SIZE * i)?
+                .into_owned();
+            squash
+                .access_indices
+                .entry(key)
+                .or_insert_with(Vec::new)
+                .push(Felt::from(i));
+        }
+        for (key, indices) in squash.access_indices.iter_mut() {
+            squash.n_accesses.insert(key.clone(), indices.len());
+            indices.reverse();
+        }
+        squash.keys = squash.access_indices.keys().cloned().collect();
+        squash.keys.sort_by(|a, b| b.cmp(a));
+
+        let is_big = match squash.keys.first() {
+            Some(biggest) => biggest.to_biguint() >= pow2_128(),
+            None => false,
+        };
+        vm.insert_value(
+            cell_ref_to_relocatable(big_keys, vm)?,
+            Felt::from(is_big as usize),
+        )?;
+        let current_key = squash.current_key().ok_or(HintError::EmptyKeys)?;
+        vm.insert_value(cell_ref_to_relocatable(first_key, vm)?, current_key)?;
+        exec_scopes.assign_or_update_variable(DICT_SQUASH, any_box!(squash));
+        Ok(())
+    }
+
+    fn get_current_access_index(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        range_check_ptr: &ResOperand,
+    ) -> Result<(), HintError> {
+        let range_check_ptr = extract_relocatable(vm, range_check_ptr)?;
+        let squash = exec_scopes.get_mut_ref::<DictSquashExecScope>(DICT_SQUASH)?;
+        let index = squash
+            .current_access_index()
+            .ok_or(HintError::EmptyCurrentAccessIndices)?;
+        vm.insert_value(range_check_ptr, index)?;
+        Ok(())
+    }
+
+    fn should_skip_squash_loop(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        should_skip_loop: &CellRef,
+    ) -> Result<(), HintError> {
+        let squash = exec_scopes.get_mut_ref::<DictSquashExecScope>(DICT_SQUASH)?;
+        let remaining = squash
+            .current_access_indices()
+            .ok_or(HintError::EmptyCurrentAccessIndices)?
+            .len();
+        vm.insert_value(
+            cell_ref_to_relocatable(should_skip_loop, vm)?,
+            Felt::from((remaining <= 1) as usize),
+        )?;
+        Ok(())
+    }
+
+    fn get_current_access_delta(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        index_delta_minus1: &CellRef,
+    ) -> Result<(), HintError> {
+        let squash = exec_scopes.get_mut_ref::<DictSquashExecScope>(DICT_SQUASH)?;
+        let prev_index = squash
+            .pop_current_access_index()
+            .ok_or(HintError::EmptyCurrentAccessIndices)?;
+        let index = squash
+            .current_access_index()
+            .ok_or(HintError::EmptyCurrentAccessIndices)?;
+        vm.insert_value(
+            cell_ref_to_relocatable(index_delta_minus1, vm)?,
+            index - prev_index - Felt::one(),
+        )?;
+        Ok(())
+    }
+
+    fn should_continue_squash_loop(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        should_continue: &CellRef,
+    ) -> Result<(), HintError> {
+        let squash = exec_scopes.get_mut_ref::<DictSquashExecScope>(DICT_SQUASH)?;
+        let remaining = squash
+            .current_access_indices()
+            .ok_or(HintError::EmptyCurrentAccessIndices)?
+            .len();
+        vm.insert_value(
+            cell_ref_to_relocatable(should_continue, vm)?,
+            Felt::from((remaining > 1) as usize),
+        )?;
+        Ok(())
+    }
+
+    fn get_next_dict_key(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        next_key: &CellRef,
+    ) -> Result<(), HintError> {
+        let squash = exec_scopes.get_mut_ref::<DictSquashExecScope>(DICT_SQUASH)?;
+        squash.pop_current_key()?;
+        let key = squash.current_key().ok_or(HintError::EmptyKeys)?;
+        vm.insert_value(cell_ref_to_relocatable(next_key, vm)?, key)?;
+        Ok(())
+    }
+
+    fn assert_all_accesses_used(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        n_used_accesses: &CellRef,
+    ) -> Result<(), HintError> {
+        let squash = exec_scopes.get_ref::<DictSquashExecScope>(DICT_SQUASH)?;
+        let key = squash.current_key().ok_or(HintError::EmptyKeys)?;
+        let n_accesses = *squash
+            .n_accesses
+            .get(&key)
+            .ok_or_else(|| HintError::NoKeyInAccessIndices(key.clone()))?;
+        let n_used_accesses = vm
+            .get_integer(cell_ref_to_relocatable(n_used_accesses, vm)?)?
+            .into_owned();
+        if n_used_accesses != Felt::from(n_accesses) {
+            return Err(HintError::NumUsedAccessesAssertFail(
+                n_used_accesses,
+                n_accesses,
+                key,
+            ));
+        }
+        Ok(())
+    }
+
+    fn assert_all_keys_used(&self, exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
+        let squash = exec_scopes.get_ref::<DictSquashExecScope>(DICT_SQUASH)?;
+        if !squash.keys.is_empty() {
+            return Err(HintError::KeysNotEmpty);
+        }
+        Ok(())
+    }
+
+    /// Splits the circle of field elements into the arcs `[0, a]`, `[a, b]` and `[b, PRIME)`,
+    /// and writes the limbs of the two smallest to the range checks.
+    fn assert_le_find_small_arcs(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        range_check_ptr: &ResOperand,
+        a: &ResOperand,
+        b: &ResOperand,
+    ) -> Result<(), HintError> {
+        let range_check_ptr = extract_relocatable(vm, range_check_ptr)?;
+        let a = res_operand_get_val(vm, a)?;
+        let b = res_operand_get_val(vm, b)?;
+        let mut lengths_and_indices = vec![
+            (a.clone(), 0_usize),
+            (&b - &a, 1_usize),
+            (Felt::zero() - Felt::one() - &b, 2_usize),
+        ];
+        lengths_and_indices.sort();
+        let prime = prime();
+        let prime_over_3 = Felt::from(&prime / 3_u32);
+        let prime_over_2 = Felt::from(&prime / 2_u32);
+        if lengths_and_indices[0].0 > prime_over_3 || lengths_and_indices[1].0 > prime_over_2 {
+            return Err(HintError::ArcTooBig(
+                lengths_and_indices[0].0.clone(),
+                prime_over_3,
+                lengths_and_indices[1].0.clone(),
+                prime_over_2,
+            ));
+        }
+        exec_scopes.assign_or_update_variable(EXCLUDED_ARC, any_box!(lengths_and_indices[2].1));
+
+        let prime_over_3_high = Felt::from(BigUint::from_str_radix(PRIME_OVER_3_HIGH, 10).unwrap());
+        let prime_over_2_high = Felt::from(BigUint::from_str_radix(PRIME_OVER_2_HIGH, 10).unwrap());
+        let (q_0, r_0) = lengths_and_indices[0].0.div_mod_floor(&prime_over_3_high);
+        let (q_1, r_1) = lengths_and_indices[1].0.div_mod_floor(&prime_over_2_high);
+        vm.insert_value(range_check_ptr, r_0)?;
+        vm.insert_value(range_check_ptr + 1_usize, q_0)?;
+        vm.insert_value(range_check_ptr + 2_usize, r_1)?;
+        vm.insert_value(range_check_ptr + 3_usize, q_1)?;
+        Ok(())
+    }
+
+    fn assert_le_is_arc_excluded(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        flag: &CellRef,
+        arc: usize,
+    ) -> Result<(), HintError> {
+        let excluded = exec_scopes.get::<usize>(EXCLUDED_ARC)?;
+        vm.insert_value(
+            cell_ref_to_relocatable(flag, vm)?,
+            Felt::from((excluded != arc) as usize),
+        )?;
+        Ok(())
+    }
+
+    fn assert_lt_assert_valid_input(
+        &self,
+        vm: &mut VirtualMachine,
+        a: &ResOperand,
+        b: &ResOperand,
+    ) -> Result<(), HintError> {
+        let a = res_operand_get_val(vm, a)?;
+        let b = res_operand_get_val(vm, b)?;
+        if a >= b {
+            return Err(HintError::AssertLtFelt(a, b));
+        }
+        Ok(())
+    }
+
+    /// Writes a random point of the STARK curve.
+    fn random_ec_point(
+        &self,
+        vm: &mut VirtualMachine,
+        x: &CellRef,
+        y: &CellRef,
+    ) -> Result<(), HintError> {
+        let prime = prime();
+        let beta = BigUint::from_str_radix(STARK_CURVE_BETA, 10).unwrap();
+        let mut rng = rand::thread_rng();
+        let (x_value, y_value) = loop {
+            let x_bytes: [u8; 32] = rng.gen();
+            let x_value = BigUint::from_bytes_be(&x_bytes) % &prime;
+            let y_squared =
+                (x_value.modpow(&BigUint::from(3_u32), &prime) + &x_value + &beta) % &prime;
+            if let Some(y_value) = sqrt_prime(&y_squared, &prime) {
+                break (x_value, y_value);
+            }
+        };
+        vm.insert_value(cell_ref_to_relocatable(x, vm)?, Felt::from(x_value))?;
+        vm.insert_value(cell_ref_to_relocatable(y, vm)?, Felt::from(y_value))?;
+        Ok(())
+    }
+
+    /// Writes the square root of `val` if it is a quadratic residue, or of `3 * val` otherwise,
+    /// as 3 isn't a quadratic residue modulo the prime.
+    fn field_sqrt(
+        &self,
+        vm: &mut VirtualMachine,
+        val: &ResOperand,
+        sqrt: &CellRef,
+    ) -> Result<(), HintError> {
+        let prime = prime();
+        let val = res_operand_get_val(vm, val)?.to_biguint();
+        let val = if is_quad_residue(&val, &prime) {
+            val
+        } else {
+            val * 3_u32
+        };
+        let root = sqrt_prime(&val, &prime)
+            .ok_or_else(|| HintError::CustomHint(format!("FieldSqrt: {val} has no square root")))?;
+        vm.insert_value(cell_ref_to_relocatable(sqrt, vm)?, Felt::from(root))?;
+        Ok(())
+    }
+
+    /// Allocates `size` cells of a segment shared by all of the constant allocations.
+    fn alloc_constant_size(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        size: &ResOperand,
+        dst: &CellRef,
+    ) -> Result<(), HintError> {
+        let size = res_operand_get_val(vm, size)?
+            .to_usize()
+            .ok_or(HintError::BigintToUsizeFail)?;
+        let next_free = match exec_scopes.get::<Relocatable>(CONSTANT_SEGMENT) {
+            Ok(next_free) => next_free,
+            Err(_) => vm.add_memory_segment(),
+        };
+        vm.insert_value(cell_ref_to_relocatable(dst, vm)?, next_free)?;
+        exec_scopes.assign_or_update_variable(CONSTANT_SEGMENT, any_box!(next_free + size));
+        Ok(())
+    }
+
+    fn debug_print(
+        &self,
+        vm: &mut VirtualMachine,
+        start: &ResOperand,
+        end: &ResOperand,
+    ) -> Result<(), HintError> {
+        let start = extract_relocatable(vm, start)?;
+        let end = extract_relocatable(vm, end)?;
+        for offset in start.offset..end.offset {
+            let value = vm.get_integer((start.segment_index, offset).into())?;
+            println!("[DEBUG] {value}");
+        }
+        Ok(())
+    }
+}
+
+impl HintProcessor for Cairo1HintProcessor {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        _constants: &HashMap<String, Felt>,
+    ) -> Result<(), HintError> {
+        let hint = hint_data
+            .downcast_ref::<Hint>()
+            .ok_or(HintError::WrongHintData)?;
+        match hint {
+            Hint::AllocSegment { dst } => self.alloc_segment(vm, dst),
+            Hint::TestLessThan { lhs, rhs, dst } => self.test_less_than(vm, lhs, rhs, dst, false),
+            Hint::TestLessThanOrEqual { lhs, rhs, dst } => {
+                self.test_less_than(vm, lhs, rhs, dst, true)
+            }
+            Hint::WideMul128 {
+                lhs,
+                rhs,
+                high,
+                low,
+            } => self.wide_mul_128(vm, lhs, rhs, high, low),
+            Hint::DivMod {
+                lhs,
+                rhs,
+                quotient,
+                remainder,
+            } => self.div_mod(vm, lhs, rhs, quotient, remainder),
+            Hint::Uint256DivMod {
+                dividend0,
+                dividend1,
+                divisor0,
+                divisor1,
+                quotient0,
+                quotient1,
+                remainder0,
+                remainder1,
+            } => self.uint256_div_mod(
+                vm, dividend0, dividend1, divisor0, divisor1, quotient0, quotient1, remainder0,
+                remainder1,
+            ),
+            Hint::Uint512DivModByUint256 {
+                dividend0,
+                dividend1,
+                dividend2,
+                dividend3,
+                divisor0,
+                divisor1,
+                quotient0,
+                quotient1,
+                quotient2,
+                quotient3,
+                remainder0,
+                remainder1,
+            } => self.uint512_div_mod_by_uint256(
+                vm,
+                &[dividend0, dividend1, dividend2, dividend3],
+                &[divisor0, divisor1],
+                &[quotient0, quotient1, quotient2, quotient3],
+                &[remainder0, remainder1],
+            ),
+            Hint::SquareRoot { value, dst } => self.square_root(vm, value, dst),
+            Hint::Uint256SquareRoot {
+                value_low,
+                value_high,
+                sqrt0,
+                sqrt1,
+                remainder_low,
+                remainder_high,
+                sqrt_mul_2_minus_remainder_ge_u128,
+            } => self.uint256_square_root(
+                vm,
+                value_low,
+                value_high,
+                sqrt0,
+                sqrt1,
+                remainder_low,
+                remainder_high,
+                sqrt_mul_2_minus_remainder_ge_u128,
+            ),
+            Hint::LinearSplit {
+                value,
+                scalar,
+                max_x,
+                x,
+                y,
+            } => self.linear_split(vm, value, scalar, max_x, x, y),
+            Hint::AllocFelt252Dict { segment_arena_ptr } => {
+                self.alloc_felt_252_dict(vm, exec_scopes, segment_arena_ptr)
+            }
+            Hint::Felt252DictEntryInit { dict_ptr, key } => {
+                self.felt_252_dict_entry_init(vm, exec_scopes, dict_ptr, key)
+            }
+            Hint::Felt252DictEntryUpdate { dict_ptr, value } => {
+                self.felt_252_dict_entry_update(vm, exec_scopes, dict_ptr, value)
+            }
+            Hint::Felt252DictRead {
+                dict_ptr,
+                key,
+                value_dst,
+            } => self.felt_252_dict_read(vm, exec_scopes, dict_ptr, key, value_dst),
+            Hint::Felt252DictWrite {
+                dict_ptr,
+                key,
+                value,
+            } => self.felt_252_dict_write(vm, exec_scopes, dict_ptr, key, value),
+            Hint::GetSegmentArenaIndex {
+                dict_end_ptr,
+                dict_index,
+            } => self.get_segment_arena_index(vm, exec_scopes, dict_end_ptr, dict_index),
+            Hint::InitSquashData {
+                dict_accesses,
+                ptr_diff,
+                n_accesses,
+                big_keys,
+                first_key,
+            } => self.init_squash_data(
+                vm,
+                exec_scopes,
+                dict_accesses,
+                ptr_diff,
+                n_accesses,
+                big_keys,
+                first_key,
+            ),
+            Hint::GetCurrentAccessIndex { range_check_ptr } => {
+                self.get_current_access_index(vm, exec_scopes, range_check_ptr)
+            }
+            Hint::ShouldSkipSquashLoop { should_skip_loop } => {
+                self.should_skip_squash_loop(vm, exec_scopes, should_skip_loop)
+            }
+            Hint::GetCurrentAccessDelta { index_delta_minus1 } => {
+                self.get_current_access_delta(vm, exec_scopes, index_delta_minus1)
+            }
+            Hint::ShouldContinueSquashLoop { should_continue } => {
+                self.should_continue_squash_loop(vm, exec_scopes, should_continue)
+            }
+            Hint::GetNextDictKey { next_key } => self.get_next_dict_key(vm, exec_scopes, next_key),
+            Hint::AssertLeFindSmallArcs {
+                range_check_ptr,
+                a,
+                b,
+            } => self.assert_le_find_small_arcs(vm, exec_scopes, range_check_ptr, a, b),
+            Hint::AssertLeIsFirstArcExcluded {
+                skip_exclude_a_flag,
+            } => self.assert_le_is_arc_excluded(vm, exec_scopes, skip_exclude_a_flag, 0),
+            Hint::AssertLeIsSecondArcExcluded {
+                skip_exclude_b_minus_a,
+            } => self.assert_le_is_arc_excluded(vm, exec_scopes, skip_exclude_b_minus_a, 1),
+            Hint::AssertLtAssertValidInput { a, b } => self.assert_lt_assert_valid_input(vm, a, b),
+            Hint::AssertAllAccessesUsed { n_used_accesses } => {
+                self.assert_all_accesses_used(vm, exec_scopes, n_used_accesses)
+            }
+            Hint::AssertAllKeysUsed => self.assert_all_keys_used(exec_scopes),
+            Hint::RandomEcPoint { x, y } => self.random_ec_point(vm, x, y),
+            Hint::FieldSqrt { val, sqrt } => self.field_sqrt(vm, val, sqrt),
+            Hint::AllocConstantSize { size, dst } => {
+                self.alloc_constant_size(vm, exec_scopes, size, dst)
+            }
+            Hint::DebugPrint { start, end } => self.debug_print(vm, start, end),
+            Hint::SystemCall { .. } => Err(HintError::UnknownHint(
+                "SystemCall: no syscall handler available".to_string(),
+            )),
+        }
+    }
+
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        _ap_tracking_data: &ApTracking,
+        _reference_ids: &HashMap<String, usize>,
+        _references: &HashMap<usize, HintReference>,
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        let hint: Hint = serde_json::from_str(hint_code)
+            .map_err(|_| VirtualMachineError::CompileHintFail(hint_code.to_string()))?;
+        Ok(any_box!(hint))
+    }
+}
+
+fn prime() -> BigUint {
+    BigUint::from_str_radix(&PRIME_STR[2..], 16).unwrap()
+}
+
+fn pow2_128() -> BigUint {
+    BigUint::one() << 128_u32
+}
+
+/// Returns `base + offset`, failing if the resulting offset would be negative.
+fn apply_offset(base: Relocatable, offset: &BigInt) -> Result<Relocatable, HintError> {
+    let new_offset = (BigInt::from(base.offset) + offset)
+        .to_usize()
+        .ok_or(HintError::BigintToUsizeFail)?;
+    Ok(Relocatable::from((base.segment_index, new_offset)))
+}
+
+/// Returns the address of a cell, relative to the current `ap` or `fp`.
+pub(crate) fn cell_ref_to_relocatable(
+    cell_ref: &CellRef,
+    vm: &VirtualMachine,
+) -> Result<Relocatable, HintError> {
+    let base = match cell_ref.register {
+        Register::AP => vm.get_ap(),
+        Register::FP => vm.get_fp(),
+    };
+    apply_offset(base, &BigInt::from(cell_ref.offset))
+}
+
+fn get_cell_val(vm: &VirtualMachine, cell: &CellRef) -> Result<Felt, HintError> {
+    Ok(vm
+        .get_integer(cell_ref_to_relocatable(cell, vm)?)?
+        .into_owned())
+}
+
+fn get_cell_maybe(vm: &VirtualMachine, cell: &CellRef) -> Result<MaybeRelocatable, HintError> {
+    let address = cell_ref_to_relocatable(cell, vm)?;
+    vm.get_maybe(&address)
+        .ok_or_else(|| VirtualMachineError::UnknownMemoryCell(address).into())
+}
+
+fn get_double_deref_maybe(
+    vm: &VirtualMachine,
+    cell: &CellRef,
+    offset: i16,
+) -> Result<MaybeRelocatable, HintError> {
+    let base = vm.get_relocatable(cell_ref_to_relocatable(cell, vm)?)?;
+    let address = apply_offset(base, &BigInt::from(offset))?;
+    vm.get_maybe(&address)
+        .ok_or_else(|| VirtualMachineError::UnknownMemoryCell(address).into())
+}
+
+/// Evaluates an operand that must be a field element.
+pub(crate) fn res_operand_get_val(
+    vm: &VirtualMachine,
+    res_operand: &ResOperand,
+) -> Result<Felt, HintError> {
+    match res_operand {
+        ResOperand::Deref(cell) => get_cell_val(vm, cell),
+        ResOperand::DoubleDeref(cell, offset) => match get_double_deref_maybe(vm, cell, *offset)? {
+            MaybeRelocatable::Int(value) => Ok(value),
+            MaybeRelocatable::RelocatableValue(address) => {
+                Err(HintError::InvalidResOperand(address.to_string()))
+            }
+        },
+        ResOperand::Immediate(value) => Ok(Felt::from(value.clone())),
+        ResOperand::BinOp(bin_op) => {
+            let a = get_cell_val(vm, &bin_op.a)?;
+            let b = match &bin_op.b {
+                DerefOrImmediate::Deref(cell) => get_cell_val(vm, cell)?,
+                DerefOrImmediate::Immediate(value) => Felt::from(value.clone()),
+            };
+            Ok(match bin_op.op {
+                Operation::Add => a + b,
+                Operation::Mul => a * b,
+            })
+        }
+    }
+}
+
+/// Evaluates an operand that may be either a field element or a pointer, such as the values
+/// stored in dictionaries.
+fn res_operand_get_maybe(
+    vm: &VirtualMachine,
+    res_operand: &ResOperand,
+) -> Result<MaybeRelocatable, HintError> {
+    match res_operand {
+        ResOperand::Deref(cell) => get_cell_maybe(vm, cell),
+        ResOperand::DoubleDeref(cell, offset) => get_double_deref_maybe(vm, cell, *offset),
+        ResOperand::BinOp(BinOpOperand {
+            op: Operation::Add,
+            a,
+            ..
+        }) => match get_cell_maybe(vm, a)? {
+            MaybeRelocatable::RelocatableValue(_) => {
+                Ok(extract_relocatable(vm, res_operand)?.into())
+            }
+            MaybeRelocatable::Int(_) => Ok(res_operand_get_val(vm, res_operand)?.into()),
+        },
+        _ => Ok(res_operand_get_val(vm, res_operand)?.into()),
+    }
+}
+
+/// Evaluates an operand that must be a pointer: `[cell]`, `[[cell] + offset]` or `[cell] + b`.
+pub(crate) fn extract_relocatable(
+    vm: &VirtualMachine,
+    res_operand: &ResOperand,
+) -> Result<Relocatable, HintError> {
+    let (base, offset) = match res_operand {
+        ResOperand::Deref(cell) => {
+            return Ok(vm.get_relocatable(cell_ref_to_relocatable(cell, vm)?)?)
+        }
+        ResOperand::DoubleDeref(cell, offset) => {
+            return match get_double_deref_maybe(vm, cell, *offset)? {
+                MaybeRelocatable::RelocatableValue(address) => Ok(address),
+                MaybeRelocatable::Int(value) => {
+                    Err(HintError::InvalidResOperand(value.to_string()))
+                }
+            }
+        }
+        ResOperand::BinOp(BinOpOperand {
+            op: Operation::Add,
+            a,
+            b,
+        }) => {
+            let base = vm.get_relocatable(cell_ref_to_relocatable(a, vm)?)?;
+            let offset = match b {
+                DerefOrImmediate::Deref(cell) => get_cell_val(vm, cell)?.to_bigint(),
+                DerefOrImmediate::Immediate(value) => value.clone(),
+            };
+            (base, offset)
+        }
+        _ => {
+            return Err(HintError::InvalidResOperand(format!(
+                "{res_operand:?} is not a pointer"
+            )))
+        }
+    };
+    apply_offset(base, &offset)
+}
+
+/// Joins the 128-bit limbs of an integer, least significant first.
+fn join_limbs(vm: &VirtualMachine, limbs: &[&ResOperand]) -> Result<BigUint, HintError> {
+    let mut value = BigUint::zero();
+    for limb in limbs.iter().rev() {
+        value = (value << 128_u32) + res_operand_get_val(vm, limb)?.to_biguint();
+    }
+    Ok(value)
+}
+
+/// Splits an integer into 128-bit limbs, least significant first. The last limb takes the
+/// remaining bits.
+fn write_limbs(
+    vm: &mut VirtualMachine,
+    mut value: BigUint,
+    limbs: &[&CellRef],
+) -> Result<(), HintError> {
+    let pow2_128 = pow2_128();
+    for (i, limb) in limbs.iter().enumerate() {
+        let limb_value = if i + 1 == limbs.len() {
+            std::mem::take(&mut value)
+        } else {
+            let (high, low) = value.div_rem(&pow2_128);
+            value = high;
+            low
+        };
+        vm.insert_value(cell_ref_to_relocatable(limb, vm)?, Felt::from(limb_value))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::{
+        errors::memory_errors::MemoryError,
+        vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+    };
+    use assert_matches::assert_matches;
+
+    fn ap(offset: i16) -> CellRef {
+        CellRef {
+            register: Register::AP,
+            offset,
+        }
+    }
+
+    fn fp(offset: i16) -> CellRef {
+        CellRef {
+            register: Register::FP,
+            offset,
+        }
+    }
+
+    fn deref(cell: CellRef) -> ResOperand {
+        ResOperand::Deref(cell)
+    }
+
+    fn imm(value: i64) -> ResOperand {
+        ResOperand::Immediate(BigInt::from(value))
+    }
+
+    fn run_hint(
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint: Hint,
+    ) -> Result<(), HintError> {
+        let mut hint_processor = Cairo1HintProcessor::new();
+        hint_processor.execute_hint(vm, exec_scopes, &any_box!(hint), &HashMap::new())
+    }
+
+    #[test]
+    fn compile_hint_parses_json() {
+        let hint_processor = Cairo1HintProcessor::new();
+        let hint_data = hint_processor
+            .compile_hint(
+                r#"{"AllocSegment": {"dst": {"register": "AP", "offset": 0}}}"#,
+                &ApTracking::default(),
+                &HashMap::new(),
+                &HashMap::new(),
+            )
+            .unwrap();
+        assert_eq!(
+            hint_data.downcast_ref::<Hint>(),
+            Some(&Hint::AllocSegment { dst: ap(0) })
+        );
+    }
+
+    #[test]
+    fn compile_hint_invalid_json() {
+        let hint_processor = Cairo1HintProcessor::new();
+        assert_matches!(
+            hint_processor.compile_hint(
+                "memory[ap] = segments.add()",
+                &ApTracking::default(),
+                &HashMap::new(),
+                &HashMap::new(),
+            ),
+            Err(VirtualMachineError::CompileHintFail(_))
+        );
+    }
+
+    #[test]
+    fn execute_wrong_hint_data() {
+        let mut vm = vm!();
+        let mut hint_processor = Cairo1HintProcessor::new();
+        assert_matches!(
+            hint_processor.execute_hint(
+                &mut vm,
+                &mut ExecutionScopes::new(),
+                &any_box!("AllocSegment"),
+                &HashMap::new()
+            ),
+            Err(HintError::WrongHintData)
+        );
+    }
+
+    #[test]
+    fn res_operands() {
+        let mut vm = vm!();
+        vm.set_fp(2);
+        vm.set_ap(3);
+        vm.segments = segments![((1, 0), 7), ((1, 1), (2, 0)), ((1, 2), 5), ((2, 1), 11)];
+        assert_eq!(
+            res_operand_get_val(&vm, &deref(fp(-2))).unwrap(),
+            Felt::new(7)
+        );
+        assert_eq!(
+            res_operand_get_val(&vm, &ResOperand::DoubleDeref(ap(-2), 1)).unwrap(),
+            Felt::new(11)
+        );
+        assert_eq!(res_operand_get_val(&vm, &imm(-1)).unwrap(), Felt::new(-1));
+        assert_eq!(
+            res_operand_get_val(
+                &vm,
+                &ResOperand::BinOp(BinOpOperand {
+                    op: Operation::Mul,
+                    a: fp(0),
+                    b: DerefOrImmediate::Deref(ap(-3)),
+                })
+            )
+            .unwrap(),
+            Felt::new(35)
+        );
+        assert_eq!(
+            extract_relocatable(
+                &vm,
+                &ResOperand::BinOp(BinOpOperand {
+                    op: Operation::Add,
+                    a: fp(-1),
+                    b: DerefOrImmediate::Immediate(BigInt::from(3)),
+                })
+            )
+            .unwrap(),
+            relocatable!(2, 3)
+        );
+        assert_matches!(
+            extract_relocatable(&vm, &imm(3)),
+            Err(HintError::InvalidResOperand(_))
+        );
+        assert_matches!(
+            cell_ref_to_relocatable(&ap(-4), &vm),
+            Err(HintError::BigintToUsizeFail)
+        );
+    }
+
+    #[test]
+    fn alloc_segment() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 1)];
+        vm.set_ap(1);
+        run_hint(
+            &mut vm,
+            &mut ExecutionScopes::new(),
+            Hint::AllocSegment { dst: ap(0) },
+        )
+        .unwrap();
+        assert_eq!(
+            vm.get_relocatable(relocatable!(1, 1)),
+            Ok(relocatable!(2, 0))
+        );
+    }
+
+    #[test]
+    fn test_less_than() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 3)];
+        vm.set_ap(1);
+        let mut exec_scopes = ExecutionScopes::new();
+        let hint = |rhs, dst, or_equal| {
+            if or_equal {
+                Hint::TestLessThanOrEqual {
+                    lhs: deref(ap(-1)),
+                    rhs: imm(rhs),
+                    dst: ap(dst),
+                }
+            } else {
+                Hint::TestLessThan {
+                    lhs: deref(ap(-1)),
+                    rhs: imm(rhs),
+                    dst: ap(dst),
+                }
+            }
+        };
+        run_hint(&mut vm, &mut exec_scopes, hint(3, 0, false)).unwrap();
+        run_hint(&mut vm, &mut exec_scopes, hint(3, 1, true)).unwrap();
+        run_hint(&mut vm, &mut exec_scopes, hint(4, 2, false)).unwrap();
+        check_memory![vm.segments.memory, ((1, 1), 0), ((1, 2), 1), ((1, 3), 1)];
+    }
+
+    #[test]
+    fn div_mod() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 17)];
+        vm.set_ap(1);
+        run_hint(
+            &mut vm,
+            &mut ExecutionScopes::new(),
+            Hint::DivMod {
+                lhs: deref(ap(-1)),
+                rhs: imm(5),
+                quotient: ap(0),
+                remainder: ap(1),
+            },
+        )
+        .unwrap();
+        check_memory![vm.segments.memory, ((1, 1), 3), ((1, 2), 2)];
+    }
+
+    #[test]
+    fn div_mod_by_zero() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 17)];
+        vm.set_ap(1);
+        assert_matches!(
+            run_hint(
+                &mut vm,
+                &mut ExecutionScopes::new(),
+                Hint::DivMod {
+                    lhs: deref(ap(-1)),
+                    rhs: imm(0),
+                    quotient: ap(0),
+                    remainder: ap(1),
+                },
+            ),
+            Err(HintError::Internal(VirtualMachineError::DividedByZero))
+        );
+    }
+
+    #[test]
+    fn wide_mul_128() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 0)];
+        vm.set_ap(1);
+        let pow2_127 = BigInt::one() << 127_u32;
+        run_hint(
+            &mut vm,
+            &mut ExecutionScopes::new(),
+            Hint::WideMul128 {
+                lhs: ResOperand::Immediate(pow2_127),
+                rhs: imm(6),
+                high: ap(0),
+                low: ap(1),
+            },
+        )
+        .unwrap();
+        check_memory![vm.segments.memory, ((1, 1), 3), ((1, 2), 0)];
+    }
+
+    #[test]
+    fn uint256_div_mod() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 0)];
+        vm.set_ap(1);
+        // (2^128 + 7) / 2 = 2^127 + 3, remainder 1
+        run_hint(
+            &mut vm,
+            &mut ExecutionScopes::new(),
+            Hint::Uint256DivMod {
+                dividend0: imm(7),
+                dividend1: imm(1),
+                divisor0: imm(2),
+                divisor1: imm(0),
+                quotient0: ap(0),
+                quotient1: ap(1),
+                remainder0: ap(2),
+                remainder1: ap(3),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            vm.get_integer(relocatable!(1, 1)).unwrap().as_ref(),
+            &(Felt::from(BigUint::one() << 127_u32) + Felt::new(3))
+        );
+        check_memory![vm.segments.memory, ((1, 2), 0), ((1, 3), 1), ((1, 4), 0)];
+    }
+
+    #[test]
+    fn uint256_square_root() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 0)];
+        vm.set_ap(1);
+        // sqrt(2^128) = 2^64
+        run_hint(
+            &mut vm,
+            &mut ExecutionScopes::new(),
+            Hint::Uint256SquareRoot {
+                value_low: imm(0),
+                value_high: imm(1),
+                sqrt0: ap(0),
+                sqrt1: ap(1),
+                remainder_low: ap(2),
+                remainder_high: ap(3),
+                sqrt_mul_2_minus_remainder_ge_u128: ap(4),
+            },
+        )
+        .unwrap();
+        check_memory![
+            vm.segments.memory,
+            ((1, 1), 0),
+            ((1, 2), 1),
+            ((1, 3), 0),
+            ((1, 4), 0),
+            ((1, 5), 0)
+        ];
+    }
+
+    #[test]
+    fn square_root_and_linear_split() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 0)];
+        vm.set_ap(1);
+        let mut exec_scopes = ExecutionScopes::new();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::SquareRoot {
+                value: imm(26),
+                dst: ap(0),
+            },
+        )
+        .unwrap();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::LinearSplit {
+                value: imm(47),
+                scalar: imm(10),
+                max_x: imm(3),
+                x: ap(1),
+                y: ap(2),
+            },
+        )
+        .unwrap();
+        check_memory![vm.segments.memory, ((1, 1), 5), ((1, 2), 3), ((1, 3), 17)];
+    }
+
+    #[test]
+    fn field_sqrt() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 0)];
+        vm.set_ap(1);
+        let mut exec_scopes = ExecutionScopes::new();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::FieldSqrt {
+                val: imm(9),
+                sqrt: ap(0),
+            },
+        )
+        .unwrap();
+        check_memory![vm.segments.memory, ((1, 1), 3)];
+        // 3 isn't a quadratic residue, so the square root of 3 * 3 is written instead
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::FieldSqrt {
+                val: imm(3),
+                sqrt: ap(1),
+            },
+        )
+        .unwrap();
+        check_memory![vm.segments.memory, ((1, 2), 3)];
+    }
+
+    #[test]
+    fn random_ec_point() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 0)];
+        vm.set_ap(1);
+        run_hint(
+            &mut vm,
+            &mut ExecutionScopes::new(),
+            Hint::RandomEcPoint { x: ap(0), y: ap(1) },
+        )
+        .unwrap();
+        let x = vm.get_integer(relocatable!(1, 1)).unwrap().into_owned();
+        let y = vm.get_integer(relocatable!(1, 2)).unwrap().into_owned();
+        let beta = Felt::from(BigUint::from_str_radix(STARK_CURVE_BETA, 10).unwrap());
+        assert_eq!(&y * &y, &x * &x * &x + &x + beta);
+    }
+
+    #[test]
+    fn alloc_constant_size() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 0)];
+        vm.set_ap(1);
+        let mut exec_scopes = ExecutionScopes::new();
+        for dst in 0..2 {
+            run_hint(
+                &mut vm,
+                &mut exec_scopes,
+                Hint::AllocConstantSize {
+                    size: imm(4),
+                    dst: ap(dst),
+                },
+            )
+            .unwrap();
+        }
+        check_memory![vm.segments.memory, ((1, 1), (2, 0)), ((1, 2), (2, 4))];
+    }
+
+    #[test]
+    fn dict_hints() {
+        let mut vm = vm!();
+        // The segment arena: info segment, n_dicts and n_finalized, followed by the builtin's
+        // pointer at fp - 1.
+        vm.segments = segments![((1, 0), (2, 0)), ((1, 1), 0), ((1, 2), 0), ((1, 3), (1, 3))];
+        vm.segments.add();
+        vm.set_fp(4);
+        vm.set_ap(4);
+        let mut exec_scopes = ExecutionScopes::new();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::AllocFelt252Dict {
+                segment_arena_ptr: deref(fp(-1)),
+            },
+        )
+        .unwrap();
+        // The new dictionary is written to the infos
+        check_memory![vm.segments.memory, ((2, 0), (3, 0))];
+
+        // dict_ptr at ap + 0, access (5, prev, 8)
+        vm.segments = segments![
+            ((1, 0), (2, 0)),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), (1, 3)),
+            ((1, 4), (3, 0)),
+            ((2, 0), (3, 0)),
+            ((3, 0), 5)
+        ];
+        vm.segments.add();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::Felt252DictEntryInit {
+                dict_ptr: deref(ap(0)),
+                key: imm(5),
+            },
+        )
+        .unwrap();
+        check_memory![vm.segments.memory, ((3, 1), 0)];
+        vm.insert_value(relocatable!(3, 2), Felt::new(8)).unwrap();
+        vm.insert_value(relocatable!(1, 5), relocatable!(3, 3))
+            .unwrap();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::Felt252DictEntryUpdate {
+                dict_ptr: deref(ap(1)),
+                value: imm(8),
+            },
+        )
+        .unwrap();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::Felt252DictRead {
+                dict_ptr: deref(ap(1)),
+                key: imm(5),
+                value_dst: ap(2),
+            },
+        )
+        .unwrap();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::GetSegmentArenaIndex {
+                dict_end_ptr: deref(ap(1)),
+                dict_index: ap(3),
+            },
+        )
+        .unwrap();
+        check_memory![vm.segments.memory, ((1, 6), 8), ((1, 7), 0)];
+    }
+
+    #[test]
+    fn squash_hints() {
+        let mut vm = vm!();
+        // Accesses to keys 3, 1 and 3, followed by the range check pointer
+        vm.segments = segments![
+            ((1, 0), (2, 0)),
+            ((1, 1), (3, 0)),
+            ((2, 0), 3),
+            ((2, 3), 1),
+            ((2, 6), 3)
+        ];
+        vm.segments.add();
+        vm.set_ap(2);
+        vm.set_fp(2);
+        let mut exec_scopes = ExecutionScopes::new();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::InitSquashData {
+                dict_accesses: deref(fp(-2)),
+                ptr_diff: imm(9),
+                n_accesses: imm(3),
+                big_keys: ap(0),
+                first_key: ap(1),
+            },
+        )
+        .unwrap();
+        check_memory![vm.segments.memory, ((1, 2), 0), ((1, 3), 1)];
+
+        // Key 1 has a single access
+        let hints = [
+            Hint::GetCurrentAccessIndex {
+                range_check_ptr: deref(fp(-1)),
+            },
+            Hint::ShouldSkipSquashLoop {
+                should_skip_loop: ap(2),
+            },
+            Hint::GetNextDictKey { next_key: ap(3) },
+            Hint::ShouldSkipSquashLoop {
+                should_skip_loop: ap(4),
+            },
+            Hint::GetCurrentAccessDelta {
+                index_delta_minus1: ap(5),
+            },
+            Hint::ShouldContinueSquashLoop {
+                should_continue: ap(6),
+            },
+        ];
+        for hint in hints {
+            run_hint(&mut vm, &mut exec_scopes, hint).unwrap();
+        }
+        check_memory![
+            vm.segments.memory,
+            ((3, 0), 1),
+            ((1, 4), 1),
+            ((1, 5), 3),
+            ((1, 6), 0),
+            ((1, 7), 1),
+            ((1, 8), 0)
+        ];
+
+        vm.insert_value(relocatable!(1, 9), Felt::new(1)).unwrap();
+        assert_matches!(
+            run_hint(
+                &mut vm,
+                &mut exec_scopes,
+                Hint::AssertAllAccessesUsed {
+                    n_used_accesses: ap(7)
+                }
+            ),
+            Err(HintError::NumUsedAccessesAssertFail(_, 2, _))
+        );
+        vm.insert_value(relocatable!(1, 10), Felt::new(2)).unwrap();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::AssertAllAccessesUsed {
+                n_used_accesses: ap(8),
+            },
+        )
+        .unwrap();
+        assert_matches!(
+            run_hint(&mut vm, &mut exec_scopes, Hint::AssertAllKeysUsed),
+            Err(HintError::KeysNotEmpty)
+        );
+    }
+
+    #[test]
+    fn init_squash_data_invalid_ptr_diff() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0))];
+        vm.set_ap(1);
+        vm.set_fp(1);
+        assert_matches!(
+            run_hint(
+                &mut vm,
+                &mut ExecutionScopes::new(),
+                Hint::InitSquashData {
+                    dict_accesses: deref(fp(-1)),
+                    ptr_diff: imm(4),
+                    n_accesses: imm(1),
+                    big_keys: ap(0),
+                    first_key: ap(1),
+                },
+            ),
+            Err(HintError::PtrDiffNotDivisibleByDictAccessSize)
+        );
+    }
+
+    #[test]
+    fn assert_le_arcs() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0))];
+        vm.segments.add();
+        vm.set_ap(1);
+        vm.set_fp(1);
+        let mut exec_scopes = ExecutionScopes::new();
+        let hints = [
+            Hint::AssertLeFindSmallArcs {
+                range_check_ptr: deref(fp(-1)),
+                a: imm(1),
+                b: imm(10),
+            },
+            Hint::AssertLeIsFirstArcExcluded {
+                skip_exclude_a_flag: ap(0),
+            },
+            Hint::AssertLeIsSecondArcExcluded {
+                skip_exclude_b_minus_a: ap(1),
+            },
+        ];
+        for hint in hints {
+            run_hint(&mut vm, &mut exec_scopes, hint).unwrap();
+        }
+        // The arc [b, PRIME) is the longest, so it's the one excluded
+        check_memory![
+            vm.segments.memory,
+            ((2, 0), 1),
+            ((2, 1), 0),
+            ((2, 2), 9),
+            ((2, 3), 0),
+            ((1, 1), 1),
+            ((1, 2), 1)
+        ];
+    }
+
+    #[test]
+    fn assert_lt_assert_valid_input() {
+        let mut vm = vm!();
+        let mut exec_scopes = ExecutionScopes::new();
+        run_hint(
+            &mut vm,
+            &mut exec_scopes,
+            Hint::AssertLtAssertValidInput {
+                a: imm(1),
+                b: imm(2),
+            },
+        )
+        .unwrap();
+        assert_matches!(
+            run_hint(
+                &mut vm,
+                &mut exec_scopes,
+                Hint::AssertLtAssertValidInput {
+                    a: imm(2),
+                    b: imm(2),
+                },
+            ),
+            Err(HintError::AssertLtFelt(_, _))
+        );
+    }
+}
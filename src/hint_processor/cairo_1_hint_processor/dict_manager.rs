@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use crate::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::Felt;
+
+/// Tracks the values of a Cairo 1 dictionary, along with the pointer to its last access.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DictTrackerExecScope {
+    /// The data of the dictionary.
+    pub data: HashMap<Felt, MaybeRelocatable>,
+    /// The start of the segment of the dictionary.
+    pub start: Relocatable,
+    /// The end of the last access to the dictionary, if any.
+    pub end: Option<Relocatable>,
+}
+
+/// Manages the dictionaries of a Cairo 1 program, which are allocated on the segment arena.
+/// The index of each dictionary in the segment arena's infos is the index of its tracker.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct DictManagerExecScope {
+    /// The dictionaries, in the order they were allocated.
+    pub trackers: Vec<DictTrackerExecScope>,
+    /// Maps the segment index of each dictionary to the index of its tracker.
+    pub segment_to_tracker: HashMap<isize, usize>,
+}
+
+impl DictTrackerExecScope {
+    pub fn new(start: Relocatable) -> Self {
+        DictTrackerExecScope {
+            data: HashMap::default(),
+            start,
+            end: None,
+        }
+    }
+}
+
+impl DictManagerExecScope {
+    /// Value of the keys of a dictionary that were never written.
+    pub const DICT_DEFAULT_VALUE: usize = 0;
+
+    /// Allocates a segment for a new dictionary and starts tracking it.
+    pub fn new_default_dict(&mut self, vm: &mut VirtualMachine) -> Result<Relocatable, HintError> {
+        let dict_segment = vm.add_memory_segment();
+        if self
+            .segment_to_tracker
+            .insert(dict_segment.segment_index, self.trackers.len())
+            .is_some()
+        {
+            return Err(HintError::CantCreateDictionaryOnTakenSegment(
+                dict_segment.segment_index,
+            ));
+        }
+        self.trackers.push(DictTrackerExecScope::new(dict_segment));
+        Ok(dict_segment)
+    }
+
+    fn get_dict_tracker_mut(
+        &mut self,
+        dict_end: Relocatable,
+    ) -> Result<&mut DictTrackerExecScope, HintError> {
+        let tracker_idx = self.get_dict_infos_index(dict_end)?;
+        Ok(&mut self.trackers[tracker_idx])
+    }
+
+    /// Returns the index of the dictionary at `dict_end` in the segment arena's infos.
+    pub fn get_dict_infos_index(&self, dict_end: Relocatable) -> Result<usize, HintError> {
+        self.segment_to_tracker
+            .get(&dict_end.segment_index)
+            .copied()
+            .ok_or(HintError::NoDictTracker(dict_end.segment_index))
+    }
+
+    /// Sets `key` to `value` in the dictionary whose last access ends at `dict_end`.
+    pub fn insert_to_tracker(
+        &mut self,
+        dict_end: Relocatable,
+        key: Felt,
+        value: MaybeRelocatable,
+    ) -> Result<(), HintError> {
+        let tracker = self.get_dict_tracker_mut(dict_end)?;
+        tracker.end = Some(dict_end);
+        tracker.data.insert(key, value);
+        Ok(())
+    }
+
+    /// Returns the value of `key` in the dictionary whose last access ends at `dict_end`, or
+    /// `None` if it was never written.
+    pub fn get_from_tracker(
+        &self,
+        dict_end: Relocatable,
+        key: &Felt,
+    ) -> Result<Option<MaybeRelocatable>, HintError> {
+        let tracker_idx = self.get_dict_infos_index(dict_end)?;
+        Ok(self.trackers[tracker_idx].data.get(key).cloned())
+    }
+}
+
+/// State of the squashing of a Cairo 1 dictionary, shared by the squash hints.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct DictSquashExecScope {
+    /// The accesses of each key, in reverse order so the next one can be popped.
+    pub access_indices: HashMap<Felt, Vec<Felt>>,
+    /// The keys that are left to squash, in descending order so the next one can be popped.
+    pub keys: Vec<Felt>,
+    /// The total number of accesses of each key.
+    pub n_accesses: HashMap<Felt, usize>,
+}
+
+impl DictSquashExecScope {
+    /// Returns the key being squashed.
+    pub fn current_key(&self) -> Option<Felt> {
+        self.keys.last().cloned()
+    }
+
+    /// Returns the remaining access indices of the key being squashed.
+    pub fn current_access_indices(&mut self) -> Option<&mut Vec<Felt>> {
+        let key = self.current_key()?;
+        self.access_indices.get_mut(&key)
+    }
+
+    /// Returns the next access index of the key being squashed.
+    pub fn current_access_index(&mut self) -> Option<Felt> {
+        self.current_access_indices()?.last().cloned()
+    }
+
+    /// Pops the next access index of the key being squashed.
+    pub fn pop_current_access_index(&mut self) -> Option<Felt> {
+        self.current_access_indices()?.pop()
+    }
+
+    /// Moves on to the next key, returning the previous one. Only the last access of the
+    /// previous key may be left.
+    pub fn pop_current_key(&mut self) -> Result<Felt, HintError> {
+        let key = self.keys.pop().ok_or(HintError::EmptyKeys)?;
+        match self.access_indices.remove(&key) {
+            Some(indices) if indices.len() == 1 => Ok(key),
+            Some(_) => Err(HintError::CurrentAccessIndicesNotEmpty),
+            None => Err(HintError::NoKeyInAccessIndices(key)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn new_default_dict_and_trackers() {
+        let mut vm = vm!();
+        let mut dict_manager = DictManagerExecScope::default();
+        let first = dict_manager.new_default_dict(&mut vm).unwrap();
+        let second = dict_manager.new_default_dict(&mut vm).unwrap();
+        assert_eq!(first, Relocatable::from((0, 0)));
+        assert_eq!(second, Relocatable::from((1, 0)));
+        assert_matches!(dict_manager.get_dict_infos_index(second + 3_usize), Ok(1));
+        assert_matches!(
+            dict_manager.get_dict_infos_index(Relocatable::from((2, 0))),
+            Err(HintError::NoDictTracker(2))
+        );
+    }
+
+    #[test]
+    fn insert_and_get_from_tracker() {
+        let mut vm = vm!();
+        let mut dict_manager = DictManagerExecScope::default();
+        let dict = dict_manager.new_default_dict(&mut vm).unwrap();
+        assert_matches!(dict_manager.get_from_tracker(dict, &Felt::new(5)), Ok(None));
+        dict_manager
+            .insert_to_tracker(dict + 3_usize, Felt::new(5), MaybeRelocatable::from(7))
+            .unwrap();
+        assert_eq!(
+            dict_manager
+                .get_from_tracker(dict + 3_usize, &Felt::new(5))
+                .unwrap(),
+            Some(MaybeRelocatable::from(7))
+        );
+        assert_eq!(dict_manager.trackers[0].end, Some(dict + 3_usize));
+    }
+
+    #[test]
+    fn squash_keys_and_accesses() {
+        let mut squash = DictSquashExecScope {
+            access_indices: HashMap::from([
+                (Felt::new(1), vec![Felt::new(2), Felt::new(0)]),
+                (Felt::new(4), vec![Felt::new(1)]),
+            ]),
+            keys: vec![Felt::new(4), Felt::new(1)],
+            n_accesses: HashMap::from([(Felt::new(1), 2), (Felt::new(4), 1)]),
+        };
+        assert_eq!(squash.current_key(), Some(Felt::new(1)));
+        assert_eq!(squash.current_access_index(), Some(Felt::new(0)));
+        assert_matches!(
+            squash.clone().pop_current_key(),
+            Err(HintError::CurrentAccessIndicesNotEmpty)
+        );
+        assert_eq!(squash.pop_current_access_index(), Some(Felt::new(0)));
+        assert_eq!(squash.current_access_index(), Some(Felt::new(2)));
+        assert_eq!(squash.pop_current_key().unwrap(), Felt::new(1));
+        assert_eq!(squash.current_key(), Some(Felt::new(4)));
+    }
+}
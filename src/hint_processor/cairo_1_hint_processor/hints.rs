@@ -0,0 +1,327 @@
+//! Structured hints of Cairo 1 programs, as they appear in the `hints` field of the CASM
+//! compiler output.
+
+use crate::types::instruction::Register;
+use num_bigint::BigInt;
+use num_traits::Num;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A memory cell, given by its offset from `ap` or `fp`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CellRef {
+    pub register: Register,
+    pub offset: i16,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum DerefOrImmediate {
+    Deref(CellRef),
+    Immediate(#[serde(with = "bigint_hex")] BigInt),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Mul,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BinOpOperand {
+    pub op: Operation,
+    pub a: CellRef,
+    pub b: DerefOrImmediate,
+}
+
+/// An operand of a hint, which evaluates to a value.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ResOperand {
+    /// `[cell]`
+    Deref(CellRef),
+    /// `[[cell] + offset]`
+    DoubleDeref(CellRef, i16),
+    Immediate(#[serde(with = "bigint_hex")] BigInt),
+    /// `[a] op b`
+    BinOp(BinOpOperand),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+    AllocSegment {
+        dst: CellRef,
+    },
+    TestLessThan {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        dst: CellRef,
+    },
+    TestLessThanOrEqual {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        dst: CellRef,
+    },
+    WideMul128 {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        high: CellRef,
+        low: CellRef,
+    },
+    DivMod {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        quotient: CellRef,
+        remainder: CellRef,
+    },
+    Uint256DivMod {
+        dividend0: ResOperand,
+        dividend1: ResOperand,
+        divisor0: ResOperand,
+        divisor1: ResOperand,
+        quotient0: CellRef,
+        quotient1: CellRef,
+        remainder0: CellRef,
+        remainder1: CellRef,
+    },
+    Uint512DivModByUint256 {
+        dividend0: ResOperand,
+        dividend1: ResOperand,
+        dividend2: ResOperand,
+        dividend3: ResOperand,
+        divisor0: ResOperand,
+        divisor1: ResOperand,
+        quotient0: CellRef,
+        quotient1: CellRef,
+        quotient2: CellRef,
+        quotient3: CellRef,
+        remainder0: CellRef,
+        remainder1: CellRef,
+    },
+    SquareRoot {
+        value: ResOperand,
+        dst: CellRef,
+    },
+    Uint256SquareRoot {
+        value_low: ResOperand,
+        value_high: ResOperand,
+        sqrt0: CellRef,
+        sqrt1: CellRef,
+        remainder_low: CellRef,
+        remainder_high: CellRef,
+        sqrt_mul_2_minus_remainder_ge_u128: CellRef,
+    },
+    LinearSplit {
+        value: ResOperand,
+        scalar: ResOperand,
+        max_x: ResOperand,
+        x: CellRef,
+        y: CellRef,
+    },
+    AllocFelt252Dict {
+        segment_arena_ptr: ResOperand,
+    },
+    Felt252DictEntryInit {
+        dict_ptr: ResOperand,
+        key: ResOperand,
+    },
+    Felt252DictEntryUpdate {
+        dict_ptr: ResOperand,
+        value: ResOperand,
+    },
+    Felt252DictRead {
+        dict_ptr: ResOperand,
+        key: ResOperand,
+        value_dst: CellRef,
+    },
+    Felt252DictWrite {
+        dict_ptr: ResOperand,
+        key: ResOperand,
+        value: ResOperand,
+    },
+    GetSegmentArenaIndex {
+        dict_end_ptr: ResOperand,
+        dict_index: CellRef,
+    },
+    InitSquashData {
+        dict_accesses: ResOperand,
+        ptr_diff: ResOperand,
+        n_accesses: ResOperand,
+        big_keys: CellRef,
+        first_key: CellRef,
+    },
+    GetCurrentAccessIndex {
+        range_check_ptr: ResOperand,
+    },
+    ShouldSkipSquashLoop {
+        should_skip_loop: CellRef,
+    },
+    GetCurrentAccessDelta {
+        index_delta_minus1: CellRef,
+    },
+    ShouldContinueSquashLoop {
+        should_continue: CellRef,
+    },
+    GetNextDictKey {
+        next_key: CellRef,
+    },
+    AssertLeFindSmallArcs {
+        range_check_ptr: ResOperand,
+        a: ResOperand,
+        b: ResOperand,
+    },
+    AssertLeIsFirstArcExcluded {
+        skip_exclude_a_flag: CellRef,
+    },
+    AssertLeIsSecondArcExcluded {
+        skip_exclude_b_minus_a: CellRef,
+    },
+    AssertLtAssertValidInput {
+        a: ResOperand,
+        b: ResOperand,
+    },
+    AssertAllAccessesUsed {
+        n_used_accesses: CellRef,
+    },
+    AssertAllKeysUsed,
+    RandomEcPoint {
+        x: CellRef,
+        y: CellRef,
+    },
+    FieldSqrt {
+        val: ResOperand,
+        sqrt: CellRef,
+    },
+    AllocConstantSize {
+        size: ResOperand,
+        dst: CellRef,
+    },
+    DebugPrint {
+        start: ResOperand,
+        end: ResOperand,
+    },
+    SystemCall {
+        system: ResOperand,
+    },
+}
+
+// Immediates are written as hex strings, with a leading minus sign if negative
+mod bigint_hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &BigInt, serializer: S) -> Result<S::Ok, S::Error> {
+        let hex = format!("{:x}", value.magnitude());
+        if value.sign() == num_bigint::Sign::Minus {
+            serializer.serialize_str(&format!("-0x{hex}"))
+        } else {
+            serializer.serialize_str(&format!("0x{hex}"))
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigInt, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value.as_str()),
+        };
+        let parsed = match digits.strip_prefix("0x") {
+            Some(hex) => BigInt::from_str_radix(hex, 16),
+            None => BigInt::from_str_radix(digits, 10),
+        }
+        .map_err(|_| de::Error::custom(format!("invalid immediate {value}")))?;
+        Ok(if negative { -parsed } else { parsed })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_hint() {
+        let hint: Hint = serde_json::from_str(
+            r#"{"TestLessThanOrEqual": {
+                "lhs": {"Immediate": "0x0"},
+                "rhs": {"Deref": {"register": "FP", "offset": -6}},
+                "dst": {"register": "AP", "offset": 0}
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            hint,
+            Hint::TestLessThanOrEqual {
+                lhs: ResOperand::Immediate(BigInt::from(0)),
+                rhs: ResOperand::Deref(CellRef {
+                    register: Register::FP,
+                    offset: -6
+                }),
+                dst: CellRef {
+                    register: Register::AP,
+                    offset: 0
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_operands() {
+        let operands: Vec<ResOperand> = serde_json::from_str(
+            r#"[
+                {"DoubleDeref": [{"register": "FP", "offset": -3}, 2]},
+                {"Immediate": "-0x10"},
+                {"BinOp": {
+                    "op": "Mul",
+                    "a": {"register": "AP", "offset": -1},
+                    "b": {"Immediate": "0x2"}
+                }}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            operands,
+            vec![
+                ResOperand::DoubleDeref(
+                    CellRef {
+                        register: Register::FP,
+                        offset: -3
+                    },
+                    2
+                ),
+                ResOperand::Immediate(BigInt::from(-16)),
+                ResOperand::BinOp(BinOpOperand {
+                    op: Operation::Mul,
+                    a: CellRef {
+                        register: Register::AP,
+                        offset: -1
+                    },
+                    b: DerefOrImmediate::Immediate(BigInt::from(2))
+                })
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_unit_hint() {
+        let hint: Hint = serde_json::from_str(r#""AssertAllKeysUsed""#).unwrap();
+        assert_eq!(hint, Hint::AssertAllKeysUsed);
+    }
+
+    #[test]
+    fn serialize_hint_round_trip() {
+        let hint = Hint::DivMod {
+            lhs: ResOperand::Immediate(BigInt::from(-255)),
+            rhs: ResOperand::Deref(CellRef {
+                register: Register::AP,
+                offset: 1,
+            }),
+            quotient: CellRef {
+                register: Register::AP,
+                offset: 2,
+            },
+            remainder: CellRef {
+                register: Register::AP,
+                offset: 3,
+            },
+        };
+        let serialized = serde_json::to_string(&hint).unwrap();
+        assert!(serialized.contains(r#""Immediate":"-0xff""#));
+        assert_eq!(serde_json::from_str::<Hint>(&serialized).unwrap(), hint);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod cairo_1_hint_processor_definition;
+pub mod dict_manager;
+pub mod hints;
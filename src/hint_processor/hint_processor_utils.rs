@@ -175,6 +175,32 @@ fn get_offset_value_reference(
     }
 }
 
+/// Normalizes the whitespace of the code of a hint, so that hints which only differ in their
+/// indentation, trailing whitespace, line endings or surrounding blank lines are the same. The
+/// relative indentation of the lines, which Python depends on, is kept.
+pub fn normalize_hint_code(code: &str) -> String {
+    let lines: Vec<&str> = code.lines().map(str::trim_end).collect();
+    let (first, last) = match (
+        lines.iter().position(|line| !line.is_empty()),
+        lines.iter().rposition(|line| !line.is_empty()),
+    ) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return String::new(),
+    };
+    let lines = &lines[first..=last];
+    let indentation = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or_default();
+    lines
+        .iter()
+        .map(|line| line.get(indentation..).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,4 +372,12 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn normalize_indented_hint_code() {
+        let code = "\r\n    if x > 0:  \r\n        y = 1\r\n\r\n    z = 2\t\n\n";
+        assert_eq!(normalize_hint_code(code), "if x > 0:\n    y = 1\n\nz = 2");
+        assert_eq!(normalize_hint_code("memory[ap] = 1"), "memory[ap] = 1");
+        assert_eq!(normalize_hint_code(" \n  "), "");
+    }
 }
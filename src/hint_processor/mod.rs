@@ -1,3 +1,7 @@
 pub mod builtin_hint_processor;
+pub mod cairo_1_hint_processor;
 pub mod hint_processor_definition;
 pub mod hint_processor_utils;
+#[cfg(feature = "python_hints")]
+#[cfg_attr(docsrs, doc(cfg(feature = "python_hints")))]
+pub mod python_hint_processor;
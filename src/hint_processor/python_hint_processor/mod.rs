@@ -0,0 +1,21 @@
+//! Execution of hints written in Python, in a CPython interpreter embedded through
+//! [PyO3](https://pyo3.rs).
+//!
+//! The [PythonHintProcessor](python_hint_processor_definition::PythonHintProcessor) runs the
+//! hints known to the [BuiltinHintProcessor](super::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor)
+//! in Rust, and only sends the remaining ones to Python. As described in
+//! `docs/python_rust_integration_specs.md`, Python hints can access:
+//! * `memory`, which is read and written by address (`memory[ap] = 1`).
+//! * `segments`, with the methods `add`, `add_temp_segment`, `gen_arg`, `write_arg`,
+//!   `get_segment_used_size` and `get_segments_used_sizes`.
+//! * `ids`, which reads and writes the references of the hint, and reads the constants of the
+//!   program.
+//! * The read-only registers `ap`, `fp` and `pc`, and `PRIME`.
+//! * The variables of the current execution scope, and `vm_enter_scope`/`vm_exit_scope`.
+//!
+//! Variables defined by Python hints are only visible to Python hints, and those defined by
+//! builtin hints only to builtin hints. The program input, if any, is available to the Python
+//! hints of the main scope as `program_input`.
+
+pub mod python_hint_processor_definition;
+mod vm_proxy;
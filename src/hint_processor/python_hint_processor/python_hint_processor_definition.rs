@@ -0,0 +1,363 @@
+use super::vm_proxy::{
+    get_scope_locals, EnterScope, ExitScope, HintState, IdsEntry, PyIds, PyMemory, PyRelocatable,
+    PySegments, SharedHintState,
+};
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::builtin_hint_processor_definition::{
+            BuiltinHintProcessor, HintProcessorData,
+        },
+        hint_processor_definition::{HintProcessor, HintReference},
+        hint_processor_utils::compute_addr_from_reference,
+    },
+    serde::deserialize_program::{ApTracking, OffsetValue},
+    types::exec_scope::ExecutionScopes,
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+        vm_memory::memory_segments::MemorySegmentManager,
+    },
+};
+use felt::{Felt, PRIME_STR};
+use num_bigint::BigUint;
+use num_traits::Num;
+use pyo3::{prelude::*, types::PyDict};
+use std::{any::Any, cell::RefCell, collections::HashMap, mem, rc::Rc};
+
+// Names given to the hints on top of the variables of their scope
+const HINT_NAMES: [&str; 9] = [
+    "memory",
+    "segments",
+    "ids",
+    "ap",
+    "fp",
+    "pc",
+    "PRIME",
+    "vm_enter_scope",
+    "vm_exit_scope",
+];
+
+/// A hint processor that runs the hints known to its [BuiltinHintProcessor], and the remaining
+/// ones in an embedded CPython interpreter.
+pub struct PythonHintProcessor {
+    /// Runs the hints implemented in Rust. Its extra hints and syscall handler can be set as
+    /// usual.
+    pub builtin_hint_processor: BuiltinHintProcessor,
+}
+
+impl PythonHintProcessor {
+    pub fn new_empty() -> Self {
+        PythonHintProcessor::new(BuiltinHintProcessor::new_empty())
+    }
+
+    pub fn new(builtin_hint_processor: BuiltinHintProcessor) -> Self {
+        PythonHintProcessor {
+            builtin_hint_processor,
+        }
+    }
+
+    fn execute_python_hint(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &HintProcessorData,
+        constants: &HashMap<String, Felt>,
+    ) -> Result<(), HintError> {
+        let ids = get_ids_entries(vm, &hint_data.ids_data, &hint_data.ap_tracking);
+        Python::with_gil(|py| {
+            let locals = get_scope_locals(py, exec_scopes)
+                .map_err(|error| HintError::PythonHint(error.to_string()))?;
+            let state = Rc::new(RefCell::new(HintState {
+                segments: mem::replace(&mut vm.segments, MemorySegmentManager::new()),
+                exec_scopes: mem::replace(exec_scopes, ExecutionScopes::new()),
+                active: true,
+            }));
+            let hint_names = HintNames {
+                state: &state,
+                ids,
+                constants,
+                registers: [vm.get_ap(), vm.get_fp(), vm.get_pc()].map(PyRelocatable::from),
+            };
+            let result = run_python_hint(py, hint_data.code(), locals, hint_names);
+            // Python may keep references to the state, which can't be used from now on
+            let mut state = state.borrow_mut();
+            state.active = false;
+            vm.segments = mem::replace(&mut state.segments, MemorySegmentManager::new());
+            *exec_scopes = mem::replace(&mut state.exec_scopes, ExecutionScopes::new());
+            result.map_err(|error| HintError::PythonHint(error.to_string()))
+        })
+    }
+}
+
+impl HintProcessor for PythonHintProcessor {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, Felt>,
+    ) -> Result<(), HintError> {
+        let hint_data = hint_data
+            .downcast_ref::<HintProcessorData>()
+            .ok_or(HintError::WrongHintData)?;
+        // The hints the interpreter of the BuiltinHintProcessor could run are left to Python too,
+        // so that they share their variables with the other Python hints
+        match self.builtin_hint_processor.execute_implemented_hint(
+            vm,
+            exec_scopes,
+            hint_data,
+            constants,
+        ) {
+            Err(HintError::UnknownHint(_)) => {
+                self.execute_python_hint(vm, exec_scopes, hint_data, constants)
+            }
+            result => result,
+        }
+    }
+
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        ap_tracking_data: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &HashMap<usize, HintReference>,
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        self.builtin_hint_processor.compile_hint(
+            hint_code,
+            ap_tracking_data,
+            reference_ids,
+            references,
+        )
+    }
+}
+
+// References whose address can't be computed, for example because ap was changed since they
+// were defined, are left out
+fn get_ids_entries(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> HashMap<String, IdsEntry> {
+    ids_data
+        .iter()
+        .filter_map(|(name, reference)| {
+            let entry = match &reference.offset1 {
+                OffsetValue::Immediate(value) => IdsEntry::Value(value.into()),
+                _ => {
+                    let address = compute_addr_from_reference(reference, vm, ap_tracking)?;
+                    if reference.dereference {
+                        IdsEntry::Cell(address)
+                    } else {
+                        IdsEntry::Value(address.into())
+                    }
+                }
+            };
+            Some((name.clone(), entry))
+        })
+        .collect()
+}
+
+struct HintNames<'a> {
+    state: &'a SharedHintState,
+    ids: HashMap<String, IdsEntry>,
+    constants: &'a HashMap<String, Felt>,
+    registers: [PyRelocatable; 3],
+}
+
+fn run_python_hint(py: Python, code: &str, locals: &PyDict, hint_names: HintNames) -> PyResult<()> {
+    let HintNames {
+        state,
+        ids,
+        constants,
+        registers: [ap, fp, pc],
+    } = hint_names;
+    let prime = BigUint::from_str_radix(&PRIME_STR[2..], 16).expect("PRIME_STR is hexadecimal");
+    locals.set_item("memory", PyMemory::new(state.clone()).into_py(py))?;
+    locals.set_item("segments", PySegments::new(state.clone()).into_py(py))?;
+    locals.set_item(
+        "ids",
+        PyIds::new(state.clone(), ids, constants.clone()).into_py(py),
+    )?;
+    locals.set_item("ap", ap.into_py(py))?;
+    locals.set_item("fp", fp.into_py(py))?;
+    locals.set_item("pc", pc.into_py(py))?;
+    locals.set_item("PRIME", prime)?;
+    locals.set_item("vm_enter_scope", EnterScope::new(state.clone()).into_py(py))?;
+    locals.set_item("vm_exit_scope", ExitScope::new(state.clone()).into_py(py))?;
+    // The variables the hint defines are kept in the scope for the following hints
+    let result = py.run(code, Some(locals), None);
+    for name in HINT_NAMES {
+        if locals.contains(name)? {
+            locals.del_item(name)?;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::builtin_hint_processor::program_input_utils::PROGRAM_INPUT,
+        relocatable,
+        types::relocatable::{MaybeRelocatable, Relocatable},
+        utils::test_utils::*,
+        vm::{errors::memory_errors::MemoryError, vm_memory::memory::Memory},
+    };
+    use assert_matches::assert_matches;
+
+    fn execute_hint(
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        ids_data: HashMap<String, HintReference>,
+        code: &str,
+    ) -> Result<(), HintError> {
+        let hint_data = HintProcessorData::new_default(code.to_string(), ids_data);
+        PythonHintProcessor::new_empty().execute_hint(
+            vm,
+            exec_scopes,
+            &any_box!(hint_data),
+            &HashMap::from([("main.SIZE".to_string(), Felt::new(3))]),
+        )
+    }
+
+    #[test]
+    fn run_builtin_hint() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        assert_matches!(
+            execute_hint(
+                &mut vm,
+                &mut ExecutionScopes::new(),
+                HashMap::new(),
+                "memory[ap] = segments.add()"
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 0), (2, 0))];
+    }
+
+    #[test]
+    fn run_ids_and_memory_hint() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        vm.segments = segments![((1, 0), 10), ((1, 1), (2, 5)), ((2, 0), 1)];
+        let ids_data = ids_data!["a", "ptr", "b"];
+        let code = "import math\nids.b = math.isqrt(ids.a) + ids.SIZE\nmemory[ids.ptr + 1] = ids.ptr - 5 + memory[ids.ptr - 5]";
+        assert_matches!(
+            execute_hint(&mut vm, &mut ExecutionScopes::new(), ids_data, code),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 2), 6), ((2, 6), (2, 1))];
+    }
+
+    #[test]
+    fn run_segments_hint() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let code = "base = segments.gen_arg([1, [2, 3], -1])\nmemory[ap] = base\nmemory[ap + 1] = segments.write_arg(base + 3, [ap, 4])\nassert segments.get_segment_used_size(base.segment_index) == 5";
+        assert_matches!(
+            execute_hint(&mut vm, &mut ExecutionScopes::new(), HashMap::new(), code),
+            Ok(())
+        );
+        // As in cairo-lang, the segment of a list is added before those of its elements
+        check_memory![
+            vm.segments.memory,
+            ((1, 0), (2, 0)),
+            ((1, 1), (2, 5)),
+            ((2, 0), 1),
+            ((2, 1), (3, 0)),
+            ((2, 3), (1, 0)),
+            ((2, 4), 4),
+            ((3, 0), 2),
+            ((3, 1), 3)
+        ];
+        assert_eq!(
+            vm.get_integer(relocatable!(2, 2)).unwrap().as_ref(),
+            &Felt::new(-1)
+        );
+    }
+
+    #[test]
+    fn python_variables_are_kept_in_their_scope() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value(PROGRAM_INPUT, serde_json::json!({"n": 2}));
+        let hints = [
+            "n = program_input['n']\nvm_enter_scope({'m': n * 2})",
+            "memory[ap] = m\nvm_exit_scope()",
+            "memory[ap + 1] = n",
+        ];
+        for code in hints {
+            assert_matches!(
+                execute_hint(&mut vm, &mut exec_scopes, HashMap::new(), code),
+                Ok(())
+            );
+        }
+        check_memory![vm.segments.memory, ((1, 0), 4), ((1, 1), 2)];
+        assert_eq!(exec_scopes.data.len(), 1);
+        // Python variables aren't visible to the builtin hints
+        assert_matches!(
+            exec_scopes.get::<Felt>("n"),
+            Err(HintError::VariableNotInScopeError(_))
+        );
+    }
+
+    #[test]
+    fn python_variables_are_shared_by_simple_hints() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let mut exec_scopes = ExecutionScopes::new();
+        let hints = [
+            "i = 4",
+            "import math\nmemory[ap] = math.isqrt(i)",
+            "memory[ap + 1] = i + 1",
+        ];
+        for code in hints {
+            assert_matches!(
+                execute_hint(&mut vm, &mut exec_scopes, HashMap::new(), code),
+                Ok(())
+            );
+        }
+        check_memory![vm.segments.memory, ((1, 0), 2), ((1, 1), 5)];
+    }
+
+    #[test]
+    fn stashed_objects_cant_access_the_vm() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_matches!(
+            execute_hint(
+                &mut vm,
+                &mut exec_scopes,
+                HashMap::new(),
+                "old_memory = memory"
+            ),
+            Ok(())
+        );
+        assert_matches!(
+            execute_hint(&mut vm, &mut exec_scopes, HashMap::new(), "old_memory[ap] = 1"),
+            Err(HintError::PythonHint(message)) if message.contains("RuntimeError")
+        );
+        assert_eq!(vm.segments.num_segments(), 2);
+    }
+
+    #[test]
+    fn failing_python_hint() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), 1)];
+        assert_matches!(
+            execute_hint(
+                &mut vm,
+                &mut ExecutionScopes::new(),
+                HashMap::new(),
+                "memory[ap] = 2 if len([]) == 0 else 1"
+            ),
+            Err(HintError::PythonHint(message)) if message.contains("ValueError")
+        );
+        check_memory![vm.segments.memory, ((1, 0), 1)];
+    }
+}
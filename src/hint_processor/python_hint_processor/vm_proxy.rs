@@ -0,0 +1,444 @@
+use crate::{
+    hint_processor::builtin_hint_processor::{
+        hint_interpreter::get_constant,
+        program_input_utils::{get_program_input, PROGRAM_INPUT},
+    },
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::memory_errors::MemoryError, vm_memory::memory_segments::MemorySegmentManager},
+};
+use felt::Felt;
+use num_bigint::BigInt;
+use pyo3::{
+    basic::CompareOp,
+    exceptions::{PyAttributeError, PyKeyError, PyRuntimeError, PyTypeError, PyValueError},
+    prelude::*,
+    types::PyDict,
+};
+use std::{
+    cell::{RefCell, RefMut},
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+/// Name of the execution scope variable holding the variables of the Python hints
+pub(crate) const PYTHON_LOCALS: &str = "__python_locals__";
+
+/// The state of the VM that Python hints can modify. It is moved out of the VM while a hint
+/// runs, and moved back once it finishes.
+pub(crate) struct HintState {
+    pub(crate) segments: MemorySegmentManager,
+    pub(crate) exec_scopes: ExecutionScopes,
+    /// Whether a hint is running. Objects kept by Python after their hint can't access the VM.
+    pub(crate) active: bool,
+}
+
+pub(crate) type SharedHintState = Rc<RefCell<HintState>>;
+
+fn borrow_state(state: &SharedHintState) -> PyResult<RefMut<HintState>> {
+    let state = state
+        .try_borrow_mut()
+        .map_err(|_| PyRuntimeError::new_err("The VM state is already in use"))?;
+    if !state.active {
+        return Err(PyRuntimeError::new_err(
+            "The VM state can only be accessed while its hint runs",
+        ));
+    }
+    Ok(state)
+}
+
+fn memory_error(error: MemoryError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// A relocatable address, as seen from Python.
+#[pyclass(name = "RelocatableValue")]
+#[derive(Clone, Copy)]
+pub(crate) struct PyRelocatable {
+    #[pyo3(get)]
+    segment_index: isize,
+    #[pyo3(get)]
+    offset: usize,
+}
+
+impl From<Relocatable> for PyRelocatable {
+    fn from(relocatable: Relocatable) -> Self {
+        PyRelocatable {
+            segment_index: relocatable.segment_index,
+            offset: relocatable.offset,
+        }
+    }
+}
+
+impl From<PyRelocatable> for Relocatable {
+    fn from(relocatable: PyRelocatable) -> Self {
+        Relocatable {
+            segment_index: relocatable.segment_index,
+            offset: relocatable.offset,
+        }
+    }
+}
+
+impl PyRelocatable {
+    fn add_offset(&self, offset: &BigInt) -> PyResult<PyRelocatable> {
+        let offset = (BigInt::from(self.offset) + offset)
+            .try_into()
+            .map_err(|_| {
+                PyValueError::new_err(format!("Offset out of bounds: {self} + {offset}"))
+            })?;
+        Ok(PyRelocatable {
+            segment_index: self.segment_index,
+            offset,
+        })
+    }
+}
+
+impl std::fmt::Display for PyRelocatable {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Relocatable::from(*self))
+    }
+}
+
+#[pymethods]
+impl PyRelocatable {
+    fn __add__(&self, other: BigInt) -> PyResult<PyRelocatable> {
+        self.add_offset(&other)
+    }
+
+    fn __radd__(&self, other: BigInt) -> PyResult<PyRelocatable> {
+        self.add_offset(&other)
+    }
+
+    fn __sub__(&self, py: Python, other: &PyAny) -> PyResult<PyObject> {
+        if let Ok(other) = other.extract::<PyRelocatable>() {
+            if self.segment_index != other.segment_index {
+                return Err(PyValueError::new_err(format!(
+                    "Can't subtract {other} from {self}, which is in another segment"
+                )));
+            }
+            return Ok((BigInt::from(self.offset) - other.offset).into_py(py));
+        }
+        match other.extract::<BigInt>() {
+            Ok(other) => Ok(self.add_offset(&-other)?.into_py(py)),
+            Err(_) => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __richcmp__(&self, py: Python, other: &PyAny, op: CompareOp) -> PyObject {
+        let other = match other.extract::<PyRelocatable>() {
+            Ok(other) => other,
+            Err(_) => return py.NotImplemented(),
+        };
+        let (left, right) = (
+            (self.segment_index, self.offset),
+            (other.segment_index, other.offset),
+        );
+        match op {
+            CompareOp::Lt => left < right,
+            CompareOp::Le => left <= right,
+            CompareOp::Eq => left == right,
+            CompareOp::Ne => left != right,
+            CompareOp::Gt => left > right,
+            CompareOp::Ge => left >= right,
+        }
+        .into_py(py)
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (self.segment_index, self.offset).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+fn to_py(py: Python, value: &MaybeRelocatable) -> PyObject {
+    match value {
+        MaybeRelocatable::Int(value) => value.to_biguint().into_py(py),
+        MaybeRelocatable::RelocatableValue(value) => PyRelocatable::from(*value).into_py(py),
+    }
+}
+
+// Ints are reduced modulo the prime
+fn from_py(value: &PyAny) -> PyResult<MaybeRelocatable> {
+    if let Ok(value) = value.extract::<PyRelocatable>() {
+        return Ok(Relocatable::from(value).into());
+    }
+    match value.extract::<BigInt>() {
+        Ok(value) => Ok(Felt::from(value).into()),
+        Err(_) => Err(PyTypeError::new_err(format!(
+            "Expected an int or a relocatable value, got {value}"
+        ))),
+    }
+}
+
+/// The memory of the VM, read and written by address.
+#[pyclass(name = "Memory", unsendable)]
+pub(crate) struct PyMemory {
+    state: SharedHintState,
+}
+
+impl PyMemory {
+    pub(crate) fn new(state: SharedHintState) -> Self {
+        PyMemory { state }
+    }
+}
+
+#[pymethods]
+impl PyMemory {
+    fn __getitem__(&self, py: Python, address: PyRelocatable) -> PyResult<PyObject> {
+        self.get(py, address, None)?
+            .ok_or_else(|| PyKeyError::new_err(format!("Unknown value for memory cell {address}")))
+    }
+
+    fn __setitem__(&self, address: PyRelocatable, value: &PyAny) -> PyResult<()> {
+        let value = from_py(value)?;
+        borrow_state(&self.state)?
+            .segments
+            .memory
+            .insert_value(address.into(), value)
+            .map_err(memory_error)
+    }
+
+    #[pyo3(signature = (address, default = None))]
+    fn get(
+        &self,
+        py: Python,
+        address: PyRelocatable,
+        default: Option<PyObject>,
+    ) -> PyResult<Option<PyObject>> {
+        let state = borrow_state(&self.state)?;
+        Ok(state
+            .segments
+            .memory
+            .get(&Relocatable::from(address))
+            .map(|value| to_py(py, &value))
+            .or(default))
+    }
+}
+
+/// The segments of the memory of the VM.
+#[pyclass(name = "MemorySegmentManager", unsendable)]
+pub(crate) struct PySegments {
+    state: SharedHintState,
+}
+
+impl PySegments {
+    pub(crate) fn new(state: SharedHintState) -> Self {
+        PySegments { state }
+    }
+}
+
+// Lists and other iterables are written into a new segment, whose base is returned
+fn gen_arg(segments: &mut MemorySegmentManager, arg: &PyAny) -> PyResult<MaybeRelocatable> {
+    if let Ok(value) = from_py(arg) {
+        return Ok(value);
+    }
+    let base = segments.add();
+    write_arg(segments, base, arg)?;
+    Ok(base.into())
+}
+
+fn write_arg(
+    segments: &mut MemorySegmentManager,
+    ptr: Relocatable,
+    arg: &PyAny,
+) -> PyResult<Relocatable> {
+    let data = arg
+        .iter()?
+        .map(|value| gen_arg(segments, value?))
+        .collect::<PyResult<Vec<_>>>()?;
+    segments
+        .load_data(&ptr.into(), &data)
+        .map_err(memory_error)?;
+    Ok(ptr + data.len())
+}
+
+#[pymethods]
+impl PySegments {
+    fn add(&self) -> PyResult<PyRelocatable> {
+        Ok(borrow_state(&self.state)?.segments.add().into())
+    }
+
+    fn add_temp_segment(&self) -> PyResult<PyRelocatable> {
+        Ok(borrow_state(&self.state)?
+            .segments
+            .add_temporary_segment()
+            .into())
+    }
+
+    fn gen_arg(&self, py: Python, arg: &PyAny) -> PyResult<PyObject> {
+        let value = gen_arg(&mut borrow_state(&self.state)?.segments, arg)?;
+        Ok(to_py(py, &value))
+    }
+
+    fn write_arg(&self, ptr: PyRelocatable, arg: &PyAny) -> PyResult<PyRelocatable> {
+        write_arg(&mut borrow_state(&self.state)?.segments, ptr.into(), arg).map(Into::into)
+    }
+
+    /// Returns the number of cells used so far by the segment.
+    fn get_segment_used_size(&self, segment_index: usize) -> PyResult<usize> {
+        borrow_state(&self.state)?
+            .segments
+            .memory
+            .data
+            .get(segment_index)
+            .map(Vec::len)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown segment {segment_index}")))
+    }
+
+    /// Returns the number of cells used so far by each segment.
+    fn get_segments_used_sizes(&self) -> PyResult<Vec<usize>> {
+        Ok(borrow_state(&self.state)?
+            .segments
+            .memory
+            .data
+            .iter()
+            .map(Vec::len)
+            .collect())
+    }
+}
+
+/// The value of a reference of the hint.
+pub(crate) enum IdsEntry {
+    /// A reference to a memory cell, which can be read and written
+    Cell(Relocatable),
+    /// A reference with a fixed value, like an immediate value or an address
+    Value(MaybeRelocatable),
+}
+
+/// The references of the hint, and the constants of the program.
+#[pyclass(name = "Ids", unsendable)]
+pub(crate) struct PyIds {
+    state: SharedHintState,
+    entries: HashMap<String, IdsEntry>,
+    constants: HashMap<String, Felt>,
+}
+
+impl PyIds {
+    pub(crate) fn new(
+        state: SharedHintState,
+        entries: HashMap<String, IdsEntry>,
+        constants: HashMap<String, Felt>,
+    ) -> Self {
+        PyIds {
+            state,
+            entries,
+            constants,
+        }
+    }
+}
+
+#[pymethods]
+impl PyIds {
+    fn __getattr__(&self, py: Python, name: &str) -> PyResult<PyObject> {
+        match self.entries.get(name) {
+            Some(IdsEntry::Cell(address)) => borrow_state(&self.state)?
+                .segments
+                .memory
+                .get(address)
+                .map(|value| to_py(py, &value))
+                .ok_or_else(|| {
+                    PyKeyError::new_err(format!("Unknown value for memory cell {address}"))
+                }),
+            Some(IdsEntry::Value(value)) => Ok(to_py(py, value)),
+            None => get_constant(&self.constants, name)
+                .map(|value| value.to_biguint().into_py(py))
+                .ok_or_else(|| PyAttributeError::new_err(format!("Unknown identifier {name}"))),
+        }
+    }
+
+    fn __setattr__(&self, name: &str, value: &PyAny) -> PyResult<()> {
+        let address = match self.entries.get(name) {
+            Some(IdsEntry::Cell(address)) => *address,
+            _ => {
+                return Err(PyAttributeError::new_err(format!(
+                    "Can't assign to ids.{name}"
+                )))
+            }
+        };
+        let value = from_py(value)?;
+        borrow_state(&self.state)?
+            .segments
+            .memory
+            .insert_value(address, value)
+            .map_err(memory_error)
+    }
+}
+
+/// Returns the variables of the Python hints in the current execution scope, creating them if
+/// needed.
+pub(crate) fn get_scope_locals<'py>(
+    py: Python<'py>,
+    exec_scopes: &mut ExecutionScopes,
+) -> PyResult<&'py PyDict> {
+    if let Ok(locals) = exec_scopes.get_ref::<Py<PyDict>>(PYTHON_LOCALS) {
+        return Ok(locals.clone_ref(py).into_ref(py));
+    }
+    let locals = PyDict::new(py);
+    if let Ok(program_input) = get_program_input(exec_scopes) {
+        let program_input = py
+            .import("json")?
+            .call_method1("loads", (program_input.to_string(),))?;
+        locals.set_item(PROGRAM_INPUT, program_input)?;
+    }
+    exec_scopes.insert_value(PYTHON_LOCALS, Py::<PyDict>::from(locals));
+    Ok(locals)
+}
+
+/// `vm_enter_scope`, which enters a new execution scope with the given variables.
+#[pyclass(unsendable)]
+pub(crate) struct EnterScope {
+    state: SharedHintState,
+}
+
+impl EnterScope {
+    pub(crate) fn new(state: SharedHintState) -> Self {
+        EnterScope { state }
+    }
+}
+
+#[pymethods]
+impl EnterScope {
+    #[pyo3(signature = (new_scope_locals = None))]
+    fn __call__(&self, py: Python, new_scope_locals: Option<&PyDict>) -> PyResult<()> {
+        let locals = match new_scope_locals {
+            Some(new_scope_locals) => new_scope_locals.copy()?,
+            None => PyDict::new(py),
+        };
+        let mut state = borrow_state(&self.state)?;
+        state.exec_scopes.enter_scope(HashMap::new());
+        state
+            .exec_scopes
+            .insert_value(PYTHON_LOCALS, Py::<PyDict>::from(locals));
+        Ok(())
+    }
+}
+
+/// `vm_exit_scope`, which exits the current execution scope.
+#[pyclass(unsendable)]
+pub(crate) struct ExitScope {
+    state: SharedHintState,
+}
+
+impl ExitScope {
+    pub(crate) fn new(state: SharedHintState) -> Self {
+        ExitScope { state }
+    }
+}
+
+#[pymethods]
+impl ExitScope {
+    fn __call__(&self) -> PyResult<()> {
+        borrow_state(&self.state)?
+            .exec_scopes
+            .exit_scope()
+            .map_err(|error| PyRuntimeError::new_err(error.to_string()))
+    }
+}
@@ -4,13 +4,19 @@
 //! - `skip_next_instruction_hint`: Enable the `skip_next_instruction()` hint. Not enabled by default.
 //! - `hooks`: Enable [Hooks](vm::hooks) support for the [VirtualMachine](vm::vm_core::VirtualMachine). Not enabled by default.
 //! - `with_mimalloc`: Use [MiMalloc](https://crates.io/crates/mimalloc) as the program global allocator.
+//! - `python_hints`: Enable the [PythonHintProcessor](hint_processor::python_hint_processor::python_hint_processor_definition::PythonHintProcessor), which runs the hints unknown to the builtin hint processor in an embedded CPython interpreter. Not enabled by default.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(warnings)]
+pub mod air_private_input;
+pub mod air_public_input;
 pub mod cairo_run;
 pub mod hint_processor;
 pub mod math_utils;
+pub mod poseidon_hash;
+pub mod program_hash;
 pub mod serde;
+pub mod starknet;
 pub mod types;
 pub mod utils;
 pub mod vm;
@@ -1,10 +1,19 @@
 #![deny(warnings)]
 use cairo_vm::cairo_run;
+#[cfg(not(feature = "python_hints"))]
 use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+#[cfg(feature = "python_hints")]
+use cairo_vm::hint_processor::python_hint_processor::python_hint_processor_definition::PythonHintProcessor;
+use cairo_vm::program_hash::{compute_output_fact, compute_program_hash_chain};
+use cairo_vm::types::errors::program_errors::ProgramError;
+use cairo_vm::types::layout::CairoLayoutParams;
 use cairo_vm::vm::errors::cairo_run_errors::CairoRunError;
 use cairo_vm::vm::errors::runner_errors::RunnerError;
 use cairo_vm::vm::errors::trace_errors::TraceError;
+use cairo_vm::vm::runners::cairo_pie::CairoPie;
 use clap::{Parser, ValueHint};
+use std::fs::File;
+use std::io::BufReader;
 use std::path::PathBuf;
 
 #[cfg(feature = "with_mimalloc")]
@@ -34,19 +43,68 @@ struct Args {
     proof_mode: bool,
     #[structopt(long = "--secure_run")]
     secure_run: Option<bool>,
+    #[clap(long = "--air_public_input", requires = "proof-mode")]
+    air_public_input: Option<PathBuf>,
+    #[clap(
+        long = "--air_private_input",
+        requires_all = &["proof-mode", "trace-file", "memory-file"]
+    )]
+    air_private_input: Option<PathBuf>,
+    #[clap(long = "--cairo_pie_output", conflicts_with = "proof-mode")]
+    cairo_pie_output: Option<PathBuf>,
+    #[clap(long = "--run_from_cairo_pie", conflicts_with = "proof-mode")]
+    run_from_cairo_pie: bool,
+    #[clap(long = "--print_program_hash")]
+    print_program_hash: bool,
+    #[clap(
+        long = "--cairo_layout_params_file",
+        required_if_eq("layout", "dynamic"),
+        value_hint = ValueHint::FilePath
+    )]
+    cairo_layout_params_file: Option<PathBuf>,
+    #[clap(long = "--program_input", value_hint = ValueHint::FilePath)]
+    program_input: Option<PathBuf>,
 }
 
 fn validate_layout(value: &str) -> Result<(), String> {
     match value {
-        "plain" | "small" | "dex" | "bitwise" | "perpetual_with_bitwise" | "all" => Ok(()),
+        "plain"
+        | "small"
+        | "dex"
+        | "bitwise"
+        | "perpetual_with_bitwise"
+        | "recursive"
+        | "starknet"
+        | "starknet_with_keccak"
+        | "recursive_large_output"
+        | "all"
+        | "all_cairo"
+        | "dynamic" => Ok(()),
         _ => Err(format!("{value} is not a valid layout")),
     }
 }
 
 fn main() -> Result<(), CairoRunError> {
     let args = Args::parse();
-    let trace_enabled = args.trace_file.is_some();
+    let trace_enabled = args.trace_file.is_some() || args.air_public_input.is_some();
+    #[cfg(not(feature = "python_hints"))]
     let mut hint_executor = BuiltinHintProcessor::new_empty();
+    #[cfg(feature = "python_hints")]
+    let mut hint_executor = PythonHintProcessor::new_empty();
+    let dynamic_layout_params =
+        match &args.cairo_layout_params_file {
+            Some(file_path) => Some(CairoLayoutParams::from_file(file_path).map_err(|e| {
+                CairoRunError::Runner(RunnerError::InvalidLayoutParams(e.to_string()))
+            })?),
+            None => None,
+        };
+    let program_input = match &args.program_input {
+        Some(file_path) => {
+            let file = File::open(file_path).map_err(ProgramError::IO)?;
+            Some(serde_json::from_reader(BufReader::new(file)).map_err(ProgramError::Parse)?)
+        }
+        None => None,
+    };
     let cairo_run_config = cairo_run::CairoRunConfig {
         entrypoint: &args.entrypoint,
         trace_enabled,
@@ -54,40 +112,98 @@ fn main() -> Result<(), CairoRunError> {
         layout: &args.layout,
         proof_mode: args.proof_mode,
         secure_run: args.secure_run,
+        dynamic_layout_params,
+        program_input,
+    };
+    let result = if args.run_from_cairo_pie {
+        CairoPie::read_zip_file(&args.filename)
+            .map_err(|e| CairoRunError::Program(ProgramError::IO(e)))
+            .and_then(|cairo_pie| {
+                cairo_run::cairo_run_from_cairo_pie(
+                    &cairo_pie,
+                    &cairo_run_config,
+                    &mut hint_executor,
+                )
+            })
+    } else {
+        cairo_run::cairo_run(&args.filename, &cairo_run_config, &mut hint_executor)
+    };
+    let (cairo_runner, vm) = match result {
+        Ok(res) => res,
+        Err(error) => {
+            println!("{error}");
+            return Err(error);
+        }
     };
-    let cairo_runner =
-        match cairo_run::cairo_run(&args.filename, &cairo_run_config, &mut hint_executor) {
-            Ok(runner) => runner,
-            Err(error) => {
-                println!("{error}");
-                return Err(error);
-            }
-        };
 
-    if let Some(trace_path) = args.trace_file {
+    if args.print_program_hash {
+        let program_hash = compute_program_hash_chain(cairo_runner.get_program(), 0)?;
+        println!("Program hash: 0x{}", program_hash.to_str_radix(16));
+        let output = cairo_runner.get_output_values(&vm)?;
+        let fact = compute_output_fact(&program_hash, &output);
+        println!("Output fact: 0x{}", hex::encode(fact));
+    }
+
+    if let Some(trace_path) = &args.trace_file {
         let relocated_trace = cairo_runner
             .relocated_trace
             .as_ref()
             .ok_or(CairoRunError::Trace(TraceError::TraceNotEnabled))?;
-        match cairo_run::write_binary_trace(relocated_trace, &trace_path) {
+        match cairo_run::write_binary_trace(relocated_trace, trace_path) {
             Ok(()) => (),
             Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
         }
     }
 
-    if let Some(memory_path) = args.memory_file {
-        match cairo_run::write_binary_memory(&cairo_runner.relocated_memory, &memory_path) {
+    if let Some(memory_path) = &args.memory_file {
+        match cairo_run::write_binary_memory(&cairo_runner.relocated_memory, memory_path) {
             Ok(()) => (),
             Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
         }
     }
 
+    if let Some(file_path) = args.air_public_input {
+        let json = cairo_runner.get_air_public_input(&vm)?.serialize_json()?;
+        std::fs::write(file_path, json)
+            .map_err(|_| CairoRunError::Runner(RunnerError::WriteFail))?;
+    }
+
+    if let (Some(file_path), Some(trace_path), Some(memory_path)) =
+        (args.air_private_input, args.trace_file, args.memory_file)
+    {
+        // The prover expects absolute paths to the trace and memory files
+        let absolute_path = |path: PathBuf| {
+            std::fs::canonicalize(path)
+                .map(|path| path.to_string_lossy().to_string())
+                .map_err(|_| CairoRunError::Runner(RunnerError::WriteFail))
+        };
+        let json = cairo_runner
+            .get_air_private_input(&vm)
+            .to_serializable(absolute_path(trace_path)?, absolute_path(memory_path)?)
+            .serialize_json()?;
+        std::fs::write(file_path, json)
+            .map_err(|_| CairoRunError::Runner(RunnerError::WriteFail))?;
+    }
+
+    if let Some(file_path) = args.cairo_pie_output {
+        cairo_runner
+            .get_cairo_pie(&vm)?
+            .write_zip_file(&file_path)
+            .map_err(|_| CairoRunError::Runner(RunnerError::WriteFail))?;
+    }
+
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn verify_args() {
+        Args::command().debug_assert();
+    }
 
     #[test]
     fn test_valid_layouts() {
@@ -97,7 +213,13 @@ mod tests {
             "dex",
             "bitwise",
             "perpetual_with_bitwise",
+            "recursive",
+            "starknet",
+            "starknet_with_keccak",
+            "recursive_large_output",
             "all",
+            "all_cairo",
+            "dynamic",
         ];
 
         for layout in valid_layouts {
@@ -79,6 +79,52 @@ pub fn safe_div_usize(x: usize, y: usize) -> Result<usize, VirtualMachineError>
     Ok(q)
 }
 
+/// Returns true if `a` is a quadratic residue modulo the odd prime `p`. Zero counts as one.
+pub fn is_quad_residue(a: &BigUint, p: &BigUint) -> bool {
+    let a = a.mod_floor(p);
+    a.is_zero() || a.modpow(&(p - 1_u32).shr(1_u32), p).is_one()
+}
+
+/// Returns the smaller of the two square roots of `a` modulo the odd prime `p`, or `None` if
+/// `a` isn't a quadratic residue. Uses the Tonelli-Shanks algorithm.
+pub fn sqrt_prime(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let a = a.mod_floor(p);
+    if a.is_zero() {
+        return Some(a);
+    }
+    if !is_quad_residue(&a, p) {
+        return None;
+    }
+    // p - 1 = q * 2^s, with q odd
+    let p_minus_one = p - 1_u32;
+    let s = p_minus_one.trailing_zeros()?;
+    let q = &p_minus_one >> s;
+    let mut z = BigUint::from(2_u32);
+    while is_quad_residue(&z, p) {
+        z += 1_u32;
+    }
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut root = a.modpow(&((&q + 1_u32) >> 1_u32), p);
+    while !t.is_one() {
+        // Find the least i such that t^(2^i) = 1
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while !t_pow.is_one() {
+            t_pow = &t_pow * &t_pow % p;
+            i += 1;
+        }
+        let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        root = root * b % p;
+    }
+    let other_root = p - &root;
+    Some(root.min(other_root))
+}
+
 ///Returns x, y, g such that g = x*a + y*b = gcd(a, b).
 fn igcdex(num_a: &BigInt, num_b: &BigInt) -> (BigInt, BigInt, BigInt) {
     match (num_a, num_b) {
@@ -164,6 +210,39 @@ mod tests {
     use assert_matches::assert_matches;
     use num_traits::Num;
 
+    #[test]
+    fn is_quad_residue_small_prime() {
+        let p = BigUint::from(7_u32);
+        let residues: Vec<u32> = (0..7)
+            .filter(|a| is_quad_residue(&BigUint::from(*a), &p))
+            .collect();
+        assert_eq!(residues, vec![0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn sqrt_prime_small_prime() {
+        let p = BigUint::from(17_u32);
+        assert_eq!(
+            sqrt_prime(&BigUint::from(2_u32), &p),
+            Some(BigUint::from(6_u32))
+        );
+        assert_eq!(
+            sqrt_prime(&BigUint::from(16_u32), &p),
+            Some(BigUint::from(4_u32))
+        );
+        assert_eq!(sqrt_prime(&BigUint::zero(), &p), Some(BigUint::zero()));
+        assert_eq!(sqrt_prime(&BigUint::from(3_u32), &p), None);
+    }
+
+    #[test]
+    fn sqrt_prime_cairo_prime() {
+        let p = BigUint::from_str_radix(&felt::PRIME_STR[2..], 16).unwrap();
+        let value = BigUint::from(1234567890_u64);
+        let square = &value * &value % &p;
+        let root = sqrt_prime(&square, &p).unwrap();
+        assert_eq!(root, value);
+    }
+
     #[test]
     fn calculate_divmod_a() {
         let a = bigint_str!(
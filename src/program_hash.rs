@@ -0,0 +1,171 @@
+use crate::{
+    types::{program::Program, relocatable::MaybeRelocatable},
+    vm::errors::program_hash_errors::ProgramHashError,
+};
+use felt::Felt;
+use sha3::{Digest, Keccak256};
+use starknet_crypto::{pedersen_hash, FieldElement};
+
+/// Computes the hash of a program the way cairo-lang's `compute_program_hash_chain` does,
+/// which is the program hash used by the bootloader and the fact registry.
+/// The hashed data is the program header (bootloader version, main, number of builtins and
+/// builtin names), followed by the program's bytecode.
+pub fn compute_program_hash_chain(
+    program: &Program,
+    bootloader_version: usize,
+) -> Result<Felt, ProgramHashError> {
+    let main = program.main.ok_or(ProgramHashError::MissingMain)?;
+    let mut data_chain = vec![
+        Felt::new(bootloader_version),
+        Felt::new(main),
+        Felt::new(program.builtin_names().count()),
+    ];
+    data_chain.extend(
+        program
+            .builtin_names()
+            .map(|name| Felt::from_bytes_be(name.as_bytes())),
+    );
+    for value in program.data.iter() {
+        match value {
+            MaybeRelocatable::Int(num) => data_chain.push(num.clone()),
+            MaybeRelocatable::RelocatableValue(rel) => {
+                return Err(ProgramHashError::ExpectedIntegerInProgramData(*rel))
+            }
+        }
+    }
+    // The length of the data is hashed along with it
+    data_chain.insert(0, Felt::new(data_chain.len()));
+
+    let data_chain = data_chain
+        .iter()
+        .map(felt_to_field_element)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(field_element_to_felt(&compute_hash_chain(&data_chain)))
+}
+
+/// Computes the fact registered on-chain for a run of the program with the given hash and
+/// output: keccak256(program_hash || keccak256(output)), with every felt encoded as 32 big
+/// endian bytes.
+pub fn compute_output_fact(program_hash: &Felt, output: &[Felt]) -> [u8; 32] {
+    let mut output_hasher = Keccak256::new();
+    for value in output {
+        output_hasher.update(felt_to_bytes(value));
+    }
+    let mut fact_hasher = Keccak256::new();
+    fact_hasher.update(felt_to_bytes(program_hash));
+    fact_hasher.update(output_hasher.finalize());
+    fact_hasher.finalize().into()
+}
+
+// Computes h(x0, h(x1, h(x2, ...))), like cairo-lang's compute_hash_chain
+fn compute_hash_chain(data: &[FieldElement]) -> FieldElement {
+    match data.split_last() {
+        Some((last, rest)) => rest
+            .iter()
+            .rev()
+            .fold(*last, |acc, value| pedersen_hash(value, &acc)),
+        None => FieldElement::ZERO,
+    }
+}
+
+fn felt_to_field_element(felt: &Felt) -> Result<FieldElement, ProgramHashError> {
+    FieldElement::from_dec_str(&felt.to_str_radix(10))
+        .map_err(|_| ProgramHashError::FeltConversion(felt.clone()))
+}
+
+fn field_element_to_felt(field_element: &FieldElement) -> Felt {
+    Felt::from_bytes_be(&field_element.to_bytes_be())
+}
+
+fn felt_to_bytes(felt: &Felt) -> [u8; 32] {
+    let bytes = felt.to_bytes_be();
+    let mut padded = [0; 32];
+    padded[32 - bytes.len()..].copy_from_slice(&bytes);
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::relocatable::Relocatable, utils::test_utils::*,
+        vm::runners::builtin_runner::OUTPUT_BUILTIN_NAME,
+    };
+
+    fn field_element(value: u64) -> FieldElement {
+        FieldElement::from_dec_str(&value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn compute_hash_chain_nests_to_the_right() {
+        let data = [field_element(1), field_element(2), field_element(3)];
+        assert_eq!(
+            compute_hash_chain(&data),
+            pedersen_hash(
+                &field_element(1),
+                &pedersen_hash(&field_element(2), &field_element(3))
+            )
+        );
+    }
+
+    #[test]
+    fn compute_program_hash_chain_hashes_header_and_data() {
+        let program = program!(
+            builtins = vec![OUTPUT_BUILTIN_NAME],
+            data = vec_data!((10), (20)),
+            main = Some(1),
+        );
+        // "output" encoded as a big endian integer
+        let output_name = 0x6f7574707574;
+        let expected_chain = [6, 0, 1, 1, output_name, 10, 20].map(field_element);
+        assert_eq!(
+            compute_program_hash_chain(&program, 0),
+            Ok(field_element_to_felt(&compute_hash_chain(&expected_chain)))
+        );
+    }
+
+    #[test]
+    fn compute_program_hash_chain_bootloader_version() {
+        let program = program!(data = vec_data!((10)), main = Some(0),);
+        assert_ne!(
+            compute_program_hash_chain(&program, 0),
+            compute_program_hash_chain(&program, 1)
+        );
+    }
+
+    #[test]
+    fn compute_program_hash_chain_no_main() {
+        let program = program!(data = vec_data!((10)),);
+        assert_eq!(
+            compute_program_hash_chain(&program, 0),
+            Err(ProgramHashError::MissingMain)
+        );
+    }
+
+    #[test]
+    fn compute_program_hash_chain_relocatable_data() {
+        let program = program!(data = vec_data!((10), ((1, 2))), main = Some(0),);
+        assert_eq!(
+            compute_program_hash_chain(&program, 0),
+            Err(ProgramHashError::ExpectedIntegerInProgramData(
+                Relocatable::from((1, 2))
+            ))
+        );
+    }
+
+    #[test]
+    fn compute_output_fact_hashes_program_hash_and_output_hash() {
+        let mut output_bytes = [0; 64];
+        output_bytes[31] = 7;
+        output_bytes[63] = 8;
+        let mut fact_preimage = [0; 64];
+        fact_preimage[31] = 5;
+        fact_preimage[32..].copy_from_slice(&Keccak256::digest(output_bytes));
+        let expected_fact: [u8; 32] = Keccak256::digest(fact_preimage).into();
+
+        assert_eq!(
+            compute_output_fact(&Felt::new(5), &[Felt::new(7), Felt::new(8)]),
+            expected_fact
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Deserialization of the CASM output of the Cairo 1 compiler, which holds the bytecode of a
+//! program along with the structured hints of each pc.
+
+use crate::{
+    hint_processor::cairo_1_hint_processor::hints::Hint,
+    serde::deserialize_program::{
+        deserialize_array_of_bigint_hex, deserialize_felt_hex, split_builtin_names, ApTracking,
+        BuiltinName, FlowTrackingData, HintParams, ReferenceManager,
+    },
+    types::{
+        errors::program_errors::ProgramError, program::Program, relocatable::MaybeRelocatable,
+    },
+};
+use felt::{Felt, PRIME_STR};
+use serde::Deserialize;
+use std::{collections::HashMap, io::Read};
+
+#[derive(Deserialize, Debug)]
+pub struct CasmContractClass {
+    pub prime: String,
+    #[serde(default)]
+    pub compiler_version: String,
+    #[serde(deserialize_with = "deserialize_array_of_bigint_hex")]
+    pub bytecode: Vec<MaybeRelocatable>,
+    pub hints: Vec<(usize, Vec<Hint>)>,
+    #[serde(default)]
+    pub entry_points_by_type: CasmContractEntryPoints,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct CasmContractEntryPoints {
+    #[serde(rename = "EXTERNAL", default)]
+    pub external: Vec<CasmContractEntryPoint>,
+    #[serde(rename = "L1_HANDLER", default)]
+    pub l1_handler: Vec<CasmContractEntryPoint>,
+    #[serde(rename = "CONSTRUCTOR", default)]
+    pub constructor: Vec<CasmContractEntryPoint>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CasmContractEntryPoint {
+    #[serde(deserialize_with = "deserialize_felt_hex")]
+    pub selector: Felt,
+    pub offset: usize,
+    pub builtins: Vec<BuiltinName>,
+}
+
+impl CasmContractClass {
+    /// Returns the entry point with the given selector, of any type.
+    pub fn get_entry_point(&self, selector: &Felt) -> Option<&CasmContractEntryPoint> {
+        self.entry_points_by_type
+            .external
+            .iter()
+            .chain(self.entry_points_by_type.l1_handler.iter())
+            .chain(self.entry_points_by_type.constructor.iter())
+            .find(|entry_point| &entry_point.selector == selector)
+    }
+}
+
+pub fn deserialize_casm_json(reader: impl Read) -> Result<CasmContractClass, ProgramError> {
+    let casm_json = serde_json::from_reader(reader)?;
+    Ok(casm_json)
+}
+
+/// Builds a program that runs the CASM bytecode from `entry_point`, or from its first
+/// instruction without builtins if no entry point is given. Each hint is stored as its JSON
+/// representation, to be executed by the `Cairo1HintProcessor`.
+pub fn casm_to_program(
+    casm: &CasmContractClass,
+    entry_point: Option<&CasmContractEntryPoint>,
+) -> Result<Program, ProgramError> {
+    if PRIME_STR != casm.prime {
+        return Err(ProgramError::PrimeDiffers(casm.prime.clone()));
+    }
+
+    let mut hints = HashMap::new();
+    for (pc, pc_hints) in casm.hints.iter() {
+        let hint_params = pc_hints
+            .iter()
+            .map(|hint| {
+                Ok(HintParams {
+                    code: serde_json::to_string(hint)?,
+                    accessible_scopes: Vec::new(),
+                    flow_tracking_data: FlowTrackingData {
+                        ap_tracking: ApTracking::new(),
+                        reference_ids: HashMap::new(),
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, ProgramError>>()?;
+        hints.insert(*pc, hint_params);
+    }
+
+    let (builtins, custom_builtins) = entry_point
+        .map(|entry_point| split_builtin_names(&entry_point.builtins))
+        .unwrap_or_default();
+    Ok(Program {
+        builtins,
+        custom_builtins,
+        prime: PRIME_STR.to_string(),
+        data: casm.bytecode.clone(),
+        constants: HashMap::new(),
+        main: Some(
+            entry_point
+                .map(|entry_point| entry_point.offset)
+                .unwrap_or(0),
+        ),
+        start: None,
+        end: None,
+        hints,
+        reference_manager: ReferenceManager {
+            references: Vec::new(),
+        },
+        identifiers: HashMap::new(),
+        error_message_attributes: Vec::new(),
+        instruction_locations: None,
+    })
+}
+
+/// Deserializes a CASM file into a program that runs the entry point with the given selector,
+/// or the bytecode from its start if no selector is given.
+pub fn deserialize_casm(
+    reader: impl Read,
+    entry_point_selector: Option<&Felt>,
+) -> Result<Program, ProgramError> {
+    let casm = deserialize_casm_json(reader)?;
+    let entry_point = match entry_point_selector {
+        Some(selector) => Some(
+            casm.get_entry_point(selector)
+                .ok_or_else(|| ProgramError::EntrypointNotFound(selector.to_str_radix(16)))?,
+        ),
+        None => None,
+    };
+    casm_to_program(&casm, entry_point)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hint_processor::cairo_1_hint_processor::hints::{CellRef, ResOperand},
+        types::instruction::Register,
+        utils::test_utils::mayberelocatable,
+    };
+    use assert_matches::assert_matches;
+    use num_bigint::BigInt;
+
+    const CASM: &str = r#"{
+        "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+        "compiler_version": "1.0.0",
+        "bytecode": ["0x480680017fff8000", "0x1", "0x208b7fff7fff7ffe"],
+        "hints": [[0, [{"TestLessThan": {
+            "lhs": {"Immediate": "0x3"},
+            "rhs": {"Immediate": "0x5"},
+            "dst": {"register": "AP", "offset": 0}
+        }}]]],
+        "entry_points_by_type": {
+            "EXTERNAL": [{"selector": "0x1234", "offset": 0, "builtins": ["range_check"]}],
+            "L1_HANDLER": [],
+            "CONSTRUCTOR": []
+        }
+    }"#;
+
+    #[test]
+    fn deserialize_casm_contract_class() {
+        let casm = deserialize_casm_json(CASM.as_bytes()).unwrap();
+        assert_eq!(casm.compiler_version, "1.0.0");
+        assert_eq!(
+            casm.bytecode,
+            vec![
+                mayberelocatable!(0x480680017fff8000_i64),
+                mayberelocatable!(1),
+                mayberelocatable!(0x208b7fff7fff7ffe_i64)
+            ]
+        );
+        assert_eq!(
+            casm.hints,
+            vec![(
+                0,
+                vec![Hint::TestLessThan {
+                    lhs: ResOperand::Immediate(BigInt::from(3)),
+                    rhs: ResOperand::Immediate(BigInt::from(5)),
+                    dst: CellRef {
+                        register: Register::AP,
+                        offset: 0
+                    }
+                }]
+            )]
+        );
+        let entry_point = casm.get_entry_point(&Felt::new(0x1234)).unwrap();
+        assert_eq!(entry_point.offset, 0);
+        assert_eq!(entry_point.builtins, vec![BuiltinName::range_check]);
+        assert!(casm.get_entry_point(&Felt::new(1)).is_none());
+    }
+
+    #[test]
+    fn deserialize_casm_to_program() {
+        let program = deserialize_casm(CASM.as_bytes(), Some(&Felt::new(0x1234))).unwrap();
+        assert_eq!(program.builtins, vec!["range_check"]);
+        assert_eq!(program.main, Some(0));
+        assert_eq!(program.data.len(), 3);
+        let hint = &program.hints[&0][0];
+        assert_eq!(
+            serde_json::from_str::<Hint>(&hint.code).unwrap(),
+            Hint::TestLessThan {
+                lhs: ResOperand::Immediate(BigInt::from(3)),
+                rhs: ResOperand::Immediate(BigInt::from(5)),
+                dst: CellRef {
+                    register: Register::AP,
+                    offset: 0
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_casm_without_entry_point() {
+        let program = deserialize_casm(CASM.as_bytes(), None).unwrap();
+        assert!(program.builtins.is_empty());
+        assert_eq!(program.main, Some(0));
+    }
+
+    #[test]
+    fn deserialize_casm_entry_point_not_found() {
+        assert_matches!(
+            deserialize_casm(CASM.as_bytes(), Some(&Felt::new(1))),
+            Err(ProgramError::EntrypointNotFound(selector)) if selector == "1"
+        );
+    }
+
+    #[test]
+    fn deserialize_casm_prime_differs() {
+        let casm = CASM.replace(
+            "0x800000000000011000000000000000000000000000000000000000000000001",
+            "0x7",
+        );
+        assert_matches!(
+            deserialize_casm(casm.as_bytes(), None),
+            Err(ProgramError::PrimeDiffers(prime)) if prime == "0x7"
+        );
+    }
+}
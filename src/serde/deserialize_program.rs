@@ -6,7 +6,8 @@ use crate::{
     },
     vm::runners::builtin_runner::{
         BITWISE_BUILTIN_NAME, EC_OP_BUILTIN_NAME, HASH_BUILTIN_NAME, KECCAK_BUILTIN_NAME,
-        OUTPUT_BUILTIN_NAME, RANGE_CHECK_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME,
+        OUTPUT_BUILTIN_NAME, POSEIDON_BUILTIN_NAME, RANGE_CHECK_BUILTIN_NAME,
+        SEGMENT_ARENA_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME,
     },
 };
 use felt::{Felt, PRIME_STR};
@@ -15,8 +16,9 @@ use serde::{de, de::MapAccess, de::SeqAccess, Deserialize, Deserializer, Seriali
 use serde_json::Number;
 use std::{collections::HashMap, fmt, io::Read};
 
-// This enum is used to deserialize program builtins into &str and catch non-valid names
-#[derive(Deserialize, Debug, PartialEq)]
+// This enum is used to deserialize program builtins into &str. Names that aren't native
+// builtins are kept as custom builtins, which must be registered on the runner to be used.
+#[derive(Debug, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum BuiltinName {
     output,
@@ -26,11 +28,22 @@ pub enum BuiltinName {
     keccak,
     bitwise,
     ec_op,
+    poseidon,
+    segment_arena,
+    Custom(String),
 }
 
 impl BuiltinName {
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &str {
         match self {
+            BuiltinName::Custom(name) => name,
+            native => native.native_name().unwrap_or_default(),
+        }
+    }
+
+    /// Returns the name of a native builtin, or `None` for a custom one.
+    pub fn native_name(&self) -> Option<&'static str> {
+        Some(match self {
             BuiltinName::output => OUTPUT_BUILTIN_NAME,
             BuiltinName::range_check => RANGE_CHECK_BUILTIN_NAME,
             BuiltinName::pedersen => HASH_BUILTIN_NAME,
@@ -38,7 +51,49 @@ impl BuiltinName {
             BuiltinName::keccak => KECCAK_BUILTIN_NAME,
             BuiltinName::bitwise => BITWISE_BUILTIN_NAME,
             BuiltinName::ec_op => EC_OP_BUILTIN_NAME,
-        }
+            BuiltinName::poseidon => POSEIDON_BUILTIN_NAME,
+            BuiltinName::segment_arena => SEGMENT_ARENA_BUILTIN_NAME,
+            BuiltinName::Custom(_) => return None,
+        })
+    }
+}
+
+/// Splits the builtins of a program into the native builtins before the first custom builtin,
+/// and the names of the builtins from the first custom one onwards.
+pub(crate) fn split_builtin_names(builtins: &[BuiltinName]) -> (Vec<&'static str>, Vec<String>) {
+    let n_native_builtins = builtins
+        .iter()
+        .position(|builtin| builtin.native_name().is_none())
+        .unwrap_or(builtins.len());
+    let (native_builtins, custom_builtins) = builtins.split_at(n_native_builtins);
+    (
+        native_builtins
+            .iter()
+            .filter_map(BuiltinName::native_name)
+            .collect(),
+        custom_builtins
+            .iter()
+            .map(|builtin| builtin.name().to_string())
+            .collect(),
+    )
+}
+
+impl<'de> Deserialize<'de> for BuiltinName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(match name.as_str() {
+            OUTPUT_BUILTIN_NAME => BuiltinName::output,
+            RANGE_CHECK_BUILTIN_NAME => BuiltinName::range_check,
+            HASH_BUILTIN_NAME => BuiltinName::pedersen,
+            SIGNATURE_BUILTIN_NAME => BuiltinName::ecdsa,
+            KECCAK_BUILTIN_NAME => BuiltinName::keccak,
+            BITWISE_BUILTIN_NAME => BuiltinName::bitwise,
+            EC_OP_BUILTIN_NAME => BuiltinName::ec_op,
+            POSEIDON_BUILTIN_NAME => BuiltinName::poseidon,
+            SEGMENT_ARENA_BUILTIN_NAME => BuiltinName::segment_arena,
+            "" => return Err(de::Error::custom("empty builtin name")),
+            name => BuiltinName::Custom(name.to_string()),
+        })
     }
 }
 
@@ -339,7 +394,15 @@ pub fn deserialize_program(
     entrypoint: Option<&str>,
 ) -> Result<Program, ProgramError> {
     let program_json: ProgramJson = deserialize_program_json(reader)?;
+    parse_program_json(program_json, entrypoint)
+}
 
+/// Builds a program from its deserialized JSON, starting at the `__main__` function named
+/// `entrypoint`, if given.
+pub fn parse_program_json(
+    program_json: ProgramJson,
+    entrypoint: Option<&str>,
+) -> Result<Program, ProgramError> {
     if PRIME_STR != program_json.prime {
         return Err(ProgramError::PrimeDiffers(program_json.prime));
     }
@@ -364,12 +427,10 @@ pub fn deserialize_program(
         None => None,
     };
 
+    let (builtins, custom_builtins) = split_builtin_names(&program_json.builtins);
     Ok(Program {
-        builtins: program_json
-            .builtins
-            .iter()
-            .map(BuiltinName::name)
-            .collect(),
+        builtins,
+        custom_builtins,
         prime: PRIME_STR.to_string(),
         data: program_json.data,
         constants: {
@@ -806,6 +867,48 @@ mod tests {
         assert_eq!(program.hints, hints);
     }
 
+    #[test]
+    fn deserialize_builtin_names() {
+        let builtins: Vec<BuiltinName> =
+            serde_json::from_str(r#"["output", "pedersen", "segment_arena", "double"]"#).unwrap();
+        assert_eq!(
+            builtins,
+            vec![
+                BuiltinName::output,
+                BuiltinName::pedersen,
+                BuiltinName::segment_arena,
+                BuiltinName::Custom("double".to_string())
+            ]
+        );
+        assert_eq!(
+            builtins.iter().map(BuiltinName::name).collect::<Vec<_>>(),
+            vec![
+                OUTPUT_BUILTIN_NAME,
+                HASH_BUILTIN_NAME,
+                SEGMENT_ARENA_BUILTIN_NAME,
+                "double"
+            ]
+        );
+    }
+
+    #[test]
+    fn split_builtins_after_first_custom_builtin() {
+        let builtins: Vec<BuiltinName> =
+            serde_json::from_str(r#"["output", "double", "pedersen"]"#).unwrap();
+        assert_eq!(
+            split_builtin_names(&builtins),
+            (
+                vec![OUTPUT_BUILTIN_NAME],
+                vec!["double".to_string(), HASH_BUILTIN_NAME.to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn deserialize_empty_builtin_name() {
+        assert!(serde_json::from_str::<Vec<BuiltinName>>(r#"["output", ""]"#).is_err());
+    }
+
     /// Deserialize a program without an entrypoint.
     #[test]
     fn deserialize_program_without_entrypoint_test() {
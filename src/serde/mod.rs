@@ -1,2 +1,3 @@
+pub mod deserialize_casm;
 pub mod deserialize_program;
 pub mod deserialize_utils;
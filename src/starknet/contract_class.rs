@@ -0,0 +1,190 @@
+//! Deserialization of deprecated (Cairo 0) StarkNet contract classes, which hold a compiled
+//! program along with the offsets of its entry points.
+
+use crate::{
+    serde::deserialize_program::{deserialize_felt_hex, parse_program_json, ProgramJson},
+    starknet::errors::StarknetError,
+    types::{errors::program_errors::ProgramError, program::Program},
+};
+use felt::Felt;
+use serde::{de, Deserialize, Deserializer};
+use sha3::{Digest, Keccak256};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum EntryPointType {
+    #[serde(rename = "EXTERNAL")]
+    External,
+    #[serde(rename = "L1_HANDLER")]
+    L1Handler,
+    #[serde(rename = "CONSTRUCTOR")]
+    Constructor,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct ContractEntryPoint {
+    #[serde(deserialize_with = "deserialize_felt_hex")]
+    pub selector: Felt,
+    #[serde(deserialize_with = "deserialize_offset")]
+    pub offset: usize,
+}
+
+#[derive(Deserialize)]
+struct ContractClassJson {
+    program: ProgramJson,
+    entry_points_by_type: HashMap<EntryPointType, Vec<ContractEntryPoint>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeprecatedContractClass {
+    pub program: Program,
+    pub entry_points_by_type: HashMap<EntryPointType, Vec<ContractEntryPoint>>,
+}
+
+impl DeprecatedContractClass {
+    pub fn from_file(path: &Path) -> Result<DeprecatedContractClass, ProgramError> {
+        let file = File::open(path)?;
+        DeprecatedContractClass::from_reader(BufReader::new(file))
+    }
+
+    pub fn from_reader(reader: impl Read) -> Result<DeprecatedContractClass, ProgramError> {
+        let contract_class_json: ContractClassJson = serde_json::from_reader(reader)?;
+        Ok(DeprecatedContractClass {
+            program: parse_program_json(contract_class_json.program, None)?,
+            entry_points_by_type: contract_class_json.entry_points_by_type,
+        })
+    }
+
+    /// Returns the entry point of the given type with the given selector.
+    pub fn get_entry_point(
+        &self,
+        entry_point_type: EntryPointType,
+        selector: &Felt,
+    ) -> Result<&ContractEntryPoint, StarknetError> {
+        self.entry_points_by_type
+            .get(&entry_point_type)
+            .and_then(|entry_points| {
+                entry_points
+                    .iter()
+                    .find(|entry_point| &entry_point.selector == selector)
+            })
+            .ok_or_else(|| StarknetError::EntryPointNotFound(selector.clone()))
+    }
+}
+
+/// Returns the selector of the entry point with the given name, which is its starknet keccak:
+/// the keccak256 of the name, truncated to 250 bits.
+pub fn get_selector_from_name(name: &str) -> Felt {
+    let mut hash = Keccak256::digest(name.as_bytes());
+    hash[0] &= 0x03;
+    Felt::from_bytes_be(&hash)
+}
+
+// Offsets are written either as numbers or as hex strings, depending on the compiler version
+fn deserialize_offset<'de, D: Deserializer<'de>>(d: D) -> Result<usize, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Offset {
+        Number(usize),
+        Hex(String),
+    }
+
+    match Offset::deserialize(d)? {
+        Offset::Number(offset) => Ok(offset),
+        Offset::Hex(hex) => hex
+            .strip_prefix("0x")
+            .and_then(|hex| usize::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| de::Error::custom(format!("invalid offset {hex}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use felt::felt_str;
+
+    const CONTRACT_CLASS: &str = r#"{
+        "abi": [],
+        "entry_points_by_type": {
+            "CONSTRUCTOR": [],
+            "EXTERNAL": [
+                {"selector": "0x1", "offset": "0x0"},
+                {"selector": "0x2", "offset": 3}
+            ],
+            "L1_HANDLER": []
+        },
+        "program": {
+            "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+            "builtins": ["range_check"],
+            "data": ["0x480680017fff8000", "0x1", "0x208b7fff7fff7ffe", "0x208b7fff7fff7ffe"],
+            "identifiers": {},
+            "hints": {},
+            "reference_manager": {"references": []},
+            "attributes": [],
+            "debug_info": null
+        }
+    }"#;
+
+    #[test]
+    fn deserialize_contract_class() {
+        let contract_class =
+            DeprecatedContractClass::from_reader(CONTRACT_CLASS.as_bytes()).unwrap();
+        assert_eq!(contract_class.program.builtins, vec!["range_check"]);
+        assert_eq!(contract_class.program.data.len(), 4);
+        assert_eq!(contract_class.program.main, None);
+        assert_eq!(
+            contract_class
+                .get_entry_point(EntryPointType::External, &Felt::new(1))
+                .unwrap()
+                .offset,
+            0
+        );
+        assert_eq!(
+            contract_class
+                .get_entry_point(EntryPointType::External, &Felt::new(2))
+                .unwrap()
+                .offset,
+            3
+        );
+    }
+
+    #[test]
+    fn get_entry_point_not_found() {
+        let contract_class =
+            DeprecatedContractClass::from_reader(CONTRACT_CLASS.as_bytes()).unwrap();
+        assert_matches!(
+            contract_class.get_entry_point(EntryPointType::External, &Felt::new(3)),
+            Err(StarknetError::EntryPointNotFound(selector)) if selector == Felt::new(3)
+        );
+        assert_matches!(
+            contract_class.get_entry_point(EntryPointType::Constructor, &Felt::new(1)),
+            Err(StarknetError::EntryPointNotFound(_))
+        );
+    }
+
+    #[test]
+    fn deserialize_contract_class_invalid_offset() {
+        let contract_class = CONTRACT_CLASS.replace(r#""0x0""#, r#""zero""#);
+        assert_matches!(
+            DeprecatedContractClass::from_reader(contract_class.as_bytes()),
+            Err(ProgramError::Parse(_))
+        );
+    }
+
+    #[test]
+    fn selector_from_name() {
+        assert_eq!(
+            get_selector_from_name("transfer"),
+            felt_str!(
+                "83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e",
+                16
+            )
+        );
+    }
+}
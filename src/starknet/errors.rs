@@ -0,0 +1,30 @@
+use crate::{
+    types::errors::program_errors::ProgramError,
+    vm::errors::{
+        cairo_run_errors::CairoRunError, memory_errors::MemoryError, runner_errors::RunnerError,
+    },
+};
+use felt::Felt;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum StarknetError {
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+    #[error(transparent)]
+    Runner(#[from] RunnerError),
+    #[error(transparent)]
+    CairoRun(#[from] CairoRunError),
+    #[error(transparent)]
+    Memory(#[from] MemoryError),
+    #[error("Class with hash {0} is not declared")]
+    ClassNotDeclared(Felt),
+    #[error("No contract is deployed at address {0}")]
+    ContractNotDeployed(Felt),
+    #[error("A contract is already deployed at address {0}")]
+    ContractAlreadyDeployed(Felt),
+    #[error("Entry point with selector {0} not found")]
+    EntryPointNotFound(Felt),
+    #[error("Expected the entry point to return its retdata size and pointer")]
+    InvalidRetdata,
+}
@@ -0,0 +1,426 @@
+use crate::{
+    starknet::{
+        contract_class::EntryPointType, errors::StarknetError, state::MockState,
+        syscall_handler::DeprecatedSyscallHintProcessor,
+    },
+    types::relocatable::MaybeRelocatable,
+    vm::{
+        runners::cairo_runner::{CairoArg, CairoRunner},
+        vm_core::VirtualMachine,
+    },
+};
+use felt::Felt;
+use num_traits::{ToPrimitive, Zero};
+use std::borrow::Cow;
+
+/// A call to an entry point of a deployed contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallEntryPoint {
+    pub contract_address: Felt,
+    /// Address returned by the `get_caller_address` syscall, zero for calls made from outside
+    /// of any contract.
+    pub caller_address: Felt,
+    /// Class whose code is run in the context of the contract, as done by library calls. The
+    /// class of the contract is used if not given.
+    pub class_hash: Option<Felt>,
+    pub entry_point_type: EntryPointType,
+    pub entry_point_selector: Felt,
+    pub calldata: Vec<Felt>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub keys: Vec<Felt>,
+    pub data: Vec<Felt>,
+}
+
+/// Result of a call, along with the events and calls it made.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallInfo {
+    pub call: CallEntryPoint,
+    pub retdata: Vec<Felt>,
+    pub events: Vec<Event>,
+    pub internal_calls: Vec<CallInfo>,
+}
+
+impl CallEntryPoint {
+    /// Creates a call to an external entry point, made from outside of any contract.
+    pub fn new(contract_address: Felt, entry_point_selector: Felt, calldata: Vec<Felt>) -> Self {
+        CallEntryPoint {
+            contract_address,
+            caller_address: Felt::zero(),
+            class_hash: None,
+            entry_point_type: EntryPointType::External,
+            entry_point_selector,
+            calldata,
+        }
+    }
+
+    /// Runs the entry point on `state`, which serves the system calls of the contract.
+    ///
+    /// The entry point receives the syscall pointer and the pointers of the builtins of the
+    /// contract, followed by the size of the calldata and the calldata. It must return the
+    /// size of its retdata and a pointer to it.
+    pub fn execute(&self, state: &mut MockState) -> Result<CallInfo, StarknetError> {
+        let class_hash = match &self.class_hash {
+            Some(class_hash) => class_hash.clone(),
+            None => state.get_class_hash_at(&self.contract_address)?.clone(),
+        };
+        let contract_class = state.get_contract_class(&class_hash)?;
+        let entry_point =
+            contract_class.get_entry_point(self.entry_point_type, &self.entry_point_selector)?;
+
+        let mut cairo_runner = CairoRunner::new(&contract_class.program, "all", false)?;
+        let mut vm = VirtualMachine::new(false);
+        cairo_runner.initialize_builtins(&mut vm)?;
+        cairo_runner.initialize_segments(&mut vm, None);
+
+        let syscall_ptr = vm.add_memory_segment();
+        let mut args = vec![CairoArg::from(MaybeRelocatable::from(syscall_ptr))];
+        args.extend(
+            vm.get_builtin_runners()
+                .iter()
+                .flat_map(|(_, builtin)| builtin.initial_stack())
+                .map(CairoArg::from),
+        );
+        args.push(MaybeRelocatable::from(Felt::from(self.calldata.len())).into());
+        args.push(
+            self.calldata
+                .iter()
+                .map(MaybeRelocatable::from)
+                .collect::<Vec<_>>()
+                .into(),
+        );
+
+        let mut hint_processor = DeprecatedSyscallHintProcessor::new(
+            state,
+            self.contract_address.clone(),
+            self.caller_address.clone(),
+        );
+        cairo_runner.run_from_entrypoint(
+            entry_point.offset,
+            &args.iter().collect::<Vec<_>>(),
+            true,
+            &mut vm,
+            &mut hint_processor,
+        )?;
+
+        Ok(CallInfo {
+            call: self.clone(),
+            retdata: get_retdata(&vm)?,
+            events: hint_processor.events,
+            internal_calls: hint_processor.internal_calls,
+        })
+    }
+}
+
+fn get_retdata(vm: &VirtualMachine) -> Result<Vec<Felt>, StarknetError> {
+    let return_values = vm.get_return_values(2)?;
+    let (retdata_size, retdata_ptr) = match &return_values[..] {
+        [MaybeRelocatable::Int(size), MaybeRelocatable::RelocatableValue(ptr)] => {
+            (size.to_usize().ok_or(StarknetError::InvalidRetdata)?, *ptr)
+        }
+        _ => return Err(StarknetError::InvalidRetdata),
+    };
+    Ok(vm
+        .get_integer_range(retdata_ptr, retdata_size)?
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hint_processor::builtin_hint_processor::hint_code::{
+            CALL_CONTRACT, EMIT_EVENT, GET_CALLER_ADDRESS, LIBRARY_CALL, STORAGE_READ,
+            STORAGE_WRITE,
+        },
+        starknet::contract_class::{get_selector_from_name, DeprecatedContractClass},
+    };
+    use assert_matches::assert_matches;
+    use serde_json::json;
+
+    const RET: i64 = 0x208b7fff7fff7ffe;
+
+    // The entry points below take no builtins, so their frame holds the syscall pointer at
+    // fp - 5, the calldata size at fp - 4 and the calldata at fp - 3
+
+    // [ap] = imm; ap++
+    fn push_imm(value: Felt) -> Vec<Felt> {
+        vec![Felt::new(0x480680017fff8000_i64), value]
+    }
+
+    // [ap] = [fp - 3] + offset; ap++
+    fn push_calldata_ptr(offset: i64) -> Vec<Felt> {
+        vec![Felt::new(0x482680017ffd8000_i64), Felt::new(offset)]
+    }
+
+    // [ap] = [fp - 5] + offset; ap++
+    fn push_syscall_ptr(offset: i64) -> Vec<Felt> {
+        vec![Felt::new(0x482680017ffb8000_i64), Felt::new(offset)]
+    }
+
+    // [ap] = [[fp - 3] + offset]; ap++
+    fn push_calldata(offset: i64) -> Vec<Felt> {
+        vec![Felt::new(0x480280007ffd8000_i64 + (offset << 32))]
+    }
+
+    // [ap] = [[fp - 5] + offset]; ap++
+    fn push_syscall(offset: i64) -> Vec<Felt> {
+        vec![Felt::new(0x480280007ffb8000_i64 + (offset << 32))]
+    }
+
+    // [ap - 1] = [[fp - 5] + offset]
+    fn write_syscall(offset: i64) -> Vec<Felt> {
+        vec![Felt::new(0x400280007ffb7fff_i64 + (offset << 32))]
+    }
+
+    fn syscall_selector(syscall_name: &str) -> Vec<Felt> {
+        [
+            push_imm(Felt::from_bytes_be(syscall_name.as_bytes())),
+            write_syscall(0),
+        ]
+        .concat()
+    }
+
+    // Each entry point is its name, the instructions before its syscall hint, and the
+    // instructions after it, which return the retdata
+    fn entry_points() -> Vec<(&'static str, &'static str, Vec<Felt>, Vec<Felt>)> {
+        let call = |syscall_name, hint_code| {
+            (
+                [
+                    syscall_selector(syscall_name),
+                    push_calldata(0),
+                    write_syscall(1),
+                    push_imm(get_selector_from_name("read")),
+                    write_syscall(2),
+                    push_imm(Felt::new(1)),
+                    write_syscall(3),
+                    push_calldata_ptr(1),
+                    write_syscall(4),
+                ]
+                .concat(),
+                hint_code,
+                [push_syscall(5), push_syscall(6)].concat(),
+            )
+        };
+        let no_retdata = || [push_imm(Felt::new(0)), push_calldata_ptr(0)].concat();
+        let (call_read, call_contract, call_read_retdata) = call("CallContract", CALL_CONTRACT);
+        let (library_read, library_call, library_read_retdata) = call("LibraryCall", LIBRARY_CALL);
+        vec![
+            (
+                "write",
+                STORAGE_WRITE,
+                [
+                    syscall_selector("StorageWrite"),
+                    push_calldata(0),
+                    write_syscall(1),
+                    push_calldata(1),
+                    write_syscall(2),
+                ]
+                .concat(),
+                no_retdata(),
+            ),
+            (
+                "read",
+                STORAGE_READ,
+                [
+                    syscall_selector("StorageRead"),
+                    push_calldata(0),
+                    write_syscall(1),
+                ]
+                .concat(),
+                [push_imm(Felt::new(1)), push_syscall_ptr(2)].concat(),
+            ),
+            (
+                "get_caller",
+                GET_CALLER_ADDRESS,
+                syscall_selector("GetCallerAddress"),
+                [push_imm(Felt::new(1)), push_syscall_ptr(1)].concat(),
+            ),
+            (
+                "emit",
+                EMIT_EVENT,
+                [
+                    syscall_selector("EmitEvent"),
+                    push_imm(Felt::new(1)),
+                    write_syscall(1),
+                    push_calldata_ptr(0),
+                    write_syscall(2),
+                    push_imm(Felt::new(1)),
+                    write_syscall(3),
+                    push_calldata_ptr(1),
+                    write_syscall(4),
+                ]
+                .concat(),
+                no_retdata(),
+            ),
+            ("call_read", call_contract, call_read, call_read_retdata),
+            (
+                "library_read",
+                library_call,
+                library_read,
+                library_read_retdata,
+            ),
+        ]
+    }
+
+    fn contract_class() -> DeprecatedContractClass {
+        let mut data = Vec::new();
+        let mut hints = serde_json::Map::new();
+        let mut external = Vec::new();
+        for (name, hint_code, before_hint, after_hint) in entry_points() {
+            external.push(json!({
+                "selector": format!("0x{}", get_selector_from_name(name).to_str_radix(16)),
+                "offset": data.len(),
+            }));
+            data.extend(before_hint);
+            hints.insert(
+                data.len().to_string(),
+                json!([{
+                    "code": hint_code,
+                    "accessible_scopes": [],
+                    "flow_tracking_data": {
+                        "ap_tracking": {"group": 0, "offset": 0},
+                        "reference_ids": {"__main__.syscall_ptr": 0}
+                    }
+                }]),
+            );
+            data.extend(after_hint);
+            data.push(Felt::new(RET));
+        }
+        let contract_class = json!({
+            "abi": [],
+            "entry_points_by_type": {
+                "CONSTRUCTOR": [],
+                "EXTERNAL": external,
+                "L1_HANDLER": []
+            },
+            "program": {
+                "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+                "builtins": [],
+                "data": data
+                    .iter()
+                    .map(|value| format!("0x{}", value.to_str_radix(16)))
+                    .collect::<Vec<_>>(),
+                "identifiers": {},
+                "hints": hints,
+                "reference_manager": {"references": [{
+                    "ap_tracking_data": {"group": 0, "offset": 0},
+                    "pc": 0,
+                    "value": "[cast(fp + (-5), felt**)]"
+                }]},
+                "attributes": [],
+                "debug_info": null
+            }
+        });
+        DeprecatedContractClass::from_reader(contract_class.to_string().as_bytes()).unwrap()
+    }
+
+    fn deployed_state(addresses: &[u64]) -> MockState {
+        let mut state = MockState::new();
+        state.declare(Felt::new(0x10), contract_class());
+        for address in addresses {
+            state.deploy(Felt::new(*address), Felt::new(0x10)).unwrap();
+        }
+        state
+    }
+
+    fn call(address: u64, name: &str, calldata: &[u64]) -> CallEntryPoint {
+        CallEntryPoint::new(
+            Felt::new(address),
+            get_selector_from_name(name),
+            calldata.iter().map(|value| Felt::new(*value)).collect(),
+        )
+    }
+
+    #[test]
+    fn storage_write_and_read() {
+        let mut state = deployed_state(&[1]);
+        let call_info = call(1, "write", &[5, 7]).execute(&mut state).unwrap();
+        assert!(call_info.retdata.is_empty());
+        assert_eq!(
+            state.get_storage_at(&Felt::new(1), &Felt::new(5)),
+            Felt::new(7)
+        );
+
+        let call_info = call(1, "read", &[5]).execute(&mut state).unwrap();
+        assert_eq!(call_info.retdata, vec![Felt::new(7)]);
+        let call_info = call(1, "read", &[6]).execute(&mut state).unwrap();
+        assert_eq!(call_info.retdata, vec![Felt::zero()]);
+    }
+
+    #[test]
+    fn get_caller_address() {
+        let mut state = deployed_state(&[1]);
+        let mut get_caller = call(1, "get_caller", &[]);
+        get_caller.caller_address = Felt::new(42);
+        let call_info = get_caller.execute(&mut state).unwrap();
+        assert_eq!(call_info.retdata, vec![Felt::new(42)]);
+    }
+
+    #[test]
+    fn emit_event() {
+        let mut state = deployed_state(&[1]);
+        let call_info = call(1, "emit", &[3, 4]).execute(&mut state).unwrap();
+        assert_eq!(
+            call_info.events,
+            vec![Event {
+                keys: vec![Felt::new(3)],
+                data: vec![Felt::new(4)]
+            }]
+        );
+    }
+
+    #[test]
+    fn call_contract() {
+        let mut state = deployed_state(&[1, 2]);
+        state.set_storage_at(Felt::new(2), Felt::new(5), Felt::new(9));
+        let call_info = call(1, "call_read", &[2, 5]).execute(&mut state).unwrap();
+        assert_eq!(call_info.retdata, vec![Felt::new(9)]);
+        assert_eq!(call_info.internal_calls.len(), 1);
+        let internal_call = &call_info.internal_calls[0];
+        assert_eq!(internal_call.call.contract_address, Felt::new(2));
+        assert_eq!(internal_call.call.caller_address, Felt::new(1));
+        assert_eq!(internal_call.retdata, vec![Felt::new(9)]);
+    }
+
+    #[test]
+    fn library_call() {
+        let mut state = deployed_state(&[1, 2]);
+        state.set_storage_at(Felt::new(1), Felt::new(5), Felt::new(3));
+        state.set_storage_at(Felt::new(2), Felt::new(5), Felt::new(9));
+        // The class is run in the context of the calling contract, reading its own storage
+        let call_info = call(1, "library_read", &[0x10, 5])
+            .execute(&mut state)
+            .unwrap();
+        assert_eq!(call_info.retdata, vec![Felt::new(3)]);
+        let internal_call = &call_info.internal_calls[0];
+        assert_eq!(internal_call.call.contract_address, Felt::new(1));
+        assert_eq!(internal_call.call.class_hash, Some(Felt::new(0x10)));
+    }
+
+    #[test]
+    fn call_contract_not_deployed() {
+        let mut state = deployed_state(&[1]);
+        assert_matches!(
+            call(1, "call_read", &[2, 5]).execute(&mut state),
+            Err(StarknetError::CairoRun(_))
+        );
+        assert_matches!(
+            call(2, "read", &[5]).execute(&mut state),
+            Err(StarknetError::ContractNotDeployed(address)) if address == Felt::new(2)
+        );
+    }
+
+    #[test]
+    fn entry_point_not_found() {
+        let mut state = deployed_state(&[1]);
+        assert_matches!(
+            call(1, "transfer", &[]).execute(&mut state),
+            Err(StarknetError::EntryPointNotFound(_))
+        );
+    }
+}
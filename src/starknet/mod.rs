@@ -0,0 +1,11 @@
+//! Local execution of deprecated (Cairo 0) StarkNet contracts.
+//!
+//! Contracts are declared and deployed on a [`MockState`](state::MockState), and their entry
+//! points are run with [`CallEntryPoint::execute`](execution::CallEntryPoint::execute), which
+//! serves the system calls of the contract from that same state. No node is needed.
+
+pub mod contract_class;
+pub mod errors;
+pub mod execution;
+pub mod state;
+pub mod syscall_handler;
@@ -0,0 +1,123 @@
+use crate::starknet::{contract_class::DeprecatedContractClass, errors::StarknetError};
+use felt::Felt;
+use num_traits::Zero;
+use std::{collections::HashMap, rc::Rc};
+
+/// In-memory StarkNet state, holding the declared classes, the deployed contracts and their
+/// storage.
+#[derive(Clone, Debug, Default)]
+pub struct MockState {
+    /// Storage values, by contract address and storage key.
+    pub storage: HashMap<(Felt, Felt), Felt>,
+    /// Class hash of each deployed contract, by address.
+    pub address_to_class_hash: HashMap<Felt, Felt>,
+    /// Declared classes, by class hash.
+    pub classes: HashMap<Felt, Rc<DeprecatedContractClass>>,
+}
+
+impl MockState {
+    pub fn new() -> Self {
+        MockState::default()
+    }
+
+    pub fn declare(&mut self, class_hash: Felt, contract_class: DeprecatedContractClass) {
+        self.classes.insert(class_hash, Rc::new(contract_class));
+    }
+
+    /// Deploys a contract of an already declared class at `address`. The constructor is not
+    /// run.
+    pub fn deploy(&mut self, address: Felt, class_hash: Felt) -> Result<(), StarknetError> {
+        if !self.classes.contains_key(&class_hash) {
+            return Err(StarknetError::ClassNotDeclared(class_hash));
+        }
+        if self.address_to_class_hash.contains_key(&address) {
+            return Err(StarknetError::ContractAlreadyDeployed(address));
+        }
+        self.address_to_class_hash.insert(address, class_hash);
+        Ok(())
+    }
+
+    pub fn get_class_hash_at(&self, address: &Felt) -> Result<&Felt, StarknetError> {
+        self.address_to_class_hash
+            .get(address)
+            .ok_or_else(|| StarknetError::ContractNotDeployed(address.clone()))
+    }
+
+    pub fn get_contract_class(
+        &self,
+        class_hash: &Felt,
+    ) -> Result<Rc<DeprecatedContractClass>, StarknetError> {
+        self.classes
+            .get(class_hash)
+            .cloned()
+            .ok_or_else(|| StarknetError::ClassNotDeclared(class_hash.clone()))
+    }
+
+    /// Returns the value stored at `key` by the contract at `address`. Keys that were never
+    /// written hold zero.
+    pub fn get_storage_at(&self, address: &Felt, key: &Felt) -> Felt {
+        self.storage
+            .get(&(address.clone(), key.clone()))
+            .cloned()
+            .unwrap_or_else(Felt::zero)
+    }
+
+    pub fn set_storage_at(&mut self, address: Felt, key: Felt, value: Felt) {
+        self.storage.insert((address, key), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::program::Program;
+    use assert_matches::assert_matches;
+
+    fn empty_class() -> DeprecatedContractClass {
+        DeprecatedContractClass {
+            program: Program::default(),
+            entry_points_by_type: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn declare_and_deploy() {
+        let mut state = MockState::new();
+        assert_matches!(
+            state.deploy(Felt::new(1), Felt::new(2)),
+            Err(StarknetError::ClassNotDeclared(class_hash)) if class_hash == Felt::new(2)
+        );
+        state.declare(Felt::new(2), empty_class());
+        state.deploy(Felt::new(1), Felt::new(2)).unwrap();
+        assert_eq!(
+            state.get_class_hash_at(&Felt::new(1)).unwrap(),
+            &Felt::new(2)
+        );
+        assert_matches!(
+            state.deploy(Felt::new(1), Felt::new(2)),
+            Err(StarknetError::ContractAlreadyDeployed(_))
+        );
+        assert_matches!(
+            state.get_class_hash_at(&Felt::new(3)),
+            Err(StarknetError::ContractNotDeployed(_))
+        );
+    }
+
+    #[test]
+    fn storage() {
+        let mut state = MockState::new();
+        assert_eq!(
+            state.get_storage_at(&Felt::new(1), &Felt::new(5)),
+            Felt::zero()
+        );
+        state.set_storage_at(Felt::new(1), Felt::new(5), Felt::new(7));
+        assert_eq!(
+            state.get_storage_at(&Felt::new(1), &Felt::new(5)),
+            Felt::new(7)
+        );
+        assert_eq!(
+            state.get_storage_at(&Felt::new(2), &Felt::new(5)),
+            Felt::zero()
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Hint processor serving the system calls of deprecated StarkNet contracts from a
+//! [`MockState`].
+
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::{
+            builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+            hint_code,
+            syscall_handler::{execute_syscall_hint, SyscallHandler},
+            syscall_request::{
+                CallContractRequest, CallContractResponse, EmitEventRequest,
+                GetCallerAddressRequest, GetCallerAddressResponse, GetContractAddressRequest,
+                GetContractAddressResponse, LibraryCallRequest, StorageReadRequest,
+                StorageReadResponse, StorageWriteRequest,
+            },
+        },
+        hint_processor_definition::HintProcessor,
+    },
+    starknet::{
+        contract_class::EntryPointType,
+        execution::{CallEntryPoint, CallInfo, Event},
+        state::MockState,
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::Felt;
+use std::{any::Any, collections::HashMap};
+
+/// Runs the hints of a contract call, serving its system calls from `state` and delegating
+/// every other hint to a `BuiltinHintProcessor`.
+pub struct DeprecatedSyscallHintProcessor<'a> {
+    builtin_hint_processor: BuiltinHintProcessor,
+    state: &'a mut MockState,
+    contract_address: Felt,
+    caller_address: Felt,
+    /// Events emitted by the call, in order.
+    pub events: Vec<Event>,
+    /// Calls made by the call to other contracts or classes, in order.
+    pub internal_calls: Vec<CallInfo>,
+}
+
+impl<'a> DeprecatedSyscallHintProcessor<'a> {
+    pub fn new(state: &'a mut MockState, contract_address: Felt, caller_address: Felt) -> Self {
+        DeprecatedSyscallHintProcessor {
+            builtin_hint_processor: BuiltinHintProcessor::new_empty(),
+            state,
+            contract_address,
+            caller_address,
+            events: Vec::new(),
+            internal_calls: Vec::new(),
+        }
+    }
+
+    fn call(&mut self, call: CallEntryPoint) -> Result<CallContractResponse, HintError> {
+        let call_info = call
+            .execute(self.state)
+            .map_err(|err| HintError::SyscallFailed(err.to_string()))?;
+        let retdata = call_info.retdata.clone();
+        self.internal_calls.push(call_info);
+        Ok(CallContractResponse { retdata })
+    }
+}
+
+impl SyscallHandler for DeprecatedSyscallHintProcessor<'_> {
+    fn storage_read(
+        &mut self,
+        request: StorageReadRequest,
+    ) -> Result<StorageReadResponse, HintError> {
+        Ok(StorageReadResponse {
+            value: self
+                .state
+                .get_storage_at(&self.contract_address, &request.address),
+        })
+    }
+
+    fn storage_write(&mut self, request: StorageWriteRequest) -> Result<(), HintError> {
+        self.state.set_storage_at(
+            self.contract_address.clone(),
+            request.address,
+            request.value,
+        );
+        Ok(())
+    }
+
+    fn get_caller_address(
+        &mut self,
+        _request: GetCallerAddressRequest,
+    ) -> Result<GetCallerAddressResponse, HintError> {
+        Ok(GetCallerAddressResponse {
+            caller_address: self.caller_address.clone(),
+        })
+    }
+
+    fn get_contract_address(
+        &mut self,
+        _request: GetContractAddressRequest,
+    ) -> Result<GetContractAddressResponse, HintError> {
+        Ok(GetContractAddressResponse {
+            contract_address: self.contract_address.clone(),
+        })
+    }
+
+    fn emit_event(&mut self, request: EmitEventRequest) -> Result<(), HintError> {
+        self.events.push(Event {
+            keys: request.keys,
+            data: request.data,
+        });
+        Ok(())
+    }
+
+    fn call_contract(
+        &mut self,
+        request: CallContractRequest,
+    ) -> Result<CallContractResponse, HintError> {
+        self.call(CallEntryPoint {
+            contract_address: request.contract_address,
+            caller_address: self.contract_address.clone(),
+            class_hash: None,
+            entry_point_type: EntryPointType::External,
+            entry_point_selector: request.function_selector,
+            calldata: request.calldata,
+        })
+    }
+
+    /// Runs the class in the context of the current contract, keeping its caller.
+    fn library_call(
+        &mut self,
+        request: LibraryCallRequest,
+    ) -> Result<CallContractResponse, HintError> {
+        self.call(CallEntryPoint {
+            contract_address: self.contract_address.clone(),
+            caller_address: self.caller_address.clone(),
+            class_hash: Some(request.class_hash),
+            entry_point_type: EntryPointType::External,
+            entry_point_selector: request.function_selector,
+            calldata: request.calldata,
+        })
+    }
+}
+
+impl HintProcessor for DeprecatedSyscallHintProcessor<'_> {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, Felt>,
+    ) -> Result<(), HintError> {
+        let data = hint_data
+            .downcast_ref::<HintProcessorData>()
+            .ok_or(HintError::WrongHintData)?;
+
+        match &*data.known_code {
+            hint_code::STORAGE_READ
+            | hint_code::STORAGE_WRITE
+            | hint_code::GET_CALLER_ADDRESS
+            | hint_code::GET_CONTRACT_ADDRESS
+            | hint_code::EMIT_EVENT
+            | hint_code::CALL_CONTRACT
+            | hint_code::LIBRARY_CALL => execute_syscall_hint(
+                self,
+                vm,
+                &data.known_code,
+                &data.ids_data,
+                &data.ap_tracking,
+            ),
+            _ => self
+                .builtin_hint_processor
+                .execute_hint(vm, exec_scopes, hint_data, constants),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::hint_processor_definition::HintReference,
+        types::relocatable::MaybeRelocatable,
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+        },
+    };
+    use assert_matches::assert_matches;
+
+    fn run_syscall_hint(
+        vm: &mut VirtualMachine,
+        state: &mut MockState,
+        hint_code: &str,
+    ) -> Result<(), HintError> {
+        let hint_data =
+            HintProcessorData::new_default(hint_code.to_string(), ids_data!["syscall_ptr"]);
+        let mut hint_processor =
+            DeprecatedSyscallHintProcessor::new(state, Felt::new(1), Felt::new(2));
+        hint_processor.execute_hint(
+            vm,
+            exec_scopes_ref!(),
+            &any_box!(hint_data),
+            &HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn storage_read() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0)), ((2, 1), 5)];
+        vm.run_context.fp = 1;
+        vm.insert_value((2, 0).into(), Felt::from_bytes_be(b"StorageRead"))
+            .unwrap();
+        let mut state = MockState::new();
+        state.set_storage_at(Felt::new(1), Felt::new(5), Felt::new(7));
+        assert_matches!(
+            run_syscall_hint(&mut vm, &mut state, hint_code::STORAGE_READ),
+            Ok(())
+        );
+        assert_eq!(
+            vm.get_integer((2, 2).into()).unwrap().as_ref(),
+            &Felt::new(7)
+        );
+    }
+
+    #[test]
+    fn storage_read_with_indented_hint() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0)), ((2, 1), 5)];
+        vm.run_context.fp = 1;
+        vm.insert_value((2, 0).into(), Felt::from_bytes_be(b"StorageRead"))
+            .unwrap();
+        let mut state = MockState::new();
+        state.set_storage_at(Felt::new(1), Felt::new(5), Felt::new(7));
+        let hint_code = format!("    {}\n", hint_code::STORAGE_READ.replace('\n', "\n    "));
+        assert_matches!(run_syscall_hint(&mut vm, &mut state, &hint_code), Ok(()));
+        assert_eq!(
+            vm.get_integer((2, 2).into()).unwrap().as_ref(),
+            &Felt::new(7)
+        );
+    }
+
+    #[test]
+    fn invalid_syscall_selector() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0)), ((2, 0), 3)];
+        vm.run_context.fp = 1;
+        assert_matches!(
+            run_syscall_hint(&mut vm, &mut MockState::new(), hint_code::GET_CALLER_ADDRESS),
+            Err(HintError::InvalidSyscallSelector(name, ptr, selector))
+                if name == "GetCallerAddress" && ptr == (2, 0).into() && selector == Felt::new(3)
+        );
+    }
+
+    #[test]
+    fn other_hints_run_by_builtin_hint_processor() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0))];
+        vm.run_context.fp = 1;
+        vm.run_context.ap = 1;
+        assert_matches!(
+            run_syscall_hint(
+                &mut vm,
+                &mut MockState::new(),
+                "memory[ap] = segments.add()"
+            ),
+            Ok(())
+        );
+        assert_eq!(vm.get_relocatable((1, 1).into()), Ok((2, 0).into()));
+    }
+}
@@ -1,11 +1,12 @@
 use super::{
     bitwise_instance_def::BitwiseInstanceDef, ec_op_instance_def::EcOpInstanceDef,
     ecdsa_instance_def::EcdsaInstanceDef, keccak_instance_def::KeccakInstanceDef,
-    pedersen_instance_def::PedersenInstanceDef, range_check_instance_def::RangeCheckInstanceDef,
+    pedersen_instance_def::PedersenInstanceDef, poseidon_instance_def::PoseidonInstanceDef,
+    range_check_instance_def::RangeCheckInstanceDef,
 };
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct BuiltinsInstanceDef {
+pub struct BuiltinsInstanceDef {
     pub(crate) output: bool,
     pub(crate) pedersen: Option<PedersenInstanceDef>,
     pub(crate) range_check: Option<RangeCheckInstanceDef>,
@@ -13,10 +14,11 @@ pub(crate) struct BuiltinsInstanceDef {
     pub(crate) bitwise: Option<BitwiseInstanceDef>,
     pub(crate) ec_op: Option<EcOpInstanceDef>,
     pub(crate) keccak: Option<KeccakInstanceDef>,
+    pub(crate) poseidon: Option<PoseidonInstanceDef>,
 }
 
 impl BuiltinsInstanceDef {
-    pub(crate) fn plain() -> BuiltinsInstanceDef {
+    pub fn plain() -> BuiltinsInstanceDef {
         BuiltinsInstanceDef {
             output: false,
             pedersen: None,
@@ -25,10 +27,11 @@ impl BuiltinsInstanceDef {
             bitwise: None,
             ec_op: None,
             keccak: None,
+            poseidon: None,
         }
     }
 
-    pub(crate) fn small() -> BuiltinsInstanceDef {
+    pub fn small() -> BuiltinsInstanceDef {
         BuiltinsInstanceDef {
             output: true,
             pedersen: Some(PedersenInstanceDef::default()),
@@ -37,10 +40,11 @@ impl BuiltinsInstanceDef {
             bitwise: None,
             ec_op: None,
             keccak: None,
+            poseidon: None,
         }
     }
 
-    pub(crate) fn dex() -> BuiltinsInstanceDef {
+    pub fn dex() -> BuiltinsInstanceDef {
         BuiltinsInstanceDef {
             output: true,
             pedersen: Some(PedersenInstanceDef::default()),
@@ -49,10 +53,11 @@ impl BuiltinsInstanceDef {
             bitwise: None,
             ec_op: None,
             keccak: None,
+            poseidon: None,
         }
     }
 
-    pub(crate) fn perpetual_with_bitwise() -> BuiltinsInstanceDef {
+    pub fn perpetual_with_bitwise() -> BuiltinsInstanceDef {
         BuiltinsInstanceDef {
             output: true,
             pedersen: Some(PedersenInstanceDef::new(32, 1)),
@@ -61,10 +66,11 @@ impl BuiltinsInstanceDef {
             bitwise: Some(BitwiseInstanceDef::new(64)),
             ec_op: Some(EcOpInstanceDef::new(1024)),
             keccak: None,
+            poseidon: None,
         }
     }
 
-    pub(crate) fn bitwise() -> BuiltinsInstanceDef {
+    pub fn bitwise() -> BuiltinsInstanceDef {
         BuiltinsInstanceDef {
             output: true,
             pedersen: Some(PedersenInstanceDef::new(256, 1)),
@@ -73,22 +79,63 @@ impl BuiltinsInstanceDef {
             bitwise: Some(BitwiseInstanceDef::new(8)),
             ec_op: None,
             keccak: None,
+            poseidon: None,
         }
     }
 
-    pub(crate) fn recursive() -> BuiltinsInstanceDef {
+    pub fn recursive() -> BuiltinsInstanceDef {
         BuiltinsInstanceDef {
             output: true,
-            pedersen: Some(PedersenInstanceDef::new(256, 1)),
+            pedersen: Some(PedersenInstanceDef::new(128, 1)),
             range_check: Some(RangeCheckInstanceDef::default()),
             ecdsa: None,
-            bitwise: Some(BitwiseInstanceDef::new(16)),
+            bitwise: Some(BitwiseInstanceDef::new(8)),
             ec_op: None,
+            keccak: None,
+            poseidon: None,
+        }
+    }
+
+    pub fn starknet() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            output: true,
+            pedersen: Some(PedersenInstanceDef::new(32, 1)),
+            range_check: Some(RangeCheckInstanceDef::new(16, 8)),
+            ecdsa: Some(EcdsaInstanceDef::new(2048)),
+            bitwise: Some(BitwiseInstanceDef::new(64)),
+            ec_op: Some(EcOpInstanceDef::new(1024)),
+            keccak: None,
+            poseidon: Some(PoseidonInstanceDef::new(32)),
+        }
+    }
+
+    pub fn starknet_with_keccak() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            output: true,
+            pedersen: Some(PedersenInstanceDef::new(32, 1)),
+            range_check: Some(RangeCheckInstanceDef::new(16, 8)),
+            ecdsa: Some(EcdsaInstanceDef::new(2048)),
+            bitwise: Some(BitwiseInstanceDef::new(64)),
+            ec_op: Some(EcOpInstanceDef::new(1024)),
             keccak: Some(KeccakInstanceDef::new(2048, vec![200; 8])),
+            poseidon: Some(PoseidonInstanceDef::new(32)),
+        }
+    }
+
+    pub fn recursive_large_output() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            output: true,
+            pedersen: Some(PedersenInstanceDef::new(128, 1)),
+            range_check: Some(RangeCheckInstanceDef::default()),
+            ecdsa: None,
+            bitwise: Some(BitwiseInstanceDef::new(8)),
+            ec_op: None,
+            keccak: None,
+            poseidon: Some(PoseidonInstanceDef::new(8)),
         }
     }
 
-    pub(crate) fn all() -> BuiltinsInstanceDef {
+    pub fn all() -> BuiltinsInstanceDef {
         BuiltinsInstanceDef {
             output: true,
             pedersen: Some(PedersenInstanceDef::default()),
@@ -97,6 +144,20 @@ impl BuiltinsInstanceDef {
             bitwise: Some(BitwiseInstanceDef::default()),
             ec_op: Some(EcOpInstanceDef::default()),
             keccak: None,
+            poseidon: None,
+        }
+    }
+
+    pub fn all_cairo() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            output: true,
+            pedersen: Some(PedersenInstanceDef::new(256, 1)),
+            range_check: Some(RangeCheckInstanceDef::default()),
+            ecdsa: Some(EcdsaInstanceDef::new(2048)),
+            bitwise: Some(BitwiseInstanceDef::new(16)),
+            ec_op: Some(EcOpInstanceDef::new(1024)),
+            keccak: Some(KeccakInstanceDef::new(2048, vec![200; 8])),
+            poseidon: Some(PoseidonInstanceDef::default()),
         }
     }
 }
@@ -171,6 +232,45 @@ mod tests {
         assert!(builtins.ec_op.is_none());
     }
 
+    #[test]
+    fn get_builtins_starknet() {
+        let builtins = BuiltinsInstanceDef::starknet();
+        assert!(builtins.output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins.ecdsa.is_some());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_some());
+        assert!(builtins.keccak.is_none());
+        assert!(builtins.poseidon.is_some());
+    }
+
+    #[test]
+    fn get_builtins_starknet_with_keccak() {
+        let builtins = BuiltinsInstanceDef::starknet_with_keccak();
+        assert!(builtins.output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins.ecdsa.is_some());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_some());
+        assert!(builtins.keccak.is_some());
+        assert!(builtins.poseidon.is_some());
+    }
+
+    #[test]
+    fn get_builtins_recursive_large_output() {
+        let builtins = BuiltinsInstanceDef::recursive_large_output();
+        assert!(builtins.output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins.ecdsa.is_none());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_none());
+        assert!(builtins.keccak.is_none());
+        assert!(builtins.poseidon.is_some());
+    }
+
     #[test]
     fn get_builtins_all() {
         let builtins = BuiltinsInstanceDef::all();
@@ -181,4 +281,17 @@ mod tests {
         assert!(builtins.bitwise.is_some());
         assert!(builtins.ec_op.is_some());
     }
+
+    #[test]
+    fn get_builtins_all_cairo() {
+        let builtins = BuiltinsInstanceDef::all_cairo();
+        assert!(builtins.output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins.ecdsa.is_some());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_some());
+        assert!(builtins.keccak.is_some());
+        assert!(builtins.poseidon.is_some());
+    }
 }
@@ -0,0 +1,67 @@
+pub(crate) const CELLS_PER_POSEIDON: u32 = 6;
+pub(crate) const INPUT_CELLS_PER_POSEIDON: u32 = 3;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PoseidonInstanceDef {
+    pub(crate) ratio: u32,
+    pub(crate) _partial_rounds_partition: Vec<u32>,
+}
+
+impl PoseidonInstanceDef {
+    pub(crate) fn default() -> Self {
+        PoseidonInstanceDef {
+            ratio: 256,
+            _partial_rounds_partition: vec![64, 22],
+        }
+    }
+
+    pub(crate) fn new(ratio: u32) -> Self {
+        PoseidonInstanceDef {
+            ratio,
+            _partial_rounds_partition: vec![64, 22],
+        }
+    }
+
+    pub(crate) fn _cells_per_builtin(&self) -> u32 {
+        CELLS_PER_POSEIDON
+    }
+
+    pub(crate) fn _range_check_units_per_builtin(&self) -> u32 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_range_check_units_per_builtin() {
+        let builtin_instance = PoseidonInstanceDef::default();
+        assert_eq!(builtin_instance._range_check_units_per_builtin(), 0);
+    }
+
+    #[test]
+    fn get_cells_per_builtin() {
+        let builtin_instance = PoseidonInstanceDef::default();
+        assert_eq!(builtin_instance._cells_per_builtin(), 6);
+    }
+
+    #[test]
+    fn test_new() {
+        let builtin_instance = PoseidonInstanceDef {
+            ratio: 8,
+            _partial_rounds_partition: vec![64, 22],
+        };
+        assert_eq!(PoseidonInstanceDef::new(8), builtin_instance);
+    }
+
+    #[test]
+    fn test_default() {
+        let builtin_instance = PoseidonInstanceDef {
+            ratio: 256,
+            _partial_rounds_partition: vec![64, 22],
+        };
+        assert_eq!(PoseidonInstanceDef::default(), builtin_instance);
+    }
+}
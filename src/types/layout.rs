@@ -1,11 +1,21 @@
 use super::instance_definitions::{
-    builtins_instance_def::BuiltinsInstanceDef, cpu_instance_def::CpuInstanceDef,
-    diluted_pool_instance_def::DilutedPoolInstanceDef,
+    bitwise_instance_def::BitwiseInstanceDef, builtins_instance_def::BuiltinsInstanceDef,
+    cpu_instance_def::CpuInstanceDef, diluted_pool_instance_def::DilutedPoolInstanceDef,
+    ec_op_instance_def::EcOpInstanceDef, ecdsa_instance_def::EcdsaInstanceDef,
+    keccak_instance_def::KeccakInstanceDef, pedersen_instance_def::PedersenInstanceDef,
+    poseidon_instance_def::PoseidonInstanceDef, range_check_instance_def::RangeCheckInstanceDef,
+};
+use crate::vm::errors::runner_errors::RunnerError;
+use serde::Deserialize;
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::Path,
 };
 
 #[derive(Debug)]
-pub(crate) struct CairoLayout {
-    pub(crate) _name: String,
+pub struct CairoLayout {
+    pub(crate) name: String,
     pub(crate) _cpu_component_step: u32,
     pub(crate) rc_units: u32,
     pub(crate) builtins: BuiltinsInstanceDef,
@@ -17,9 +27,9 @@ pub(crate) struct CairoLayout {
 }
 
 impl CairoLayout {
-    pub(crate) fn plain_instance() -> CairoLayout {
+    pub fn plain_instance() -> CairoLayout {
         CairoLayout {
-            _name: String::from("plain"),
+            name: String::from("plain"),
             _cpu_component_step: 1,
             rc_units: 16,
             builtins: BuiltinsInstanceDef::plain(),
@@ -31,9 +41,9 @@ impl CairoLayout {
         }
     }
 
-    pub(crate) fn small_instance() -> CairoLayout {
+    pub fn small_instance() -> CairoLayout {
         CairoLayout {
-            _name: String::from("small"),
+            name: String::from("small"),
             _cpu_component_step: 1,
             rc_units: 16,
             builtins: BuiltinsInstanceDef::small(),
@@ -45,9 +55,9 @@ impl CairoLayout {
         }
     }
 
-    pub(crate) fn dex_instance() -> CairoLayout {
+    pub fn dex_instance() -> CairoLayout {
         CairoLayout {
-            _name: String::from("dex"),
+            name: String::from("dex"),
             _cpu_component_step: 1,
             rc_units: 4,
             builtins: BuiltinsInstanceDef::dex(),
@@ -59,9 +69,9 @@ impl CairoLayout {
         }
     }
 
-    pub(crate) fn perpetual_with_bitwise_instance() -> CairoLayout {
+    pub fn perpetual_with_bitwise_instance() -> CairoLayout {
         CairoLayout {
-            _name: String::from("perpetual_with_bitwise"),
+            name: String::from("perpetual_with_bitwise"),
             _cpu_component_step: 1,
             rc_units: 4,
             builtins: BuiltinsInstanceDef::perpetual_with_bitwise(),
@@ -73,9 +83,9 @@ impl CairoLayout {
         }
     }
 
-    pub(crate) fn bitwise_instance() -> CairoLayout {
+    pub fn bitwise_instance() -> CairoLayout {
         CairoLayout {
-            _name: String::from("bitwise"),
+            name: String::from("bitwise"),
             _cpu_component_step: 1,
             rc_units: 4,
             builtins: BuiltinsInstanceDef::bitwise(),
@@ -86,24 +96,66 @@ impl CairoLayout {
             _cpu_instance_def: CpuInstanceDef::default(),
         }
     }
-    #[allow(dead_code)]
-    pub(crate) fn recursive_instance() -> CairoLayout {
+
+    pub fn recursive_instance() -> CairoLayout {
         CairoLayout {
-            _name: String::from("recursive"),
+            name: String::from("recursive"),
             _cpu_component_step: 1,
             rc_units: 4,
             builtins: BuiltinsInstanceDef::recursive(),
             _public_memory_fraction: 8,
             _memory_units_per_step: 8,
             diluted_pool_instance_def: Some(DilutedPoolInstanceDef::default()),
-            _n_trace_colums: 11,
+            _n_trace_colums: 10,
+            _cpu_instance_def: CpuInstanceDef::default(),
+        }
+    }
+
+    pub fn starknet_instance() -> CairoLayout {
+        CairoLayout {
+            name: String::from("starknet"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::starknet(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::new(2, 4, 16)),
+            _n_trace_colums: 10,
+            _cpu_instance_def: CpuInstanceDef::default(),
+        }
+    }
+
+    pub fn starknet_with_keccak_instance() -> CairoLayout {
+        CairoLayout {
+            name: String::from("starknet_with_keccak"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::starknet_with_keccak(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::new(4, 4, 16)),
+            _n_trace_colums: 15,
             _cpu_instance_def: CpuInstanceDef::default(),
         }
     }
 
-    pub(crate) fn all_instance() -> CairoLayout {
+    pub fn recursive_large_output_instance() -> CairoLayout {
         CairoLayout {
-            _name: String::from("all"),
+            name: String::from("recursive_large_output"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::recursive_large_output(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::default()),
+            _n_trace_colums: 13,
+            _cpu_instance_def: CpuInstanceDef::default(),
+        }
+    }
+
+    pub fn all_instance() -> CairoLayout {
+        CairoLayout {
+            name: String::from("all"),
             _cpu_component_step: 1,
             rc_units: 8,
             builtins: BuiltinsInstanceDef::all(),
@@ -114,6 +166,286 @@ impl CairoLayout {
             _cpu_instance_def: CpuInstanceDef::default(),
         }
     }
+
+    pub fn all_cairo_instance() -> CairoLayout {
+        CairoLayout {
+            name: String::from("all_cairo"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::all_cairo(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::new(4, 4, 16)),
+            _n_trace_colums: 11,
+            _cpu_instance_def: CpuInstanceDef::default(),
+        }
+    }
+
+    /// Builds the `dynamic` layout described by `params`.
+    pub fn dynamic_instance(params: &CairoLayoutParams) -> Result<CairoLayout, RunnerError> {
+        let mut builder = CairoLayoutBuilder::new("dynamic")
+            .cpu_component_step(params.cpu_component_step)
+            .rc_units(params.rc_units)
+            .memory_units_per_step(params.memory_units_per_step)
+            .public_memory_fraction(params.public_memory_fraction);
+        if let Some(diluted_pool) = &params.diluted_pool {
+            builder = builder.diluted_pool(
+                diluted_pool.units_per_step,
+                diluted_pool.spacing,
+                diluted_pool.n_bits,
+            );
+        }
+        if params.uses_output_builtin {
+            builder = builder.output();
+        }
+        if let Some(ratio) = builtin_ratio(
+            "pedersen",
+            params.uses_pedersen_builtin,
+            params.pedersen_ratio,
+        )? {
+            builder = builder.pedersen(ratio);
+        }
+        if let Some(ratio) = builtin_ratio(
+            "range_check",
+            params.uses_range_check_builtin,
+            params.range_check_ratio,
+        )? {
+            builder = builder.range_check(ratio);
+        }
+        if let Some(ratio) = builtin_ratio("ecdsa", params.uses_ecdsa_builtin, params.ecdsa_ratio)?
+        {
+            builder = builder.ecdsa(ratio);
+        }
+        if let Some(ratio) =
+            builtin_ratio("bitwise", params.uses_bitwise_builtin, params.bitwise_ratio)?
+        {
+            builder = builder.bitwise(ratio);
+        }
+        if let Some(ratio) = builtin_ratio("ec_op", params.uses_ec_op_builtin, params.ec_op_ratio)?
+        {
+            builder = builder.ec_op(ratio);
+        }
+        if let Some(ratio) =
+            builtin_ratio("keccak", params.uses_keccak_builtin, params.keccak_ratio)?
+        {
+            builder = builder.keccak(ratio);
+        }
+        if let Some(ratio) = builtin_ratio(
+            "poseidon",
+            params.uses_poseidon_builtin,
+            params.poseidon_ratio,
+        )? {
+            builder = builder.poseidon(ratio);
+        }
+        builder.build()
+    }
+}
+
+fn builtin_ratio(name: &str, uses: bool, ratio: Option<u32>) -> Result<Option<u32>, RunnerError> {
+    match (uses, ratio) {
+        (false, _) => Ok(None),
+        (true, Some(ratio)) => Ok(Some(ratio)),
+        (true, None) => Err(RunnerError::InvalidLayoutParams(format!(
+            "uses_{name}_builtin is set but {name}_ratio is missing"
+        ))),
+    }
+}
+
+/// Builder for custom layouts. Parameters that aren't set default to the ones shared by
+/// the starknet layouts, and only the builtins that are explicitly added are included.
+#[derive(Debug)]
+pub struct CairoLayoutBuilder {
+    name: String,
+    cpu_component_step: u32,
+    rc_units: u32,
+    public_memory_fraction: u32,
+    memory_units_per_step: u32,
+    diluted_pool_instance_def: Option<DilutedPoolInstanceDef>,
+    builtins: BuiltinsInstanceDef,
+}
+
+impl CairoLayoutBuilder {
+    pub fn new(name: &str) -> Self {
+        CairoLayoutBuilder {
+            name: name.to_string(),
+            cpu_component_step: 1,
+            rc_units: 4,
+            public_memory_fraction: 8,
+            memory_units_per_step: 8,
+            diluted_pool_instance_def: None,
+            builtins: BuiltinsInstanceDef::plain(),
+        }
+    }
+
+    pub fn cpu_component_step(mut self, cpu_component_step: u32) -> Self {
+        self.cpu_component_step = cpu_component_step;
+        self
+    }
+
+    pub fn rc_units(mut self, rc_units: u32) -> Self {
+        self.rc_units = rc_units;
+        self
+    }
+
+    pub fn public_memory_fraction(mut self, public_memory_fraction: u32) -> Self {
+        self.public_memory_fraction = public_memory_fraction;
+        self
+    }
+
+    pub fn memory_units_per_step(mut self, memory_units_per_step: u32) -> Self {
+        self.memory_units_per_step = memory_units_per_step;
+        self
+    }
+
+    pub fn diluted_pool(mut self, units_per_step: u32, spacing: u32, n_bits: u32) -> Self {
+        self.diluted_pool_instance_def =
+            Some(DilutedPoolInstanceDef::new(units_per_step, spacing, n_bits));
+        self
+    }
+
+    /// Replaces all the builtins added so far with `builtins`.
+    pub fn builtins(mut self, builtins: BuiltinsInstanceDef) -> Self {
+        self.builtins = builtins;
+        self
+    }
+
+    pub fn output(mut self) -> Self {
+        self.builtins.output = true;
+        self
+    }
+
+    pub fn pedersen(mut self, ratio: u32) -> Self {
+        self.builtins.pedersen = Some(PedersenInstanceDef::new(ratio, 1));
+        self
+    }
+
+    pub fn range_check(mut self, ratio: u32) -> Self {
+        self.builtins.range_check = Some(RangeCheckInstanceDef::new(ratio, 8));
+        self
+    }
+
+    pub fn ecdsa(mut self, ratio: u32) -> Self {
+        self.builtins.ecdsa = Some(EcdsaInstanceDef::new(ratio));
+        self
+    }
+
+    pub fn bitwise(mut self, ratio: u32) -> Self {
+        self.builtins.bitwise = Some(BitwiseInstanceDef::new(ratio));
+        self
+    }
+
+    pub fn ec_op(mut self, ratio: u32) -> Self {
+        self.builtins.ec_op = Some(EcOpInstanceDef::new(ratio));
+        self
+    }
+
+    pub fn keccak(mut self, ratio: u32) -> Self {
+        self.builtins.keccak = Some(KeccakInstanceDef::new(ratio, vec![200; 8]));
+        self
+    }
+
+    pub fn poseidon(mut self, ratio: u32) -> Self {
+        self.builtins.poseidon = Some(PoseidonInstanceDef::new(ratio));
+        self
+    }
+
+    pub fn build(self) -> Result<CairoLayout, RunnerError> {
+        let invalid = |msg: &str| Err(RunnerError::InvalidLayoutParams(msg.to_string()));
+        // The last 3 range check units of each step are used by the instruction offsets
+        if self.rc_units < 3 {
+            return invalid("rc_units must be at least 3");
+        }
+        if self.cpu_component_step == 0
+            || self.public_memory_fraction == 0
+            || self.memory_units_per_step == 0
+        {
+            return invalid(
+                "cpu_component_step, memory_units_per_step and public_memory_fraction must be positive",
+            );
+        }
+        if let Some(diluted_pool) = &self.diluted_pool_instance_def {
+            if diluted_pool.units_per_step == 0
+                || diluted_pool.spacing == 0
+                || diluted_pool.n_bits == 0
+            {
+                return invalid("diluted pool parameters must be positive");
+            }
+        }
+        let builtins = &self.builtins;
+        let ratios = [
+            builtins.pedersen.as_ref().map(|def| def.ratio),
+            builtins.range_check.as_ref().map(|def| def.ratio),
+            builtins.ecdsa.as_ref().map(|def| def.ratio),
+            builtins.bitwise.as_ref().map(|def| def.ratio),
+            builtins.ec_op.as_ref().map(|def| def.ratio),
+            builtins.keccak.as_ref().map(|def| def._ratio),
+            builtins.poseidon.as_ref().map(|def| def.ratio),
+        ];
+        if ratios.contains(&Some(0)) {
+            return invalid("builtin ratios must be positive");
+        }
+        Ok(CairoLayout {
+            name: self.name,
+            _cpu_component_step: self.cpu_component_step,
+            rc_units: self.rc_units,
+            builtins: self.builtins,
+            _public_memory_fraction: self.public_memory_fraction,
+            _memory_units_per_step: self.memory_units_per_step,
+            diluted_pool_instance_def: self.diluted_pool_instance_def,
+            // Not used by the VM
+            _n_trace_colums: 0,
+            _cpu_instance_def: CpuInstanceDef::default(),
+        })
+    }
+}
+
+/// Parameters of a `dynamic` layout, as found in a `--cairo_layout_params_file`.
+/// Builtins are only included if their `uses_<name>_builtin` flag is set, in which case
+/// their `<name>_ratio` is required.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CairoLayoutParams {
+    pub cpu_component_step: u32,
+    pub rc_units: u32,
+    pub memory_units_per_step: u32,
+    pub public_memory_fraction: u32,
+    pub diluted_pool: Option<DilutedPoolParams>,
+    #[serde(default)]
+    pub uses_output_builtin: bool,
+    #[serde(default)]
+    pub uses_pedersen_builtin: bool,
+    pub pedersen_ratio: Option<u32>,
+    #[serde(default)]
+    pub uses_range_check_builtin: bool,
+    pub range_check_ratio: Option<u32>,
+    #[serde(default)]
+    pub uses_ecdsa_builtin: bool,
+    pub ecdsa_ratio: Option<u32>,
+    #[serde(default)]
+    pub uses_bitwise_builtin: bool,
+    pub bitwise_ratio: Option<u32>,
+    #[serde(default)]
+    pub uses_ec_op_builtin: bool,
+    pub ec_op_ratio: Option<u32>,
+    #[serde(default)]
+    pub uses_keccak_builtin: bool,
+    pub keccak_ratio: Option<u32>,
+    #[serde(default)]
+    pub uses_poseidon_builtin: bool,
+    pub poseidon_ratio: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DilutedPoolParams {
+    pub units_per_step: u32,
+    pub spacing: u32,
+    pub n_bits: u32,
+}
+
+impl CairoLayoutParams {
+    pub fn from_file(path: &Path) -> io::Result<CairoLayoutParams> {
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(BufReader::new(file))?)
+    }
 }
 
 #[cfg(test)]
@@ -124,7 +456,7 @@ mod tests {
     fn get_plain_instance() {
         let layout = CairoLayout::plain_instance();
         let builtins = BuiltinsInstanceDef::plain();
-        assert_eq!(&layout._name, "plain");
+        assert_eq!(&layout.name, "plain");
         assert_eq!(layout._cpu_component_step, 1);
         assert_eq!(layout.rc_units, 16);
         assert_eq!(layout.builtins, builtins);
@@ -139,7 +471,7 @@ mod tests {
     fn get_small_instance() {
         let layout = CairoLayout::small_instance();
         let builtins = BuiltinsInstanceDef::small();
-        assert_eq!(&layout._name, "small");
+        assert_eq!(&layout.name, "small");
         assert_eq!(layout._cpu_component_step, 1);
         assert_eq!(layout.rc_units, 16);
         assert_eq!(layout.builtins, builtins);
@@ -154,7 +486,7 @@ mod tests {
     fn get_dex_instance() {
         let layout = CairoLayout::dex_instance();
         let builtins = BuiltinsInstanceDef::dex();
-        assert_eq!(&layout._name, "dex");
+        assert_eq!(&layout.name, "dex");
         assert_eq!(layout._cpu_component_step, 1);
         assert_eq!(layout.rc_units, 4);
         assert_eq!(layout.builtins, builtins);
@@ -169,7 +501,7 @@ mod tests {
     fn get_perpetual_with_bitwise_instance() {
         let layout = CairoLayout::perpetual_with_bitwise_instance();
         let builtins = BuiltinsInstanceDef::perpetual_with_bitwise();
-        assert_eq!(&layout._name, "perpetual_with_bitwise");
+        assert_eq!(&layout.name, "perpetual_with_bitwise");
         assert_eq!(layout._cpu_component_step, 1);
         assert_eq!(layout.rc_units, 4);
         assert_eq!(layout.builtins, builtins);
@@ -187,7 +519,7 @@ mod tests {
     fn get_bitwise_instance() {
         let layout = CairoLayout::bitwise_instance();
         let builtins = BuiltinsInstanceDef::bitwise();
-        assert_eq!(&layout._name, "bitwise");
+        assert_eq!(&layout.name, "bitwise");
         assert_eq!(layout._cpu_component_step, 1);
         assert_eq!(layout.rc_units, 4);
         assert_eq!(layout.builtins, builtins);
@@ -205,7 +537,7 @@ mod tests {
     fn get_recursive_instance() {
         let layout = CairoLayout::recursive_instance();
         let builtins = BuiltinsInstanceDef::recursive();
-        assert_eq!(&layout._name, "recursive");
+        assert_eq!(&layout.name, "recursive");
         assert_eq!(layout._cpu_component_step, 1);
         assert_eq!(layout.rc_units, 4);
         assert_eq!(layout.builtins, builtins);
@@ -215,7 +547,61 @@ mod tests {
             layout.diluted_pool_instance_def,
             Some(DilutedPoolInstanceDef::default())
         );
-        assert_eq!(layout._n_trace_colums, 11);
+        assert_eq!(layout._n_trace_colums, 10);
+        assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
+    }
+
+    #[test]
+    fn get_starknet_instance() {
+        let layout = CairoLayout::starknet_instance();
+        let builtins = BuiltinsInstanceDef::starknet();
+        assert_eq!(&layout.name, "starknet");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::new(2, 4, 16))
+        );
+        assert_eq!(layout._n_trace_colums, 10);
+        assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
+    }
+
+    #[test]
+    fn get_starknet_with_keccak_instance() {
+        let layout = CairoLayout::starknet_with_keccak_instance();
+        let builtins = BuiltinsInstanceDef::starknet_with_keccak();
+        assert_eq!(&layout.name, "starknet_with_keccak");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::new(4, 4, 16))
+        );
+        assert_eq!(layout._n_trace_colums, 15);
+        assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
+    }
+
+    #[test]
+    fn get_recursive_large_output_instance() {
+        let layout = CairoLayout::recursive_large_output_instance();
+        let builtins = BuiltinsInstanceDef::recursive_large_output();
+        assert_eq!(&layout.name, "recursive_large_output");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::default())
+        );
+        assert_eq!(layout._n_trace_colums, 13);
         assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
     }
 
@@ -223,7 +609,7 @@ mod tests {
     fn get_all_instance() {
         let layout = CairoLayout::all_instance();
         let builtins = BuiltinsInstanceDef::all();
-        assert_eq!(&layout._name, "all");
+        assert_eq!(&layout.name, "all");
         assert_eq!(layout._cpu_component_step, 1);
         assert_eq!(layout.rc_units, 8);
         assert_eq!(layout.builtins, builtins);
@@ -236,4 +622,192 @@ mod tests {
         assert_eq!(layout._n_trace_colums, 27);
         assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
     }
+
+    #[test]
+    fn get_all_cairo_instance() {
+        let layout = CairoLayout::all_cairo_instance();
+        let builtins = BuiltinsInstanceDef::all_cairo();
+        assert_eq!(&layout.name, "all_cairo");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::new(4, 4, 16))
+        );
+        assert_eq!(layout._n_trace_colums, 11);
+        assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
+    }
+
+    fn dynamic_params() -> CairoLayoutParams {
+        serde_json::from_str(
+            r#"{
+                "cpu_component_step": 1,
+                "rc_units": 4,
+                "memory_units_per_step": 8,
+                "public_memory_fraction": 8,
+                "diluted_pool": { "units_per_step": 16, "spacing": 4, "n_bits": 16 },
+                "uses_output_builtin": true,
+                "uses_pedersen_builtin": true,
+                "pedersen_ratio": 128,
+                "uses_range_check_builtin": true,
+                "range_check_ratio": 8,
+                "uses_bitwise_builtin": false,
+                "bitwise_ratio": 8
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn build_custom_layout() {
+        let layout = CairoLayoutBuilder::new("custom")
+            .rc_units(8)
+            .public_memory_fraction(4)
+            .diluted_pool(4, 4, 16)
+            .output()
+            .range_check(16)
+            .poseidon(32)
+            .build()
+            .unwrap();
+        assert_eq!(&layout.name, "custom");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 8);
+        assert_eq!(layout._public_memory_fraction, 4);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::new(4, 4, 16))
+        );
+        assert_eq!(
+            layout.builtins,
+            BuiltinsInstanceDef {
+                output: true,
+                pedersen: None,
+                range_check: Some(RangeCheckInstanceDef::new(16, 8)),
+                ecdsa: None,
+                bitwise: None,
+                ec_op: None,
+                keccak: None,
+                poseidon: Some(PoseidonInstanceDef::new(32)),
+            }
+        );
+    }
+
+    #[test]
+    fn build_layout_with_preset_builtins() {
+        let layout = CairoLayoutBuilder::new("custom")
+            .builtins(BuiltinsInstanceDef::starknet())
+            .keccak(1024)
+            .build()
+            .unwrap();
+        let mut builtins = BuiltinsInstanceDef::starknet();
+        builtins.keccak = Some(KeccakInstanceDef::new(1024, vec![200; 8]));
+        assert_eq!(layout.builtins, builtins);
+    }
+
+    #[test]
+    fn build_layout_invalid_rc_units() {
+        assert_eq!(
+            CairoLayoutBuilder::new("custom").rc_units(2).build().err(),
+            Some(RunnerError::InvalidLayoutParams(
+                "rc_units must be at least 3".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn build_layout_zero_ratio() {
+        assert_eq!(
+            CairoLayoutBuilder::new("custom").bitwise(0).build().err(),
+            Some(RunnerError::InvalidLayoutParams(
+                "builtin ratios must be positive".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn build_layout_zero_public_memory_fraction() {
+        assert!(CairoLayoutBuilder::new("custom")
+            .public_memory_fraction(0)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn get_dynamic_instance() {
+        let layout = CairoLayout::dynamic_instance(&dynamic_params()).unwrap();
+        assert_eq!(&layout.name, "dynamic");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::default())
+        );
+        assert_eq!(
+            layout.builtins,
+            BuiltinsInstanceDef {
+                output: true,
+                pedersen: Some(PedersenInstanceDef::new(128, 1)),
+                range_check: Some(RangeCheckInstanceDef::default()),
+                ecdsa: None,
+                bitwise: None,
+                ec_op: None,
+                keccak: None,
+                poseidon: None,
+            }
+        );
+    }
+
+    #[test]
+    fn get_dynamic_instance_missing_ratio() {
+        let params = CairoLayoutParams {
+            uses_ecdsa_builtin: true,
+            ..dynamic_params()
+        };
+        assert_eq!(
+            CairoLayout::dynamic_instance(&params).err(),
+            Some(RunnerError::InvalidLayoutParams(
+                "uses_ecdsa_builtin is set but ecdsa_ratio is missing".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn layout_params_from_file() {
+        let path = std::env::temp_dir().join("cairo_vm_layout_params_from_file.json");
+        std::fs::write(
+            &path,
+            r#"{
+                "cpu_component_step": 2,
+                "rc_units": 16,
+                "memory_units_per_step": 8,
+                "public_memory_fraction": 4,
+                "uses_ec_op_builtin": true,
+                "ec_op_ratio": 1024
+            }"#,
+        )
+        .unwrap();
+        let params = CairoLayoutParams::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(params.cpu_component_step, 2);
+        assert_eq!(params.rc_units, 16);
+        assert_eq!(params.diluted_pool, None);
+        assert!(!params.uses_output_builtin);
+        assert!(params.uses_ec_op_builtin);
+        assert_eq!(params.ec_op_ratio, Some(1024));
+    }
+
+    #[test]
+    fn layout_params_from_invalid_file() {
+        let path = std::env::temp_dir().join("cairo_vm_layout_params_from_invalid_file.json");
+        std::fs::write(&path, r#"{ "rc_units": 4 }"#).unwrap();
+        let result = CairoLayoutParams::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    serde::deserialize_casm::deserialize_casm,
     serde::deserialize_program::{
         deserialize_program, Attribute, HintParams, Identifier, InstructionLocation,
         ReferenceManager,
@@ -16,6 +17,9 @@ use std::{
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct Program {
     pub builtins: Vec<&'static str>,
+    /// Names of the builtins from the first builtin that isn't native to the VM onwards. They
+    /// must be registered on the runner with `CairoRunner::add_custom_builtin`.
+    pub custom_builtins: Vec<String>,
     pub prime: String,
     pub data: Vec<MaybeRelocatable>,
     pub constants: HashMap<String, Felt>,
@@ -45,6 +49,7 @@ impl Program {
     ) -> Result<Program, ProgramError> {
         Ok(Self {
             builtins,
+            custom_builtins: Vec::new(),
             prime,
             data,
             constants: {
@@ -72,6 +77,14 @@ impl Program {
         })
     }
 
+    /// Returns the names of all the builtins of the program, native and custom, in order.
+    pub fn builtin_names(&self) -> impl Iterator<Item = &str> {
+        self.builtins
+            .iter()
+            .copied()
+            .chain(self.custom_builtins.iter().map(String::as_str))
+    }
+
     pub fn from_file(path: &Path, entrypoint: Option<&str>) -> Result<Program, ProgramError> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
@@ -85,12 +98,33 @@ impl Program {
     ) -> Result<Program, ProgramError> {
         deserialize_program(reader, entrypoint)
     }
+
+    /// Loads the CASM output of the Cairo 1 compiler. The program starts at the entry point
+    /// with the given selector, or at the first instruction if there is none, and its hints must
+    /// be run by the `Cairo1HintProcessor`.
+    pub fn from_casm_file(
+        path: &Path,
+        entry_point_selector: Option<&Felt>,
+    ) -> Result<Program, ProgramError> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        deserialize_casm(reader, entry_point_selector)
+    }
+
+    pub fn from_casm_reader(
+        reader: impl Read,
+        entry_point_selector: Option<&Felt>,
+    ) -> Result<Program, ProgramError> {
+        deserialize_casm(reader, entry_point_selector)
+    }
 }
 
 impl Default for Program {
     fn default() -> Self {
         Program {
             builtins: Vec::new(),
+            custom_builtins: Vec::new(),
             prime: PRIME_STR.to_string(),
             data: Vec::new(),
             constants: HashMap::new(),
@@ -502,6 +536,7 @@ mod tests {
     fn default_program() {
         let program = Program {
             builtins: Vec::new(),
+            custom_builtins: Vec::new(),
             prime: PRIME_STR.to_string(),
             data: Vec::new(),
             constants: HashMap::new(),
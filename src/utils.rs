@@ -234,6 +234,7 @@ pub mod test_utils {
         ( $( $builtin_name: expr ),* ) => {
             Program {
                 builtins: vec![$( $builtin_name ),*],
+                custom_builtins: Vec::new(),
                 prime: "0x800000000000011000000000000000000000000000000000000000000000001".to_string(),
                 data: Vec::new(),
                 constants: HashMap::new(),
@@ -859,6 +860,7 @@ mod test {
     fn program_macro() {
         let program = Program {
             builtins: Vec::new(),
+            custom_builtins: Vec::new(),
             prime: "0x800000000000011000000000000000000000000000000000000000000000001".to_string(),
             data: Vec::new(),
             constants: HashMap::new(),
@@ -881,6 +883,7 @@ mod test {
     fn program_macro_with_builtin() {
         let program = Program {
             builtins: vec![RANGE_CHECK_BUILTIN_NAME],
+            custom_builtins: Vec::new(),
             prime: "0x800000000000011000000000000000000000000000000000000000000000001".to_string(),
             data: Vec::new(),
             constants: HashMap::new(),
@@ -903,6 +906,7 @@ mod test {
     fn program_macro_custom_definition() {
         let program = Program {
             builtins: vec![RANGE_CHECK_BUILTIN_NAME],
+            custom_builtins: Vec::new(),
             prime: "0x800000000000011000000000000000000000000000000000000000000000001".to_string(),
             data: Vec::new(),
             constants: HashMap::new(),
@@ -0,0 +1,25 @@
+use super::{
+    memory_errors::MemoryError, runner_errors::RunnerError, trace_errors::TraceError,
+    vm_errors::VirtualMachineError,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PublicInputError {
+    #[error("The trace slice provided is empty")]
+    EmptyTrace,
+    #[error("The provided memory doesn't contain public address {0}")]
+    MemoryNotFound(usize),
+    #[error("Range check values are missing")]
+    NoRangeCheckLimits,
+    #[error("Failed to (de)serialize data: {0}")]
+    Serde(String),
+    #[error(transparent)]
+    Memory(#[from] MemoryError),
+    #[error(transparent)]
+    Runner(#[from] RunnerError),
+    #[error(transparent)]
+    Trace(#[from] TraceError),
+    #[error(transparent)]
+    VirtualMachine(#[from] VirtualMachineError),
+}
@@ -0,0 +1,29 @@
+use crate::types::relocatable::Relocatable;
+use felt::PRIME_STR;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum CairoPieError {
+    #[error("Expected prime {PRIME_STR}, but the CairoPie's program uses {0}")]
+    PrimeDiffers(String),
+    #[error("The CairoPie's main entrypoint {0} is outside of its program ({1} instructions)")]
+    InvalidMainAddress(usize, usize),
+    #[error("The CairoPie's program segment has size {0}, but its program has {1} instructions")]
+    ProgramLenMismatch(usize, usize),
+    #[error("The CairoPie's builtin segments don't match its program's builtins")]
+    BuiltinListMismatch,
+    #[error("The CairoPie's memory contains address {0}, which is outside of its segments")]
+    OutOfBoundsMemoryAddress(Relocatable),
+    #[error("Expected the {0} segment at index {1}, but the CairoPie places it at index {2}")]
+    SegmentIndexMismatch(String, isize, isize),
+    #[error("Expected {0} extra segments, but the run created {1}")]
+    ExtraSegmentsMismatch(usize, usize),
+    #[error("The final pointer of builtin {0} is {1}, but the CairoPie claims {2}")]
+    BuiltinStopPointerMismatch(String, usize, usize),
+    #[error("The {0} segment has size {1}, but the CairoPie claims {2}")]
+    SegmentSizeMismatch(String, usize, usize),
+    #[error("The memory of the run doesn't match the CairoPie's memory")]
+    MemoryMismatch,
+    #[error("The execution resources of the run don't match the CairoPie's")]
+    ExecutionResourcesMismatch,
+}
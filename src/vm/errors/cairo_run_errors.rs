@@ -1,4 +1,6 @@
+use super::air_public_input_errors::PublicInputError;
 use super::memory_errors::MemoryError;
+use super::program_hash_errors::ProgramHashError;
 use super::vm_exception::VmException;
 use crate::types::errors::program_errors::ProgramError;
 use crate::vm::errors::{
@@ -20,4 +22,8 @@ pub enum CairoRunError {
     MemoryError(#[from] MemoryError),
     #[error(transparent)]
     VmException(#[from] VmException),
+    #[error(transparent)]
+    PublicInput(#[from] PublicInputError),
+    #[error(transparent)]
+    ProgramHash(#[from] ProgramHashError),
 }
@@ -5,7 +5,8 @@ use thiserror::Error;
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 
 use super::{
-    exec_scope_errors::ExecScopeError, memory_errors::MemoryError, vm_errors::VirtualMachineError,
+    exec_scope_errors::ExecScopeError, memory_errors::MemoryError,
+    program_hash_errors::ProgramHashError, vm_errors::VirtualMachineError,
 };
 
 #[derive(Debug, Error)]
@@ -156,8 +157,39 @@ pub enum HintError {
     NonLeFelt(Felt, Felt),
     #[error("Unknown Hint: {0}")]
     UnknownHint(String),
+    #[error("Invalid hint operand: {0}")]
+    InvalidResOperand(String),
+    #[error("Expected a {0} syscall at {1}, got selector {2}")]
+    InvalidSyscallSelector(String, Relocatable, Felt),
+    #[error("Syscall failed: {0}")]
+    SyscallFailed(String),
     #[error("Signature hint must point to the signature builtin segment, not {0}.")]
     AddSignatureWrongEcdsaPtr(Relocatable),
     #[error("Signature hint must point to the public key cell, not {0}.")]
     AddSignatureNotAPublicKey(Relocatable),
+    #[error("Invalid bootloader input: {0}")]
+    InvalidBootloaderInput(String),
+    #[error("Computed program hash {0} does not match the hash {1} written to the output")]
+    ProgramHashMismatch(Felt, Felt),
+    #[error("Expected the task program to be loaded at {0}, found {1}")]
+    ProgramAddressMismatch(Relocatable, Relocatable),
+    #[error("Usage of the {0} builtin is inconsistent with the CairoPie")]
+    BuiltinUsageMismatch(String),
+    #[error("Invalid fact topology: {0}")]
+    InvalidFactTopology(String),
+    #[error("Failed to write the fact topologies file: {0}")]
+    FactTopologiesFile(String),
+    #[error("Composite packed outputs are not supported")]
+    CompositePackedOutput,
+    #[error(transparent)]
+    ProgramHash(#[from] ProgramHashError),
+    #[error("Program input has no key {0}")]
+    MissingProgramInputKey(String),
+    #[error("Invalid program input {0}: {1}")]
+    InvalidProgramInput(String, String),
+    #[error("Failed to interpret hint: {0}")]
+    HintInterpreter(String),
+    #[cfg(feature = "python_hints")]
+    #[error("Python hint failed: {0}")]
+    PythonHint(String),
 }
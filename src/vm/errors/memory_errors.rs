@@ -37,6 +37,8 @@ pub enum MemoryError {
     MissingSegmentUsedSizes,
     #[error("Segment at index {0} either doesn't exist or is not finalized.")]
     SegmentNotFinalized(usize),
+    #[error("Public memory was given for segment {0}, which has no relocated address")]
+    MalformedPublicMemory(usize),
     #[error("Invalid memory value at address {0:?}: {1:?}")]
     InvalidMemoryValue(Relocatable, MaybeRelocatable),
     #[error("Found a memory gap when calling get_continuous_range")]
@@ -45,6 +47,8 @@ pub enum MemoryError {
     ErrorCalculatingMemoryUnits,
     #[error("Missing memory cells for builtin {0}")]
     MissingMemoryCells(&'static str),
+    #[error("Segment arena builtin segment has a used size of {0}, below its initial size")]
+    InvalidUsedSizeSegmentArena(usize),
     #[error("Missing memory cells for builtin {0}: {1:?}")]
     MissingMemoryCellsWithOffsets(&'static str, Vec<usize>),
     #[error("ErrorInitializing Verifying Key from public key: {0:?}")]
@@ -0,0 +1,13 @@
+use crate::types::relocatable::Relocatable;
+use felt::Felt;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ProgramHashError {
+    #[error("The program has no main entrypoint")]
+    MissingMain,
+    #[error("Expected program data to contain only felts, found relocatable {0}")]
+    ExpectedIntegerInProgramData(Relocatable),
+    #[error("Failed to convert {0} to a field element")]
+    FeltConversion(Felt),
+}
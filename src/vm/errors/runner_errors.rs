@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
-use super::memory_errors::MemoryError;
+use super::{
+    cairo_pie_errors::CairoPieError, memory_errors::MemoryError, trace_errors::TraceError,
+};
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use felt::Felt;
 use thiserror::Error;
@@ -9,6 +11,10 @@ use thiserror::Error;
 pub enum RunnerError {
     #[error("Can't initialize state without an execution base")]
     NoExecBase,
+    #[error("Expected the return fp to be at the start of a segment, got {0}")]
+    UnexpectedRetFpOffset(Relocatable),
+    #[error("Expected the return pc to be at the start of a segment, got {0}")]
+    UnexpectedRetPcOffset(Relocatable),
     #[error("Can't initialize the function entrypoint without an execution base")]
     NoExecBaseForEntrypoint,
     #[error("Initialization failure: No program base")]
@@ -45,6 +51,14 @@ pub enum RunnerError {
     NoBuiltinForInstance(HashSet<&'static str>, String),
     #[error("Invalid layout {0}")]
     InvalidLayoutName(String),
+    #[error("Builtin {0} is not a native builtin, and no custom builtin was registered for it")]
+    MissingCustomBuiltin(String),
+    #[error("Invalid layout params: {0}")]
+    InvalidLayoutParams(String),
+    #[error("The dynamic layout requires layout params")]
+    MissingDynamicLayoutParams,
+    #[error("Layout params can only be used with the dynamic layout, got layout {0}")]
+    UnexpectedLayoutParams(String),
     #[error("Run has already ended.")]
     RunAlreadyFinished,
     #[error("end_run must be called before finalize_segments.")]
@@ -87,8 +101,36 @@ pub enum RunnerError {
     SafeDivFailUsize(usize, usize),
     #[error(transparent)]
     Memory(#[from] MemoryError),
+    #[error(transparent)]
+    Trace(#[from] TraceError),
+    #[error(transparent)]
+    CairoPie(#[from] CairoPieError),
+    #[error("Invalid additional data for builtin {0}")]
+    InvalidAdditionalData(&'static str),
+    #[error("Page start {0} is not in the output segment {1}")]
+    PageNotOnSegment(Relocatable, usize),
+    #[error("Page {0} was already used")]
+    PageAlreadyUsed(usize),
+    #[error("Page {0} goes beyond the end of the output segment, at offset {1}")]
+    PageOutOfBounds(usize, usize),
+    #[error("Output offset {0} was already assigned a page")]
+    OffsetAlreadyInPage(usize),
+    #[error("Duplicate output builtin attribute {0}")]
+    DuplicateAttribute(String),
     #[error("keccak_builtin: Failed to get first input address")]
     KeccakNoFirstInput,
     #[error("keccak_builtin: Failed to convert input cells to u64 values")]
     KeccakInputCellsNotU64,
+    #[error("poseidon_builtin: Failed to get first input address")]
+    PoseidonNoFirstInput,
+    #[error("poseidon_builtin: Expected integer values in input cells")]
+    PoseidonInputCellsNotFelts,
+    #[error("Expected the gas counter after the {1} builtin arguments, but only {0} arguments were given")]
+    MissingGasArgument(usize, usize),
+    #[error("Expected the remaining gas after the {1} builtin pointers, but only {0} values are returned")]
+    MissingRemainingGas(usize, usize),
+    #[error("Invalid remaining gas: {0}")]
+    InvalidRemainingGas(MaybeRelocatable),
+    #[error("No gas config was set for the run")]
+    MissingGasConfig,
 }
@@ -17,7 +17,8 @@ use crate::{
 };
 use num_integer::div_ceil;
 
-use super::BITWISE_BUILTIN_NAME;
+use super::{get_instances_input_cells, BITWISE_BUILTIN_NAME};
+use crate::air_private_input::{PrivateInput, PrivateInputPair};
 
 #[derive(Debug, Clone)]
 pub struct BitwiseBuiltinRunner {
@@ -210,8 +211,7 @@ impl BitwiseBuiltinRunner {
             self.stop_ptr = Some(stop_ptr);
             Ok(stop_pointer_addr)
         } else {
-            let stop_ptr = self.base;
-            self.stop_ptr = Some(stop_ptr);
+            self.stop_ptr = Some(0);
             Ok(pointer)
         }
     }
@@ -224,6 +224,24 @@ impl BitwiseBuiltinRunner {
         dbg!(div_ceil(used_cells, self.cells_per_instance as usize));
         Ok(div_ceil(used_cells, self.cells_per_instance as usize))
     }
+
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instances_input_cells(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::Pair(PrivateInputPair {
+                index,
+                x: inputs[0].clone(),
+                y: inputs[1].clone(),
+            })
+        })
+        .collect()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,168 @@
+use crate::{
+    math_utils::safe_div_usize,
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{
+        errors::{
+            memory_errors::{InsufficientAllocatedCellsError, MemoryError},
+            runner_errors::RunnerError,
+            vm_errors::VirtualMachineError,
+        },
+        vm_core::VirtualMachine,
+        vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+    },
+};
+use num_integer::div_ceil;
+use std::fmt::Debug;
+
+/// A builtin implemented outside of the VM, such as an application-specific hash.
+///
+/// Custom builtins are registered on the runner with `CairoRunner::add_custom_builtin`, and
+/// are used by the programs that list a builtin of the same name. Like the native builtins,
+/// each instance takes `cells_per_instance` consecutive cells of the builtin segment, the first
+/// `n_input_cells` of which are written by the program, while the rest are deduced by
+/// `deduce_memory_cell`.
+pub trait CustomBuiltin: CustomBuiltinClone + Debug + Send {
+    /// Name of the builtin, as listed in the program's `%builtins` directive.
+    fn name(&self) -> &'static str;
+
+    fn base(&self) -> usize;
+
+    /// Creates the builtin segment and stores its index as the builtin's base.
+    fn initialize_segments(&mut self, segments: &mut MemorySegmentManager);
+
+    fn cells_per_instance(&self) -> u32;
+
+    fn n_input_cells(&self) -> u32;
+
+    fn stop_ptr(&self) -> Option<usize>;
+
+    fn set_stop_ptr(&mut self, stop_ptr: usize);
+
+    /// Steps per builtin instance. Builtins without a ratio aren't limited by the run's length.
+    fn ratio(&self) -> Option<u32> {
+        None
+    }
+
+    fn initial_stack(&self) -> Vec<MaybeRelocatable> {
+        vec![MaybeRelocatable::from((self.base() as isize, 0))]
+    }
+
+    /// Reads the builtin's stop pointer, just below `pointer`, and checks that it points to the
+    /// end of the used instances.
+    fn final_stack(
+        &mut self,
+        segments: &MemorySegmentManager,
+        pointer: Relocatable,
+    ) -> Result<Relocatable, RunnerError> {
+        let stop_pointer_addr = pointer
+            .sub_usize(1)
+            .map_err(|_| RunnerError::NoStopPointer(self.name()))?;
+        let stop_pointer = segments
+            .memory
+            .get_relocatable(stop_pointer_addr)
+            .map_err(|_| RunnerError::NoStopPointer(self.name()))?;
+        if self.base() as isize != stop_pointer.segment_index {
+            return Err(RunnerError::InvalidStopPointerIndex(
+                self.name(),
+                stop_pointer,
+                self.base(),
+            ));
+        }
+        let stop_ptr = stop_pointer.offset;
+        let used = self.get_used_instances(segments)? * self.cells_per_instance() as usize;
+        if stop_ptr != used {
+            return Err(RunnerError::InvalidStopPointer(
+                self.name(),
+                Relocatable::from((self.base() as isize, used)),
+                Relocatable::from((self.base() as isize, stop_ptr)),
+            ));
+        }
+        self.set_stop_ptr(stop_ptr);
+        Ok(stop_pointer_addr)
+    }
+
+    fn add_validation_rule(&self, _memory: &mut Memory) {}
+
+    fn deduce_memory_cell(
+        &self,
+        _address: Relocatable,
+        _memory: &Memory,
+    ) -> Result<Option<MaybeRelocatable>, RunnerError> {
+        Ok(None)
+    }
+
+    fn get_used_cells(&self, segments: &MemorySegmentManager) -> Result<usize, MemoryError> {
+        segments
+            .get_segment_used_size(self.base())
+            .ok_or(MemoryError::MissingSegmentUsedSizes)
+    }
+
+    fn get_used_instances(&self, segments: &MemorySegmentManager) -> Result<usize, MemoryError> {
+        let used_cells = self.get_used_cells(segments)?;
+        Ok(div_ceil(used_cells, self.cells_per_instance() as usize))
+    }
+
+    fn get_allocated_memory_units(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        match self.ratio() {
+            Some(ratio) => {
+                let value = safe_div_usize(vm.current_step, ratio as usize)
+                    .map_err(|_| MemoryError::ErrorCalculatingMemoryUnits)?;
+                Ok(self.cells_per_instance() as usize * value)
+            }
+            None => Ok(0),
+        }
+    }
+
+    fn get_used_cells_and_allocated_size(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<(usize, usize), MemoryError> {
+        let used = self.get_used_cells(&vm.segments)?;
+        let ratio = match self.ratio() {
+            Some(ratio) => ratio as usize,
+            None => return Ok((used, used)),
+        };
+        if vm.current_step < ratio {
+            return Err(
+                InsufficientAllocatedCellsError::MinStepNotReached(ratio, self.name()).into(),
+            );
+        }
+        let size = self.cells_per_instance() as usize
+            * safe_div_usize(vm.current_step, ratio).map_err(|_| {
+                InsufficientAllocatedCellsError::CurrentStepNotDivisibleByBuiltinRatio(
+                    self.name(),
+                    vm.current_step,
+                    ratio,
+                )
+            })?;
+        if used > size {
+            return Err(
+                InsufficientAllocatedCellsError::BuiltinCells(self.name(), used, size).into(),
+            );
+        }
+        Ok((used, size))
+    }
+
+    /// Checks to run after the builtin segment's input cells have been verified to be set and
+    /// its output cells to match `deduce_memory_cell`.
+    fn run_security_checks(&self, _vm: &VirtualMachine) -> Result<(), VirtualMachineError> {
+        Ok(())
+    }
+}
+
+/// Allows cloning boxed custom builtins. Implemented for every custom builtin that is `Clone`.
+pub trait CustomBuiltinClone {
+    fn clone_box(&self) -> Box<dyn CustomBuiltin>;
+}
+
+impl<T: 'static + CustomBuiltin + Clone> CustomBuiltinClone for T {
+    fn clone_box(&self) -> Box<dyn CustomBuiltin> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn CustomBuiltin> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
@@ -15,7 +15,8 @@ use num_bigint::{BigInt, ToBigInt};
 use num_integer::{div_ceil, Integer};
 use num_traits::{Num, One, Pow, Zero};
 
-use super::EC_OP_BUILTIN_NAME;
+use super::{get_instances_input_cells, EC_OP_BUILTIN_NAME};
+use crate::air_private_input::{PrivateInput, PrivateInputEcOp};
 
 #[derive(Debug, Clone)]
 pub struct EcOpBuiltinRunner {
@@ -302,8 +303,7 @@ impl EcOpBuiltinRunner {
             self.stop_ptr = Some(stop_ptr);
             Ok(stop_pointer_addr)
         } else {
-            let stop_ptr = self.base;
-            self.stop_ptr = Some(stop_ptr);
+            self.stop_ptr = Some(0);
             Ok(pointer)
         }
     }
@@ -319,6 +319,27 @@ impl EcOpBuiltinRunner {
     m = {m:?}\n
     Q = {q:?}.")
     }
+
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instances_input_cells(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::EcOp(PrivateInputEcOp {
+                index,
+                p_x: inputs[0].clone(),
+                p_y: inputs[1].clone(),
+                q_x: inputs[2].clone(),
+                q_y: inputs[3].clone(),
+                m: inputs[4].clone(),
+            })
+        })
+        .collect()
+    }
 }
 
 #[cfg(test)]
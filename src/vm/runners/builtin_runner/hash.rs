@@ -14,7 +14,9 @@ use felt::Felt;
 use num_integer::{div_ceil, Integer};
 use starknet_crypto::{pedersen_hash, FieldElement};
 
-use super::EC_OP_BUILTIN_NAME;
+use super::{get_instances_input_cells, EC_OP_BUILTIN_NAME, HASH_BUILTIN_NAME};
+use crate::air_private_input::{PrivateInput, PrivateInputPair};
+use crate::vm::runners::cairo_pie::BuiltinAdditionalData;
 
 #[derive(Debug, Clone)]
 pub struct HashBuiltinRunner {
@@ -204,11 +206,47 @@ impl HashBuiltinRunner {
             self.stop_ptr = Some(stop_ptr);
             Ok(stop_pointer_addr)
         } else {
-            let stop_ptr = self.base;
-            self.stop_ptr = Some(stop_ptr);
+            self.stop_ptr = Some(0);
             Ok(pointer)
         }
     }
+
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instances_input_cells(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::Pair(PrivateInputPair {
+                index,
+                x: inputs[0].clone(),
+                y: inputs[1].clone(),
+            })
+        })
+        .collect()
+    }
+
+    pub fn get_additional_data(&self) -> BuiltinAdditionalData {
+        BuiltinAdditionalData::Hash(self.verified_addresses.borrow().clone())
+    }
+
+    pub fn extend_additional_data(
+        &mut self,
+        additional_data: &BuiltinAdditionalData,
+    ) -> Result<(), RunnerError> {
+        match additional_data {
+            BuiltinAdditionalData::Hash(addresses) => {
+                self.verified_addresses
+                    .borrow_mut()
+                    .extend(addresses.iter().copied());
+                Ok(())
+            }
+            _ => Err(RunnerError::InvalidAdditionalData(HASH_BUILTIN_NAME)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -536,4 +574,33 @@ mod tests {
         vm.segments.segment_used_sizes = Some(vec![4]);
         assert_eq!(builtin.get_used_cells(&vm.segments), Ok(4));
     }
+
+    #[test]
+    fn air_private_input() {
+        let builtin = HashBuiltinRunner::new(8, true);
+        // The second instance is missing its y input, so it's not included
+        let memory = memory![
+            ((0, 0), 1),
+            ((0, 1), 2),
+            ((0, 2), 3),
+            ((0, 3), 4),
+            ((0, 6), 5),
+            ((0, 7), 6)
+        ];
+        assert_eq!(
+            builtin.air_private_input(&memory),
+            vec![
+                PrivateInput::Pair(PrivateInputPair {
+                    index: 0,
+                    x: Felt::new(1),
+                    y: Felt::new(2)
+                }),
+                PrivateInput::Pair(PrivateInputPair {
+                    index: 2,
+                    x: Felt::new(5),
+                    y: Felt::new(6)
+                }),
+            ]
+        );
+    }
 }
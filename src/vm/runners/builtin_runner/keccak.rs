@@ -11,7 +11,8 @@ use felt::Felt;
 use num_integer::div_ceil;
 use num_traits::{One, ToPrimitive};
 
-use super::KECCAK_BUILTIN_NAME;
+use super::{get_instances_input_cells, KECCAK_BUILTIN_NAME};
+use crate::air_private_input::{PrivateInput, PrivateInputKeccakState};
 
 const KECCAK_ARRAY_LEN: usize = 25;
 
@@ -212,8 +213,7 @@ impl KeccakBuiltinRunner {
             self.stop_ptr = Some(stop_ptr);
             Ok(stop_pointer_addr)
         } else {
-            let stop_ptr = self.base;
-            self.stop_ptr = Some(stop_ptr);
+            self.stop_ptr = Some(0);
             Ok(pointer)
         }
     }
@@ -245,6 +245,30 @@ impl KeccakBuiltinRunner {
         // So the real number is 4 * 64 * 1024 = 262144.
         safe_div_usize(262144_usize, diluted_n_bits as usize).unwrap_or(0)
     }
+
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instances_input_cells(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::KeccakState(PrivateInputKeccakState {
+                index,
+                input_s0: inputs[0].clone(),
+                input_s1: inputs[1].clone(),
+                input_s2: inputs[2].clone(),
+                input_s3: inputs[3].clone(),
+                input_s4: inputs[4].clone(),
+                input_s5: inputs[5].clone(),
+                input_s6: inputs[6].clone(),
+                input_s7: inputs[7].clone(),
+            })
+        })
+        .collect()
+    }
 }
 
 #[cfg(test)]
@@ -1,26 +1,35 @@
+use crate::air_private_input::PrivateInput;
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::{self, MemoryError};
 use crate::vm::errors::runner_errors::RunnerError;
 use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::runners::cairo_pie::BuiltinAdditionalData;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+use felt::Felt;
 
 mod bitwise;
+mod custom;
 mod ec_op;
 mod hash;
 mod keccak;
 mod output;
+mod poseidon;
 mod range_check;
+mod segment_arena;
 mod signature;
 
 pub use self::keccak::KeccakBuiltinRunner;
 pub use bitwise::BitwiseBuiltinRunner;
+pub use custom::{CustomBuiltin, CustomBuiltinClone};
 pub use ec_op::EcOpBuiltinRunner;
 pub use hash::HashBuiltinRunner;
-use num_integer::div_floor;
-pub use output::OutputBuiltinRunner;
+use num_integer::{div_ceil, div_floor};
+pub use output::{OutputBuiltinRunner, OutputBuiltinState};
+pub use poseidon::PoseidonBuiltinRunner;
 pub use range_check::RangeCheckBuiltinRunner;
+pub use segment_arena::SegmentArenaBuiltinRunner;
 pub use signature::SignatureBuiltinRunner;
 
 pub const OUTPUT_BUILTIN_NAME: &str = "output";
@@ -30,6 +39,8 @@ pub const SIGNATURE_BUILTIN_NAME: &str = "ecdsa";
 pub const BITWISE_BUILTIN_NAME: &str = "bitwise";
 pub const EC_OP_BUILTIN_NAME: &str = "ec_op";
 pub const KECCAK_BUILTIN_NAME: &str = "keccak";
+pub const POSEIDON_BUILTIN_NAME: &str = "poseidon";
+pub const SEGMENT_ARENA_BUILTIN_NAME: &str = "segment_arena";
 
 /* NB: this enum is no accident: we may need (and cairo-rs-py *does* need)
  * structs containing this to be `Send`. The only two ways to achieve that
@@ -37,7 +48,8 @@ pub const KECCAK_BUILTIN_NAME: &str = "keccak";
  * making the type itself `Send`. We opted for not complicating the user nor
  * moving the guarantees to runtime by using an `enum` rather than a `Trait`.
  * This works under the assumption that we don't expect downstream users to
- * extend Cairo by adding new builtin runners.
+ * extend Cairo by adding new builtin runners. The exception are custom builtins,
+ * which are required to be `Send` by the `CustomBuiltin` trait.
  */
 #[derive(Debug, Clone)]
 pub enum BuiltinRunner {
@@ -48,6 +60,9 @@ pub enum BuiltinRunner {
     RangeCheck(RangeCheckBuiltinRunner),
     Keccak(KeccakBuiltinRunner),
     Signature(SignatureBuiltinRunner),
+    Poseidon(PoseidonBuiltinRunner),
+    SegmentArena(SegmentArenaBuiltinRunner),
+    Custom(Box<dyn CustomBuiltin>),
 }
 
 impl BuiltinRunner {
@@ -63,6 +78,11 @@ impl BuiltinRunner {
             }
             BuiltinRunner::Keccak(ref mut keccak) => keccak.initialize_segments(segments),
             BuiltinRunner::Signature(ref mut signature) => signature.initialize_segments(segments),
+            BuiltinRunner::Poseidon(ref mut poseidon) => poseidon.initialize_segments(segments),
+            BuiltinRunner::SegmentArena(ref mut segment_arena) => {
+                segment_arena.initialize_segments(segments)
+            }
+            BuiltinRunner::Custom(ref mut custom) => custom.initialize_segments(segments),
         }
     }
 
@@ -75,6 +95,9 @@ impl BuiltinRunner {
             BuiltinRunner::RangeCheck(ref range_check) => range_check.initial_stack(),
             BuiltinRunner::Keccak(ref keccak) => keccak.initial_stack(),
             BuiltinRunner::Signature(ref signature) => signature.initial_stack(),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.initial_stack(),
+            BuiltinRunner::SegmentArena(ref segment_arena) => segment_arena.initial_stack(),
+            BuiltinRunner::Custom(ref custom) => custom.initial_stack(),
         }
     }
 
@@ -96,6 +119,13 @@ impl BuiltinRunner {
             BuiltinRunner::Signature(ref mut signature) => {
                 signature.final_stack(segments, stack_pointer)
             }
+            BuiltinRunner::Poseidon(ref mut poseidon) => {
+                poseidon.final_stack(segments, stack_pointer)
+            }
+            BuiltinRunner::SegmentArena(ref mut segment_arena) => {
+                segment_arena.final_stack(segments, stack_pointer)
+            }
+            BuiltinRunner::Custom(ref mut custom) => custom.final_stack(segments, stack_pointer),
         }
     }
 
@@ -114,6 +144,11 @@ impl BuiltinRunner {
             }
             BuiltinRunner::Keccak(ref keccak) => keccak.get_allocated_memory_units(vm),
             BuiltinRunner::Signature(ref signature) => signature.get_allocated_memory_units(vm),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_allocated_memory_units(vm),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.get_allocated_memory_units(vm)
+            }
+            BuiltinRunner::Custom(ref custom) => custom.get_allocated_memory_units(vm),
         }
     }
 
@@ -127,6 +162,9 @@ impl BuiltinRunner {
             BuiltinRunner::RangeCheck(ref range_check) => range_check.base(),
             BuiltinRunner::Keccak(ref keccak) => keccak.base(),
             BuiltinRunner::Signature(ref signature) => signature.base(),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.base(),
+            BuiltinRunner::SegmentArena(ref segment_arena) => segment_arena.base(),
+            BuiltinRunner::Custom(ref custom) => custom.base(),
         }
     }
 
@@ -139,6 +177,9 @@ impl BuiltinRunner {
             BuiltinRunner::RangeCheck(range_check) => Some(range_check.ratio()),
             BuiltinRunner::Keccak(keccak) => Some(keccak.ratio()),
             BuiltinRunner::Signature(ref signature) => Some(signature.ratio()),
+            BuiltinRunner::Poseidon(poseidon) => Some(poseidon.ratio()),
+            BuiltinRunner::SegmentArena(_) => None,
+            BuiltinRunner::Custom(custom) => custom.ratio(),
         }
     }
 
@@ -151,6 +192,11 @@ impl BuiltinRunner {
             BuiltinRunner::RangeCheck(ref range_check) => range_check.add_validation_rule(memory),
             BuiltinRunner::Keccak(ref keccak) => keccak.add_validation_rule(memory),
             BuiltinRunner::Signature(ref signature) => signature.add_validation_rule(memory),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.add_validation_rule(memory),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.add_validation_rule(memory)
+            }
+            BuiltinRunner::Custom(ref custom) => custom.add_validation_rule(memory),
         }
     }
 
@@ -171,6 +217,11 @@ impl BuiltinRunner {
             BuiltinRunner::Signature(ref signature) => {
                 signature.deduce_memory_cell(address, memory)
             }
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.deduce_memory_cell(address, memory),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.deduce_memory_cell(address, memory)
+            }
+            BuiltinRunner::Custom(ref custom) => custom.deduce_memory_cell(address, memory),
         }
     }
 
@@ -200,6 +251,11 @@ impl BuiltinRunner {
             }
             BuiltinRunner::Keccak(ref keccak) => keccak.get_memory_segment_addresses(),
             BuiltinRunner::Signature(ref signature) => signature.get_memory_segment_addresses(),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_memory_segment_addresses(),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.get_memory_segment_addresses()
+            }
+            BuiltinRunner::Custom(ref custom) => (custom.base(), custom.stop_ptr()),
         }
     }
 
@@ -212,6 +268,11 @@ impl BuiltinRunner {
             BuiltinRunner::RangeCheck(ref range_check) => range_check.get_used_cells(segments),
             BuiltinRunner::Keccak(ref keccak) => keccak.get_used_cells(segments),
             BuiltinRunner::Signature(ref signature) => signature.get_used_cells(segments),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_used_cells(segments),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.get_used_cells(segments)
+            }
+            BuiltinRunner::Custom(ref custom) => custom.get_used_cells(segments),
         }
     }
 
@@ -227,6 +288,11 @@ impl BuiltinRunner {
             BuiltinRunner::RangeCheck(ref range_check) => range_check.get_used_instances(segments),
             BuiltinRunner::Keccak(ref keccak) => keccak.get_used_instances(segments),
             BuiltinRunner::Signature(ref signature) => signature.get_used_instances(segments),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_used_instances(segments),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.get_used_instances(segments)
+            }
+            BuiltinRunner::Custom(ref custom) => custom.get_used_instances(segments),
         }
     }
 
@@ -271,6 +337,9 @@ impl BuiltinRunner {
             BuiltinRunner::Output(_) => 0,
             BuiltinRunner::Keccak(builtin) => builtin.cells_per_instance,
             BuiltinRunner::Signature(builtin) => builtin.cells_per_instance,
+            BuiltinRunner::Poseidon(builtin) => builtin.cells_per_instance,
+            BuiltinRunner::SegmentArena(builtin) => builtin.cells_per_instance,
+            BuiltinRunner::Custom(builtin) => builtin.cells_per_instance(),
         }
     }
 
@@ -283,6 +352,9 @@ impl BuiltinRunner {
             BuiltinRunner::Output(_) => 0,
             BuiltinRunner::Keccak(builtin) => builtin.n_input_cells,
             BuiltinRunner::Signature(builtin) => builtin.n_input_cells,
+            BuiltinRunner::Poseidon(builtin) => builtin.n_input_cells,
+            BuiltinRunner::SegmentArena(builtin) => builtin.n_input_cells,
+            BuiltinRunner::Custom(builtin) => builtin.n_input_cells(),
         }
     }
 
@@ -295,11 +367,14 @@ impl BuiltinRunner {
             BuiltinRunner::Output(_) => OUTPUT_BUILTIN_NAME,
             BuiltinRunner::Keccak(_) => KECCAK_BUILTIN_NAME,
             BuiltinRunner::Signature(_) => SIGNATURE_BUILTIN_NAME,
+            BuiltinRunner::Poseidon(_) => POSEIDON_BUILTIN_NAME,
+            BuiltinRunner::SegmentArena(_) => SEGMENT_ARENA_BUILTIN_NAME,
+            BuiltinRunner::Custom(custom) => custom.name(),
         }
     }
 
     pub fn run_security_checks(&self, vm: &VirtualMachine) -> Result<(), VirtualMachineError> {
-        if let BuiltinRunner::Output(_) = self {
+        if let BuiltinRunner::Output(_) | BuiltinRunner::SegmentArena(_) = self {
             return Ok(());
         }
         let cells_per_instance = self.cells_per_instance() as usize;
@@ -355,6 +430,9 @@ impl BuiltinRunner {
                 }
             }
         }
+        if let BuiltinRunner::Custom(custom) = self {
+            custom.run_security_checks(vm)?;
+        }
         Ok(())
     }
 
@@ -374,6 +452,64 @@ impl BuiltinRunner {
             BuiltinRunner::Signature(ref signature) => {
                 signature.get_used_cells_and_allocated_size(vm)
             }
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_used_cells_and_allocated_size(vm),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.get_used_cells_and_allocated_size(vm)
+            }
+            BuiltinRunner::Custom(ref custom) => custom.get_used_cells_and_allocated_size(vm),
+        }
+    }
+
+    /// Returns the witness data of each builtin instance, to be included in the AIR private input.
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        match self {
+            BuiltinRunner::Bitwise(ref bitwise) => bitwise.air_private_input(memory),
+            BuiltinRunner::EcOp(ref ec) => ec.air_private_input(memory),
+            BuiltinRunner::Hash(ref hash) => hash.air_private_input(memory),
+            BuiltinRunner::Output(_) => vec![],
+            BuiltinRunner::RangeCheck(ref range_check) => range_check.air_private_input(memory),
+            BuiltinRunner::Keccak(ref keccak) => keccak.air_private_input(memory),
+            BuiltinRunner::Signature(ref signature) => signature.air_private_input(memory),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.air_private_input(memory),
+            BuiltinRunner::SegmentArena(_) => vec![],
+            BuiltinRunner::Custom(_) => vec![],
+        }
+    }
+
+    /// Returns the data needed to re-run the builtin from a CairoPie, besides its memory segment.
+    pub fn get_additional_data(&self) -> BuiltinAdditionalData {
+        match self {
+            BuiltinRunner::Hash(ref hash) => hash.get_additional_data(),
+            BuiltinRunner::Output(ref output) => output.get_additional_data(),
+            BuiltinRunner::Signature(ref signature) => signature.get_additional_data(),
+            _ => BuiltinAdditionalData::None,
+        }
+    }
+
+    /// Returns the public memory of the builtin segment as (offset, page_id) pairs, for the
+    /// builtins whose memory is public.
+    pub fn get_public_memory(
+        &self,
+        segments: &MemorySegmentManager,
+    ) -> Result<Option<Vec<(usize, usize)>>, RunnerError> {
+        match self {
+            BuiltinRunner::Output(ref output) => output.get_public_memory(segments).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Loads the additional data of a CairoPie into the builtin before re-running it.
+    pub fn extend_additional_data(
+        &mut self,
+        additional_data: &BuiltinAdditionalData,
+    ) -> Result<(), RunnerError> {
+        match self {
+            BuiltinRunner::Hash(ref mut hash) => hash.extend_additional_data(additional_data),
+            BuiltinRunner::Output(ref mut output) => output.extend_additional_data(additional_data),
+            BuiltinRunner::Signature(ref mut signature) => {
+                signature.extend_additional_data(additional_data)
+            }
+            _ => Ok(()),
         }
     }
 
@@ -387,10 +523,49 @@ impl BuiltinRunner {
             BuiltinRunner::RangeCheck(ref mut range_check) => range_check.stop_ptr = Some(stop_ptr),
             BuiltinRunner::Keccak(ref mut keccak) => keccak.stop_ptr = Some(stop_ptr),
             BuiltinRunner::Signature(ref mut signature) => signature.stop_ptr = Some(stop_ptr),
+            BuiltinRunner::Poseidon(ref mut poseidon) => poseidon.stop_ptr = Some(stop_ptr),
+            BuiltinRunner::SegmentArena(ref mut segment_arena) => {
+                segment_arena.stop_ptr = Some(stop_ptr)
+            }
+            BuiltinRunner::Custom(ref mut custom) => custom.set_stop_ptr(stop_ptr),
         }
     }
 }
 
+// Returns the index and input cells of every instance in the builtin segment whose input cells
+// are all integers. Used to build the AIR private input.
+pub(crate) fn get_instances_input_cells(
+    memory: &Memory,
+    base: usize,
+    cells_per_instance: u32,
+    n_input_cells: u32,
+) -> Vec<(usize, Vec<Felt>)> {
+    let segment_len = memory.data.get(base).map(Vec::len).unwrap_or(0);
+    let cells_per_instance = cells_per_instance as usize;
+    (0..div_ceil(segment_len, cells_per_instance))
+        .filter_map(|index| {
+            let inputs = (0..n_input_cells as usize)
+                .map(|i| {
+                    memory
+                        .get_integer(Relocatable::from((
+                            base as isize,
+                            index * cells_per_instance + i,
+                        )))
+                        .ok()
+                        .map(|value| value.into_owned())
+                })
+                .collect::<Option<Vec<Felt>>>()?;
+            Some((index, inputs))
+        })
+        .collect()
+}
+
+impl From<Box<dyn CustomBuiltin>> for BuiltinRunner {
+    fn from(runner: Box<dyn CustomBuiltin>) -> Self {
+        BuiltinRunner::Custom(runner)
+    }
+}
+
 impl From<KeccakBuiltinRunner> for BuiltinRunner {
     fn from(runner: KeccakBuiltinRunner) -> Self {
         BuiltinRunner::Keccak(runner)
@@ -433,13 +608,27 @@ impl From<SignatureBuiltinRunner> for BuiltinRunner {
     }
 }
 
+impl From<PoseidonBuiltinRunner> for BuiltinRunner {
+    fn from(runner: PoseidonBuiltinRunner) -> Self {
+        BuiltinRunner::Poseidon(runner)
+    }
+}
+
+impl From<SegmentArenaBuiltinRunner> for BuiltinRunner {
+    fn from(runner: SegmentArenaBuiltinRunner) -> Self {
+        BuiltinRunner::SegmentArena(runner)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::air_private_input::PrivateInputPair;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
     use crate::relocatable;
     use crate::types::instance_definitions::ecdsa_instance_def::EcdsaInstanceDef;
     use crate::types::instance_definitions::keccak_instance_def::KeccakInstanceDef;
+    use crate::types::instance_definitions::poseidon_instance_def::PoseidonInstanceDef;
     use crate::types::program::Program;
     use crate::vm::runners::cairo_runner::CairoRunner;
     use crate::{
@@ -582,6 +771,20 @@ mod tests {
         assert_eq!(keccak.cells_per_instance, builtin.cells_per_instance())
     }
 
+    #[test]
+    fn get_cells_per_instance_poseidon() {
+        let poseidon = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        let builtin: BuiltinRunner = poseidon.clone().into();
+        assert_eq!(poseidon.cells_per_instance, builtin.cells_per_instance())
+    }
+
+    #[test]
+    fn get_n_input_cells_poseidon() {
+        let poseidon = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        let builtin: BuiltinRunner = poseidon.clone().into();
+        assert_eq!(poseidon.n_input_cells, builtin.n_input_cells())
+    }
+
     #[test]
     fn get_name_bitwise() {
         let bitwise = BitwiseBuiltinRunner::new(&BitwiseInstanceDef::new(10), true);
@@ -624,6 +827,13 @@ mod tests {
         assert_eq!(OUTPUT_BUILTIN_NAME, builtin.name())
     }
 
+    #[test]
+    fn get_name_poseidon() {
+        let poseidon = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        let builtin: BuiltinRunner = poseidon.into();
+        assert_eq!(POSEIDON_BUILTIN_NAME, builtin.name())
+    }
+
     #[test]
     fn get_allocated_memory_units_bitwise_with_items() {
         let builtin = BuiltinRunner::Bitwise(BitwiseBuiltinRunner::new(
@@ -1464,4 +1674,47 @@ mod tests {
             assert_eq!(stop_ptr, Some(ptr));
         }
     }
+
+    #[test]
+    fn air_private_input_output_builtin() {
+        let builtin: BuiltinRunner = OutputBuiltinRunner::new(true).into();
+        let memory = memory![((0, 0), 1), ((0, 1), 2)];
+        assert!(builtin.air_private_input(&memory).is_empty());
+    }
+
+    #[test]
+    fn air_private_input_bitwise() {
+        let builtin: BuiltinRunner =
+            BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true).into();
+        let memory = memory![
+            ((0, 0), 12),
+            ((0, 1), 10),
+            ((0, 2), 8),
+            ((0, 3), 6),
+            ((0, 4), 14)
+        ];
+        assert_eq!(
+            builtin.air_private_input(&memory),
+            vec![PrivateInput::Pair(PrivateInputPair {
+                index: 0,
+                x: Felt::new(12),
+                y: Felt::new(10)
+            })]
+        );
+    }
+
+    #[test]
+    fn get_instances_input_cells_skips_incomplete_instances() {
+        let memory = memory![
+            ((0, 0), 1),
+            ((0, 1), 2),
+            ((0, 3), 3),
+            ((0, 6), 4),
+            ((0, 7), (1, 0))
+        ];
+        assert_eq!(
+            get_instances_input_cells(&memory, 0, 3, 2),
+            vec![(0, vec![Felt::new(1), Felt::new(2)])]
+        );
+    }
 }
@@ -1,15 +1,30 @@
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::MemoryError;
 use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::runners::cairo_pie::{
+    Attributes, BuiltinAdditionalData, OutputBuiltinAdditionalData, Pages, PublicMemoryPage,
+};
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+use std::collections::HashMap;
 
 use super::OUTPUT_BUILTIN_NAME;
 
+/// Snapshot of an output builtin, used to split the output of a program into the outputs
+/// of its tasks (see `get_state` and `set_state`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputBuiltinState {
+    pub base: usize,
+    pub pages: Pages,
+    pub attributes: Attributes,
+}
+
 #[derive(Debug, Clone)]
 pub struct OutputBuiltinRunner {
     base: usize,
+    pub(crate) pages: Pages,
+    pub(crate) attributes: Attributes,
     pub(crate) stop_ptr: Option<usize>,
     pub(crate) included: bool,
 }
@@ -18,11 +33,88 @@ impl OutputBuiltinRunner {
     pub fn new(included: bool) -> OutputBuiltinRunner {
         OutputBuiltinRunner {
             base: 0,
+            pages: HashMap::default(),
+            attributes: HashMap::default(),
             stop_ptr: None,
             included,
         }
     }
 
+    pub fn get_state(&self) -> OutputBuiltinState {
+        OutputBuiltinState {
+            base: self.base,
+            pages: self.pages.clone(),
+            attributes: self.attributes.clone(),
+        }
+    }
+
+    pub fn set_state(&mut self, state: OutputBuiltinState) {
+        self.base = state.base;
+        self.pages = state.pages;
+        self.attributes = state.attributes;
+    }
+
+    /// Starts a fresh output on segment `base`, with no pages or attributes.
+    pub fn new_state(&mut self, base: usize) {
+        self.base = base;
+        self.pages = HashMap::default();
+        self.attributes = HashMap::default();
+        self.stop_ptr = None;
+    }
+
+    /// Marks the `page_size` cells starting at `page_start` as public memory page `page_id`.
+    pub fn add_page(
+        &mut self,
+        page_id: usize,
+        page_start: Relocatable,
+        page_size: usize,
+    ) -> Result<(), RunnerError> {
+        if page_start.segment_index != self.base as isize {
+            return Err(RunnerError::PageNotOnSegment(page_start, self.base));
+        }
+        if self.pages.contains_key(&page_id) {
+            return Err(RunnerError::PageAlreadyUsed(page_id));
+        }
+        self.pages.insert(
+            page_id,
+            PublicMemoryPage {
+                start: page_start.offset,
+                size: page_size,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn add_attribute(&mut self, name: &str, value: Vec<usize>) -> Result<(), RunnerError> {
+        if self.attributes.contains_key(name) {
+            return Err(RunnerError::DuplicateAttribute(name.to_string()));
+        }
+        self.attributes.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    /// Returns the (offset, page_id) pairs of the output segment. Cells outside of every page
+    /// belong to page 0.
+    pub fn get_public_memory(
+        &self,
+        segments: &MemorySegmentManager,
+    ) -> Result<Vec<(usize, usize)>, RunnerError> {
+        let size = self.get_used_cells(segments)?;
+        let mut public_memory: Vec<(usize, usize)> = (0..size).map(|i| (i, 0)).collect();
+        for (page_id, page) in self.pages.iter() {
+            for offset in page.start..page.start + page.size {
+                let entry = public_memory
+                    .get_mut(offset)
+                    .ok_or(RunnerError::PageOutOfBounds(*page_id, offset))?;
+                if entry.1 != 0 {
+                    return Err(RunnerError::OffsetAlreadyInPage(offset));
+                }
+                entry.1 = *page_id;
+            }
+        }
+        Ok(public_memory)
+    }
+
     pub fn initialize_segments(&mut self, segments: &mut MemorySegmentManager) {
         self.base = segments.add().segment_index as usize // segments.add() always returns a positive index
     }
@@ -110,11 +202,31 @@ impl OutputBuiltinRunner {
             self.stop_ptr = Some(stop_ptr);
             Ok(stop_pointer_addr)
         } else {
-            let stop_ptr = self.base;
-            self.stop_ptr = Some(stop_ptr);
+            self.stop_ptr = Some(0);
             Ok(pointer)
         }
     }
+
+    pub fn get_additional_data(&self) -> BuiltinAdditionalData {
+        BuiltinAdditionalData::Output(OutputBuiltinAdditionalData {
+            pages: self.pages.clone(),
+            attributes: self.attributes.clone(),
+        })
+    }
+
+    pub fn extend_additional_data(
+        &mut self,
+        additional_data: &BuiltinAdditionalData,
+    ) -> Result<(), RunnerError> {
+        match additional_data {
+            BuiltinAdditionalData::Output(data) => {
+                self.pages.extend(data.pages.clone());
+                self.attributes.extend(data.attributes.clone());
+                Ok(())
+            }
+            _ => Err(RunnerError::InvalidAdditionalData(OUTPUT_BUILTIN_NAME)),
+        }
+    }
 }
 
 impl Default for OutputBuiltinRunner {
@@ -416,4 +528,123 @@ mod tests {
         vm.segments.segment_used_sizes = Some(vec![0]);
         builtin.add_validation_rule(&mut vm.segments.memory);
     }
+
+    #[test]
+    fn add_page() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        assert_eq!(builtin.add_page(1, Relocatable::from((0, 2)), 3), Ok(()));
+        assert_eq!(
+            builtin.pages,
+            HashMap::from([(1, PublicMemoryPage { start: 2, size: 3 })])
+        );
+    }
+
+    #[test]
+    fn add_page_wrong_segment() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        assert_eq!(
+            builtin.add_page(1, Relocatable::from((1, 0)), 3),
+            Err(RunnerError::PageNotOnSegment(Relocatable::from((1, 0)), 0))
+        );
+    }
+
+    #[test]
+    fn add_page_already_used() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, Relocatable::from((0, 0)), 3).unwrap();
+        assert_eq!(
+            builtin.add_page(1, Relocatable::from((0, 3)), 3),
+            Err(RunnerError::PageAlreadyUsed(1))
+        );
+    }
+
+    #[test]
+    fn add_attribute() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        assert_eq!(
+            builtin.add_attribute("gps_fact_topology", vec![2, 1, 0, 2]),
+            Ok(())
+        );
+        assert_eq!(
+            builtin.add_attribute("gps_fact_topology", vec![1]),
+            Err(RunnerError::DuplicateAttribute(
+                "gps_fact_topology".to_string()
+            ))
+        );
+        assert_eq!(
+            builtin.attributes,
+            HashMap::from([("gps_fact_topology".to_string(), vec![2, 1, 0, 2])])
+        );
+    }
+
+    #[test]
+    fn get_and_set_state() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, Relocatable::from((0, 0)), 3).unwrap();
+        builtin.add_attribute("attribute", vec![1]).unwrap();
+        let state = builtin.get_state();
+
+        builtin.new_state(4);
+        assert_eq!(
+            builtin.get_state(),
+            OutputBuiltinState {
+                base: 4,
+                pages: HashMap::new(),
+                attributes: HashMap::new(),
+            }
+        );
+
+        builtin.set_state(state.clone());
+        assert_eq!(builtin.base(), 0);
+        assert_eq!(builtin.get_state(), state);
+    }
+
+    #[test]
+    fn get_public_memory() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, Relocatable::from((0, 1)), 2).unwrap();
+        builtin.add_page(2, Relocatable::from((0, 3)), 1).unwrap();
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![5]);
+        assert_eq!(
+            builtin.get_public_memory(&vm.segments),
+            Ok(vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 0)])
+        );
+    }
+
+    #[test]
+    fn get_public_memory_overlapping_pages() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, Relocatable::from((0, 0)), 2).unwrap();
+        builtin.add_page(2, Relocatable::from((0, 1)), 2).unwrap();
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![4]);
+        assert_eq!(
+            builtin.get_public_memory(&vm.segments),
+            Err(RunnerError::OffsetAlreadyInPage(1))
+        );
+    }
+
+    #[test]
+    fn get_public_memory_page_out_of_bounds() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, Relocatable::from((0, 2)), 2).unwrap();
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![3]);
+        assert_eq!(
+            builtin.get_public_memory(&vm.segments),
+            Err(RunnerError::PageOutOfBounds(1, 3))
+        );
+    }
+
+    #[test]
+    fn extend_and_get_additional_data() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        let additional_data = BuiltinAdditionalData::Output(OutputBuiltinAdditionalData {
+            pages: HashMap::from([(1, PublicMemoryPage { start: 0, size: 2 })]),
+            attributes: HashMap::from([("attribute".to_string(), vec![1, 2])]),
+        });
+        assert_eq!(builtin.extend_additional_data(&additional_data), Ok(()));
+        assert_eq!(builtin.get_additional_data(), additional_data);
+    }
 }
@@ -0,0 +1,461 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::math_utils::safe_div_usize;
+use crate::poseidon_hash::poseidon_permute;
+use crate::types::instance_definitions::poseidon_instance_def::{
+    PoseidonInstanceDef, CELLS_PER_POSEIDON, INPUT_CELLS_PER_POSEIDON,
+};
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::memory_errors::{InsufficientAllocatedCellsError, MemoryError};
+use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::vm_core::VirtualMachine;
+use crate::vm::vm_memory::memory::Memory;
+use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+use felt::Felt;
+use num_integer::div_ceil;
+
+use super::{get_instances_input_cells, POSEIDON_BUILTIN_NAME};
+use crate::air_private_input::{PrivateInput, PrivateInputPoseidonState};
+
+#[derive(Debug, Clone)]
+pub struct PoseidonBuiltinRunner {
+    pub base: usize,
+    ratio: u32,
+    pub(crate) cells_per_instance: u32,
+    pub(crate) n_input_cells: u32,
+    pub(crate) stop_ptr: Option<usize>,
+    pub(crate) included: bool,
+    instances_per_component: u32,
+    // Maps each output cell to its deduced value, as all three outputs of an instance
+    // are computed at once. Needs interior mutability as it's filled by deduce_memory_cell
+    cache: RefCell<HashMap<Relocatable, Felt>>,
+}
+
+impl PoseidonBuiltinRunner {
+    pub(crate) fn new(instance_def: &PoseidonInstanceDef, included: bool) -> Self {
+        PoseidonBuiltinRunner {
+            base: 0,
+            ratio: instance_def.ratio,
+            cells_per_instance: CELLS_PER_POSEIDON,
+            n_input_cells: INPUT_CELLS_PER_POSEIDON,
+            stop_ptr: None,
+            included,
+            instances_per_component: 1,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn initialize_segments(&mut self, segments: &mut MemorySegmentManager) {
+        self.base = segments.add().segment_index as usize // segments.add() always returns a positive index
+    }
+
+    pub fn initial_stack(&self) -> Vec<MaybeRelocatable> {
+        if self.included {
+            vec![MaybeRelocatable::from((self.base as isize, 0))]
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn base(&self) -> usize {
+        self.base
+    }
+
+    pub fn ratio(&self) -> u32 {
+        self.ratio
+    }
+
+    pub fn add_validation_rule(&self, _memory: &mut Memory) {}
+
+    pub fn deduce_memory_cell(
+        &self,
+        address: Relocatable,
+        memory: &Memory,
+    ) -> Result<Option<MaybeRelocatable>, RunnerError> {
+        let index = address.offset % self.cells_per_instance as usize;
+        if index < self.n_input_cells as usize {
+            return Ok(None);
+        }
+        if let Some(felt) = self.cache.borrow().get(&address) {
+            return Ok(Some(felt.into()));
+        }
+        let first_input_addr = address
+            .sub_usize(index)
+            .map_err(|_| RunnerError::PoseidonNoFirstInput)?;
+        let first_output_addr = first_input_addr + self.n_input_cells as usize;
+
+        let mut input_felts = Vec::<Felt>::with_capacity(self.n_input_cells as usize);
+        for i in 0..self.n_input_cells as usize {
+            let val = match memory.get(&(first_input_addr + i)) {
+                Some(value) => value
+                    .as_ref()
+                    .get_int_ref()
+                    .ok_or(RunnerError::PoseidonInputCellsNotFelts)?
+                    .clone(),
+                None => return Ok(None),
+            };
+            input_felts.push(val)
+        }
+        let mut state: [Felt; 3] = input_felts
+            .try_into()
+            .map_err(|_| RunnerError::SliceToArrayError)?;
+        poseidon_permute(&mut state);
+        for (i, elem) in state.into_iter().enumerate() {
+            self.cache.borrow_mut().insert(first_output_addr + i, elem);
+        }
+        Ok(self.cache.borrow().get(&address).map(|x| x.into()))
+    }
+
+    pub fn get_allocated_memory_units(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        let value = safe_div_usize(vm.current_step, self.ratio as usize)
+            .map_err(|_| MemoryError::ErrorCalculatingMemoryUnits)?;
+        Ok(self.cells_per_instance as usize * value)
+    }
+
+    pub fn get_memory_segment_addresses(&self) -> (usize, Option<usize>) {
+        (self.base, self.stop_ptr)
+    }
+
+    pub fn get_used_cells(&self, segments: &MemorySegmentManager) -> Result<usize, MemoryError> {
+        segments
+            .get_segment_used_size(self.base())
+            .ok_or(MemoryError::MissingSegmentUsedSizes)
+    }
+
+    pub fn get_used_cells_and_allocated_size(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<(usize, usize), MemoryError> {
+        let ratio = self.ratio as usize;
+        let min_step = ratio * self.instances_per_component as usize;
+        if vm.current_step < min_step {
+            Err(
+                InsufficientAllocatedCellsError::MinStepNotReached(min_step, POSEIDON_BUILTIN_NAME)
+                    .into(),
+            )
+        } else {
+            let used = self.get_used_cells(&vm.segments)?;
+            let size = self.cells_per_instance as usize
+                * safe_div_usize(vm.current_step, ratio).map_err(|_| {
+                    InsufficientAllocatedCellsError::CurrentStepNotDivisibleByBuiltinRatio(
+                        POSEIDON_BUILTIN_NAME,
+                        vm.current_step,
+                        ratio,
+                    )
+                })?;
+            if used > size {
+                return Err(InsufficientAllocatedCellsError::BuiltinCells(
+                    POSEIDON_BUILTIN_NAME,
+                    used,
+                    size,
+                )
+                .into());
+            }
+            Ok((used, size))
+        }
+    }
+
+    pub fn get_used_instances(
+        &self,
+        segments: &MemorySegmentManager,
+    ) -> Result<usize, MemoryError> {
+        let used_cells = self.get_used_cells(segments)?;
+        Ok(div_ceil(used_cells, self.cells_per_instance as usize))
+    }
+
+    pub fn final_stack(
+        &mut self,
+        segments: &MemorySegmentManager,
+        pointer: Relocatable,
+    ) -> Result<Relocatable, RunnerError> {
+        if self.included {
+            let stop_pointer_addr = pointer
+                .sub_usize(1)
+                .map_err(|_| RunnerError::NoStopPointer(POSEIDON_BUILTIN_NAME))?;
+            let stop_pointer = segments
+                .memory
+                .get_relocatable(stop_pointer_addr)
+                .map_err(|_| RunnerError::NoStopPointer(POSEIDON_BUILTIN_NAME))?;
+            if self.base as isize != stop_pointer.segment_index {
+                return Err(RunnerError::InvalidStopPointerIndex(
+                    POSEIDON_BUILTIN_NAME,
+                    stop_pointer,
+                    self.base,
+                ));
+            }
+            let stop_ptr = stop_pointer.offset;
+            let num_instances = self.get_used_instances(segments)?;
+            let used = num_instances * self.cells_per_instance as usize;
+            if stop_ptr != used {
+                return Err(RunnerError::InvalidStopPointer(
+                    POSEIDON_BUILTIN_NAME,
+                    Relocatable::from((self.base as isize, used)),
+                    Relocatable::from((self.base as isize, stop_ptr)),
+                ));
+            }
+            self.stop_ptr = Some(stop_ptr);
+            Ok(stop_pointer_addr)
+        } else {
+            self.stop_ptr = Some(0);
+            Ok(pointer)
+        }
+    }
+
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instances_input_cells(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::PoseidonState(PrivateInputPoseidonState {
+                index,
+                input_s0: inputs[0].clone(),
+                input_s1: inputs[1].clone(),
+                input_s2: inputs[2].clone(),
+            })
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::vm_memory::memory::Memory;
+    use crate::vm::{
+        errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+        runners::builtin_runner::BuiltinRunner,
+        vm_core::VirtualMachine,
+    };
+    use felt::felt_str;
+    use std::collections::HashMap;
+
+    #[test]
+    fn get_used_instances() {
+        let builtin: BuiltinRunner =
+            PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), true).into();
+
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![1]);
+
+        assert_eq!(builtin.get_used_instances(&vm.segments), Ok(1));
+    }
+
+    #[test]
+    fn final_stack() {
+        let mut builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), true);
+
+        let mut vm = vm!();
+
+        vm.segments = segments![
+            ((0, 0), (0, 0)),
+            ((0, 1), (0, 1)),
+            ((2, 0), (0, 0)),
+            ((2, 1), (0, 0))
+        ];
+
+        vm.segments.segment_used_sizes = Some(vec![0]);
+
+        let pointer = Relocatable::from((2, 2));
+
+        assert_eq!(
+            builtin.final_stack(&vm.segments, pointer).unwrap(),
+            Relocatable::from((2, 1))
+        );
+    }
+
+    #[test]
+    fn final_stack_error_stop_pointer() {
+        let mut builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), true);
+
+        let mut vm = vm!();
+
+        vm.segments = segments![
+            ((0, 0), (0, 0)),
+            ((0, 1), (0, 1)),
+            ((2, 0), (0, 0)),
+            ((2, 1), (0, 0))
+        ];
+
+        vm.segments.segment_used_sizes = Some(vec![996]);
+
+        let pointer = Relocatable::from((2, 2));
+
+        assert_eq!(
+            builtin.final_stack(&vm.segments, pointer),
+            Err(RunnerError::InvalidStopPointer(
+                POSEIDON_BUILTIN_NAME,
+                relocatable!(0, 996),
+                relocatable!(0, 0)
+            ))
+        );
+    }
+
+    #[test]
+    fn final_stack_error_when_not_included() {
+        let mut builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), false);
+
+        let mut vm = vm!();
+
+        vm.segments = segments![
+            ((0, 0), (0, 0)),
+            ((0, 1), (0, 1)),
+            ((2, 0), (0, 0)),
+            ((2, 1), (0, 0))
+        ];
+
+        vm.segments.segment_used_sizes = Some(vec![0]);
+
+        let pointer = Relocatable::from((2, 2));
+
+        assert_eq!(
+            builtin.final_stack(&vm.segments, pointer).unwrap(),
+            Relocatable::from((2, 2))
+        );
+    }
+
+    #[test]
+    fn final_stack_error_non_relocatable() {
+        let mut builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), true);
+
+        let mut vm = vm!();
+
+        vm.segments = segments![
+            ((0, 0), (0, 0)),
+            ((0, 1), (0, 1)),
+            ((2, 0), (0, 0)),
+            ((2, 1), 2)
+        ];
+
+        vm.segments.segment_used_sizes = Some(vec![0]);
+
+        let pointer = Relocatable::from((2, 2));
+
+        assert_eq!(
+            builtin.final_stack(&vm.segments, pointer),
+            Err(RunnerError::NoStopPointer(POSEIDON_BUILTIN_NAME))
+        );
+    }
+
+    #[test]
+    fn deduce_memory_cell_poseidon_for_preset_memory_valid() {
+        let memory = memory![((0, 0), 1), ((0, 1), 2), ((0, 2), 3)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+
+        let expected_outputs = [
+            felt_str!(
+                "442682200349489646213731521593476982257703159825582578145778919623645026501"
+            ),
+            felt_str!(
+                "2233832504250924383748553933071188903279928981104663696710686541536735838182"
+            ),
+            felt_str!(
+                "2512222140811166287287541003826449032093371832913959128171347018667852712082"
+            ),
+        ];
+        for (i, expected) in expected_outputs.into_iter().enumerate() {
+            assert_eq!(
+                builtin.deduce_memory_cell(Relocatable::from((0, 3 + i)), &memory),
+                Ok(Some(MaybeRelocatable::from(expected)))
+            );
+        }
+    }
+
+    #[test]
+    fn deduce_memory_cell_poseidon_for_input_cell() {
+        let memory = memory![((0, 0), 1), ((0, 1), 2), ((0, 2), 3)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        let result = builtin.deduce_memory_cell(Relocatable::from((0, 1)), &memory);
+        assert_eq!(result, Ok(None));
+        assert!(builtin.cache.borrow().is_empty());
+    }
+
+    #[test]
+    fn deduce_memory_cell_poseidon_missing_input() {
+        let memory = memory![((0, 0), 1), ((0, 1), 2)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        let result = builtin.deduce_memory_cell(Relocatable::from((0, 4)), &memory);
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn deduce_memory_cell_poseidon_relocatable_input() {
+        let memory = memory![((0, 0), 1), ((0, 1), (1, 2)), ((0, 2), 3)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        let result = builtin.deduce_memory_cell(Relocatable::from((0, 4)), &memory);
+        assert_eq!(result, Err(RunnerError::PoseidonInputCellsNotFelts));
+    }
+
+    #[test]
+    fn deduce_memory_cell_poseidon_already_computed() {
+        let memory = memory![((0, 0), 1)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        builtin
+            .cache
+            .borrow_mut()
+            .insert(Relocatable::from((0, 4)), Felt::new(7));
+        let result = builtin.deduce_memory_cell(Relocatable::from((0, 4)), &memory);
+        assert_eq!(result, Ok(Some(MaybeRelocatable::from(Felt::new(7)))));
+    }
+
+    #[test]
+    fn get_memory_segment_addresses() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+
+        assert_eq!(builtin.get_memory_segment_addresses(), (0, None),);
+    }
+
+    #[test]
+    fn get_used_cells_missing_segment_used_sizes() {
+        let builtin = BuiltinRunner::Poseidon(PoseidonBuiltinRunner::new(
+            &PoseidonInstanceDef::default(),
+            true,
+        ));
+        let vm = vm!();
+
+        assert_eq!(
+            builtin.get_used_cells(&vm.segments),
+            Err(MemoryError::MissingSegmentUsedSizes)
+        );
+    }
+
+    #[test]
+    fn get_used_cells() {
+        let builtin = BuiltinRunner::Poseidon(PoseidonBuiltinRunner::new(
+            &PoseidonInstanceDef::default(),
+            true,
+        ));
+        let mut vm = vm!();
+
+        vm.segments.segment_used_sizes = Some(vec![4]);
+        assert_eq!(builtin.get_used_cells(&vm.segments), Ok(4));
+    }
+
+    #[test]
+    fn get_used_cells_and_allocated_size_min_step_not_reached() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(32), true);
+        let mut vm = vm!();
+        vm.current_step = 8;
+        assert_eq!(
+            builtin.get_used_cells_and_allocated_size(&vm),
+            Err(MemoryError::InsufficientAllocatedCells(
+                InsufficientAllocatedCellsError::MinStepNotReached(32, POSEIDON_BUILTIN_NAME)
+            ))
+        );
+    }
+
+    #[test]
+    fn get_used_cells_and_allocated_size() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(32), true);
+        let mut vm = vm!();
+        vm.current_step = 64;
+        vm.segments.segment_used_sizes = Some(vec![6]);
+        assert_eq!(builtin.get_used_cells_and_allocated_size(&vm), Ok((6, 12)));
+    }
+}
@@ -24,7 +24,8 @@ use std::{
     ops::Shl,
 };
 
-use super::RANGE_CHECK_BUILTIN_NAME;
+use super::{get_instances_input_cells, RANGE_CHECK_BUILTIN_NAME};
+use crate::air_private_input::{PrivateInput, PrivateInputValue};
 
 #[derive(Debug, Clone)]
 pub struct RangeCheckBuiltinRunner {
@@ -228,8 +229,7 @@ impl RangeCheckBuiltinRunner {
             self.stop_ptr = Some(stop_ptr);
             Ok(stop_pointer_addr)
         } else {
-            let stop_ptr = self.base;
-            self.stop_ptr = Some(stop_ptr);
+            self.stop_ptr = Some(0);
             Ok(pointer)
         }
     }
@@ -242,6 +242,23 @@ impl RangeCheckBuiltinRunner {
         let (used_cells, _) = self.get_used_cells_and_allocated_size(vm)?;
         Ok(used_cells * self.n_parts as usize)
     }
+
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instances_input_cells(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::Value(PrivateInputValue {
+                index,
+                value: inputs[0].clone(),
+            })
+        })
+        .collect()
+    }
 }
 
 #[cfg(test)]
@@ -609,4 +626,27 @@ mod tests {
         vm.segments.segment_used_sizes = Some(vec![1]);
         assert_eq!(builtin_runner.get_used_perm_range_check_units(&vm), Ok(8));
     }
+
+    #[test]
+    fn air_private_input() {
+        let builtin = RangeCheckBuiltinRunner::new(8, 8, true);
+        let memory = memory![((0, 0), 5), ((0, 1), 18), ((0, 3), 27)];
+        assert_eq!(
+            builtin.air_private_input(&memory),
+            vec![
+                PrivateInput::Value(PrivateInputValue {
+                    index: 0,
+                    value: Felt::new(5)
+                }),
+                PrivateInput::Value(PrivateInputValue {
+                    index: 1,
+                    value: Felt::new(18)
+                }),
+                PrivateInput::Value(PrivateInputValue {
+                    index: 3,
+                    value: Felt::new(27)
+                }),
+            ]
+        );
+    }
 }
@@ -0,0 +1,305 @@
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::memory_errors::MemoryError;
+use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::vm_core::VirtualMachine;
+use crate::vm::vm_memory::memory::Memory;
+use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+use felt::Felt;
+use num_integer::div_ceil;
+use num_traits::Zero;
+
+use super::SEGMENT_ARENA_BUILTIN_NAME;
+
+pub(crate) const ARENA_BUILTIN_SIZE: u32 = 3;
+// The size of the builtin segment at the time of its creation.
+const INITIAL_SEGMENT_SIZE: usize = ARENA_BUILTIN_SIZE as usize;
+
+/// Builtin used by Cairo 1 programs to keep track of the segments of their dictionaries.
+///
+/// Each instance holds three cells: a pointer to the segment of dictionary infos, the number
+/// of dictionary segments created, and the number of them that have been finalized. The
+/// segment starts with an initial instance, pointing to an empty info segment, and the
+/// builtin's pointer is placed right after it.
+#[derive(Debug, Clone)]
+pub struct SegmentArenaBuiltinRunner {
+    base: Relocatable,
+    pub(crate) cells_per_instance: u32,
+    pub(crate) n_input_cells: u32,
+    pub(crate) stop_ptr: Option<usize>,
+    pub(crate) included: bool,
+}
+
+impl SegmentArenaBuiltinRunner {
+    pub fn new(included: bool) -> Self {
+        SegmentArenaBuiltinRunner {
+            base: Relocatable::from((0, 0)),
+            cells_per_instance: ARENA_BUILTIN_SIZE,
+            n_input_cells: ARENA_BUILTIN_SIZE,
+            stop_ptr: None,
+            included,
+        }
+    }
+
+    pub fn initialize_segments(&mut self, segments: &mut MemorySegmentManager) {
+        let info = vec![
+            MaybeRelocatable::from(segments.add()),
+            MaybeRelocatable::from(Felt::zero()),
+            MaybeRelocatable::from(Felt::zero()),
+        ];
+        let segment_start = segments.add();
+        // Writing to a segment that was just added can't fail
+        let _ = segments.load_data(&segment_start.into(), &info);
+        self.base = segment_start + INITIAL_SEGMENT_SIZE;
+    }
+
+    pub fn initial_stack(&self) -> Vec<MaybeRelocatable> {
+        if self.included {
+            vec![MaybeRelocatable::from(self.base)]
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn base(&self) -> usize {
+        self.base.segment_index as usize
+    }
+
+    pub fn add_validation_rule(&self, _memory: &mut Memory) {}
+
+    pub fn deduce_memory_cell(
+        &self,
+        _address: Relocatable,
+        _memory: &Memory,
+    ) -> Result<Option<MaybeRelocatable>, RunnerError> {
+        Ok(None)
+    }
+
+    pub fn get_allocated_memory_units(&self, _vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        Ok(0)
+    }
+
+    pub fn get_memory_segment_addresses(&self) -> (usize, Option<usize>) {
+        (self.base(), self.stop_ptr)
+    }
+
+    /// Returns the number of cells used after the initial instance.
+    pub fn get_used_cells(&self, segments: &MemorySegmentManager) -> Result<usize, MemoryError> {
+        let used = segments
+            .get_segment_used_size(self.base())
+            .ok_or(MemoryError::MissingSegmentUsedSizes)?;
+        used.checked_sub(INITIAL_SEGMENT_SIZE)
+            .ok_or(MemoryError::InvalidUsedSizeSegmentArena(used))
+    }
+
+    pub fn get_used_cells_and_allocated_size(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<(usize, usize), MemoryError> {
+        let used = self.get_used_cells(&vm.segments)?;
+        Ok((used, used))
+    }
+
+    pub fn get_used_instances(
+        &self,
+        segments: &MemorySegmentManager,
+    ) -> Result<usize, MemoryError> {
+        let used_cells = self.get_used_cells(segments)?;
+        Ok(div_ceil(used_cells, self.cells_per_instance as usize))
+    }
+
+    pub fn final_stack(
+        &mut self,
+        segments: &MemorySegmentManager,
+        pointer: Relocatable,
+    ) -> Result<Relocatable, RunnerError> {
+        if self.included {
+            let stop_pointer_addr = pointer
+                .sub_usize(1)
+                .map_err(|_| RunnerError::NoStopPointer(SEGMENT_ARENA_BUILTIN_NAME))?;
+            let stop_pointer = segments
+                .memory
+                .get_relocatable(stop_pointer_addr)
+                .map_err(|_| RunnerError::NoStopPointer(SEGMENT_ARENA_BUILTIN_NAME))?;
+            if self.base.segment_index != stop_pointer.segment_index {
+                return Err(RunnerError::InvalidStopPointerIndex(
+                    SEGMENT_ARENA_BUILTIN_NAME,
+                    stop_pointer,
+                    self.base(),
+                ));
+            }
+            let used = self.get_used_cells(segments).map_err(RunnerError::Memory)?;
+            if stop_pointer != self.base + used {
+                return Err(RunnerError::InvalidStopPointer(
+                    SEGMENT_ARENA_BUILTIN_NAME,
+                    self.base + used,
+                    stop_pointer,
+                ));
+            }
+            self.stop_ptr = Some(stop_pointer.offset);
+            Ok(stop_pointer_addr)
+        } else {
+            self.stop_ptr = Some(self.base.offset);
+            Ok(pointer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::runners::builtin_runner::BuiltinRunner;
+    use assert_matches::assert_matches;
+    use std::collections::HashMap;
+
+    #[test]
+    fn initialize_segments() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        let mut segments = MemorySegmentManager::new();
+        builtin.initialize_segments(&mut segments);
+        assert_eq!(segments.num_segments(), 2);
+        assert_eq!(builtin.base(), 1);
+        assert_eq!(
+            builtin.initial_stack(),
+            vec![MaybeRelocatable::from((1, 3))]
+        );
+        assert_eq!(
+            segments.memory.get_relocatable(relocatable!(1, 0)),
+            Ok(relocatable!(0, 0))
+        );
+        assert_eq!(
+            segments
+                .memory
+                .get_integer(relocatable!(1, 1))
+                .unwrap()
+                .as_ref(),
+            &Felt::zero()
+        );
+        assert_eq!(
+            segments
+                .memory
+                .get_integer(relocatable!(1, 2))
+                .unwrap()
+                .as_ref(),
+            &Felt::zero()
+        );
+    }
+
+    #[test]
+    fn initial_stack_not_included() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(false);
+        builtin.initialize_segments(&mut MemorySegmentManager::new());
+        assert_eq!(builtin.initial_stack(), Vec::new());
+    }
+
+    #[test]
+    fn get_used_cells_and_instances() {
+        let builtin: BuiltinRunner = SegmentArenaBuiltinRunner::new(true).into();
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![10]);
+        assert_eq!(builtin.get_used_cells(&vm.segments), Ok(7));
+        assert_eq!(builtin.get_used_instances(&vm.segments), Ok(3));
+        assert_eq!(builtin.get_used_cells_and_allocated_size(&vm), Ok((7, 7)));
+        assert_eq!(builtin.get_allocated_memory_units(&vm), Ok(0));
+    }
+
+    #[test]
+    fn get_used_cells_missing_initial_instance() {
+        let builtin = SegmentArenaBuiltinRunner::new(true);
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![2]);
+        assert_eq!(
+            builtin.get_used_cells(&vm.segments),
+            Err(MemoryError::InvalidUsedSizeSegmentArena(2))
+        );
+    }
+
+    #[test]
+    fn final_stack() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        let mut vm = vm!();
+        builtin.initialize_segments(&mut vm.segments);
+        vm.segments = segments![
+            ((1, 0), (0, 0)),
+            ((1, 1), 1),
+            ((1, 2), 0),
+            ((1, 3), (0, 0)),
+            ((1, 4), 1),
+            ((1, 5), 0),
+            ((2, 0), (1, 6))
+        ];
+        vm.segments.segment_used_sizes = Some(vec![0, 6, 1]);
+        assert_eq!(
+            builtin.final_stack(&vm.segments, relocatable!(2, 1)),
+            Ok(relocatable!(2, 0))
+        );
+        assert_eq!(builtin.get_memory_segment_addresses(), (1, Some(6)));
+    }
+
+    #[test]
+    fn final_stack_error_stop_pointer() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        let mut vm = vm!();
+        builtin.initialize_segments(&mut vm.segments);
+        vm.segments = segments![((1, 0), (0, 0)), ((1, 1), 0), ((1, 2), 0), ((2, 0), (1, 3))];
+        vm.segments.segment_used_sizes = Some(vec![0, 6, 1]);
+        assert_eq!(
+            builtin.final_stack(&vm.segments, relocatable!(2, 1)),
+            Err(RunnerError::InvalidStopPointer(
+                SEGMENT_ARENA_BUILTIN_NAME,
+                relocatable!(1, 6),
+                relocatable!(1, 3)
+            ))
+        );
+    }
+
+    #[test]
+    fn final_stack_error_stop_pointer_index() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        let mut vm = vm!();
+        builtin.initialize_segments(&mut vm.segments);
+        vm.segments = segments![((1, 0), (0, 0)), ((2, 0), (0, 3))];
+        vm.segments.segment_used_sizes = Some(vec![0, 3, 1]);
+        assert_eq!(
+            builtin.final_stack(&vm.segments, relocatable!(2, 1)),
+            Err(RunnerError::InvalidStopPointerIndex(
+                SEGMENT_ARENA_BUILTIN_NAME,
+                relocatable!(0, 3),
+                1
+            ))
+        );
+    }
+
+    #[test]
+    fn final_stack_error_non_relocatable() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        let mut vm = vm!();
+        builtin.initialize_segments(&mut vm.segments);
+        vm.segments = segments![((2, 0), 3)];
+        assert_eq!(
+            builtin.final_stack(&vm.segments, relocatable!(2, 1)),
+            Err(RunnerError::NoStopPointer(SEGMENT_ARENA_BUILTIN_NAME))
+        );
+    }
+
+    #[test]
+    fn final_stack_not_included() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(false);
+        let mut vm = vm!();
+        builtin.initialize_segments(&mut vm.segments);
+        assert_eq!(
+            builtin.final_stack(&vm.segments, relocatable!(2, 1)),
+            Ok(relocatable!(2, 1))
+        );
+        assert_eq!(builtin.get_memory_segment_addresses(), (1, Some(3)));
+    }
+
+    #[test]
+    fn run_security_checks_skips_segment_arena() {
+        let mut builtin: BuiltinRunner = SegmentArenaBuiltinRunner::new(true).into();
+        let mut vm = vm!();
+        builtin.initialize_segments(&mut vm.segments);
+        assert_matches!(builtin.run_security_checks(&vm), Ok(()));
+    }
+}
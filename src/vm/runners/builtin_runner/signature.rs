@@ -1,5 +1,6 @@
 use crate::{
-    math_utils::safe_div_usize,
+    air_private_input::{PrivateInput, PrivateInputSignature, SignatureInput},
+    math_utils::{div_mod, safe_div_usize},
     types::{
         instance_definitions::ecdsa_instance_def::EcdsaInstanceDef,
         relocatable::{MaybeRelocatable, Relocatable},
@@ -9,6 +10,7 @@ use crate::{
             memory_errors::{InsufficientAllocatedCellsError, MemoryError},
             runner_errors::RunnerError,
         },
+        runners::cairo_pie::BuiltinAdditionalData,
         vm_core::VirtualMachine,
         vm_memory::{
             memory::{Memory, ValidationRule},
@@ -17,12 +19,24 @@ use crate::{
     },
 };
 use felt::Felt;
+use lazy_static::lazy_static;
+use num_bigint::{BigInt, Sign};
 use num_integer::div_ceil;
+use num_traits::{Num, One};
 use starknet_crypto::{verify, FieldElement, Signature};
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use super::SIGNATURE_BUILTIN_NAME;
 
+lazy_static! {
+    // Order of the STARK curve, used to compute the signature's w = s^-1
+    static ref EC_ORDER: BigInt = BigInt::from_str_radix(
+        "800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f",
+        16
+    )
+    .unwrap();
+}
+
 #[derive(Debug, Clone)]
 pub struct SignatureBuiltinRunner {
     pub(crate) included: bool,
@@ -249,11 +263,67 @@ impl SignatureBuiltinRunner {
             self.stop_ptr = Some(stop_ptr);
             Ok(stop_pointer_addr)
         } else {
-            let stop_ptr = self.base;
-            self.stop_ptr = Some(stop_ptr);
+            self.stop_ptr = Some(0);
             Ok(pointer)
         }
     }
+
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        let mut private_inputs = vec![];
+        for (addr, signature) in self.signatures.borrow().iter() {
+            let (pubkey, msg) = match (memory.get_integer(*addr), memory.get_integer(*addr + 1)) {
+                (Ok(pubkey), Ok(msg)) => (pubkey.into_owned(), msg.into_owned()),
+                _ => continue,
+            };
+            let r = Felt::from_bytes_be(&signature.r.to_bytes_be());
+            let s = BigInt::from_bytes_be(Sign::Plus, &signature.s.to_bytes_be());
+            let w = div_mod(&BigInt::one(), &s, &EC_ORDER);
+            private_inputs.push(PrivateInput::Signature(PrivateInputSignature {
+                index: addr.offset / self.cells_per_instance as usize,
+                pubkey,
+                msg,
+                signature_input: SignatureInput { r, w: w.into() },
+            }));
+        }
+        private_inputs.sort_by_key(|input| match input {
+            PrivateInput::Signature(signature) => signature.index,
+            _ => 0,
+        });
+        private_inputs
+    }
+
+    pub fn get_additional_data(&self) -> BuiltinAdditionalData {
+        let signatures = self
+            .signatures
+            .borrow()
+            .iter()
+            .map(|(addr, signature)| {
+                (
+                    *addr,
+                    (
+                        Felt::from_bytes_be(&signature.r.to_bytes_be()),
+                        Felt::from_bytes_be(&signature.s.to_bytes_be()),
+                    ),
+                )
+            })
+            .collect();
+        BuiltinAdditionalData::Signature(signatures)
+    }
+
+    pub fn extend_additional_data(
+        &mut self,
+        additional_data: &BuiltinAdditionalData,
+    ) -> Result<(), RunnerError> {
+        match additional_data {
+            BuiltinAdditionalData::Signature(signatures) => {
+                for (addr, signature) in signatures {
+                    self.add_signature(*addr, signature)?;
+                }
+                Ok(())
+            }
+            _ => Err(RunnerError::InvalidAdditionalData(SIGNATURE_BUILTIN_NAME)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -270,6 +340,7 @@ mod tests {
             vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
         },
     };
+    use felt::felt_str;
 
     #[test]
     fn get_used_cells_and_allocated_size_min_step_not_reached() {
@@ -598,4 +669,42 @@ mod tests {
             Err(RunnerError::Memory(MemoryError::MissingSegmentUsedSizes))
         )
     }
+
+    #[test]
+    fn air_private_input() {
+        let mut builtin = SignatureBuiltinRunner::new(&EcdsaInstanceDef::default(), true);
+        builtin
+            .add_signature(relocatable!(0, 2), &(Felt::new(3), Felt::new(2)))
+            .unwrap();
+        builtin
+            .add_signature(relocatable!(0, 0), &(Felt::new(7), Felt::one()))
+            .unwrap();
+        let memory = memory![((0, 0), 10), ((0, 1), 11), ((0, 2), 12), ((0, 3), 13)];
+        assert_eq!(
+            builtin.air_private_input(&memory),
+            vec![
+                PrivateInput::Signature(PrivateInputSignature {
+                    index: 0,
+                    pubkey: Felt::new(10),
+                    msg: Felt::new(11),
+                    signature_input: SignatureInput {
+                        r: Felt::new(7),
+                        w: Felt::one(),
+                    }
+                }),
+                PrivateInput::Signature(PrivateInputSignature {
+                    index: 1,
+                    pubkey: Felt::new(12),
+                    msg: Felt::new(13),
+                    signature_input: SignatureInput {
+                        r: Felt::new(3),
+                        // Inverse of 2 modulo the curve order
+                        w: felt_str!(
+                            "1809251394333065606848661391547535052763371875858043744577039728942256432792"
+                        ),
+                    }
+                }),
+            ]
+        );
+    }
 }
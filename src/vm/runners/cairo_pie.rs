@@ -0,0 +1,820 @@
+use super::{
+    builtin_runner::{HASH_BUILTIN_NAME, OUTPUT_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME},
+    cairo_runner::{ExecutionResources, SegmentInfo},
+};
+use crate::{
+    serde::deserialize_program::{deserialize_array_of_bigint_hex, BuiltinName},
+    types::{
+        program::Program,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::errors::cairo_pie_errors::CairoPieError,
+};
+use felt::{Felt, PRIME_STR};
+use num_bigint::BigUint;
+use num_traits::One;
+use serde::{ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{self, Read, Write},
+    path::Path,
+};
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+pub const CAIRO_PIE_VERSION: &str = "1.1";
+
+// Size in bytes of each address and value in the serialized memory (memory.bin)
+const ADDR_BYTE_LEN: usize = 8;
+const FIELD_BYTE_LEN: usize = 32;
+// Relocatable values are written with their offset in the lowest 47 bits
+const OFFSET_BITS: usize = 47;
+
+// A public memory page of the output builtin, serialized as [start, size]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicMemoryPage {
+    pub start: usize,
+    pub size: usize,
+}
+
+impl Serialize for PublicMemoryPage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.start, self.size).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicMemoryPage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (start, size) = <(usize, usize)>::deserialize(deserializer)?;
+        Ok(PublicMemoryPage { start, size })
+    }
+}
+
+// Maps each attribute name to its (start, size) range in the output segment
+pub type Attributes = HashMap<String, Vec<usize>>;
+pub type Pages = HashMap<usize, PublicMemoryPage>;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct OutputBuiltinAdditionalData {
+    pub pages: Pages,
+    pub attributes: Attributes,
+}
+
+/// Data that a builtin needs besides its memory segment to be re-executed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuiltinAdditionalData {
+    // Addresses of the verified hash outputs
+    Hash(Vec<Relocatable>),
+    Output(OutputBuiltinAdditionalData),
+    // Signatures (r, s) by public key address
+    Signature(HashMap<Relocatable, (Felt, Felt)>),
+    None,
+}
+
+impl Serialize for BuiltinAdditionalData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            BuiltinAdditionalData::Hash(addresses) => {
+                let mut addresses: Vec<(isize, usize)> = addresses
+                    .iter()
+                    .map(|addr| (addr.segment_index, addr.offset))
+                    .collect();
+                addresses.sort();
+                addresses.serialize(serializer)
+            }
+            BuiltinAdditionalData::Output(data) => data.serialize(serializer),
+            BuiltinAdditionalData::Signature(signatures) => {
+                let mut signatures: Vec<(&Relocatable, &(Felt, Felt))> =
+                    signatures.iter().collect();
+                signatures.sort_by_key(|(addr, _)| (addr.segment_index, addr.offset));
+                let mut seq = serializer.serialize_seq(Some(signatures.len()))?;
+                for (addr, (r, s)) in signatures {
+                    let (r, s) = (
+                        felt_to_number(r).map_err(serde::ser::Error::custom)?,
+                        felt_to_number(s).map_err(serde::ser::Error::custom)?,
+                    );
+                    seq.serialize_element(&((addr.segment_index, addr.offset), (r, s)))?;
+                }
+                seq.end()
+            }
+            BuiltinAdditionalData::None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl BuiltinAdditionalData {
+    // The shape of the data depends on the builtin it belongs to, so it can only be
+    // deserialized knowing its key in additional_data.json
+    fn from_json(key: &str, value: serde_json::Value) -> serde_json::Result<Self> {
+        let data = match key.strip_suffix("_builtin") {
+            Some(HASH_BUILTIN_NAME) => {
+                let addresses: Vec<(isize, usize)> = serde_json::from_value(value)?;
+                BuiltinAdditionalData::Hash(addresses.into_iter().map(Relocatable::from).collect())
+            }
+            Some(OUTPUT_BUILTIN_NAME) => {
+                BuiltinAdditionalData::Output(serde_json::from_value(value)?)
+            }
+            Some(SIGNATURE_BUILTIN_NAME) => {
+                let signatures: Vec<((isize, usize), (serde_json::Number, serde_json::Number))> =
+                    serde_json::from_value(value)?;
+                let mut signatures_map = HashMap::new();
+                for (addr, (r, s)) in signatures {
+                    signatures_map.insert(
+                        Relocatable::from(addr),
+                        (number_to_felt(&r)?, number_to_felt(&s)?),
+                    );
+                }
+                BuiltinAdditionalData::Signature(signatures_map)
+            }
+            _ => BuiltinAdditionalData::None,
+        };
+        Ok(data)
+    }
+}
+
+/// The parts of the program needed to run it: bytecode, builtins and entrypoint.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StrippedProgram {
+    #[serde(
+        serialize_with = "serialize_program_data",
+        deserialize_with = "deserialize_array_of_bigint_hex"
+    )]
+    pub data: Vec<MaybeRelocatable>,
+    #[serde(deserialize_with = "deserialize_builtin_names")]
+    pub builtins: Vec<&'static str>,
+    pub main: usize,
+    pub prime: String,
+}
+
+impl From<&StrippedProgram> for Program {
+    fn from(program: &StrippedProgram) -> Self {
+        Program {
+            builtins: program.builtins.clone(),
+            prime: program.prime.clone(),
+            data: program.data.clone(),
+            main: Some(program.main),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CairoPieMetadata {
+    pub program: StrippedProgram,
+    pub program_segment: SegmentInfo,
+    pub execution_segment: SegmentInfo,
+    pub ret_fp_segment: SegmentInfo,
+    pub ret_pc_segment: SegmentInfo,
+    pub builtin_segments: HashMap<String, SegmentInfo>,
+    pub extra_segments: Vec<SegmentInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CairoPieVersion {
+    pub cairo_pie: String,
+}
+
+// Unrelocated memory cells, as ((segment_index, offset), value)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CairoPieMemory(pub Vec<((usize, usize), MaybeRelocatable)>);
+
+impl CairoPieMemory {
+    /// Serializes the memory as a sequence of little endian (address, value) pairs,
+    /// where relocatable values are encoded with their highest bit set.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.0.len() * (ADDR_BYTE_LEN + FIELD_BYTE_LEN));
+        for ((segment_index, offset), value) in self.0.iter() {
+            bytes.extend(encode_relocatable(
+                *segment_index as isize,
+                *offset,
+                ADDR_BYTE_LEN,
+            ));
+            match value {
+                MaybeRelocatable::Int(num) => {
+                    let mut value_bytes = num.to_bytes_be();
+                    value_bytes.reverse();
+                    value_bytes.resize(FIELD_BYTE_LEN, 0);
+                    bytes.extend(value_bytes);
+                }
+                MaybeRelocatable::RelocatableValue(rel) => bytes.extend(encode_relocatable(
+                    rel.segment_index,
+                    rel.offset,
+                    FIELD_BYTE_LEN,
+                )),
+            }
+        }
+        bytes
+    }
+
+    /// Parses memory serialized by `to_bytes`, returning None if it is malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Option<CairoPieMemory> {
+        if bytes.len() % (ADDR_BYTE_LEN + FIELD_BYTE_LEN) != 0 {
+            return None;
+        }
+        let mut memory = Vec::with_capacity(bytes.len() / (ADDR_BYTE_LEN + FIELD_BYTE_LEN));
+        for cell in bytes.chunks_exact(ADDR_BYTE_LEN + FIELD_BYTE_LEN) {
+            let (addr_bytes, value_bytes) = cell.split_at(ADDR_BYTE_LEN);
+            let (segment_index, offset) = decode_relocatable(addr_bytes)?;
+            let value = if value_bytes[FIELD_BYTE_LEN - 1] & 0x80 != 0 {
+                MaybeRelocatable::from(decode_relocatable(value_bytes)?)
+            } else {
+                let mut value_bytes = value_bytes.to_vec();
+                value_bytes.reverse();
+                MaybeRelocatable::from(Felt::from_bytes_be(&value_bytes))
+            };
+            memory.push(((usize::try_from(segment_index).ok()?, offset), value));
+        }
+        Some(CairoPieMemory(memory))
+    }
+}
+
+/// Position independent execution of a Cairo program, which can be re-run later on with a
+/// different memory layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CairoPie {
+    pub metadata: CairoPieMetadata,
+    pub memory: CairoPieMemory,
+    pub execution_resources: ExecutionResources,
+    // Keyed by builtin name followed by the "_builtin" suffix
+    pub additional_data: HashMap<String, BuiltinAdditionalData>,
+    pub version: CairoPieVersion,
+}
+
+impl CairoPie {
+    /// Writes the CairoPie as a zip file with the same structure as the one produced by
+    /// cairo-lang: metadata.json, memory.bin, additional_data.json,
+    /// execution_resources.json and version.json.
+    pub fn write_zip_file(&self, file_path: &Path) -> io::Result<()> {
+        let file = File::create(file_path)?;
+        let mut zip_writer = ZipWriter::new(file);
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        zip_writer.start_file("metadata.json", options)?;
+        serde_json::to_writer(&mut zip_writer, &self.metadata)?;
+        zip_writer.start_file("memory.bin", options)?;
+        zip_writer.write_all(&self.memory.to_bytes())?;
+        zip_writer.start_file("additional_data.json", options)?;
+        serde_json::to_writer(&mut zip_writer, &self.additional_data)?;
+        zip_writer.start_file("execution_resources.json", options)?;
+        serde_json::to_writer(
+            &mut zip_writer,
+            &serialize_execution_resources(&self.execution_resources),
+        )?;
+        zip_writer.start_file("version.json", options)?;
+        serde_json::to_writer(&mut zip_writer, &self.version)?;
+        zip_writer.finish()?;
+        Ok(())
+    }
+
+    /// Reads a CairoPie from a zip file with the structure described in `write_zip_file`.
+    pub fn read_zip_file(file_path: &Path) -> io::Result<CairoPie> {
+        let file = File::open(file_path)?;
+        let mut zip_reader = ZipArchive::new(file)?;
+
+        let metadata = serde_json::from_reader(zip_reader.by_name("metadata.json")?)?;
+        let mut memory_bytes = Vec::new();
+        zip_reader
+            .by_name("memory.bin")?
+            .read_to_end(&mut memory_bytes)?;
+        let memory = CairoPieMemory::from_bytes(&memory_bytes).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "malformed CairoPie memory")
+        })?;
+        let additional_data: HashMap<String, serde_json::Value> =
+            serde_json::from_reader(zip_reader.by_name("additional_data.json")?)?;
+        let additional_data = additional_data
+            .into_iter()
+            .map(|(key, value)| {
+                let data = BuiltinAdditionalData::from_json(&key, value)?;
+                Ok((key, data))
+            })
+            .collect::<serde_json::Result<_>>()?;
+        let execution_resources = deserialize_execution_resources(serde_json::from_reader(
+            zip_reader.by_name("execution_resources.json")?,
+        )?)?;
+        let version = serde_json::from_reader(zip_reader.by_name("version.json")?)?;
+
+        Ok(CairoPie {
+            metadata,
+            memory,
+            execution_resources,
+            additional_data,
+            version,
+        })
+    }
+
+    /// Checks that the CairoPie is consistent on its own: its program targets the field used by
+    /// the VM and fits in the program segment, its builtins have a segment each and its memory
+    /// lies within its segments.
+    pub fn run_validity_checks(&self) -> Result<(), CairoPieError> {
+        let program = &self.metadata.program;
+        if program.prime != PRIME_STR {
+            return Err(CairoPieError::PrimeDiffers(program.prime.clone()));
+        }
+        if program.main >= program.data.len() {
+            return Err(CairoPieError::InvalidMainAddress(
+                program.main,
+                program.data.len(),
+            ));
+        }
+        if self.metadata.program_segment.size != program.data.len() {
+            return Err(CairoPieError::ProgramLenMismatch(
+                self.metadata.program_segment.size,
+                program.data.len(),
+            ));
+        }
+        let builtin_names: HashSet<&str> = program.builtins.iter().copied().collect();
+        if builtin_names.len() != self.metadata.builtin_segments.len()
+            || !builtin_names
+                .iter()
+                .all(|name| self.metadata.builtin_segments.contains_key(*name))
+        {
+            return Err(CairoPieError::BuiltinListMismatch);
+        }
+
+        let segment_sizes: HashMap<isize, usize> = self
+            .metadata
+            .all_segments()
+            .into_iter()
+            .map(|(_, info)| (info.index, info.size))
+            .collect();
+        for ((segment_index, offset), _) in self.memory.0.iter() {
+            let addr = Relocatable::from((*segment_index as isize, *offset));
+            match segment_sizes.get(&addr.segment_index) {
+                Some(size) if addr.offset < *size => (),
+                _ => return Err(CairoPieError::OutOfBoundsMemoryAddress(addr)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that `other`, the CairoPie obtained by re-executing this one, reaches the same
+    /// final state: same segment sizes (and therefore builtin pointers), memory and
+    /// execution resources.
+    pub fn check_compatibility(&self, other: &CairoPie) -> Result<(), CairoPieError> {
+        for (name, info) in self.metadata.builtin_segments.iter() {
+            let size = other
+                .metadata
+                .builtin_segments
+                .get(name)
+                .map(|info| info.size)
+                .ok_or(CairoPieError::BuiltinListMismatch)?;
+            if size != info.size {
+                return Err(CairoPieError::BuiltinStopPointerMismatch(
+                    name.clone(),
+                    size,
+                    info.size,
+                ));
+            }
+        }
+        if self.metadata.extra_segments.len() != other.metadata.extra_segments.len() {
+            return Err(CairoPieError::ExtraSegmentsMismatch(
+                self.metadata.extra_segments.len(),
+                other.metadata.extra_segments.len(),
+            ));
+        }
+        for ((name, claimed), (_, actual)) in self
+            .metadata
+            .all_segments()
+            .into_iter()
+            .zip(other.metadata.all_segments())
+        {
+            if claimed.index != actual.index {
+                return Err(CairoPieError::SegmentIndexMismatch(
+                    name,
+                    actual.index,
+                    claimed.index,
+                ));
+            }
+            if claimed.size != actual.size {
+                return Err(CairoPieError::SegmentSizeMismatch(
+                    name,
+                    actual.size,
+                    claimed.size,
+                ));
+            }
+        }
+
+        let sorted_memory = |memory: &CairoPieMemory| {
+            let mut cells = memory.0.clone();
+            cells.sort_by_key(|(addr, _)| *addr);
+            cells
+        };
+        if sorted_memory(&self.memory) != sorted_memory(&other.memory) {
+            return Err(CairoPieError::MemoryMismatch);
+        }
+        if self.execution_resources.filter_unused_builtins()
+            != other.execution_resources.filter_unused_builtins()
+        {
+            return Err(CairoPieError::ExecutionResourcesMismatch);
+        }
+        Ok(())
+    }
+}
+
+impl CairoPieMetadata {
+    // Every segment described by the metadata, named after its role, with the builtin segments
+    // sorted by name so that two metadata with the same segments list them in the same order
+    fn all_segments(&self) -> Vec<(String, &SegmentInfo)> {
+        let mut segments = vec![
+            ("program".to_string(), &self.program_segment),
+            ("execution".to_string(), &self.execution_segment),
+            ("ret_fp".to_string(), &self.ret_fp_segment),
+            ("ret_pc".to_string(), &self.ret_pc_segment),
+        ];
+        let mut builtin_segments: Vec<(String, &SegmentInfo)> = self
+            .builtin_segments
+            .iter()
+            .map(|(name, info)| (name.clone(), info))
+            .collect();
+        builtin_segments.sort_by(|a, b| a.0.cmp(&b.0));
+        segments.extend(builtin_segments);
+        segments.extend(
+            self.extra_segments
+                .iter()
+                .enumerate()
+                .map(|(i, info)| (format!("extra_{i}"), info)),
+        );
+        segments
+    }
+}
+
+// Builtin names in the execution resources carry the "_builtin" suffix in the CairoPie
+fn serialize_execution_resources(execution_resources: &ExecutionResources) -> serde_json::Value {
+    let builtin_instance_counter: HashMap<String, usize> = execution_resources
+        .builtin_instance_counter
+        .iter()
+        .map(|(name, count)| (format!("{name}_builtin"), *count))
+        .collect();
+    serde_json::json!({
+        "n_steps": execution_resources.n_steps,
+        "n_memory_holes": execution_resources.n_memory_holes,
+        "builtin_instance_counter": builtin_instance_counter,
+    })
+}
+
+#[derive(Deserialize)]
+struct CairoPieExecutionResources {
+    n_steps: usize,
+    n_memory_holes: usize,
+    builtin_instance_counter: HashMap<String, usize>,
+}
+
+fn deserialize_execution_resources(
+    execution_resources: CairoPieExecutionResources,
+) -> io::Result<ExecutionResources> {
+    let builtin_instance_counter = execution_resources
+        .builtin_instance_counter
+        .into_iter()
+        .map(|(name, count)| match name.strip_suffix("_builtin") {
+            Some(name) => Ok((name.to_string(), count)),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid builtin name in execution resources: {name}"),
+            )),
+        })
+        .collect::<io::Result<_>>()?;
+    Ok(ExecutionResources {
+        n_steps: execution_resources.n_steps,
+        n_memory_holes: execution_resources.n_memory_holes,
+        builtin_instance_counter,
+    })
+}
+
+// Encodes a relocatable value in n_bytes little endian bytes as
+// 2^(8 * n_bytes - 1) + segment_index * 2^47 + offset, like cairo-lang's RelocatableValue.to_bytes
+fn encode_relocatable(segment_index: isize, offset: usize, n_bytes: usize) -> Vec<u8> {
+    let n_bits = 8 * n_bytes;
+    let num = (BigUint::one() << (n_bits - 1))
+        + (BigUint::from(segment_index as usize) << OFFSET_BITS)
+        + BigUint::from(offset);
+    let mut bytes = num.to_bytes_le();
+    bytes.resize(n_bytes, 0);
+    bytes
+}
+
+// Inverse of encode_relocatable, returns None if the highest bit isn't set
+fn decode_relocatable(bytes: &[u8]) -> Option<(isize, usize)> {
+    let n_bits = 8 * bytes.len();
+    let num = BigUint::from_bytes_le(bytes);
+    if !num.bit(n_bits as u64 - 1) {
+        return None;
+    }
+    let segment_index = (&num >> OFFSET_BITS) & BigUint::from(0xffff_u32);
+    let offset = num & ((BigUint::one() << OFFSET_BITS) - BigUint::one());
+    Some((
+        isize::try_from(&segment_index).ok()?,
+        usize::try_from(&offset).ok()?,
+    ))
+}
+
+// Signature values are written as plain JSON integers, which may exceed 64 bits
+fn felt_to_number(felt: &Felt) -> Result<serde_json::Number, serde_json::Error> {
+    felt.to_str_radix(10).parse()
+}
+
+fn number_to_felt(number: &serde_json::Number) -> serde_json::Result<Felt> {
+    Felt::parse_bytes(number.to_string().as_bytes(), 10)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid felt: {number}")))
+}
+
+fn deserialize_builtin_names<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<&'static str>, D::Error> {
+    let builtins = Vec::<BuiltinName>::deserialize(deserializer)?;
+    builtins
+        .iter()
+        .map(|builtin| {
+            builtin.native_name().ok_or_else(|| {
+                serde::de::Error::custom(format!("unknown builtin: {}", builtin.name()))
+            })
+        })
+        .collect()
+}
+
+fn serialize_program_data<S: Serializer>(
+    data: &[MaybeRelocatable],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(data.len()))?;
+    for value in data {
+        match value {
+            MaybeRelocatable::Int(num) => {
+                seq.serialize_element(&format!("0x{}", num.to_str_radix(16)))?
+            }
+            MaybeRelocatable::RelocatableValue(_) => {
+                return Err(serde::ser::Error::custom(
+                    "program data can't contain relocatable values",
+                ))
+            }
+        }
+    }
+    seq.end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relocatable;
+    use num_traits::Bounded;
+
+    #[test]
+    fn memory_to_bytes() {
+        let memory = CairoPieMemory(vec![
+            ((0, 1), MaybeRelocatable::from(Felt::new(258))),
+            ((1, 0), MaybeRelocatable::from((2, 3))),
+        ]);
+        let bytes = memory.to_bytes();
+        assert_eq!(bytes.len(), 2 * (ADDR_BYTE_LEN + FIELD_BYTE_LEN));
+
+        // (0, 1) -> 2^63 + 1
+        assert_eq!(&bytes[..8], &[1, 0, 0, 0, 0, 0, 0, 0x80]);
+        // 258 -> 0x0102
+        assert_eq!(&bytes[8..10], &[2, 1]);
+        assert!(bytes[10..40].iter().all(|b| *b == 0));
+        // (1, 0) -> 2^63 + 2^47
+        assert_eq!(&bytes[40..48], &[0, 0, 0, 0, 0, 0x80, 0, 0x80]);
+        // (2, 3) -> 2^255 + 2 * 2^47 + 3
+        let mut expected_value = vec![0; 32];
+        expected_value[0] = 3;
+        expected_value[6] = 1;
+        expected_value[31] = 0x80;
+        assert_eq!(&bytes[48..], &expected_value[..]);
+    }
+
+    #[test]
+    fn serialize_additional_data() {
+        let additional_data = HashMap::from([
+            (
+                "pedersen_builtin".to_string(),
+                BuiltinAdditionalData::Hash(vec![relocatable!(3, 5), relocatable!(3, 2)]),
+            ),
+            (
+                "ecdsa_builtin".to_string(),
+                BuiltinAdditionalData::Signature(HashMap::from([(
+                    relocatable!(4, 0),
+                    (Felt::new(10), Felt::new(20)),
+                )])),
+            ),
+            (
+                "output_builtin".to_string(),
+                BuiltinAdditionalData::Output(OutputBuiltinAdditionalData::default()),
+            ),
+            (
+                "range_check_builtin".to_string(),
+                BuiltinAdditionalData::None,
+            ),
+        ]);
+        let json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&additional_data).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "pedersen_builtin": [[3, 2], [3, 5]],
+                "ecdsa_builtin": [[[4, 0], [10, 20]]],
+                "output_builtin": {"pages": {}, "attributes": {}},
+                "range_check_builtin": null,
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_stripped_program() {
+        let program = StrippedProgram {
+            data: vec![Felt::new(0x40780017fff7fff_u64).into(), Felt::new(1).into()],
+            builtins: vec!["output"],
+            main: 0,
+            prime: "0x800000000000011000000000000000000000000000000000000000000000001".to_string(),
+        };
+        let json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&program).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "data": ["0x40780017fff7fff", "0x1"],
+                "builtins": ["output"],
+                "main": 0,
+                "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_execution_resources_with_builtin_suffix() {
+        let execution_resources = ExecutionResources {
+            n_steps: 10,
+            n_memory_holes: 2,
+            builtin_instance_counter: HashMap::from([("output".to_string(), 3)]),
+        };
+        assert_eq!(
+            serialize_execution_resources(&execution_resources),
+            serde_json::json!({
+                "n_steps": 10,
+                "n_memory_holes": 2,
+                "builtin_instance_counter": {"output_builtin": 3},
+            })
+        );
+    }
+
+    fn cairo_pie() -> CairoPie {
+        CairoPie {
+            metadata: CairoPieMetadata {
+                program: StrippedProgram {
+                    data: vec![Felt::new(0x208b7fff7fff7ffe_u64).into()],
+                    builtins: vec!["output"],
+                    main: 0,
+                    prime: PRIME_STR.to_string(),
+                },
+                program_segment: (0, 1).into(),
+                execution_segment: (1, 3).into(),
+                ret_fp_segment: (3, 0).into(),
+                ret_pc_segment: (4, 0).into(),
+                builtin_segments: HashMap::from([("output".to_string(), (2, 1).into())]),
+                extra_segments: vec![],
+            },
+            memory: CairoPieMemory(vec![
+                ((0, 0), Felt::new(0x208b7fff7fff7ffe_u64).into()),
+                ((1, 0), (2, 0).into()),
+                ((1, 1), (3, 0).into()),
+                ((1, 2), (4, 0).into()),
+                ((2, 0), Felt::new(7).into()),
+            ]),
+            execution_resources: ExecutionResources {
+                n_steps: 1,
+                n_memory_holes: 0,
+                builtin_instance_counter: HashMap::from([("output".to_string(), 1)]),
+            },
+            additional_data: HashMap::from([
+                (
+                    "output_builtin".to_string(),
+                    BuiltinAdditionalData::Output(OutputBuiltinAdditionalData::default()),
+                ),
+                (
+                    "ecdsa_builtin".to_string(),
+                    BuiltinAdditionalData::Signature(HashMap::from([(
+                        relocatable!(5, 0),
+                        (Felt::new(10), Felt::max_value()),
+                    )])),
+                ),
+            ]),
+            version: CairoPieVersion {
+                cairo_pie: CAIRO_PIE_VERSION.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn memory_from_bytes() {
+        let memory = CairoPieMemory(vec![
+            ((0, 1), MaybeRelocatable::from(Felt::new(258))),
+            ((1, 0), MaybeRelocatable::from((2, 3))),
+            ((3, 4), MaybeRelocatable::from(Felt::max_value())),
+        ]);
+        assert_eq!(CairoPieMemory::from_bytes(&memory.to_bytes()), Some(memory));
+    }
+
+    #[test]
+    fn memory_from_cairo_lang_bytes() {
+        // Cell (1, 5) holding (0x8001, 2^40), written by cairo-lang as
+        // (2^63 + 2^47 + 5).to_bytes(8, "little") + (2^255 + 0x8001 * 2^47 + 2^40).to_bytes(32, "little")
+        let mut bytes = vec![5, 0, 0, 0, 0, 0x80, 0, 0x80];
+        let mut value = vec![0; 32];
+        value[5] = 0x81;
+        value[7] = 0x40;
+        value[31] = 0x80;
+        bytes.extend(value);
+        assert_eq!(
+            CairoPieMemory::from_bytes(&bytes),
+            Some(CairoPieMemory(vec![(
+                (1, 5),
+                MaybeRelocatable::from((0x8001, 1 << 40))
+            )]))
+        );
+    }
+
+    #[test]
+    fn memory_from_bytes_malformed() {
+        // Wrong length
+        assert_eq!(CairoPieMemory::from_bytes(&[0; 39]), None);
+        // Address without its highest bit set
+        assert_eq!(CairoPieMemory::from_bytes(&[0; 40]), None);
+    }
+
+    #[test]
+    fn write_and_read_zip_file() {
+        let cairo_pie = cairo_pie();
+        let file_path = std::env::temp_dir().join("cairo_pie_write_and_read_zip_file.zip");
+        cairo_pie.write_zip_file(&file_path).unwrap();
+        let read_cairo_pie = CairoPie::read_zip_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+        assert_eq!(read_cairo_pie, cairo_pie);
+    }
+
+    #[test]
+    fn run_validity_checks() {
+        assert_eq!(cairo_pie().run_validity_checks(), Ok(()));
+    }
+
+    #[test]
+    fn run_validity_checks_invalid_main() {
+        let mut cairo_pie = cairo_pie();
+        cairo_pie.metadata.program.main = 1;
+        assert_eq!(
+            cairo_pie.run_validity_checks(),
+            Err(CairoPieError::InvalidMainAddress(1, 1))
+        );
+    }
+
+    #[test]
+    fn run_validity_checks_builtin_list_mismatch() {
+        let mut cairo_pie = cairo_pie();
+        cairo_pie.metadata.program.builtins = vec!["output", "pedersen"];
+        assert_eq!(
+            cairo_pie.run_validity_checks(),
+            Err(CairoPieError::BuiltinListMismatch)
+        );
+    }
+
+    #[test]
+    fn run_validity_checks_out_of_bounds_memory() {
+        let mut cairo_pie = cairo_pie();
+        cairo_pie
+            .memory
+            .0
+            .push(((2, 1), MaybeRelocatable::from(Felt::new(8))));
+        assert_eq!(
+            cairo_pie.run_validity_checks(),
+            Err(CairoPieError::OutOfBoundsMemoryAddress(relocatable!(2, 1)))
+        );
+    }
+
+    #[test]
+    fn check_compatibility_builtin_stop_pointer_mismatch() {
+        let cairo_pie = cairo_pie();
+        let mut other = cairo_pie.clone();
+        other
+            .metadata
+            .builtin_segments
+            .insert("output".to_string(), (2, 2).into());
+        assert_eq!(
+            cairo_pie.check_compatibility(&other),
+            Err(CairoPieError::BuiltinStopPointerMismatch(
+                "output".to_string(),
+                2,
+                1
+            ))
+        );
+    }
+
+    #[test]
+    fn check_compatibility_memory_mismatch() {
+        let cairo_pie = cairo_pie();
+        let mut other = cairo_pie.clone();
+        other.memory.0.reverse();
+        assert_eq!(cairo_pie.check_compatibility(&other), Ok(()));
+        other.memory.0[0].1 = MaybeRelocatable::from(Felt::new(8));
+        assert_eq!(
+            cairo_pie.check_compatibility(&other),
+            Err(CairoPieError::MemoryMismatch)
+        );
+    }
+}
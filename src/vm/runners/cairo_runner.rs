@@ -1,5 +1,10 @@
 use crate::{
-    hint_processor::hint_processor_definition::{HintProcessor, HintReference},
+    air_private_input::AirPrivateInput,
+    air_public_input::PublicInput,
+    hint_processor::{
+        builtin_hint_processor::program_input_utils::PROGRAM_INPUT,
+        hint_processor_definition::{HintProcessor, HintReference},
+    },
     math_utils::safe_div_usize,
     serde::deserialize_program::OffsetValue,
     types::{
@@ -7,7 +12,7 @@ use crate::{
         exec_scope::ExecutionScopes,
         instance_definitions::{
             bitwise_instance_def::BitwiseInstanceDef, ec_op_instance_def::EcOpInstanceDef,
-            ecdsa_instance_def::EcdsaInstanceDef,
+            ecdsa_instance_def::EcdsaInstanceDef, poseidon_instance_def::PoseidonInstanceDef,
         },
         instruction::Register,
         layout::CairoLayout,
@@ -17,6 +22,8 @@ use crate::{
     utils::is_subsequence,
     vm::{
         errors::{
+            air_public_input_errors::PublicInputError,
+            cairo_pie_errors::CairoPieError,
             cairo_run_errors::CairoRunError,
             memory_errors::{InsufficientAllocatedCellsError, MemoryError},
             runner_errors::RunnerError,
@@ -29,9 +36,15 @@ use crate::{
         vm_memory::memory::RelocateValue,
         {
             runners::builtin_runner::{
-                BitwiseBuiltinRunner, BuiltinRunner, EcOpBuiltinRunner, HashBuiltinRunner,
-                OutputBuiltinRunner, RangeCheckBuiltinRunner, SignatureBuiltinRunner,
+                BitwiseBuiltinRunner, BuiltinRunner, CustomBuiltin, EcOpBuiltinRunner,
+                HashBuiltinRunner, OutputBuiltinRunner, PoseidonBuiltinRunner,
+                RangeCheckBuiltinRunner, SegmentArenaBuiltinRunner, SignatureBuiltinRunner,
+            },
+            runners::cairo_pie::{
+                CairoPie, CairoPieMemory, CairoPieMetadata, CairoPieVersion, StrippedProgram,
+                CAIRO_PIE_VERSION,
             },
+            runners::gas::{get_gas_usage, GasConfig, GasUsage},
             trace::trace_entry::{relocate_trace_register, RelocatedTraceEntry},
             vm_core::VirtualMachine,
         },
@@ -40,6 +53,7 @@ use crate::{
 use felt::Felt;
 use num_integer::div_rem;
 use num_traits::Zero;
+use serde::{Deserialize, Serialize};
 use std::{
     any::Any,
     collections::{HashMap, HashSet},
@@ -49,7 +63,8 @@ use std::{
 
 use super::builtin_runner::{
     KeccakBuiltinRunner, BITWISE_BUILTIN_NAME, EC_OP_BUILTIN_NAME, HASH_BUILTIN_NAME,
-    KECCAK_BUILTIN_NAME, OUTPUT_BUILTIN_NAME, RANGE_CHECK_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME,
+    KECCAK_BUILTIN_NAME, OUTPUT_BUILTIN_NAME, POSEIDON_BUILTIN_NAME, RANGE_CHECK_BUILTIN_NAME,
+    SEGMENT_ARENA_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME,
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -71,6 +86,19 @@ impl From<Vec<MaybeRelocatable>> for CairoArg {
     }
 }
 
+// The native builtins, in the order in which programs must declare them
+const BUILTIN_ORDERED_LIST: [&str; 9] = [
+    OUTPUT_BUILTIN_NAME,
+    HASH_BUILTIN_NAME,
+    RANGE_CHECK_BUILTIN_NAME,
+    SIGNATURE_BUILTIN_NAME,
+    BITWISE_BUILTIN_NAME,
+    EC_OP_BUILTIN_NAME,
+    KECCAK_BUILTIN_NAME,
+    POSEIDON_BUILTIN_NAME,
+    SEGMENT_ARENA_BUILTIN_NAME,
+];
+
 pub struct CairoRunner {
     pub(crate) program: Program,
     layout: CairoLayout,
@@ -88,6 +116,8 @@ pub struct CairoRunner {
     pub relocated_memory: Vec<Option<Felt>>,
     pub relocated_trace: Option<Vec<RelocatedTraceEntry>>,
     pub exec_scopes: ExecutionScopes,
+    custom_builtins: Vec<Box<dyn CustomBuiltin>>,
+    gas_config: Option<GasConfig>,
 }
 
 impl CairoRunner {
@@ -102,10 +132,29 @@ impl CairoRunner {
             "dex" => CairoLayout::dex_instance(),
             "perpetual_with_bitwise" => CairoLayout::perpetual_with_bitwise_instance(),
             "bitwise" => CairoLayout::bitwise_instance(),
+            "recursive" => CairoLayout::recursive_instance(),
+            "starknet" => CairoLayout::starknet_instance(),
+            "starknet_with_keccak" => CairoLayout::starknet_with_keccak_instance(),
+            "recursive_large_output" => CairoLayout::recursive_large_output_instance(),
             "all" => CairoLayout::all_instance(),
+            "all_cairo" => CairoLayout::all_cairo_instance(),
+            "dynamic" => return Err(RunnerError::MissingDynamicLayoutParams),
             name => return Err(RunnerError::InvalidLayoutName(name.to_string())),
         };
-        Ok(CairoRunner {
+        Ok(CairoRunner::new_with_layout(
+            program,
+            cairo_layout,
+            proof_mode,
+        ))
+    }
+
+    /// Creates a runner for a custom layout, such as one built with `CairoLayoutBuilder`.
+    pub fn new_with_layout(
+        program: &Program,
+        cairo_layout: CairoLayout,
+        proof_mode: bool,
+    ) -> CairoRunner {
+        CairoRunner {
             program: program.clone(),
             layout: cairo_layout,
             final_pc: None,
@@ -122,7 +171,30 @@ impl CairoRunner {
             relocated_trace: None,
             exec_scopes: ExecutionScopes::new(),
             execution_public_memory: if proof_mode { Some(Vec::new()) } else { None },
-        })
+            custom_builtins: Vec::new(),
+            gas_config: None,
+        }
+    }
+
+    /// Registers a custom builtin, to be used by the programs that list a builtin with the same
+    /// name. Registering a builtin replaces any previously registered builtin of that name.
+    pub fn add_custom_builtin<B: CustomBuiltin + 'static>(&mut self, builtin: B) {
+        self.custom_builtins
+            .retain(|registered| registered.name() != builtin.name());
+        self.custom_builtins.push(Box::new(builtin));
+    }
+
+    // Native builtins can't be replaced by custom ones, so a native name found among the custom
+    // builtins of a program means it was declared after a custom builtin
+    fn get_custom_builtin(&self, name: &str) -> Result<(&'static str, BuiltinRunner), RunnerError> {
+        if BUILTIN_ORDERED_LIST.contains(&name) {
+            return Err(RunnerError::DisorderedBuiltins);
+        }
+        self.custom_builtins
+            .iter()
+            .find(|builtin| builtin.name() == name)
+            .map(|builtin| (builtin.name(), BuiltinRunner::Custom(builtin.clone())))
+            .ok_or_else(|| RunnerError::MissingCustomBuiltin(name.to_string()))
     }
 
     pub fn initialize(&mut self, vm: &mut VirtualMachine) -> Result<Relocatable, RunnerError> {
@@ -133,17 +205,122 @@ impl CairoRunner {
         Ok(end)
     }
 
+    /// Initializes the runner to re-execute a CairoPie.
+    /// The segments are created following the layout described by the CairoPie's metadata, and
+    /// its memory and builtin additional data are loaded before the run, so that the execution
+    /// checks them instead of producing them.
+    /// The segments' indices and sizes are checked against the metadata, so that a CairoPie that
+    /// doesn't match the runner's program and layout is rejected before the run.
+    /// Returns the value of the program counter after returning from main.
+    pub fn initialize_from_cairo_pie(
+        &mut self,
+        vm: &mut VirtualMachine,
+        cairo_pie: &CairoPie,
+    ) -> Result<Relocatable, RunnerError> {
+        cairo_pie.run_validity_checks()?;
+        let metadata = &cairo_pie.metadata;
+        self.initialize_builtins(vm)?;
+        if vm.builtin_runners.len() != metadata.builtin_segments.len() {
+            return Err(CairoPieError::BuiltinListMismatch.into());
+        }
+        self.initialize_segments(vm, None);
+        let end = self.initialize_main_entrypoint(vm)?;
+        // Segments created by the hints of the original run
+        for _ in metadata.extra_segments.iter() {
+            vm.segments.add();
+        }
+
+        // Segments whose size is known before the run must have that size, the others must at
+        // least hold what the initialization wrote into them
+        let check_segment = |name: &str, index: isize, info: &SegmentInfo, size: Option<usize>| {
+            if index != info.index {
+                return Err(CairoPieError::SegmentIndexMismatch(
+                    name.to_string(),
+                    index,
+                    info.index,
+                ));
+            }
+            let used_size = vm
+                .segments
+                .memory
+                .data
+                .get(index as usize)
+                .map_or(0, Vec::len);
+            match size {
+                Some(size) if size != info.size => Err(CairoPieError::SegmentSizeMismatch(
+                    name.to_string(),
+                    size,
+                    info.size,
+                )),
+                _ if used_size > info.size => Err(CairoPieError::SegmentSizeMismatch(
+                    name.to_string(),
+                    used_size,
+                    info.size,
+                )),
+                _ => Ok(()),
+            }
+        };
+        let program_base = self.program_base.ok_or(RunnerError::NoProgBase)?;
+        let execution_base = self.execution_base.ok_or(RunnerError::NoExecBase)?;
+        check_segment(
+            "program",
+            program_base.segment_index,
+            &metadata.program_segment,
+            Some(self.program.data.len()),
+        )?;
+        check_segment(
+            "execution",
+            execution_base.segment_index,
+            &metadata.execution_segment,
+            None,
+        )?;
+        for (name, builtin) in vm.builtin_runners.iter() {
+            let info = metadata
+                .builtin_segments
+                .get(*name)
+                .ok_or(CairoPieError::BuiltinListMismatch)?;
+            check_segment(name, builtin.base() as isize, info, None)?;
+        }
+        // The return fp and pc segments are created right before the return pc, and stay empty
+        check_segment(
+            "ret_fp",
+            end.segment_index - 1,
+            &metadata.ret_fp_segment,
+            Some(0),
+        )?;
+        check_segment(
+            "ret_pc",
+            end.segment_index,
+            &metadata.ret_pc_segment,
+            Some(0),
+        )?;
+        for (i, info) in metadata.extra_segments.iter().enumerate() {
+            check_segment(
+                &format!("extra_{i}"),
+                end.segment_index + 1 + i as isize,
+                info,
+                None,
+            )?;
+        }
+
+        for ((segment_index, offset), value) in cairo_pie.memory.0.iter() {
+            vm.segments.memory.insert(
+                &Relocatable::from((*segment_index as isize, *offset)),
+                value,
+            )?;
+        }
+        for (name, builtin) in vm.builtin_runners.iter_mut() {
+            if let Some(data) = cairo_pie.additional_data.get(&format!("{name}_builtin")) {
+                builtin.extend_additional_data(data)?;
+            }
+        }
+
+        self.initialize_vm(vm)?;
+        Ok(end)
+    }
+
     pub fn initialize_builtins(&self, vm: &mut VirtualMachine) -> Result<(), RunnerError> {
-        let builtin_ordered_list = vec![
-            OUTPUT_BUILTIN_NAME,
-            HASH_BUILTIN_NAME,
-            RANGE_CHECK_BUILTIN_NAME,
-            SIGNATURE_BUILTIN_NAME,
-            BITWISE_BUILTIN_NAME,
-            EC_OP_BUILTIN_NAME,
-            KECCAK_BUILTIN_NAME,
-        ];
-        if !is_subsequence(&self.program.builtins, &builtin_ordered_list) {
+        if !is_subsequence(&self.program.builtins, &BUILTIN_ORDERED_LIST) {
             return Err(RunnerError::DisorderedBuiltins);
         };
         let mut builtin_runners = Vec::<(&'static str, BuiltinRunner)>::new();
@@ -223,6 +400,29 @@ impl CairoRunner {
             }
         }
 
+        if let Some(instance_def) = self.layout.builtins.poseidon.as_ref() {
+            let included = self.program.builtins.contains(&POSEIDON_BUILTIN_NAME);
+            if included || self.proof_mode {
+                builtin_runners.push((
+                    POSEIDON_BUILTIN_NAME,
+                    PoseidonBuiltinRunner::new(instance_def, included).into(),
+                ));
+            }
+        }
+
+        // The segment arena builtin doesn't take any cells of the trace, so it's not part of the layout
+        if self.program.builtins.contains(&SEGMENT_ARENA_BUILTIN_NAME) {
+            builtin_runners.push((
+                SEGMENT_ARENA_BUILTIN_NAME,
+                SegmentArenaBuiltinRunner::new(true).into(),
+            ));
+        }
+
+        // Custom builtins, which aren't part of the layout, go after the native ones
+        for name in &self.program.custom_builtins {
+            builtin_runners.push(self.get_custom_builtin(name)?);
+        }
+
         let inserted_builtins = builtin_runners
             .iter()
             .map(|x| &x.0)
@@ -236,7 +436,7 @@ impl CairoRunner {
                     .difference(&inserted_builtins)
                     .map(|x| **x)
                     .collect(),
-                self.layout._name.clone(),
+                self.layout.name.clone(),
             ));
         }
 
@@ -255,6 +455,7 @@ impl CairoRunner {
             BITWISE_BUILTIN_NAME,
             EC_OP_BUILTIN_NAME,
             KECCAK_BUILTIN_NAME,
+            POSEIDON_BUILTIN_NAME,
         ];
 
         fn initialize_builtin(name: &'static str, vm: &mut VirtualMachine) {
@@ -284,6 +485,13 @@ impl CairoRunner {
                     name,
                     EcOpBuiltinRunner::new(&EcOpInstanceDef::new(1), true).into(),
                 )),
+                POSEIDON_BUILTIN_NAME => vm.builtin_runners.push((
+                    name,
+                    PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(1), true).into(),
+                )),
+                SEGMENT_ARENA_BUILTIN_NAME => vm
+                    .builtin_runners
+                    .push((name, SegmentArenaBuiltinRunner::new(true).into())),
                 _ => {}
             }
         }
@@ -291,6 +499,10 @@ impl CairoRunner {
         for builtin_name in &self.program.builtins {
             initialize_builtin(builtin_name, vm);
         }
+        for name in &self.program.custom_builtins {
+            let builtin = self.get_custom_builtin(name)?;
+            vm.builtin_runners.push(builtin);
+        }
         for builtin_name in starknet_preset_builtins {
             if !self.program.builtins.contains(&builtin_name) {
                 initialize_builtin(builtin_name, vm)
@@ -458,31 +670,7 @@ impl CairoRunner {
     }
 
     pub fn get_reference_list(&self) -> HashMap<usize, HintReference> {
-        let mut references = HashMap::<usize, HintReference>::new();
-
-        for (i, reference) in self.program.reference_manager.references.iter().enumerate() {
-            references.insert(
-                i,
-                HintReference {
-                    offset1: reference.value_address.offset1.clone(),
-                    offset2: reference.value_address.offset2.clone(),
-                    dereference: reference.value_address.dereference,
-                    // only store `ap` tracking data if the reference is referred to it
-                    ap_tracking_data: match (
-                        &reference.value_address.offset1,
-                        &reference.value_address.offset2,
-                    ) {
-                        (OffsetValue::Reference(Register::AP, _, _), _)
-                        | (_, OffsetValue::Reference(Register::AP, _, _)) => {
-                            Some(reference.ap_tracking_data.clone())
-                        }
-                        _ => None,
-                    },
-                    cairo_type: Some(reference.value_address.value_type.clone()),
-                },
-            );
-        }
-        references
+        get_reference_list(&self.program)
     }
 
     /// Gets the data used by the HintProcessor to execute each hint
@@ -491,22 +679,7 @@ impl CairoRunner {
         references: &HashMap<usize, HintReference>,
         hint_executor: &mut dyn HintProcessor,
     ) -> Result<HashMap<usize, Vec<Box<dyn Any>>>, VirtualMachineError> {
-        let mut hint_data_dictionary = HashMap::<usize, Vec<Box<dyn Any>>>::new();
-        for (hint_index, hints) in self.program.hints.iter() {
-            for hint in hints {
-                let hint_data = hint_executor.compile_hint(
-                    &hint.code,
-                    &hint.flow_tracking_data.ap_tracking,
-                    &hint.flow_tracking_data.reference_ids,
-                    references,
-                );
-                hint_data_dictionary.entry(*hint_index).or_default().push(
-                    hint_data
-                        .map_err(|_| VirtualMachineError::CompileHintFail(hint.code.clone()))?,
-                );
-            }
-        }
-        Ok(hint_data_dictionary)
+        get_hint_data_dictionary(&self.program, references, hint_executor)
     }
 
     pub fn get_constants(&self) -> &HashMap<String, Felt> {
@@ -842,6 +1015,167 @@ impl CairoRunner {
         Ok(builtin_segment_info)
     }
 
+    // Returns a map from builtin name to its relocated segment addresses (begin_addr, stop_ptr)
+    // Requires the segments to be finalized and the builtins' stop pointers to be set
+    pub fn get_memory_segment_addresses(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<HashMap<&'static str, (usize, usize)>, RunnerError> {
+        let relocation_table = vm.segments.relocate_segments()?;
+        let mut addresses = HashMap::new();
+        for (name, builtin) in &vm.builtin_runners {
+            let (index, stop_ptr) = builtin.get_memory_segment_addresses();
+            let stop_ptr = stop_ptr.ok_or(RunnerError::NoStopPointer(name))?;
+            let base = *relocation_table
+                .get(index)
+                .ok_or(RunnerError::BaseNotFinished)?;
+            addresses.insert(*name, (base, base + stop_ptr));
+        }
+        Ok(addresses)
+    }
+
+    /// Returns the AIR public input of a relocated proof mode run.
+    /// Requires the trace to be enabled and finalize_segments() to be called before relocation.
+    pub fn get_air_public_input(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<PublicInput, PublicInputError> {
+        let relocated_trace = self
+            .relocated_trace
+            .as_ref()
+            .ok_or(PublicInputError::Trace(TraceError::TraceNotEnabled))?;
+        let rc_limits = self
+            .get_perm_range_check_limits(vm)?
+            .ok_or(PublicInputError::NoRangeCheckLimits)?;
+        let relocation_table = vm.segments.relocate_segments()?;
+        let public_memory_addresses = vm.segments.get_public_memory_addresses(&relocation_table)?;
+        let memory_segment_addresses = self.get_memory_segment_addresses(vm)?;
+        PublicInput::new(
+            &self.relocated_memory,
+            &self.layout.name,
+            &public_memory_addresses,
+            memory_segment_addresses,
+            relocated_trace,
+            rc_limits,
+        )
+    }
+
+    /// Returns the witness data of every builtin used in the run, keyed by builtin name.
+    pub fn get_air_private_input(&self, vm: &VirtualMachine) -> AirPrivateInput {
+        let mut private_inputs = HashMap::new();
+        for (name, builtin) in &vm.builtin_runners {
+            if *name != OUTPUT_BUILTIN_NAME {
+                private_inputs.insert(*name, builtin.air_private_input(&vm.segments.memory));
+            }
+        }
+        AirPrivateInput(private_inputs)
+    }
+
+    /// Returns the CairoPie of a finished (non proof mode) run.
+    /// Requires read_return_values() and relocate() to be called beforehand.
+    pub fn get_cairo_pie(&self, vm: &VirtualMachine) -> Result<CairoPie, RunnerError> {
+        let program_base = self.program_base.ok_or(RunnerError::NoProgBase)?;
+        let execution_base = self.execution_base.ok_or(RunnerError::NoExecBase)?;
+        let segment_info = |segment_index: isize| -> Result<SegmentInfo, RunnerError> {
+            let size = vm
+                .segments
+                .get_segment_used_size(segment_index as usize)
+                .ok_or(MemoryError::MissingSegmentUsedSizes)?;
+            Ok((segment_index, size).into())
+        };
+
+        let builtin_segments: HashMap<String, SegmentInfo> = self
+            .get_builtin_segments_info(vm)?
+            .into_iter()
+            .zip(vm.builtin_runners.iter())
+            .map(|((index, stop_ptr), (name, _))| {
+                (name.to_string(), (index as isize, stop_ptr).into())
+            })
+            .collect();
+
+        // The return fp and pc are placed in the execution segment after the builtins' bases
+        let n_used_builtins = self.program.builtin_names().count();
+        let ret_fp = vm
+            .segments
+            .memory
+            .get_relocatable(execution_base + n_used_builtins)?;
+        let ret_pc = vm
+            .segments
+            .memory
+            .get_relocatable(execution_base + n_used_builtins + 1)?;
+        if ret_fp.offset != 0 {
+            return Err(RunnerError::UnexpectedRetFpOffset(ret_fp));
+        }
+        if ret_pc.offset != 0 {
+            return Err(RunnerError::UnexpectedRetPcOffset(ret_pc));
+        }
+
+        let known_segment_indices: HashSet<isize> = builtin_segments
+            .values()
+            .map(|info| info.index)
+            .chain([
+                program_base.segment_index,
+                execution_base.segment_index,
+                ret_fp.segment_index,
+                ret_pc.segment_index,
+            ])
+            .collect();
+        let extra_segments = (0..vm.segments.num_segments() as isize)
+            .filter(|index| !known_segment_indices.contains(index))
+            .map(segment_info)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let metadata = CairoPieMetadata {
+            program: StrippedProgram {
+                data: self.program.data.clone(),
+                builtins: self
+                    .program
+                    .builtins
+                    .iter()
+                    .copied()
+                    .map(Ok)
+                    .chain(
+                        self.program
+                            .custom_builtins
+                            .iter()
+                            .map(|name| Ok(self.get_custom_builtin(name)?.0)),
+                    )
+                    .collect::<Result<_, RunnerError>>()?,
+                main: self.program.main.ok_or(RunnerError::MissingMain)?,
+                prime: self.program.prime.clone(),
+            },
+            program_segment: (program_base.segment_index, self.program.data.len()).into(),
+            execution_segment: segment_info(execution_base.segment_index)?,
+            ret_fp_segment: segment_info(ret_fp.segment_index)?,
+            ret_pc_segment: segment_info(ret_pc.segment_index)?,
+            builtin_segments,
+            extra_segments,
+        };
+
+        let mut memory = Vec::new();
+        for (segment_index, segment) in vm.segments.memory.data.iter().enumerate() {
+            for (offset, value) in segment.iter().enumerate() {
+                if let Some(value) = value {
+                    memory.push(((segment_index, offset), value.clone()));
+                }
+            }
+        }
+
+        Ok(CairoPie {
+            metadata,
+            memory: CairoPieMemory(memory),
+            execution_resources: self.get_execution_resources(vm)?,
+            additional_data: vm
+                .builtin_runners
+                .iter()
+                .map(|(name, builtin)| (format!("{name}_builtin"), builtin.get_additional_data()))
+                .collect(),
+            version: CairoPieVersion {
+                cairo_pie: CAIRO_PIE_VERSION.to_string(),
+            },
+        })
+    }
+
     pub fn get_execution_resources(
         &self,
         vm: &VirtualMachine,
@@ -874,6 +1208,36 @@ impl CairoRunner {
         Ok(output)
     }
 
+    /// Returns the program being run.
+    pub fn get_program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Returns the values written to the output builtin's segment, or an empty vector if the
+    /// program doesn't use it.
+    /// Requires the segments' effective sizes to be computed beforehand.
+    pub fn get_output_values(&self, vm: &VirtualMachine) -> Result<Vec<Felt>, RunnerError> {
+        let builtin = match vm
+            .builtin_runners
+            .iter()
+            .find(|(name, _)| name == &OUTPUT_BUILTIN_NAME)
+        {
+            Some((_, builtin)) => builtin,
+            None => return Ok(Vec::new()),
+        };
+        let segment_index = builtin.base();
+        let size = vm
+            .segments
+            .get_segment_used_size(segment_index)
+            .ok_or(MemoryError::MissingSegmentUsedSizes)?;
+        (0..size)
+            .map(|offset| {
+                let addr = Relocatable::from((segment_index as isize, offset));
+                Ok(vm.segments.memory.get_integer(addr)?.into_owned())
+            })
+            .collect()
+    }
+
     /// Writes the values hosted in the output builtin's segment.
     /// Does nothing if the output builtin is not present in the program.
     pub fn write_output(
@@ -955,13 +1319,37 @@ impl CairoRunner {
             let (_, size) = builtin_runner
                 .get_used_cells_and_allocated_size(vm)
                 .map_err(RunnerError::FinalizeSegements)?;
+            let public_memory = builtin_runner.get_public_memory(&vm.segments)?;
             vm.segments
-                .finalize(Some(size), builtin_runner.base(), None)
+                .finalize(Some(size), builtin_runner.base(), public_memory.as_ref())
         }
         self.segments_finalized = true;
         Ok(())
     }
 
+    /// Makes `run_from_entrypoint` pass a gas counter to the entrypoint, right after the
+    /// arguments of the program's builtins, as Cairo 1 functions expect. The gas used by the run
+    /// can then be read with `get_gas_usage`.
+    pub fn set_gas_config(&mut self, gas_config: GasConfig) {
+        self.gas_config = Some(gas_config);
+    }
+
+    /// Makes `program_input` available to the hints of the main scope as the `program_input`
+    /// variable.
+    pub fn set_program_input(&mut self, program_input: serde_json::Value) {
+        self.exec_scopes.insert_value(PROGRAM_INPUT, program_input);
+    }
+
+    /// Returns the remaining gas of a Cairo 1 entrypoint run, along with the gas charged for its
+    /// steps and builtins according to the gas config's cost table.
+    pub fn get_gas_usage(&self, vm: &VirtualMachine) -> Result<GasUsage, RunnerError> {
+        let gas_config = self
+            .gas_config
+            .as_ref()
+            .ok_or(RunnerError::MissingGasConfig)?;
+        get_gas_usage(gas_config, self.program.builtin_names().count(), vm)
+    }
+
     pub fn run_from_entrypoint(
         &mut self,
         entrypoint: usize,
@@ -970,10 +1358,17 @@ impl CairoRunner {
         vm: &mut VirtualMachine,
         hint_processor: &mut dyn HintProcessor,
     ) -> Result<(), CairoRunError> {
-        let stack = args
+        let mut stack = args
             .iter()
             .map(|arg| vm.segments.gen_cairo_arg(arg))
             .collect::<Result<Vec<MaybeRelocatable>, VirtualMachineError>>()?;
+        if let Some(gas_config) = &self.gas_config {
+            let n_builtins = self.program.builtin_names().count();
+            if stack.len() < n_builtins {
+                return Err(RunnerError::MissingGasArgument(stack.len(), n_builtins).into());
+            }
+            stack.insert(n_builtins, Felt::from(gas_config.initial_gas).into());
+        }
         let return_fp = MaybeRelocatable::from(0);
         let end = self.initialize_function_entrypoint(vm, entrypoint, stack, return_fp)?;
 
@@ -1144,12 +1539,21 @@ impl CairoRunner {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SegmentInfo {
     pub index: isize,
     pub size: usize,
 }
 
+impl From<(isize, usize)> for SegmentInfo {
+    fn from(value: (isize, usize)) -> Self {
+        SegmentInfo {
+            index: value.0,
+            size: value.1,
+        }
+    }
+}
+
 //* ----------------------
 //*   ExecutionResources
 //* ----------------------
@@ -1230,16 +1634,70 @@ impl Sub for ExecutionResources {
     }
 }
 
+pub(crate) fn get_reference_list(program: &Program) -> HashMap<usize, HintReference> {
+    let mut references = HashMap::<usize, HintReference>::new();
+
+    for (i, reference) in program.reference_manager.references.iter().enumerate() {
+        references.insert(
+            i,
+            HintReference {
+                offset1: reference.value_address.offset1.clone(),
+                offset2: reference.value_address.offset2.clone(),
+                dereference: reference.value_address.dereference,
+                // only store `ap` tracking data if the reference is referred to it
+                ap_tracking_data: match (
+                    &reference.value_address.offset1,
+                    &reference.value_address.offset2,
+                ) {
+                    (OffsetValue::Reference(Register::AP, _, _), _)
+                    | (_, OffsetValue::Reference(Register::AP, _, _)) => {
+                        Some(reference.ap_tracking_data.clone())
+                    }
+                    _ => None,
+                },
+                cairo_type: Some(reference.value_address.value_type.clone()),
+            },
+        );
+    }
+    references
+}
+
+/// Gets the data used by the HintProcessor to execute each hint of `program`, by its pc
+pub(crate) fn get_hint_data_dictionary(
+    program: &Program,
+    references: &HashMap<usize, HintReference>,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<HashMap<usize, Vec<Box<dyn Any>>>, VirtualMachineError> {
+    let mut hint_data_dictionary = HashMap::<usize, Vec<Box<dyn Any>>>::new();
+    for (hint_index, hints) in program.hints.iter() {
+        for hint in hints {
+            let hint_data = hint_executor.compile_hint(
+                &hint.code,
+                &hint.flow_tracking_data.ap_tracking,
+                &hint.flow_tracking_data.reference_ids,
+                references,
+            );
+            hint_data_dictionary.entry(*hint_index).or_default().push(
+                hint_data.map_err(|_| VirtualMachineError::CompileHintFail(hint.code.clone()))?,
+            );
+        }
+    }
+    Ok(hint_data_dictionary)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
     use crate::{
+        air_private_input::{PrivateInput, PrivateInputPair},
         hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
+        hint_processor::cairo_1_hint_processor::cairo_1_hint_processor_definition::Cairo1HintProcessor,
         relocatable,
         serde::deserialize_program::{Identifier, ReferenceManager},
         types::instance_definitions::bitwise_instance_def::BitwiseInstanceDef,
         utils::test_utils::*,
+        vm::runners::gas::STEP_GAS_COST,
         vm::{trace::trace_entry::TraceEntry, vm_memory::memory::Memory},
     };
     use assert_matches::assert_matches;
@@ -1250,6 +1708,280 @@ mod tests {
         path::Path,
     };
 
+    // Custom builtin whose instances are a value followed by its double
+    #[derive(Debug, Clone, Default)]
+    struct DoubleBuiltin {
+        base: usize,
+        stop_ptr: Option<usize>,
+    }
+
+    impl CustomBuiltin for DoubleBuiltin {
+        fn name(&self) -> &'static str {
+            "double"
+        }
+
+        fn base(&self) -> usize {
+            self.base
+        }
+
+        fn initialize_segments(&mut self, segments: &mut MemorySegmentManager) {
+            self.base = segments.add().segment_index as usize
+        }
+
+        fn cells_per_instance(&self) -> u32 {
+            2
+        }
+
+        fn n_input_cells(&self) -> u32 {
+            1
+        }
+
+        fn stop_ptr(&self) -> Option<usize> {
+            self.stop_ptr
+        }
+
+        fn set_stop_ptr(&mut self, stop_ptr: usize) {
+            self.stop_ptr = Some(stop_ptr)
+        }
+
+        fn deduce_memory_cell(
+            &self,
+            address: Relocatable,
+            memory: &Memory,
+        ) -> Result<Option<MaybeRelocatable>, RunnerError> {
+            if address.offset % 2 == 0 {
+                return Ok(None);
+            }
+            Ok(memory
+                .get_integer(Relocatable::from((
+                    address.segment_index,
+                    address.offset - 1,
+                )))
+                .ok()
+                .map(|value| MaybeRelocatable::from(value.as_ref() * &Felt::new(2))))
+        }
+    }
+
+    // [ap] = 21; ap++
+    // assert [ap - 1] = [[fp - 3]]
+    // [ap] = [[fp - 3] + 1]; ap++
+    // [ap] = [fp - 3] + 2; ap++
+    // ret
+    fn double_builtin_program() -> Program {
+        program!(
+            custom_builtins = vec!["double".to_string()],
+            data = vec_data!(
+                (0x480680017fff8000_i64),
+                (21),
+                (0x400280007ffd7fff_i64),
+                (0x480280017ffd8000_i64),
+                (0x482680017ffd8000_i64),
+                (2),
+                (0x208b7fff7fff7ffe_i64)
+            ),
+            main = Some(0),
+        )
+    }
+
+    #[test]
+    fn run_program_with_custom_builtin() {
+        let program = double_builtin_program();
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.add_custom_builtin(DoubleBuiltin::default());
+        let mut vm = vm!();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        assert_matches!(
+            cairo_runner.run_until_pc(end, &mut vm, &mut hint_processor),
+            Ok(())
+        );
+        assert_matches!(
+            cairo_runner.end_run(false, false, &mut vm, &mut hint_processor),
+            Ok(())
+        );
+        assert_matches!(cairo_runner.read_return_values(&mut vm), Ok(()));
+        assert_matches!(verify_secure_runner(&cairo_runner, true, &mut vm), Ok(()));
+
+        assert_eq!(vm.builtin_runners[0].0, "double");
+        assert_eq!(
+            vm.builtin_runners[0].1.get_memory_segment_addresses(),
+            (2, Some(2))
+        );
+        assert_eq!(
+            vm.segments
+                .memory
+                .get_integer(Relocatable::from((2, 1)))
+                .unwrap()
+                .as_ref(),
+            &Felt::new(42)
+        );
+    }
+
+    #[test]
+    fn run_program_with_custom_builtin_wrong_output() {
+        let mut program = double_builtin_program();
+        // Assert that the output cell holds the input instead of its double:
+        // assert [ap - 1] = [[fp - 3] + 1]
+        program.data[3] = MaybeRelocatable::from(Felt::new(0x400280017ffd7fff_i64));
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.add_custom_builtin(DoubleBuiltin::default());
+        let mut vm = vm!();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        assert_matches!(
+            cairo_runner.run_until_pc(end, &mut vm, &mut BuiltinHintProcessor::new_empty()),
+            Err(VirtualMachineError::DiffAssertValues(_, _))
+        );
+    }
+
+    #[test]
+    fn initialize_builtins_missing_custom_builtin() {
+        let program = double_builtin_program();
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::MissingCustomBuiltin("double".to_string()))
+        );
+    }
+
+    #[test]
+    fn initialize_builtins_custom_builtin_before_native_builtins() {
+        let program = program!(
+            custom_builtins = vec!["double".to_string(), OUTPUT_BUILTIN_NAME.to_string()],
+        );
+        let mut cairo_runner = cairo_runner!(program, "small");
+        cairo_runner.add_custom_builtin(DoubleBuiltin::default());
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::DisorderedBuiltins)
+        );
+    }
+
+    #[test]
+    fn initialize_builtins_custom_builtin_after_native_builtins() {
+        let program = program!(
+            builtins = vec![OUTPUT_BUILTIN_NAME],
+            custom_builtins = vec!["double".to_string()],
+        );
+        let mut cairo_runner = cairo_runner!(program, "small");
+        cairo_runner.add_custom_builtin(DoubleBuiltin::default());
+        let mut vm = vm!();
+        assert_eq!(cairo_runner.initialize_builtins(&mut vm), Ok(()));
+        assert_eq!(
+            vm.builtin_runners
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>(),
+            vec![OUTPUT_BUILTIN_NAME, "double"]
+        );
+    }
+
+    #[test]
+    fn initialize_builtins_with_segment_arena() {
+        let program = program![OUTPUT_BUILTIN_NAME, SEGMENT_ARENA_BUILTIN_NAME];
+        let cairo_runner = cairo_runner!(program, "small");
+        let mut vm = vm!();
+        assert_eq!(cairo_runner.initialize_builtins(&mut vm), Ok(()));
+        assert_eq!(
+            vm.builtin_runners
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>(),
+            vec![OUTPUT_BUILTIN_NAME, SEGMENT_ARENA_BUILTIN_NAME]
+        );
+    }
+
+    #[test]
+    fn initialize_builtins_segment_arena_before_poseidon() {
+        let program = program![SEGMENT_ARENA_BUILTIN_NAME, POSEIDON_BUILTIN_NAME];
+        let cairo_runner = cairo_runner!(program, "all_cairo");
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::DisorderedBuiltins)
+        );
+    }
+
+    #[test]
+    fn run_program_with_segment_arena() {
+        // [ap] = [fp - 3]; ap++
+        // ret
+        let program = program!(
+            builtins = vec![SEGMENT_ARENA_BUILTIN_NAME],
+            data = vec_data!((0x480a7ffd7fff8000_i64), (0x208b7fff7fff7ffe_i64)),
+            main = Some(0),
+        );
+        let mut cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        // The builtin's pointer is placed after the initial instance
+        assert_eq!(
+            vm.segments
+                .memory
+                .get_relocatable(Relocatable::from((1, 0))),
+            Ok(Relocatable::from((3, 3)))
+        );
+        assert_matches!(
+            cairo_runner.run_until_pc(end, &mut vm, &mut hint_processor),
+            Ok(())
+        );
+        assert_matches!(
+            cairo_runner.end_run(false, false, &mut vm, &mut hint_processor),
+            Ok(())
+        );
+        assert_matches!(cairo_runner.read_return_values(&mut vm), Ok(()));
+        assert_matches!(verify_secure_runner(&cairo_runner, true, &mut vm), Ok(()));
+        assert_eq!(
+            vm.builtin_runners[0].1.get_memory_segment_addresses(),
+            (3, Some(3))
+        );
+    }
+
+    #[test]
+    fn run_casm_program_with_cairo_1_hints() {
+        // %{ memory[ap + 1] = segments.add() %}
+        // %{ memory[ap] = 1 if 3 < 5 else 0 %}
+        // [ap] = 1; ap++
+        // ret
+        let casm = r#"{
+            "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+            "bytecode": ["0x480680017fff8000", "0x1", "0x208b7fff7fff7ffe"],
+            "hints": [[0, [
+                {"AllocSegment": {"dst": {"register": "AP", "offset": 1}}},
+                {"TestLessThan": {
+                    "lhs": {"Immediate": "0x3"},
+                    "rhs": {"Immediate": "0x5"},
+                    "dst": {"register": "AP", "offset": 0}
+                }}
+            ]]]
+        }"#;
+        let program = Program::from_casm_reader(casm.as_bytes(), None).unwrap();
+        let mut cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        let mut hint_processor = Cairo1HintProcessor::new();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        assert_matches!(
+            cairo_runner.run_until_pc(end, &mut vm, &mut hint_processor),
+            Ok(())
+        );
+        assert_eq!(
+            vm.segments
+                .memory
+                .get_relocatable(Relocatable::from((1, 3))),
+            Ok(Relocatable::from((4, 0)))
+        );
+        assert_eq!(
+            vm.segments
+                .memory
+                .get_integer(Relocatable::from((1, 2)))
+                .unwrap()
+                .as_ref(),
+            &Felt::one()
+        );
+    }
+
     #[test]
     fn check_memory_usage_ok_case() {
         //This test works with basic Program definition, will later be updated to use Program::new() when fully defined
@@ -3435,6 +4167,219 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_air_public_input_proof_mode() {
+        let program = Program::from_file(
+            Path::new("cairo_programs/proof_programs/fibonacci.json"),
+            Some("main"),
+        )
+        .expect("Call to `Program::from_file()` failed.");
+
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut cairo_runner = cairo_runner!(program, "all", true);
+        let mut vm = vm!(true);
+
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        cairo_runner
+            .run_until_pc(end, &mut vm, &mut hint_processor)
+            .unwrap();
+        cairo_runner
+            .end_run(false, false, &mut vm, &mut hint_processor)
+            .unwrap();
+        cairo_runner.read_return_values(&mut vm).unwrap();
+        cairo_runner.finalize_segments(&mut vm).unwrap();
+        cairo_runner.relocate(&mut vm).unwrap();
+
+        let public_input = cairo_runner.get_air_public_input(&vm).unwrap();
+        assert_eq!(public_input.layout, "all");
+        assert_eq!(
+            public_input.n_steps,
+            cairo_runner.relocated_trace.as_ref().unwrap().len()
+        );
+        assert_eq!(
+            public_input.memory_segments["program"].begin_addr,
+            cairo_runner.relocated_trace.as_ref().unwrap()[0].pc
+        );
+        // The layout's builtins aren't used by the program, so their segments are empty
+        let relocation_table = vm.segments.relocate_segments().unwrap();
+        assert_eq!(vm.builtin_runners.len(), 6);
+        for (name, builtin) in &vm.builtin_runners {
+            let (index, _) = builtin.get_memory_segment_addresses();
+            let segment = &public_input.memory_segments[*name];
+            assert_eq!(segment.begin_addr, relocation_table[index]);
+            assert_eq!(segment.stop_ptr, segment.begin_addr);
+        }
+        // The whole program is part of the public memory
+        assert!(public_input.public_memory.len() >= cairo_runner.program.data.len());
+        assert!(public_input
+            .public_memory
+            .iter()
+            .all(|entry| entry.page == 0));
+    }
+
+    #[test]
+    fn get_memory_segment_addresses_not_included_builtin() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.segments.add();
+        vm.segments.add();
+        let mut builtin = RangeCheckBuiltinRunner::new(8, 8, false);
+        builtin.initialize_segments(&mut vm.segments);
+        assert_eq!(
+            builtin.final_stack(&vm.segments, (1, 2).into()).unwrap(),
+            (1, 2).into()
+        );
+        vm.builtin_runners = vec![(RANGE_CHECK_BUILTIN_NAME, builtin.into())];
+        vm.segments.segment_used_sizes = Some(vec![3, 2, 0]);
+        assert_eq!(
+            cairo_runner.get_memory_segment_addresses(&vm).unwrap(),
+            HashMap::from([(RANGE_CHECK_BUILTIN_NAME, (6, 6))])
+        );
+    }
+
+    #[test]
+    fn get_air_public_input_no_trace() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let vm = vm!();
+
+        assert_matches!(
+            cairo_runner.get_air_public_input(&vm),
+            Err(PublicInputError::Trace(TraceError::TraceNotEnabled))
+        );
+    }
+
+    #[test]
+    fn get_air_private_input() {
+        let program = program![OUTPUT_BUILTIN_NAME, HASH_BUILTIN_NAME];
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.builtin_runners = vec![
+            (OUTPUT_BUILTIN_NAME, OutputBuiltinRunner::new(true).into()),
+            (HASH_BUILTIN_NAME, {
+                let mut builtin = HashBuiltinRunner::new(8, true);
+                builtin.base = 1;
+                builtin.into()
+            }),
+        ];
+        vm.segments = segments![((0, 0), 7), ((1, 0), 1), ((1, 1), 2), ((1, 2), 3)];
+
+        assert_eq!(
+            cairo_runner.get_air_private_input(&vm),
+            AirPrivateInput(HashMap::from([(
+                HASH_BUILTIN_NAME,
+                vec![PrivateInput::Pair(PrivateInputPair {
+                    index: 0,
+                    x: Felt::new(1),
+                    y: Felt::new(2)
+                })]
+            )]))
+        );
+    }
+
+    #[test]
+    fn get_cairo_pie() {
+        let program = program!(
+            builtins = vec![OUTPUT_BUILTIN_NAME],
+            data = vec_data!((2345108766317314046_i64), (1)),
+            main = Some(0),
+            prime = "0x800000000000011000000000000000000000000000000000000000000000001".to_string(),
+        );
+        let mut cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        cairo_runner.initialize(&mut vm).unwrap();
+        vm.segments
+            .memory
+            .insert(
+                &Relocatable::from((2, 0)),
+                &MaybeRelocatable::from(Felt::new(7)),
+            )
+            .unwrap();
+        vm.segments.compute_effective_sizes();
+        match &mut vm.builtin_runners[0].1 {
+            BuiltinRunner::Output(builtin) => builtin.stop_ptr = Some(1),
+            _ => unreachable!(),
+        }
+
+        let cairo_pie = cairo_runner.get_cairo_pie(&vm).unwrap();
+        let metadata = cairo_pie.metadata;
+        assert_eq!(metadata.program.main, 0);
+        assert_eq!(metadata.program.builtins, vec![OUTPUT_BUILTIN_NAME]);
+        assert_eq!(metadata.program_segment, (0, 2).into());
+        assert_eq!(metadata.execution_segment, (1, 3).into());
+        assert_eq!(
+            metadata.builtin_segments,
+            HashMap::from([(OUTPUT_BUILTIN_NAME.to_string(), (2, 1).into())])
+        );
+        assert_eq!(metadata.ret_fp_segment, (3, 0).into());
+        assert_eq!(metadata.ret_pc_segment, (4, 0).into());
+        assert!(metadata.extra_segments.is_empty());
+        assert_eq!(cairo_pie.memory.0.len(), 6);
+        assert_eq!(cairo_pie.version.cairo_pie, CAIRO_PIE_VERSION);
+        assert!(cairo_pie.additional_data.contains_key("output_builtin"));
+    }
+
+    #[test]
+    fn get_cairo_pie_compatible_with_cairo_lang_pie() {
+        // [ap] = 7; ap++
+        // assert [ap - 1] = [[fp - 3]]
+        // [ap] = [fp - 3] + 1; ap++
+        // ret
+        let program = program!(
+            builtins = vec![OUTPUT_BUILTIN_NAME],
+            data = vec_data!(
+                (0x480680017fff8000_i64),
+                (7),
+                (0x400280007ffd7fff_i64),
+                (0x482680017ffd8000_i64),
+                (1),
+                (0x208b7fff7fff7ffe_i64)
+            ),
+            main = Some(0),
+        );
+        let mut cairo_runner = cairo_runner!(program, "small");
+        // The steps are counted from the trace
+        let mut vm = vm!(true);
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        cairo_runner
+            .run_until_pc(end, &mut vm, &mut hint_processor)
+            .unwrap();
+        cairo_runner
+            .end_run(false, false, &mut vm, &mut hint_processor)
+            .unwrap();
+        cairo_runner.read_return_values(&mut vm).unwrap();
+
+        // Written in the format of cairo-lang's CairoPie.to_file for a run of the same program
+        let cairo_lang_pie = CairoPie::read_zip_file(Path::new(
+            "cairo_programs/manually_compiled/output_constant_cairo_pie.zip",
+        ))
+        .unwrap();
+        assert_eq!(cairo_lang_pie.run_validity_checks(), Ok(()));
+        assert_eq!(
+            cairo_runner
+                .get_cairo_pie(&vm)
+                .unwrap()
+                .check_compatibility(&cairo_lang_pie),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn get_cairo_pie_no_main() {
+        let program = program!();
+        let mut cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        cairo_runner.initialize_segments(&mut vm, None);
+        vm.segments = segments![((1, 0), (2, 0)), ((1, 1), (3, 0))];
+        vm.segments.compute_effective_sizes();
+        assert!(matches!(
+            cairo_runner.get_cairo_pie(&vm),
+            Err(RunnerError::MissingMain)
+        ));
+    }
+
     #[test]
     fn get_builtin_segments_info_empty() {
         let program = program!();
@@ -3462,6 +4407,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_output_values() {
+        let program = program![OUTPUT_BUILTIN_NAME];
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.builtin_runners = vec![(OUTPUT_BUILTIN_NAME, OutputBuiltinRunner::new(true).into())];
+        vm.segments = segments![((0, 0), 1), ((0, 1), 2)];
+        vm.segments.compute_effective_sizes();
+        assert_eq!(
+            cairo_runner.get_output_values(&vm),
+            Ok(vec![Felt::new(1), Felt::new(2)])
+        );
+    }
+
+    #[test]
+    fn get_output_values_no_output_builtin() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let vm = vm!();
+        assert_eq!(cairo_runner.get_output_values(&vm), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn get_output_values_relocatable_value() {
+        let program = program![OUTPUT_BUILTIN_NAME];
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.builtin_runners = vec![(OUTPUT_BUILTIN_NAME, OutputBuiltinRunner::new(true).into())];
+        vm.segments = segments![((0, 0), (1, 2))];
+        vm.segments.compute_effective_sizes();
+        assert_eq!(
+            cairo_runner.get_output_values(&vm),
+            Err(RunnerError::Memory(MemoryError::ExpectedInteger(
+                Relocatable::from((0, 0))
+            )))
+        );
+    }
+
     #[test]
     fn get_execution_resources_trace_not_enabled() {
         let program = program!();
@@ -3658,6 +4641,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn finalize_segments_output_builtin_pages() {
+        let mut program = program!();
+        program.data = vec_data![(1)];
+        let mut cairo_runner = cairo_runner!(program, "plain", true);
+        cairo_runner.program_base = Some(Relocatable::from((0, 0)));
+        cairo_runner.execution_base = Some(Relocatable::from((1, 0)));
+        cairo_runner.run_ended = true;
+        let mut vm = vm!();
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        vm.segments.add();
+        vm.segments.add();
+        output_builtin.initialize_segments(&mut vm.segments);
+        output_builtin
+            .add_page(1, Relocatable::from((2, 1)), 2)
+            .unwrap();
+        vm.builtin_runners = vec![(OUTPUT_BUILTIN_NAME, output_builtin.into())];
+        vm.segments.segment_used_sizes = Some(vec![1, 0, 4]);
+        assert_eq!(cairo_runner.finalize_segments(&mut vm), Ok(()));
+        assert_eq!(vm.segments.segment_sizes.get(&2), Some(&4));
+        assert_eq!(
+            vm.segments.public_memory_offsets.get(&2),
+            Some(&vec![(0, 0), (1, 1), (2, 1), (3, 0)])
+        );
+    }
+
     /// Test that ensures get_perm_range_check_limits() returns an error when
     /// trace is not enabled.
     #[test]
@@ -3937,6 +4946,7 @@ mod tests {
         assert_eq!(given_output[4].0, BITWISE_BUILTIN_NAME);
         assert_eq!(given_output[5].0, EC_OP_BUILTIN_NAME);
         assert_eq!(given_output[6].0, KECCAK_BUILTIN_NAME);
+        assert_eq!(given_output[7].0, POSEIDON_BUILTIN_NAME);
     }
 
     #[test]
@@ -3963,6 +4973,42 @@ mod tests {
         assert_eq!(given_output[4].0, BITWISE_BUILTIN_NAME);
         assert_eq!(given_output[5].0, EC_OP_BUILTIN_NAME);
         assert_eq!(given_output[6].0, KECCAK_BUILTIN_NAME);
+        assert_eq!(given_output[7].0, POSEIDON_BUILTIN_NAME);
+    }
+
+    #[test]
+    fn initialize_all_builtins_with_custom_builtin() {
+        let program = program!(
+            builtins = vec![OUTPUT_BUILTIN_NAME],
+            custom_builtins = vec!["double".to_string()],
+        );
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.add_custom_builtin(DoubleBuiltin::default());
+        let mut vm = vm!();
+
+        assert_eq!(cairo_runner.initialize_all_builtins(&mut vm), Ok(()));
+
+        let given_output = vm.get_builtin_runners();
+
+        assert_eq!(given_output[0].0, OUTPUT_BUILTIN_NAME);
+        assert_eq!(given_output[1].0, "double");
+        assert_eq!(given_output[2].0, HASH_BUILTIN_NAME);
+        assert_eq!(given_output.len(), 9);
+    }
+
+    #[test]
+    fn initialize_all_builtins_custom_builtin_shadowing_native_builtin() {
+        let program = program!(
+            custom_builtins = vec!["double".to_string(), OUTPUT_BUILTIN_NAME.to_string()],
+        );
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.add_custom_builtin(DoubleBuiltin::default());
+        let mut vm = vm!();
+
+        assert_eq!(
+            cairo_runner.initialize_all_builtins(&mut vm),
+            Err(RunnerError::DisorderedBuiltins)
+        );
     }
 
     #[test]
@@ -3985,6 +5031,7 @@ mod tests {
         assert_eq!(builtin_runners[4].0, BITWISE_BUILTIN_NAME);
         assert_eq!(builtin_runners[5].0, EC_OP_BUILTIN_NAME);
         assert_eq!(builtin_runners[6].0, KECCAK_BUILTIN_NAME);
+        assert_eq!(builtin_runners[7].0, POSEIDON_BUILTIN_NAME);
 
         assert_eq!(
             cairo_runner.program_base,
@@ -4000,7 +5047,54 @@ mod tests {
                 offset: 0,
             })
         );
-        assert_eq!(vm.segments.num_segments(), 9);
+        assert_eq!(vm.segments.num_segments(), 10);
+    }
+
+    #[test]
+    fn new_cairo_runner_with_production_layouts() {
+        let program = program!();
+        for layout in [
+            "recursive",
+            "starknet",
+            "starknet_with_keccak",
+            "recursive_large_output",
+        ] {
+            let cairo_runner = cairo_runner!(program, layout);
+            assert_eq!(cairo_runner.layout.name, layout);
+        }
+    }
+
+    #[test]
+    fn initialize_builtins_starknet_with_keccak_layout() {
+        let program = program![
+            OUTPUT_BUILTIN_NAME,
+            HASH_BUILTIN_NAME,
+            RANGE_CHECK_BUILTIN_NAME,
+            SIGNATURE_BUILTIN_NAME,
+            BITWISE_BUILTIN_NAME,
+            EC_OP_BUILTIN_NAME,
+            KECCAK_BUILTIN_NAME,
+            POSEIDON_BUILTIN_NAME
+        ];
+        let cairo_runner = cairo_runner!(program, "starknet_with_keccak");
+        let mut vm = vm!();
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        let builtin_names: Vec<&str> = vm.builtin_runners.iter().map(|(name, _)| *name).collect();
+        assert_eq!(builtin_names, program.builtins);
+    }
+
+    #[test]
+    fn initialize_builtins_recursive_layout_missing_builtin() {
+        let program = program![OUTPUT_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME];
+        let cairo_runner = cairo_runner!(program, "recursive");
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::NoBuiltinForInstance(
+                HashSet::from([SIGNATURE_BUILTIN_NAME]),
+                "recursive".to_string()
+            ))
+        );
     }
 
     #[test]
@@ -4538,6 +5632,77 @@ mod tests {
             .contains_key(RANGE_CHECK_BUILTIN_NAME));
     }
 
+    // fn main(range_check_ptr, gas) -> (range_check_ptr, gas - 10):
+    // [ap] = [fp - 4]; ap++
+    // [ap] = [fp - 3] + (-10); ap++
+    // ret
+    fn gas_program() -> Program {
+        program!(
+            builtins = vec![RANGE_CHECK_BUILTIN_NAME],
+            data = vec![
+                MaybeRelocatable::from(Felt::new(0x480a7ffc7fff8000_i64)),
+                MaybeRelocatable::from(Felt::new(0x482680017ffd8000_i64)),
+                MaybeRelocatable::from(Felt::new(-10)),
+                MaybeRelocatable::from(Felt::new(0x208b7fff7fff7ffe_i64)),
+            ],
+            main = Some(0),
+        )
+    }
+
+    #[test]
+    fn run_from_entrypoint_with_gas() {
+        let program = gas_program();
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.set_gas_config(GasConfig::new(1000, 2));
+        let mut vm = vm!();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        cairo_runner.initialize_segments(&mut vm, None);
+        assert_matches!(
+            cairo_runner.run_from_entrypoint(
+                0,
+                &[&MaybeRelocatable::from((2, 0)).into()],
+                true,
+                &mut vm,
+                &mut hint_processor,
+            ),
+            Ok(())
+        );
+        let gas_usage = cairo_runner.get_gas_usage(&vm).unwrap();
+        assert_eq!(gas_usage.remaining_gas, 990);
+        assert_eq!(gas_usage.gas_used(), 10);
+        assert_eq!(gas_usage.steps_gas, 3 * STEP_GAS_COST);
+        assert_eq!(
+            gas_usage.builtins_gas,
+            HashMap::from([(RANGE_CHECK_BUILTIN_NAME, 0)])
+        );
+    }
+
+    #[test]
+    fn run_from_entrypoint_with_gas_missing_builtin_args() {
+        let program = gas_program();
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.set_gas_config(GasConfig::new(1000, 2));
+        let mut vm = vm!();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        cairo_runner.initialize_segments(&mut vm, None);
+        assert_matches!(
+            cairo_runner.run_from_entrypoint(0, &[], true, &mut vm, &mut hint_processor),
+            Err(CairoRunError::Runner(RunnerError::MissingGasArgument(0, 1)))
+        );
+    }
+
+    #[test]
+    fn get_gas_usage_without_gas_config() {
+        let program = gas_program();
+        let cairo_runner = cairo_runner!(program);
+        assert_eq!(
+            cairo_runner.get_gas_usage(&vm!()),
+            Err(RunnerError::MissingGasConfig)
+        );
+    }
+
     #[test]
     fn run_from_entrypoint_substitute_error_message_test() {
         let program = Program::from_file(
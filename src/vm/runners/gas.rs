@@ -0,0 +1,206 @@
+use crate::vm::{
+    errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+    runners::builtin_runner::{
+        BITWISE_BUILTIN_NAME, EC_OP_BUILTIN_NAME, HASH_BUILTIN_NAME, POSEIDON_BUILTIN_NAME,
+        RANGE_CHECK_BUILTIN_NAME,
+    },
+    vm_core::VirtualMachine,
+};
+use num_traits::ToPrimitive;
+use std::collections::HashMap;
+
+/// Gas cost of a single step.
+pub const STEP_GAS_COST: u64 = 100;
+/// Gas cost of a range check instance.
+pub const RANGE_CHECK_GAS_COST: u64 = 70;
+/// Gas cost of a pedersen instance.
+pub const PEDERSEN_GAS_COST: u64 = 4050;
+/// Gas cost of a bitwise instance.
+pub const BITWISE_GAS_COST: u64 = 583;
+/// Gas cost of an ec_op instance.
+pub const EC_OP_GAS_COST: u64 = 4085;
+/// Gas cost of a poseidon instance.
+pub const POSEIDON_GAS_COST: u64 = 491;
+
+/// Gas costs of the steps and builtin instances of a Cairo 1 run. Builtins missing from the
+/// table are free.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BuiltinCostTable {
+    pub step: u64,
+    pub builtins: HashMap<&'static str, u64>,
+}
+
+impl Default for BuiltinCostTable {
+    fn default() -> Self {
+        BuiltinCostTable {
+            step: STEP_GAS_COST,
+            builtins: HashMap::from([
+                (RANGE_CHECK_BUILTIN_NAME, RANGE_CHECK_GAS_COST),
+                (HASH_BUILTIN_NAME, PEDERSEN_GAS_COST),
+                (BITWISE_BUILTIN_NAME, BITWISE_GAS_COST),
+                (EC_OP_BUILTIN_NAME, EC_OP_GAS_COST),
+                (POSEIDON_BUILTIN_NAME, POSEIDON_GAS_COST),
+            ]),
+        }
+    }
+}
+
+impl BuiltinCostTable {
+    /// Returns the gas charged for each builtin of the run, as its number of used instances
+    /// times its cost. The builtin segments' used sizes must have been computed.
+    pub fn get_builtins_gas(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<HashMap<&'static str, u64>, MemoryError> {
+        let mut builtins_gas = HashMap::new();
+        for (name, builtin) in vm.get_builtin_runners() {
+            if let Some(cost) = self.builtins.get(name) {
+                let used_instances = builtin.get_used_instances(&vm.segments)? as u64;
+                builtins_gas.insert(*name, used_instances * cost);
+            }
+        }
+        Ok(builtins_gas)
+    }
+}
+
+/// Gas counter passed to a Cairo 1 entrypoint by `CairoRunner::run_from_entrypoint`.
+///
+/// Cairo 1 functions take the gas counter right after the pointers of their builtins, and
+/// return the remaining gas at the same position, right after the final builtin pointers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasConfig {
+    /// Gas available to the entrypoint.
+    pub initial_gas: u64,
+    /// Number of values returned by the entrypoint, used to locate the remaining gas.
+    pub n_return_values: usize,
+    pub cost_table: BuiltinCostTable,
+}
+
+impl GasConfig {
+    pub fn new(initial_gas: u64, n_return_values: usize) -> Self {
+        GasConfig {
+            initial_gas,
+            n_return_values,
+            cost_table: BuiltinCostTable::default(),
+        }
+    }
+}
+
+/// Gas used by a Cairo 1 entrypoint run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasUsage {
+    pub initial_gas: u64,
+    /// Gas left, as returned by the entrypoint.
+    pub remaining_gas: u64,
+    /// Gas charged for the steps of the run, according to the cost table.
+    pub steps_gas: u64,
+    /// Gas charged for each builtin, according to the cost table.
+    pub builtins_gas: HashMap<&'static str, u64>,
+}
+
+impl GasUsage {
+    /// Gas consumed by the entrypoint, according to its own accounting.
+    pub fn gas_used(&self) -> u64 {
+        self.initial_gas - self.remaining_gas
+    }
+
+    /// Gas of the run according to the cost table: its steps plus its builtin instances.
+    pub fn total_cost(&self) -> u64 {
+        self.steps_gas + self.builtins_gas.values().sum::<u64>()
+    }
+}
+
+pub(crate) fn get_gas_usage(
+    gas_config: &GasConfig,
+    n_builtins: usize,
+    vm: &VirtualMachine,
+) -> Result<GasUsage, RunnerError> {
+    let return_values = vm.get_return_values(gas_config.n_return_values)?;
+    let remaining_gas = return_values
+        .get(n_builtins)
+        .ok_or(RunnerError::MissingRemainingGas(
+            gas_config.n_return_values,
+            n_builtins,
+        ))?;
+    let remaining_gas = remaining_gas
+        .get_int_ref()
+        .and_then(|gas| gas.to_u64())
+        .filter(|gas| *gas <= gas_config.initial_gas)
+        .ok_or_else(|| RunnerError::InvalidRemainingGas(remaining_gas.clone()))?;
+    Ok(GasUsage {
+        initial_gas: gas_config.initial_gas,
+        remaining_gas,
+        steps_gas: vm.current_step as u64 * gas_config.cost_table.step,
+        builtins_gas: gas_config.cost_table.get_builtins_gas(vm)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::relocatable::MaybeRelocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::runners::builtin_runner::{BitwiseBuiltinRunner, OutputBuiltinRunner};
+    use crate::vm::vm_memory::{memory::Memory, memory_segments::MemorySegmentManager};
+    use crate::{
+        types::instance_definitions::bitwise_instance_def::BitwiseInstanceDef,
+        vm::errors::memory_errors::MemoryError,
+    };
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn builtins_gas() {
+        let mut vm = vm!();
+        vm.builtin_runners = vec![
+            (
+                BITWISE_BUILTIN_NAME,
+                BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true).into(),
+            ),
+            ("output", OutputBuiltinRunner::new(true).into()),
+        ];
+        vm.segments.segment_used_sizes = Some(vec![10]);
+        assert_eq!(
+            BuiltinCostTable::default().get_builtins_gas(&vm),
+            Ok(HashMap::from([(
+                BITWISE_BUILTIN_NAME,
+                2 * BITWISE_GAS_COST
+            )]))
+        );
+    }
+
+    #[test]
+    fn gas_usage() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0)), ((1, 1), 30), ((1, 2), 7)];
+        vm.set_ap(3);
+        vm.current_step = 4;
+        let gas_usage = get_gas_usage(&GasConfig::new(100, 3), 1, &vm).unwrap();
+        assert_eq!(gas_usage.remaining_gas, 30);
+        assert_eq!(gas_usage.gas_used(), 70);
+        assert_eq!(gas_usage.steps_gas, 4 * STEP_GAS_COST);
+        assert_eq!(gas_usage.total_cost(), 4 * STEP_GAS_COST);
+    }
+
+    #[test]
+    fn gas_usage_invalid_remaining_gas() {
+        let mut vm = vm!();
+        vm.segments = segments![((1, 0), (2, 0)), ((1, 1), 300)];
+        vm.set_ap(2);
+        assert_eq!(
+            get_gas_usage(&GasConfig::new(100, 2), 1, &vm),
+            Err(RunnerError::InvalidRemainingGas(MaybeRelocatable::from(
+                300
+            )))
+        );
+        assert_eq!(
+            get_gas_usage(&GasConfig::new(100, 2), 0, &vm),
+            Err(RunnerError::InvalidRemainingGas(MaybeRelocatable::from((
+                2, 0
+            ))))
+        );
+        assert_matches!(
+            get_gas_usage(&GasConfig::new(100, 2), 2, &vm),
+            Err(RunnerError::MissingRemainingGas(2, 2))
+        );
+    }
+}
@@ -1,2 +1,4 @@
 pub mod builtin_runner;
+pub mod cairo_pie;
 pub mod cairo_runner;
+pub mod gas;
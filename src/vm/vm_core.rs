@@ -6,6 +6,7 @@ use crate::{
         instruction::{
             is_call_instruction, ApUpdate, FpUpdate, Instruction, Opcode, PcUpdate, Res,
         },
+        program::Program,
         relocatable::{MaybeRelocatable, Relocatable},
     },
     vm::{
@@ -15,16 +16,23 @@ use crate::{
             exec_scope_errors::ExecScopeError, memory_errors::MemoryError,
             vm_errors::VirtualMachineError,
         },
-        runners::builtin_runner::{BuiltinRunner, RangeCheckBuiltinRunner, SignatureBuiltinRunner},
+        runners::{
+            builtin_runner::{
+                BuiltinRunner, OutputBuiltinRunner, RangeCheckBuiltinRunner, SignatureBuiltinRunner,
+            },
+            cairo_runner::{get_hint_data_dictionary, get_reference_list},
+        },
         trace::trace_entry::TraceEntry,
         vm_memory::memory_segments::MemorySegmentManager,
     },
 };
 use felt::Felt;
 use num_traits::{ToPrimitive, Zero};
-use std::{any::Any, borrow::Cow, collections::HashMap};
+use std::{any::Any, borrow::Cow, collections::HashMap, mem, rc::Rc};
 
-use super::runners::builtin_runner::{RANGE_CHECK_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME};
+use super::runners::builtin_runner::{
+    OUTPUT_BUILTIN_NAME, RANGE_CHECK_BUILTIN_NAME, SIGNATURE_BUILTIN_NAME,
+};
 
 const MAX_TRACEBACK_ENTRIES: u32 = 20;
 
@@ -68,6 +76,13 @@ impl DeducedOperands {
     }
 }
 
+// Hints of a program loaded by a hint into a segment other than the program segment, like the
+// tasks of the bootloader, by their address in that segment
+struct LoadedProgramHints {
+    hint_data_dictionary: HashMap<usize, Vec<Box<dyn Any>>>,
+    constants: HashMap<String, Felt>,
+}
+
 #[derive(Clone, Debug)]
 pub struct HintData {
     pub hint_code: String,
@@ -86,6 +101,9 @@ pub struct VirtualMachine {
     pub(crate) current_step: usize,
     skip_instruction_execution: bool,
     run_finished: bool,
+    // Programs loaded by hints, whose hints are compiled at the start of the next step
+    pub(crate) programs_to_load: Vec<(Program, Relocatable)>,
+    loaded_program_hints: HashMap<isize, Rc<LoadedProgramHints>>,
     #[cfg(feature = "hooks")]
     pub(crate) hooks: crate::vm::hooks::Hooks,
 }
@@ -130,6 +148,8 @@ impl VirtualMachine {
             skip_instruction_execution: false,
             segments: MemorySegmentManager::new(),
             run_finished: false,
+            programs_to_load: Vec::new(),
+            loaded_program_hints: HashMap::new(),
             #[cfg(feature = "hooks")]
             hooks: Default::default(),
         }
@@ -438,7 +458,7 @@ impl VirtualMachine {
         Ok(())
     }
 
-    fn decode_current_instruction(&self) -> Result<Instruction, VirtualMachineError> {
+    pub(crate) fn decode_current_instruction(&self) -> Result<Instruction, VirtualMachineError> {
         let (instruction_ref, imm) = self.get_instruction_encoding()?;
         match instruction_ref.to_i64() {
             Some(instruction) => {
@@ -460,16 +480,73 @@ impl VirtualMachine {
         hint_data_dictionary: &HashMap<usize, Vec<Box<dyn Any>>>,
         constants: &HashMap<String, Felt>,
     ) -> Result<(), VirtualMachineError> {
-        if let Some(hint_list) = hint_data_dictionary.get(&self.run_context.pc.offset) {
-            for (hint_index, hint_data) in hint_list.iter().enumerate() {
-                hint_executor
-                    .execute_hint(self, exec_scopes, hint_data, constants)
-                    .map_err(|err| VirtualMachineError::Hint(hint_index, Box::new(err)))?
+        self.compile_loaded_program_hints(hint_executor)?;
+        let pc = self.run_context.pc;
+        match self.loaded_program_hints.get(&pc.segment_index).cloned() {
+            Some(loaded_hints) => {
+                if let Some(hint_list) = loaded_hints.hint_data_dictionary.get(&pc.offset) {
+                    self.execute_hints(
+                        hint_executor,
+                        exec_scopes,
+                        hint_list,
+                        &loaded_hints.constants,
+                    )?;
+                }
+            }
+            None => {
+                if let Some(hint_list) = hint_data_dictionary.get(&pc.offset) {
+                    self.execute_hints(hint_executor, exec_scopes, hint_list, constants)?;
+                }
             }
         }
         Ok(())
     }
 
+    fn execute_hints(
+        &mut self,
+        hint_executor: &mut dyn HintProcessor,
+        exec_scopes: &mut ExecutionScopes,
+        hint_list: &[Box<dyn Any>],
+        constants: &HashMap<String, Felt>,
+    ) -> Result<(), VirtualMachineError> {
+        for (hint_index, hint_data) in hint_list.iter().enumerate() {
+            hint_executor
+                .execute_hint(self, exec_scopes, hint_data, constants)
+                .map_err(|err| VirtualMachineError::Hint(hint_index, Box::new(err)))?
+        }
+        Ok(())
+    }
+
+    /// Runs the hints of `program` along with its code, which the caller loaded at
+    /// `program_base`, like cairo-lang's `vm_load_program`. The hints are compiled by the hint
+    /// processor of the next step, and replace those of any program loaded into the same segment
+    /// before.
+    pub fn load_program_hints(&mut self, program: Program, program_base: Relocatable) {
+        self.programs_to_load.push((program, program_base));
+    }
+
+    fn compile_loaded_program_hints(
+        &mut self,
+        hint_executor: &mut dyn HintProcessor,
+    ) -> Result<(), VirtualMachineError> {
+        for (program, program_base) in mem::take(&mut self.programs_to_load) {
+            let references = get_reference_list(&program);
+            let hint_data_dictionary =
+                get_hint_data_dictionary(&program, &references, hint_executor)?
+                    .into_iter()
+                    .map(|(pc, hint_list)| (program_base.offset + pc, hint_list))
+                    .collect();
+            self.loaded_program_hints.insert(
+                program_base.segment_index,
+                Rc::new(LoadedProgramHints {
+                    hint_data_dictionary,
+                    constants: program.constants,
+                }),
+            );
+        }
+        Ok(())
+    }
+
     pub fn step_instruction(&mut self) -> Result<(), VirtualMachineError> {
         let instruction = self.decode_current_instruction()?;
         if !self.skip_instruction_execution {
@@ -904,6 +981,19 @@ impl VirtualMachine {
 
         Err(VirtualMachineError::NoSignatureBuiltin)
     }
+
+    pub fn get_output_builtin(&mut self) -> Result<&mut OutputBuiltinRunner, VirtualMachineError> {
+        for (name, builtin) in self.get_builtin_runners_as_mut() {
+            if name == &OUTPUT_BUILTIN_NAME {
+                if let BuiltinRunner::Output(output_builtin) = builtin {
+                    return Ok(output_builtin);
+                };
+            }
+        }
+
+        Err(VirtualMachineError::NoOutputBuiltin)
+    }
+
     pub fn disable_trace(&mut self) {
         self.trace = None
     }
@@ -1074,6 +1164,8 @@ impl VirtualMachineBuilder {
             skip_instruction_execution: self.skip_instruction_execution,
             segments: self.segments,
             run_finished: self.run_finished,
+            programs_to_load: Vec::new(),
+            loaded_program_hints: HashMap::new(),
             #[cfg(feature = "hooks")]
             hooks: self.hooks,
         }
@@ -1083,6 +1175,7 @@ impl VirtualMachineBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::serde::deserialize_program::{FlowTrackingData, HintParams};
     use crate::vm::runners::builtin_runner::{
         BITWISE_BUILTIN_NAME, EC_OP_BUILTIN_NAME, HASH_BUILTIN_NAME,
     };
@@ -3712,6 +3805,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn step_hint_of_loaded_program() {
+        let mut vm = vm!();
+        add_segments!(vm, 3);
+        vm.run_context.pc = Relocatable::from((2, 3));
+        let hint_data_dictionary = HashMap::from([(
+            3_usize,
+            vec![any_box!(HintProcessorData::new_default(
+                "unknown hint".to_string(),
+                HashMap::new(),
+            ))],
+        )]);
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        assert_matches!(
+            vm.step_hint(
+                &mut hint_processor,
+                exec_scopes_ref!(),
+                &hint_data_dictionary,
+                &HashMap::new()
+            ),
+            Err(VirtualMachineError::Hint(0, _))
+        );
+
+        // The hints of a program loaded into the segment replace the ones of the program run
+        let mut program = program!();
+        program.hints = HashMap::from([(
+            1,
+            vec![HintParams {
+                code: "memory[ap] = 5".to_string(),
+                accessible_scopes: Vec::new(),
+                flow_tracking_data: FlowTrackingData {
+                    ap_tracking: ApTracking::new(),
+                    reference_ids: HashMap::new(),
+                },
+            }],
+        )]);
+        vm.load_program_hints(program, Relocatable::from((2, 2)));
+        assert_matches!(
+            vm.step_hint(
+                &mut hint_processor,
+                exec_scopes_ref!(),
+                &hint_data_dictionary,
+                &HashMap::new()
+            ),
+            Ok(())
+        );
+        check_memory![vm.segments.memory, ((1, 0), 5)];
+    }
+
     #[test]
     fn test_get_builtin_runners() {
         let mut vm = vm!();
@@ -251,6 +251,28 @@ impl MemorySegmentManager {
                 .insert(segment_index, public_memory.clone());
         }
     }
+
+    /// Returns the relocated public memory as a list of (address, page_id) pairs.
+    /// `segment_offsets` is the relocation table returned by `relocate_segments`.
+    pub fn get_public_memory_addresses(
+        &self,
+        segment_offsets: &[usize],
+    ) -> Result<Vec<(usize, usize)>, MemoryError> {
+        let mut addresses = Vec::new();
+        for segment_index in 0..self.num_segments() {
+            let offsets = match self.public_memory_offsets.get(&segment_index) {
+                Some(offsets) => offsets,
+                None => continue,
+            };
+            let segment_start = segment_offsets
+                .get(segment_index)
+                .ok_or(MemoryError::MalformedPublicMemory(segment_index))?;
+            for (offset, page_id) in offsets {
+                addresses.push((segment_start + offset, *page_id));
+            }
+        }
+        Ok(addresses)
+    }
 }
 
 impl Default for MemorySegmentManager {
@@ -897,4 +919,30 @@ mod tests {
             Ok(x) if x == mayberelocatable!(2, 0)
         );
     }
+
+    #[test]
+    fn get_public_memory_addresses() {
+        let mut segments = MemorySegmentManager::new();
+        segments.add();
+        segments.add();
+        segments.add();
+        segments.finalize(None, 0, Some(&vec![(0, 0), (1, 0)]));
+        segments.finalize(None, 2, Some(&vec![(2, 1)]));
+        assert_eq!(
+            segments.get_public_memory_addresses(&[1, 4, 9]),
+            Ok(vec![(1, 0), (2, 0), (11, 1)])
+        );
+    }
+
+    #[test]
+    fn get_public_memory_addresses_missing_segment_offset() {
+        let mut segments = MemorySegmentManager::new();
+        segments.add();
+        segments.add();
+        segments.finalize(None, 1, Some(&vec![(0, 0)]));
+        assert_eq!(
+            segments.get_public_memory_addresses(&[1]),
+            Err(MemoryError::MalformedPublicMemory(1))
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use cairo_vm::cairo_run;
 use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+use felt::Felt;
 use std::path::Path;
 
 #[test]
@@ -1130,6 +1131,171 @@ fn cairo_run_uint256_integration() {
     .expect("Couldn't run program");
 }
 
+#[test]
+fn cairo_run_uint256_mul_div_mod() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/uint256_mul_div_mod.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_uint256_sub() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/uint256_sub.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_uint256_expanded_unsigned_div_rem() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/uint256_expanded_unsigned_div_rem.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_uint256_sqrt_felt() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/uint256_sqrt_felt.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_split_128() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/split_128.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_ec_recover() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/ec_recover.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_secp256r1_slope() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/secp256r1_slope.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_uint384() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/uint384.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_uint384_extension() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/uint384_extension.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_math_hints() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/math_hints.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_poseidon_hash_many() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all_cairo",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/poseidon_hash_many.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
 #[test]
 fn cairo_run_set_integration() {
     let mut hint_executor = BuiltinHintProcessor::new_empty();
@@ -1317,3 +1483,79 @@ fn cairo_run_verify_signature_hint() {
     )
     .expect("Couldn't run program");
 }
+
+#[test]
+fn cairo_run_poseidon_builtin() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "all_cairo",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/poseidon_builtin.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_simple_bootloader() {
+    let task_path = Path::new("cairo_programs/bootloader/task_with_hint.json");
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+
+    // The task is also given as the CairoPie of a run of its own
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "small",
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    let (cairo_runner, vm) = cairo_run::cairo_run(task_path, &cairo_run_config, &mut hint_executor)
+        .expect("Couldn't run program");
+    let cairo_pie_path = std::env::temp_dir().join(format!(
+        "cairo_run_simple_bootloader_task_{}.zip",
+        std::process::id()
+    ));
+    cairo_runner
+        .get_cairo_pie(&vm)
+        .expect("Couldn't get the CairoPie")
+        .write_zip_file(&cairo_pie_path)
+        .expect("Couldn't write the CairoPie");
+
+    let task_program: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(task_path).expect("Couldn't read the task"))
+            .expect("Couldn't parse the task");
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: "starknet_with_keccak",
+        program_input: Some(serde_json::json!({
+            "tasks": [
+                {"type": "RunProgramTask", "program": task_program},
+                {"type": "CairoPiePath", "path": cairo_pie_path},
+                // A CairoPie in the format cairo-lang writes, of a program that outputs 7 too
+                {
+                    "type": "CairoPiePath",
+                    "path": "cairo_programs/manually_compiled/output_constant_cairo_pie.zip"
+                },
+            ],
+            "single_page": true,
+        })),
+        ..cairo_vm::cairo_run::CairoRunConfig::default()
+    };
+    let result = cairo_run::cairo_run(
+        Path::new("cairo_programs/bootloader/simple_bootloader.json"),
+        &cairo_run_config,
+        &mut hint_executor,
+    );
+    std::fs::remove_file(&cairo_pie_path).expect("Couldn't remove the CairoPie");
+    let (cairo_runner, vm) = result.expect("Couldn't run program");
+
+    // The number of tasks, followed by the output size, program hash and output of each task
+    let output = cairo_runner.get_output_values(&vm).unwrap();
+    assert_eq!(output.len(), 10);
+    assert_eq!(output[0], Felt::new(3));
+    assert_eq!(output[1..4], output[4..7]);
+    assert_eq!(output[1], Felt::new(3));
+    assert_eq!(output[3], Felt::new(7));
+    assert_eq!(output[7], Felt::new(3));
+    assert_ne!(output[8], output[2]);
+    assert_eq!(output[9], Felt::new(7));
+}